@@ -23,6 +23,7 @@ use criterion::{
 use handwritten::{
     Generated,
     Handwritten,
+    Unaligned,
 };
 use utils::repeat;
 
@@ -44,7 +45,8 @@ criterion_group!(
     bench_set_e,
     bench_set_f,
 );
-criterion_main!(bench_get, bench_set);
+criterion_group!(bench_set_aligned, bench_set_aligned_f);
+criterion_main!(bench_get, bench_set, bench_set_aligned);
 
 macro_rules! generate_cmp_benchmark_for {
     (
@@ -130,3 +132,33 @@ generate_cmp_benchmark_for!(
         fn set_f("generated_vs_handwritten/set_f");
     }
 );
+
+/// Compares the setter of the byte-aligned `Generated::f` field that copies the bytes
+/// of the new value directly with `Handwritten::set_f` and with the generic write path.
+fn bench_set_aligned_f(c: &mut Criterion) {
+    let mut g = c.benchmark_group("generated_vs_handwritten/set_aligned_f");
+    g.bench_function("generated", |b| {
+        let mut input = Generated::new();
+        b.iter(|| {
+            repeat(|| {
+                black_box(&mut input).set_f(black_box(0xDEAD_BEEF));
+            })
+        });
+    });
+    g.bench_function("handwritten", |b| {
+        let mut input = Handwritten::new();
+        b.iter(|| {
+            repeat(|| {
+                black_box(&mut input).set_f(black_box(0xDEAD_BEEF));
+            })
+        });
+    });
+    g.bench_function("unaligned", |b| {
+        let mut input = Unaligned::new();
+        b.iter(|| {
+            repeat(|| {
+                black_box(&mut input).set_f(black_box(0xDEAD_BEEF));
+            })
+        });
+    });
+}
@@ -40,6 +40,16 @@ pub struct Generated {
     pub f: B32,
 }
 
+/// Has the same `B32` field as `Generated::f` but shifted by 4 bits.
+///
+/// Its setter therefore cannot copy the bytes of the new value directly.
+#[bitfield]
+pub struct Unaligned {
+    pub head: B4,
+    pub f: B32,
+    pub tail: B28,
+}
+
 /// This is the hand-written part that the macro generated getters
 /// and setters are compared against.
 ///
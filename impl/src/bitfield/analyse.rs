@@ -1,17 +1,33 @@
 use super::{
     config::{
+        Computed,
         Config,
+        ConfigValue,
+        InterceptableDerive,
+        ReprEndian,
         ReprKind,
     },
     field_config::{
+        ConstValue,
         FieldConfig,
+        PayloadOf,
+        Quantity,
         SkipWhich,
+        Validate,
     },
+    field_info::FieldInfo,
     BitfieldStruct,
 };
 use crate::errors::CombineError;
-use core::convert::TryFrom;
-use quote::quote;
+use core::{
+    convert::TryFrom,
+    ops::Range,
+};
+use proc_macro2::Span;
+use quote::{
+    format_ident,
+    quote,
+};
 use std::collections::HashMap;
 use syn::{
     self,
@@ -22,13 +38,29 @@ use syn::{
 impl TryFrom<(&mut Config, syn::ItemStruct)> for BitfieldStruct {
     type Error = syn::Error;
 
-    fn try_from((config, item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
+    fn try_from((config, mut item_struct): (&mut Config, syn::ItemStruct)) -> Result<Self> {
         Self::ensure_has_fields(&item_struct)?;
         Self::ensure_no_generics(&item_struct)?;
+        Self::extract_rest_field(&mut item_struct, config)?;
+        Self::sort_fields_by_bit_ranges(&mut item_struct, config)?;
+        config.field_defaults = Self::has_field_defaults(&item_struct);
         Self::extract_attributes(&item_struct.attrs, config)?;
-        Self::analyse_config_for_fields(&item_struct, config)?;
+        Self::analyse_config_for_fields(&mut item_struct, config)?;
+        Self::append_auto_padding(&mut item_struct, config)?;
+        Self::ensure_dirty_tracking_field_count(&item_struct, config)?;
+        Self::ensure_valid_split_at(&item_struct, config)?;
+        Self::ensure_valid_fields_struct(&item_struct, config)?;
+        Self::ensure_valid_payload_of(&item_struct, config)?;
+        Self::ensure_valid_try_from(&item_struct, config)?;
+        Self::ensure_valid_defaults(&item_struct, config)?;
+        Self::ensure_valid_offset_by(&item_struct, config)?;
+        Self::ensure_valid_const_values(&item_struct, config)?;
+        Self::ensure_valid_groups(&item_struct, config)?;
+        Self::ensure_valid_computed(&item_struct, config)?;
         config.ensure_no_conflicts()?;
-        Ok(Self { item_struct })
+        let bitfield = Self { item_struct };
+        bitfield.ensure_no_method_collisions(config)?;
+        Ok(bitfield)
     }
 }
 
@@ -44,6 +76,747 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns `true` if the given field is of the `Rest` pseudo-type.
+    fn is_rest_field(field: &syn::Field) -> bool {
+        match &field.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                matches!(
+                    type_path.path.segments.last(),
+                    Some(segment) if segment.ident == "Rest" && segment.arguments.is_empty()
+                )
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes the trailing field of the `Rest` pseudo-type from the input struct
+    /// and registers it in the config.
+    ///
+    /// # Errors
+    ///
+    /// - If a `Rest` field is not the last field or is unnamed.
+    /// - If the `Rest` field is the only field or has attributes other than documentation.
+    fn extract_rest_field(item_struct: &mut syn::ItemStruct, config: &mut Config) -> Result<()> {
+        let fields = match &mut item_struct.fields {
+            syn::Fields::Named(fields) => &mut fields.named,
+            syn::Fields::Unnamed(fields) => {
+                if let Some(field) = fields.unnamed.iter().find(|field| Self::is_rest_field(field)) {
+                    return Err(format_err_spanned!(
+                        field,
+                        "encountered unnamed `Rest` field in bitfield struct"
+                    ))
+                }
+                return Ok(())
+            }
+            syn::Fields::Unit => return Ok(()),
+        };
+        let last = fields.len() - 1;
+        if let Some(field) = fields
+            .iter()
+            .take(last)
+            .find(|field| Self::is_rest_field(field))
+        {
+            return Err(format_err_spanned!(
+                field,
+                "encountered `Rest` field that is not the last field of the bitfield struct"
+            ))
+        }
+        if !matches!(fields.last(), Some(field) if Self::is_rest_field(field)) {
+            return Ok(())
+        }
+        let field = fields.pop().expect("checked that the last field exists").into_value();
+        if fields.is_empty() {
+            return Err(format_err_spanned!(
+                field,
+                "encountered bitfield struct without fields besides its `Rest` field"
+            ))
+        }
+        if let Some(attr) = field.attrs.iter().find(|attr| !attr.path.is_ident("doc")) {
+            return Err(format_err_spanned!(
+                attr,
+                "encountered invalid attribute for `Rest` field, only documentation is allowed"
+            ))
+        }
+        let span = field.span();
+        config.rest = Some(ConfigValue::new(field, span));
+        Ok(())
+    }
+
+    /// Returns `true` if the attribute is of the form `#[bits(..)]`.
+    fn is_bit_range_attr(attr: &syn::Attribute) -> bool {
+        attr.path.is_ident("bits")
+            && matches!(
+                attr.tokens.clone().into_iter().next(),
+                Some(proc_macro2::TokenTree::Group(group))
+                    if group.delimiter() == proc_macro2::Delimiter::Parenthesis
+            )
+    }
+
+    /// Parses the bit range of a `#[bits(start..end)]` or `#[bits(start..=end)]` field attribute.
+    ///
+    /// Returns the bit range together with the span of the range.
+    ///
+    /// # Errors
+    ///
+    /// If the bit range is not a range of integer literals or is empty.
+    fn parse_bit_range_attr(attr: &syn::Attribute) -> Result<(Range<usize>, Span)> {
+        let range = attr.parse_args::<syn::ExprRange>()?;
+        let bound = |expr: &Option<Box<syn::Expr>>| -> Result<usize> {
+            match expr.as_deref() {
+                Some(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                })) => lit_int.base10_parse::<usize>(),
+                _ => {
+                    Err(format_err_spanned!(
+                        range,
+                        "encountered invalid bit range for #[bits(start..end)], expected integer bounds"
+                    ))
+                }
+            }
+        };
+        let start = bound(&range.from)?;
+        let end = match range.limits {
+            syn::RangeLimits::HalfOpen(_) => bound(&range.to)?,
+            syn::RangeLimits::Closed(_) => bound(&range.to)? + 1,
+        };
+        if start >= end {
+            return Err(format_err_spanned!(
+                range,
+                "encountered empty bit range for #[bits(start..end)]"
+            ))
+        }
+        Ok((start..end, range.span()))
+    }
+
+    /// Sorts the fields by their `#[bits(start..end)]` bit ranges and replaces the bit ranges
+    /// with the equivalent `#[bits = N]` attributes.
+    ///
+    /// This allows to declare the fields in a different order than they are stored.
+    /// Bits in between the bit ranges are covered by inserted `#[skip]` padding fields.
+    /// Does nothing if none of the fields has a bit range.
+    ///
+    /// # Errors
+    ///
+    /// - If only some of the fields have bit ranges or if the fields are unnamed.
+    /// - If the bit ranges overlap or, with `contiguous`, leave bits in between them uncovered.
+    fn sort_fields_by_bit_ranges(
+        item_struct: &mut syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        let mut any_range = None;
+        let mut fields = Vec::new();
+        for field in item_struct.fields.iter_mut() {
+            let mut range = None;
+            for attr in &mut field.attrs {
+                if !Self::is_bit_range_attr(attr) {
+                    continue
+                }
+                let (bits, span) = Self::parse_bit_range_attr(attr)?;
+                if let Some((_, previous)) = &range {
+                    return Err(format_err!(
+                        span,
+                        "encountered duplicate #[bits(start..end)] attribute for field"
+                    )
+                    .into_combine(format_err!(*previous, "duplicate #[bits(start..end)] here")))
+                }
+                let width = syn::LitInt::new(&(bits.end - bits.start).to_string(), span);
+                *attr = syn::parse_quote_spanned!(span=> #[bits = #width]);
+                any_range.get_or_insert(span);
+                range = Some((bits, span));
+            }
+            fields.push(range);
+        }
+        let any_range = match any_range {
+            Some(span) => span,
+            None => return Ok(()),
+        };
+        let named = match &mut item_struct.fields {
+            syn::Fields::Named(fields) => &mut fields.named,
+            _ => {
+                return Err(format_err!(
+                    any_range,
+                    "encountered #[bits(start..end)] attribute in bitfield struct with unnamed fields"
+                ))
+            }
+        };
+        let mut sorted = Vec::new();
+        for (field, range) in core::mem::take(named).into_iter().zip(fields) {
+            match range {
+                Some((range, span)) => sorted.push((range, span, field)),
+                None => {
+                    return Err(format_err_spanned!(
+                        field,
+                        "encountered field without #[bits(start..end)] attribute while other fields have one"
+                    )
+                    .into_combine(format_err!(any_range, "#[bits(start..end)] attribute here")))
+                }
+            }
+        }
+        sorted.sort_by_key(|(range, _, _)| range.start);
+        let mut covered = 0;
+        let mut previous: Option<(&syn::Field, Span)> = None;
+        let mut paddings = Vec::new();
+        for (range, span, field) in &sorted {
+            if let (true, Some(contiguous)) = (range.start > covered, &config.contiguous) {
+                let mut error = format_err!(
+                    *span,
+                    "encountered uncovered bits {}..{} before field `{}`",
+                    covered,
+                    range.start,
+                    Self::field_name(field),
+                );
+                if let Some((previous, previous_span)) = previous {
+                    error = error.into_combine(format_err!(
+                        previous_span,
+                        "previous field `{}` ends here",
+                        Self::field_name(previous),
+                    ))
+                }
+                return Err(error.into_combine(format_err!(
+                    contiguous.span,
+                    "`contiguous` parameter here"
+                )))
+            }
+            if let Some((previous, previous_span)) = previous {
+                if range.start < covered {
+                    return Err(format_err!(
+                        *span,
+                        "encountered bit range of field `{}` overlapping the bit range of field `{}`",
+                        Self::field_name(field),
+                        Self::field_name(previous),
+                    )
+                    .into_combine(format_err!(
+                        previous_span,
+                        "overlapping bit range here"
+                    )))
+                }
+            }
+            if range.start > covered {
+                paddings.extend(Self::padding_fields(covered..range.start, *span));
+            }
+            covered = range.end;
+            previous = Some((field, *span));
+        }
+        sorted.extend(paddings);
+        sorted.sort_by_key(|(range, _, _)| range.start);
+        named.extend(sorted.into_iter().map(|(_, _, field)| field));
+        Ok(())
+    }
+
+    /// Returns the skipped padding fields covering the given bits that are not covered by
+    /// any `#[bits(start..end)]` field.
+    ///
+    /// Every padding field spans at most 128 bits.
+    fn padding_fields(
+        bits: Range<usize>,
+        span: Span,
+    ) -> impl Iterator<Item = (Range<usize>, Span, syn::Field)> {
+        (bits.start..bits.end).step_by(128).map(move |start| {
+            let end = bits.end.min(start + 128);
+            let ident = format_ident!("__bf_padding_{}", start, span = span);
+            let ty = format_ident!("B{}", end - start, span = span);
+            let field: syn::FieldsNamed = syn::parse_quote_spanned!(span=> {
+                #[skip]
+                #ident: ::modular_bitfield::specifiers::#ty
+            });
+            let field = field.named.into_iter().next().expect("parsed a single field");
+            (start..end, span, field)
+        })
+    }
+
+    /// Appends skipped padding fields up to the `bits = N` parameter if `auto_pad` is set.
+    ///
+    /// Does nothing if the fields already span `N` bits or more in which case the bit width
+    /// is checked as usual.
+    ///
+    /// # Errors
+    ///
+    /// - If `auto_pad` is set without `bits = N`.
+    /// - If the bit width of a field is not known without type information.
+    fn append_auto_padding(item_struct: &mut syn::ItemStruct, config: &mut Config) -> Result<()> {
+        let auto_pad = match &config.auto_pad {
+            Some(auto_pad) => auto_pad.span,
+            None => return Ok(()),
+        };
+        let bits = match &config.bits {
+            Some(bits) => bits.value,
+            None => {
+                return Err(format_err!(
+                    auto_pad,
+                    "encountered `auto_pad` parameter without a `bits = N` parameter"
+                ))
+            }
+        };
+        let mut covered = 0;
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = config
+                .field_configs
+                .get(&index)
+                .map(|field_config| field_config.value.clone())
+                .unwrap_or_default();
+            match FieldInfo::new(index, field, field_config).static_bits() {
+                Some(field_bits) => covered += field_bits,
+                None => {
+                    return Err(format_err!(
+                        field.ty,
+                        "encountered field `{}` of unknown bit width in bitfield with `auto_pad` parameter, \
+                         consider annotating it with #[bits = N]",
+                        FieldInfo::ident_as_string(field, index),
+                    )
+                    .into_combine(format_err!(auto_pad, "`auto_pad` parameter here")))
+                }
+            }
+        }
+        if covered >= bits {
+            return Ok(())
+        }
+        for (_, span, mut field) in Self::padding_fields(covered..bits, auto_pad) {
+            let index = item_struct.fields.len();
+            config.field_config(index, span, Self::extract_field_config(&field)?)?;
+            match &mut item_struct.fields {
+                syn::Fields::Named(fields) => fields.named.push(field),
+                syn::Fields::Unnamed(fields) => {
+                    field.ident = None;
+                    field.colon_token = None;
+                    fields.unnamed.push(field);
+                }
+                syn::Fields::Unit => unreachable!("checked that the struct has fields"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the name of a named field.
+    fn field_name(field: &syn::Field) -> String {
+        field
+            .ident
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default()
+    }
+
+    /// Returns an error if `dirty_tracking` is set for a struct with more than 128 fields.
+    fn ensure_dirty_tracking_field_count(
+        item_struct: &syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        if let Some(dirty_tracking) = &config.dirty_tracking {
+            if item_struct.fields.len() > 128 {
+                return Err(format_err_spanned!(
+                    item_struct,
+                    "encountered bitfield struct with {} fields but `dirty_tracking` supports at most 128 fields",
+                    item_struct.fields.len(),
+                )
+                .into_combine(format_err!(
+                    dirty_tracking.span,
+                    "`dirty_tracking` parameter here"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the `split_at` parameter does not name a field other than the first.
+    fn ensure_valid_split_at(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if let Some(split_at) = &config.split_at {
+            let position = item_struct
+                .fields
+                .iter()
+                .position(|field| Self::field_name(field) == split_at.value);
+            match position {
+                None => {
+                    return Err(format_err!(
+                        split_at.span,
+                        "encountered `split_at` parameter naming unknown field `{}`",
+                        split_at.value,
+                    ))
+                }
+                Some(0) => {
+                    return Err(format_err!(
+                        split_at.span,
+                        "encountered `split_at` parameter naming the first field `{}`, \
+                         expected a field that is preceded by other fields",
+                        split_at.value,
+                    ))
+                }
+                Some(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the `fields_struct` parameter is used with a tuple struct.
+    ///
+    /// The fields of the generated struct are named after the fields of the bitfield.
+    fn ensure_valid_fields_struct(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if let Some(fields_struct) = &config.fields_struct {
+            if !matches!(item_struct.fields, syn::Fields::Named(_)) {
+                return Err(format_err!(
+                    fields_struct.span,
+                    "encountered `fields_struct` parameter for a struct without named fields"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the fields of a `#[group = "name"]` are invalid.
+    ///
+    /// The fields of a group must be named `bool` fields with getters and setters
+    /// that are declared next to each other.
+    fn ensure_valid_groups(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut previous: Option<&syn::Ident> = None;
+        let mut finished: Vec<&syn::Ident> = Vec::new();
+        for (index, field) in Self::fields(item_struct) {
+            let grouped = config.field_configs.get(&index).and_then(|field_config| {
+                let field_config = &field_config.value;
+                field_config.group.as_ref().map(|group| (field_config, group))
+            });
+            let (field_config, group) = match grouped {
+                Some(grouped) => grouped,
+                None => {
+                    finished.extend(previous.take());
+                    continue
+                }
+            };
+            if previous != Some(&group.value) {
+                finished.extend(previous.take());
+                if finished.contains(&&group.value) {
+                    return Err(format_err!(
+                        group.span,
+                        "encountered field `{}` of group `{}` that is not next to the other fields of the group",
+                        Self::field_name(field),
+                        group.value,
+                    ))
+                }
+                previous = Some(&group.value);
+            }
+            let info = FieldInfo::new(index, field, field_config.clone());
+            if field.ident.is_none() || !info.is_bool() {
+                return Err(format_err!(
+                    group.span,
+                    "encountered #[group = \"{}\"] on field `{}` that is not a named `bool` field",
+                    group.value,
+                    info.name(),
+                )
+                .into_combine(format_err!(field.ty, "field type here")))
+            }
+            if !field_config.has_scalar_getter() || !field_config.has_scalar_setter() {
+                return Err(format_err!(
+                    group.span,
+                    "encountered #[group = \"{}\"] on field `{}` without getters or setters",
+                    group.value,
+                    info.name(),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[computed(..)]` attribute is named after a field or refers to
+    /// a field that does not exist or has no getter.
+    fn ensure_valid_computed(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for computed in &config.computed {
+            let name = &computed.value.name;
+            if Self::fields(item_struct).any(|(_, field)| field.ident.as_ref() == Some(name)) {
+                return Err(format_err!(
+                    computed.span,
+                    "encountered #[computed(name = \"{}\", ..)] attribute that collides with the getter of field `{}`",
+                    name,
+                    name,
+                ))
+            }
+            for from in &computed.value.from {
+                let field = Self::fields(item_struct)
+                    .find(|(_, field)| field.ident.as_ref() == Some(from));
+                let (index, _) = match field {
+                    Some(field) => field,
+                    None => {
+                        return Err(format_err!(
+                            from,
+                            "encountered unknown field `{}` in #[computed(name = \"{}\", ..)] attribute",
+                            from,
+                            computed.value.name,
+                        ))
+                    }
+                };
+                let skips_getters = config
+                    .field_configs
+                    .get(&index)
+                    .is_some_and(|field_config| !field_config.value.has_scalar_getter());
+                if skips_getters {
+                    return Err(format_err!(
+                        from,
+                        "encountered field `{}` without getters in #[computed(name = \"{}\", ..)] attribute",
+                        from,
+                        computed.value.name,
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if an `#[offset_by = K]` field is invalid.
+    ///
+    /// The field must be of an unsigned integer type that is neither `#[repeat = N]` nor
+    /// `#[payload_of(..)]`, and its largest value plus `K` must fit into its `InOut` type.
+    fn ensure_valid_offset_by(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = match config.field_configs.get(&index) {
+                Some(field_config) => &field_config.value,
+                None => continue,
+            };
+            let offset_by = match &field_config.offset_by {
+                Some(offset_by) => offset_by,
+                None => continue,
+            };
+            let conflict = field_config
+                .repeat
+                .as_ref()
+                .map(|repeat| ("#[repeat = N]", repeat.span))
+                .or_else(|| {
+                    field_config
+                        .payload_of
+                        .as_ref()
+                        .map(|payload_of| ("#[payload_of(..)]", payload_of.span))
+                });
+            if let Some((conflict, span)) = conflict {
+                return Err(format_err!(
+                    offset_by.span,
+                    "encountered conflicting `#[offset_by = K]` and `{}` attributes for field",
+                    conflict,
+                )
+                .into_combine(format_err!(span, "conflicting `{}` here", conflict)))
+            }
+            let info = FieldInfo::new(index, field, field_config.clone());
+            let bits = match info.static_bits() {
+                Some(bits) if info.is_integer() => bits,
+                _ => {
+                    return Err(format_err!(
+                        offset_by.span,
+                        "encountered #[offset_by = K] on field `{}` that is not of an unsigned integer type",
+                        info.name(),
+                    )
+                    .into_combine(format_err!(field.ty, "field type here")))
+                }
+            };
+            let in_out_bits = bits.next_power_of_two().max(8);
+            let max_in_out = u128::MAX >> (128 - in_out_bits);
+            let max_raw = u128::MAX >> (128 - bits);
+            if max_in_out - max_raw < offset_by.value {
+                return Err(format_err!(
+                    offset_by.span,
+                    "encountered #[offset_by = {}] for field `{}` whose largest value does not fit into `u{}`",
+                    offset_by.value,
+                    info.name(),
+                    in_out_bits,
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[try_from = Type]` field is invalid.
+    ///
+    /// The field must be of an unsigned integer type and must not be annotated with any other
+    /// attribute that changes the values of its accessors.
+    fn ensure_valid_try_from(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = match config.field_configs.get(&index) {
+                Some(field_config) => &field_config.value,
+                None => continue,
+            };
+            let try_from = match &field_config.try_from {
+                Some(try_from) => try_from,
+                None => continue,
+            };
+            let conflict = field_config
+                .quantity
+                .as_ref()
+                .map(|quantity| ("#[quantity(..)]", quantity.span))
+                .or_else(|| {
+                    field_config
+                        .offset_by
+                        .as_ref()
+                        .map(|offset_by| ("#[offset_by = K]", offset_by.span))
+                })
+                .or_else(|| {
+                    field_config
+                        .payload_of
+                        .as_ref()
+                        .map(|payload_of| ("#[payload_of(..)]", payload_of.span))
+                })
+                .or_else(|| {
+                    field_config
+                        .const_values
+                        .as_ref()
+                        .map(|const_values| ("#[const_values(..)]", const_values.span))
+                });
+            if let Some((conflict, span)) = conflict {
+                return Err(format_err!(
+                    try_from.span,
+                    "encountered conflicting `#[try_from = Type]` and `{}` attributes for field",
+                    conflict,
+                )
+                .into_combine(format_err!(span, "conflicting `{}` here", conflict)))
+            }
+            if !FieldInfo::is_integer_type(&field.ty) {
+                return Err(format_err!(
+                    try_from.span,
+                    "encountered #[try_from = Type] on field `{}` that is not of an unsigned integer type",
+                    FieldInfo::ident_as_string(field, index),
+                )
+                .into_combine(format_err!(field.ty, "field type here")))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if any field of the struct has a `#[default = EXPR]` attribute.
+    ///
+    /// This is known before the `#[derive(..)]` attributes are extracted so that
+    /// `#[derive(Default)]` is intercepted for such structs unless passed through.
+    fn has_field_defaults(item_struct: &syn::ItemStruct) -> bool {
+        Self::fields(item_struct)
+            .any(|(_, field)| field.attrs.iter().any(|attr| attr.path.is_ident("default")))
+    }
+
+    /// Returns an error if a `#[default = EXPR]` field has no setters.
+    fn ensure_valid_defaults(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = match config.field_configs.get(&index) {
+                Some(field_config) => &field_config.value,
+                None => continue,
+            };
+            let default = match &field_config.default {
+                Some(default) => default,
+                None => continue,
+            };
+            if !field_config.has_scalar_setter() {
+                return Err(format_err!(
+                    default.span,
+                    "encountered #[default = EXPR] on field `{}` without setters",
+                    FieldInfo::ident_as_string(field, index),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the values of a `#[const_values(..)]` field are invalid.
+    ///
+    /// The field must be of an unsigned integer type and all values must fit into its bit width.
+    /// Values of `#[offset_by = K]` fields are offset by `K` as well.
+    fn ensure_valid_const_values(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = match config.field_configs.get(&index) {
+                Some(field_config) => &field_config.value,
+                None => continue,
+            };
+            let const_values = match &field_config.const_values {
+                Some(const_values) => const_values,
+                None => continue,
+            };
+            if let Some(quantity) = &field_config.quantity {
+                return Err(format_err!(
+                    const_values.span,
+                    "encountered conflicting `#[const_values(..)]` and `#[quantity(..)]` attributes for field",
+                )
+                .into_combine(format_err!(quantity.span, "conflicting `#[quantity(..)]` here")))
+            }
+            let info = FieldInfo::new(index, field, field_config.clone());
+            let bits = match info.static_bits() {
+                Some(bits) if info.is_integer() => bits,
+                _ => {
+                    return Err(format_err!(
+                        const_values.span,
+                        "encountered #[const_values(..)] on field `{}` that is not of an unsigned integer type",
+                        info.name(),
+                    )
+                    .into_combine(format_err!(field.ty, "field type here")))
+                }
+            };
+            let offset = field_config.offset();
+            for const_value in &const_values.value {
+                let value = const_value.value.base10_parse::<u128>()?;
+                let fits = match value.checked_sub(offset) {
+                    Some(raw) => bits >= 128 || raw >> bits == 0,
+                    None => false,
+                };
+                if !fits {
+                    return Err(format_err!(
+                        const_value.value,
+                        "encountered value {} of `{}` that does not fit into the {} bits of field `{}`",
+                        value,
+                        const_value.name,
+                        bits,
+                        info.name(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the tag field of a `#[payload_of(..)]` field is invalid.
+    ///
+    /// The tag field must be another field of the struct that is not annotated with
+    /// `#[repeat = N]` and whose type is a path to the enum of the mapped variants.
+    fn ensure_valid_payload_of(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let payload_of = match config
+                .field_configs
+                .get(&index)
+                .and_then(|field_config| field_config.value.payload_of.as_ref())
+            {
+                Some(payload_of) => &payload_of.value,
+                None => continue,
+            };
+            let tag = Self::fields(item_struct)
+                .find(|(_, tag)| tag.ident.is_some() && Self::field_name(tag) == payload_of.tag);
+            let (tag_index, tag) = match tag {
+                Some(tag) => tag,
+                None => {
+                    return Err(format_err!(
+                        payload_of.tag_span,
+                        "encountered #[payload_of(..)] naming unknown tag field `{}`",
+                        payload_of.tag,
+                    ))
+                }
+            };
+            if tag_index == index {
+                return Err(format_err!(
+                    payload_of.tag_span,
+                    "encountered #[payload_of(..)] naming its own field `{}` as tag field",
+                    payload_of.tag,
+                ))
+            }
+            if let Some(repeat) = config
+                .field_configs
+                .get(&tag_index)
+                .and_then(|field_config| field_config.value.repeat.as_ref())
+            {
+                return Err(format_err!(
+                    payload_of.tag_span,
+                    "encountered #[payload_of(..)] naming tag field `{}` that is annotated with #[repeat = N]",
+                    payload_of.tag,
+                )
+                .into_combine(format_err!(repeat.span, "#[repeat = N] here")))
+            }
+            if !matches!(&tag.ty, syn::Type::Path(path) if path.qself.is_none()) {
+                return Err(format_err!(
+                    tag.ty,
+                    "encountered tag field `{}` of #[payload_of(..)] that is not of an enum type",
+                    payload_of.tag,
+                )
+                .into_combine(format_err_spanned!(field, "#[payload_of(..)] field here")))
+            }
+        }
+        Ok(())
+    }
+
     /// Returns an error if the input struct is generic.
     fn ensure_no_generics(item_struct: &syn::ItemStruct) -> Result<()> {
         if !item_struct.generics.params.is_empty() {
@@ -106,7 +879,147 @@ impl BitfieldStruct {
         Ok(())
     }
 
-    /// Extracts the `#[derive(Debug)]` annotations from the given `#[bitfield]` struct.
+    /// Extracts the `#[repr_endian = "little"]` or `#[repr_endian = "big"]` annotation.
+    ///
+    /// # Errors
+    ///
+    /// If the attribute is malformed or names an unknown byte order.
+    fn extract_repr_endian_attribute(attr: &syn::Attribute, config: &mut Config) -> Result<()> {
+        let invalid = || {
+            format_err_spanned!(
+                attr,
+                "encountered invalid #[repr_endian = \"..\"] attribute, expected \"little\" or \"big\""
+            )
+        };
+        let value = match attr.parse_meta() {
+            Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) => lit_str.value(),
+            _ => return Err(invalid()),
+        };
+        let repr_endian = match value.as_str() {
+            "little" => ReprEndian::Little,
+            "big" => ReprEndian::Big,
+            _ => return Err(invalid()),
+        };
+        config.set_repr_endian(repr_endian, attr.span())
+    }
+
+    /// Extracts a `#[computed(name = "..", from = "..", expr = "..")]` attribute with an
+    /// optional `ty = ".."` argument.
+    ///
+    /// # Errors
+    ///
+    /// - If the attribute does not follow the above format.
+    /// - If `ty` is missing and cannot be inferred from `expr`.
+    fn extract_computed_attribute(attr: &syn::Attribute, config: &mut Config) -> Result<()> {
+        let invalid_format = |span: Span| {
+            format_err!(
+                span,
+                "encountered invalid format for #[computed(name = \"..\", from = \"..\", expr = \"..\")] attribute"
+            )
+        };
+        let meta_list = match attr.parse_meta() {
+            Ok(syn::Meta::List(meta_list)) => meta_list,
+            _ => return Err(invalid_format(attr.span())),
+        };
+        let mut name = None;
+        let mut span = attr.span();
+        let mut from = Vec::new();
+        let mut ty = None;
+        let mut expr = None;
+        for nested in &meta_list.nested {
+            let (key, lit_str) = match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                })) if path.get_ident().is_some() => {
+                    (path.get_ident().expect("checked that the path is an identifier"), lit_str)
+                }
+                _ => return Err(invalid_format(nested.span())),
+            };
+            if key == "name" {
+                name = Some(lit_str.parse::<syn::Ident>()?);
+                span = lit_str.span();
+            } else if key == "from" {
+                let parser =
+                    syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated;
+                from = lit_str.parse_with(parser)?.into_iter().collect();
+            } else if key == "ty" {
+                ty = Some(lit_str.parse::<syn::Type>()?);
+            } else if key == "expr" {
+                expr = Some(lit_str.parse::<syn::Expr>()?);
+            } else {
+                return Err(format_err!(
+                    key,
+                    "encountered unknown `{}` argument of #[computed(..)] attribute, expected one of `name`, `from`, `ty` or `expr`",
+                    key,
+                ))
+            }
+        }
+        let name = name.ok_or_else(|| {
+            format_err_spanned!(
+                attr,
+                "missing `name = \"..\"` argument of #[computed(..)] attribute"
+            )
+        })?;
+        let expr = expr.ok_or_else(|| {
+            format_err_spanned!(
+                attr,
+                "missing `expr = \"..\"` argument of #[computed(..)] attribute"
+            )
+        })?;
+        let ty = match ty.or_else(|| Self::infer_computed_type(&expr)) {
+            Some(ty) => ty,
+            None => {
+                return Err(format_err_spanned!(
+                    attr,
+                    "cannot infer the type of computed field `{}`, specify it with `ty = \"..\"`",
+                    name,
+                ))
+            }
+        };
+        config.computed(Computed { name, from, ty, expr }, span)
+    }
+
+    /// Returns the type of the `as T` cast of the leftmost operand of the expression if any.
+    ///
+    /// This is the type of the value of arithmetic expressions such as `a as u32 + b as u32`.
+    fn infer_computed_type(expr: &syn::Expr) -> Option<syn::Type> {
+        match expr {
+            syn::Expr::Cast(cast) => Some((*cast.ty).clone()),
+            syn::Expr::Paren(paren) => Self::infer_computed_type(&paren.expr),
+            syn::Expr::Unary(unary) if !matches!(unary.op, syn::UnOp::Deref(_)) => {
+                Self::infer_computed_type(&unary.expr)
+            }
+            syn::Expr::Binary(binary) => {
+                match binary.op {
+                    syn::BinOp::Add(_)
+                    | syn::BinOp::Sub(_)
+                    | syn::BinOp::Mul(_)
+                    | syn::BinOp::Div(_)
+                    | syn::BinOp::Rem(_)
+                    | syn::BinOp::BitAnd(_)
+                    | syn::BinOp::BitOr(_)
+                    | syn::BinOp::BitXor(_)
+                    | syn::BinOp::Shl(_)
+                    | syn::BinOp::Shr(_) => Self::infer_computed_type(&binary.left),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Extracts the `#[derive(Debug)]`, `#[derive(PartialOrd)]`, `#[derive(Ord)]` and
+    /// `#[derive(BitfieldSpecifier)]` annotations from the given `#[bitfield]` struct.
+    ///
+    /// With the `defmt` crate feature `#[derive(defmt::Format)]` is intercepted as well.
+    ///
+    /// Whether `Debug`, `Default`, `Hash`, `PartialOrd` and `Ord` are intercepted or passed
+    /// through depends on the `intercept(..)` and `passthrough(..)` parameters.
     fn extract_derive_debug_attribute(
         attr: &syn::Attribute,
         config: &mut Config,
@@ -119,11 +1032,38 @@ impl BitfieldStruct {
             let meta_span = nested_meta.span();
             match nested_meta {
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
-                    if path.is_ident("Debug") {
+                    let intercepts = |derive| config.intercepts(derive);
+                    if path.is_ident("Debug") && intercepts(InterceptableDerive::Debug) {
                         config.derive_debug(meta_span)?;
+                    } else if path.is_ident("PartialOrd")
+                        && intercepts(InterceptableDerive::PartialOrd)
+                    {
+                        config.derive_partial_ord(meta_span)?;
+                    } else if path.is_ident("Ord") && intercepts(InterceptableDerive::Ord) {
+                        config.derive_ord(meta_span)?;
+                    } else if path.is_ident("Default") && intercepts(InterceptableDerive::Default) {
+                        config.derive_default(meta_span)?;
+                    } else if cfg!(feature = "defmt") && Self::is_defmt_format(&path) {
+                        config.derive_defmt(meta_span)?;
                     } else if path.is_ident("BitfieldSpecifier") {
                         config.derive_specifier(meta_span)?;
+                    } else if path.is_ident("Hash") {
+                        // An intercepted `Hash` is implemented like for the `hash` parameter.
+                        let intercepted = intercepts(InterceptableDerive::Hash);
+                        config.derive_hash(meta_span)?;
+                        if !intercepted {
+                            retained_derives
+                                .push(syn::NestedMeta::Meta(syn::Meta::Path(path)));
+                        }
+                    } else if Self::is_copy(&path) {
+                        config.derive_copy(meta_span)?;
+                        // With `copy = false` the `Copy` derive is stripped.
+                        if config.copy_enabled() != Some(false) {
+                            retained_derives
+                                .push(syn::NestedMeta::Meta(syn::Meta::Path(path)));
+                        }
                     } else {
+                        Self::register_methods_of_derive(&path, config);
                         // Other derives are going to be re-expanded them into a new
                         // `#[derive(..)]` that is ignored by the rest of this macro.
                         retained_derives
@@ -150,6 +1090,21 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns `true` if the derive path names `Copy`, e.g. `Copy` or `core::marker::Copy`.
+    fn is_copy(path: &syn::Path) -> bool {
+        matches!(path.segments.last(), Some(segment) if segment.ident == "Copy")
+    }
+
+    /// Returns `true` if the derive path is `defmt::Format`.
+    fn is_defmt_format(path: &syn::Path) -> bool {
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>();
+        segments == ["defmt", "Format"]
+    }
+
     /// Analyses and extracts the `#[repr(uN)]` or other annotations from the given struct.
     fn extract_attributes(
         attributes: &[syn::Attribute],
@@ -158,8 +1113,12 @@ impl BitfieldStruct {
         for attr in attributes {
             if attr.path.is_ident("repr") {
                 Self::extract_repr_attribute(attr, config)?;
+            } else if attr.path.is_ident("repr_endian") {
+                Self::extract_repr_endian_attribute(attr, config)?;
             } else if attr.path.is_ident("derive") {
                 Self::extract_derive_debug_attribute(attr, config)?;
+            } else if attr.path.is_ident("computed") {
+                Self::extract_computed_attribute(attr, config)?;
             } else {
                 config.push_retained_attribute(attr.clone());
             }
@@ -169,18 +1128,301 @@ impl BitfieldStruct {
 
     /// Analyses and extracts the configuration for all bitfield fields.
     fn analyse_config_for_fields(
-        item_struct: &syn::ItemStruct,
+        item_struct: &mut syn::ItemStruct,
         config: &mut Config,
     ) -> Result<()> {
-        for (index, field) in Self::fields(item_struct) {
+        for (index, field) in item_struct.fields.iter_mut().enumerate() {
             let span = field.span();
-            let field_config = Self::extract_field_config(field)?;
+            let mut field_config = Self::extract_field_config(field)?;
+            if let Some(elem) = Self::extract_field_array(field, &mut field_config)? {
+                field.ty = elem;
+            }
+            if let Some(keep_phantom_fields) = &config.keep_phantom_fields {
+                if field.ident.is_none() {
+                    return Err(format_err!(
+                        span,
+                        "encountered unnamed field in bitfield struct with `keep_phantom_fields` parameter"
+                    )
+                    .into_combine(format_err!(
+                        keep_phantom_fields.span,
+                        "`keep_phantom_fields` parameter here"
+                    )))
+                }
+                field_config.retain_attrs_for_phantom_field();
+            }
             config.field_config(index, span, field_config)?;
         }
         Ok(())
     }
 
-    /// Extracts the `#[bits = N]` and `#[skip(..)]` attributes for a given field.
+    /// Registers the length of a field declared as a field array `[T; N]`.
+    ///
+    /// Returns the element type `T` that replaces the type of the field.
+    ///
+    /// # Errors
+    ///
+    /// - If the length of the field array is not an integer literal.
+    /// - If the field array conflicts with other attributes of the field.
+    fn extract_field_array(
+        field: &syn::Field,
+        field_config: &mut FieldConfig,
+    ) -> Result<Option<syn::Type>> {
+        let array = match &field.ty {
+            syn::Type::Array(array) => array,
+            _ => return Ok(None),
+        };
+        let len = match &array.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => lit_int.base10_parse::<usize>()?,
+            len => {
+                return Err(format_err_spanned!(
+                    len,
+                    "encountered field array with invalid length, expected an integer literal"
+                ))
+            }
+        };
+        field_config.array(len, array.span())?;
+        Ok(Some((*array.elem).clone()))
+    }
+
+    /// Parses the type of a `#[try_from = Type]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// If the attribute is not of the form `#[try_from = Type]`.
+    fn parse_try_from_attr(attr: &syn::Attribute) -> Result<syn::Type> {
+        let parser = |input: syn::parse::ParseStream| {
+            input.parse::<syn::Token![=]>()?;
+            input.parse::<syn::Type>()
+        };
+        syn::parse::Parser::parse2(parser, attr.tokens.clone()).map_err(|_| {
+            format_err_spanned!(
+                attr,
+                "encountered invalid format for #[try_from = Type] field attribute"
+            )
+        })
+    }
+
+    /// Parses the expression of a `#[default = EXPR]` attribute.
+    fn parse_default_attr(attr: &syn::Attribute) -> Result<syn::Expr> {
+        let parser = |input: syn::parse::ParseStream| {
+            input.parse::<syn::Token![=]>()?;
+            input.parse::<syn::Expr>()
+        };
+        syn::parse::Parser::parse2(parser, attr.tokens.clone()).map_err(|_| {
+            format_err_spanned!(
+                attr,
+                "encountered invalid format for #[default = EXPR] field attribute"
+            )
+        })
+    }
+
+    /// Parses the arguments of a `#[quantity(Kind, unit)]` or `#[quantity(Kind, unit, storage)]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// - If the `uom` crate feature has not been enabled.
+    /// - If the attribute does not consist of two or three identifiers.
+    /// - If the storage type is neither `f32` nor `f64`.
+    fn parse_quantity_attr(attr: &syn::Attribute) -> Result<Quantity> {
+        if cfg!(not(feature = "uom")) {
+            return Err(format_err_spanned!(
+                attr,
+                "the #[quantity(..)] field attribute requires the `uom` crate feature"
+            ))
+        }
+        let args = attr
+            .parse_args_with(
+                syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+            )
+            .map_err(|_| {
+                format_err_spanned!(
+                    attr,
+                    "encountered invalid format for #[quantity(Kind, unit)] field attribute"
+                )
+            })?;
+        let mut args = args.into_iter();
+        let (kind, unit) = match (args.next(), args.next()) {
+            (Some(kind), Some(unit)) => (kind, unit),
+            _ => {
+                return Err(format_err_spanned!(
+                    attr,
+                    "encountered invalid format for #[quantity(Kind, unit)] field attribute"
+                ))
+            }
+        };
+        let storage = args
+            .next()
+            .unwrap_or_else(|| syn::Ident::new("f32", attr.path.span()));
+        if storage != "f32" && storage != "f64" {
+            return Err(format_err!(
+                storage,
+                "encountered invalid quantity storage type `{}`, expected `f32` or `f64`",
+                storage
+            ))
+        }
+        if let Some(unexpected) = args.next() {
+            return Err(format_err!(
+                unexpected,
+                "encountered unexpected argument for #[quantity(Kind, unit)] field attribute"
+            ))
+        }
+        Ok(Quantity {
+            kind,
+            unit,
+            storage,
+        })
+    }
+
+    /// Parses the argument of a `#[validate(zero)]`, `#[validate(ones)]` or `#[validate(any)]` attribute.
+    fn parse_validate_attr(attr: &syn::Attribute) -> Result<Validate> {
+        let invalid_format = || {
+            format_err_spanned!(
+                attr,
+                "encountered invalid format for #[validate(..)] field attribute, expected one of `zero`, `ones` or `any`"
+            )
+        };
+        let meta_list = match attr.parse_meta()? {
+            syn::Meta::List(meta_list) if meta_list.nested.len() == 1 => meta_list,
+            _ => return Err(invalid_format()),
+        };
+        let path = match &meta_list.nested[0] {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) => path,
+            _ => return Err(invalid_format()),
+        };
+        if path.is_ident("zero") {
+            Ok(Validate::Zero)
+        } else if path.is_ident("ones") {
+            Ok(Validate::Ones)
+        } else if path.is_ident("any") {
+            Ok(Validate::Any)
+        } else {
+            Err(invalid_format())
+        }
+    }
+
+    /// Parses the arguments of a `#[payload_of(tag = "field", map(Variant => Type, ..))]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// - If the attribute does not follow the above format.
+    /// - If the mapping is empty or maps the same variant twice.
+    fn parse_payload_of_attr(attr: &syn::Attribute) -> Result<PayloadOf> {
+        let payload_of = attr
+            .parse_args_with(|input: syn::parse::ParseStream| {
+                let tag_key: syn::Ident = input.parse()?;
+                if tag_key != "tag" {
+                    return Err(format_err!(tag_key, "expected `tag`"))
+                }
+                input.parse::<syn::Token![=]>()?;
+                let tag: syn::LitStr = input.parse()?;
+                input.parse::<syn::Token![,]>()?;
+                let map_key: syn::Ident = input.parse()?;
+                if map_key != "map" {
+                    return Err(format_err!(map_key, "expected `map`"))
+                }
+                let content;
+                syn::parenthesized!(content in input);
+                let map = content.parse_terminated::<_, syn::Token![,]>(
+                    |input: syn::parse::ParseStream| {
+                        let variant: syn::Ident = input.parse()?;
+                        input.parse::<syn::Token![=>]>()?;
+                        let ty: syn::Type = input.parse()?;
+                        Ok((variant, ty))
+                    },
+                )?;
+                if input.peek(syn::Token![,]) {
+                    input.parse::<syn::Token![,]>()?;
+                }
+                Ok(PayloadOf {
+                    tag: tag.value(),
+                    tag_span: tag.span(),
+                    map: map.into_iter().collect(),
+                })
+            })
+            .map_err(|error| {
+                format_err!(
+                    error.span(),
+                    "encountered invalid format for #[payload_of(tag = \"field\", map(Variant => Type, ..))] field attribute"
+                )
+            })?;
+        if payload_of.map.is_empty() {
+            return Err(format_err_spanned!(
+                attr,
+                "encountered #[payload_of(..)] field attribute without any mapped variants"
+            ))
+        }
+        for (index, (variant, _)) in payload_of.map.iter().enumerate() {
+            if let Some((previous, _)) =
+                payload_of.map[..index].iter().find(|(previous, _)| previous == variant)
+            {
+                return Err(format_err!(
+                    variant,
+                    "encountered duplicate variant `{}` in #[payload_of(..)] field attribute",
+                    variant,
+                )
+                .into_combine(format_err!(previous, "duplicate variant here")))
+            }
+        }
+        Ok(payload_of)
+    }
+
+    /// Parses the arguments of a `#[const_values(NAME = value, ..)]` attribute.
+    ///
+    /// # Errors
+    ///
+    /// - If the attribute does not follow the above format.
+    /// - If there are no values or if the same name is given twice.
+    fn parse_const_values_attr(attr: &syn::Attribute) -> Result<Vec<ConstValue>> {
+        let invalid_format = |span: Span| {
+            format_err!(
+                span,
+                "encountered invalid format for #[const_values(NAME = value, ..)] field attribute"
+            )
+        };
+        let meta_list = match attr.parse_meta()? {
+            syn::Meta::List(meta_list) => meta_list,
+            _ => return Err(invalid_format(attr.span())),
+        };
+        if meta_list.nested.is_empty() {
+            return Err(format_err_spanned!(
+                attr,
+                "encountered #[const_values(..)] field attribute without any values"
+            ))
+        }
+        let mut const_values = Vec::<ConstValue>::new();
+        for nested_meta in &meta_list.nested {
+            let (name, value) = match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Int(value),
+                    ..
+                })) => {
+                    match path.get_ident() {
+                        Some(name) => (name.clone(), value.clone()),
+                        None => return Err(invalid_format(nested_meta.span())),
+                    }
+                }
+                _ => return Err(invalid_format(nested_meta.span())),
+            };
+            if let Some(previous) = const_values.iter().find(|previous| previous.name == name) {
+                return Err(format_err!(
+                    name,
+                    "encountered duplicate name `{}` in #[const_values(..)] field attribute",
+                    name,
+                )
+                .into_combine(format_err!(previous.name, "duplicate name here")))
+            }
+            const_values.push(ConstValue { name, value });
+        }
+        Ok(const_values)
+    }
+
+    /// Extracts the `#[bits = N]`, `#[skip(..)]`, `#[reserved]`, `#[quantity(..)]`, `#[repeat = N]`,
+    /// `#[validate(..)]`, `#[payload_of(..)]`, `#[const_values(..)]`, `#[offset_by = K]`,
+    /// `#[try_from = Type]` and `#[default = EXPR]` attributes for a given field.
     fn extract_field_config(field: &syn::Field) -> Result<FieldConfig> {
         let mut config = FieldConfig::default();
         for attr in &field.attrs {
@@ -201,6 +1443,85 @@ impl BitfieldStruct {
                         ))
                     }
                 }
+            } else if attr.path.is_ident("repeat") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.repeat(lit_int.base10_parse::<usize>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[repeat = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("offset_by") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.offset_by(lit_int.base10_parse::<u128>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[offset_by = K]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("group") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                let name = match &name_value.lit {
+                    syn::Lit::Str(lit_str) => lit_str.parse::<syn::Ident>().ok(),
+                    _ => None,
+                };
+                match name {
+                    Some(name) => config.group(name, span)?,
+                    None => {
+                        return Err(format_err!(
+                            name_value.lit,
+                            "encountered invalid value for #[group = \"name\"], expected a string with an identifier"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("reserved") {
+                if !attr.tokens.is_empty() {
+                    return Err(format_err_spanned!(
+                        attr,
+                        "encountered invalid format for #[reserved] field attribute"
+                    ))
+                }
+                config.reserved(attr.path.span())?;
+            } else if attr.path.is_ident("validate") {
+                let rule = Self::parse_validate_attr(attr)?;
+                config.validate(rule, attr.path.span())?;
+            } else if attr.path.is_ident("payload_of") {
+                let payload_of = Self::parse_payload_of_attr(attr)?;
+                config.payload_of(payload_of, attr.path.span())?;
+            } else if attr.path.is_ident("const_values") {
+                let const_values = Self::parse_const_values_attr(attr)?;
+                config.const_values(const_values, attr.path.span())?;
+            } else if attr.path.is_ident("quantity") {
+                let quantity = Self::parse_quantity_attr(attr)?;
+                config.quantity(quantity, attr.path.span())?;
+            } else if attr.path.is_ident("try_from") {
+                let ty = Self::parse_try_from_attr(attr)?;
+                config.try_from(ty, attr.path.span())?;
+            } else if attr.path.is_ident("default") {
+                let expr = Self::parse_default_attr(attr)?;
+                config.default_value(expr, attr.path.span())?;
             } else if attr.path.is_ident("skip") {
                 let path = &attr.path;
                 let args = &attr.tokens;
@@ -0,0 +1,124 @@
+use super::{
+    analyse_and_expand_struct,
+    params::ParamArgs,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use syn::parse::{
+    Parse,
+    ParseStream,
+    Result,
+};
+
+/// The input of a `bitfields!` invocation.
+///
+/// Inner attributes are shared by all structs. The parameters of an inner `#![bitfield(..)]`
+/// attribute are applied to all structs unless a struct provides a parameter of the same name
+/// itself while all other inner attributes are added to the attributes of every struct.
+struct BitfieldsInput {
+    /// The parameters of the inner `#![bitfield(..)]` attributes.
+    shared_params: Vec<syn::NestedMeta>,
+    /// All other inner attributes.
+    shared_attrs: Vec<syn::Attribute>,
+    /// The bitfield structs.
+    items: Vec<syn::ItemStruct>,
+}
+
+impl Parse for BitfieldsInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut shared_params = Vec::new();
+        let mut shared_attrs = Vec::new();
+        for attr in input.call(syn::Attribute::parse_inner)? {
+            match is_bitfield_attr(&attr) {
+                true => shared_params.extend(parse_bitfield_attr(&attr)?),
+                false => {
+                    shared_attrs.push(syn::Attribute {
+                        style: syn::AttrStyle::Outer,
+                        ..attr
+                    })
+                }
+            }
+        }
+        let mut items = Vec::new();
+        while !input.is_empty() {
+            items.push(input.parse::<syn::ItemStruct>()?);
+        }
+        Ok(Self {
+            shared_params,
+            shared_attrs,
+            items,
+        })
+    }
+}
+
+/// Returns `true` if the attribute is a `#[bitfield]` or `#[bitfield(..)]` attribute.
+fn is_bitfield_attr(attr: &syn::Attribute) -> bool {
+    attr.path.is_ident("bitfield")
+}
+
+/// Returns the parameters of a `#[bitfield]` or `#[bitfield(..)]` attribute.
+fn parse_bitfield_attr(attr: &syn::Attribute) -> Result<ParamArgs> {
+    if attr.tokens.is_empty() {
+        return syn::parse2::<ParamArgs>(TokenStream2::new())
+    }
+    attr.parse_args::<ParamArgs>()
+}
+
+/// Returns the name of the given parameter, e.g. `bit_order` for `bit_order = be`.
+///
+/// The `order` alias is named `bit_order` so that both override each other.
+fn param_name(param: &syn::NestedMeta) -> Option<String> {
+    match param {
+        syn::NestedMeta::Meta(meta) => {
+            meta.path().get_ident().map(|ident| {
+                match ident == "order" {
+                    true => "bit_order".to_string(),
+                    false => ident.to_string(),
+                }
+            })
+        }
+        syn::NestedMeta::Lit(_) => None,
+    }
+}
+
+/// Analyzes the bitfield structs of a `bitfields!` invocation and expands code for all of them.
+///
+/// Errors of the individual structs are reported together.
+pub fn analyse_and_expand_all(input: TokenStream2) -> TokenStream2 {
+    let BitfieldsInput {
+        shared_params,
+        shared_attrs,
+        items,
+    } = match syn::parse2::<BitfieldsInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error(),
+    };
+    items
+        .into_iter()
+        .map(|mut item| {
+            let mut params = Vec::new();
+            let mut attrs = shared_attrs.clone();
+            for attr in core::mem::take(&mut item.attrs) {
+                match is_bitfield_attr(&attr) {
+                    true => {
+                        match parse_bitfield_attr(&attr) {
+                            Ok(own_params) => params.extend(own_params),
+                            Err(err) => return err.to_compile_error(),
+                        }
+                    }
+                    false => attrs.push(attr),
+                }
+            }
+            let shared_params = shared_params
+                .iter()
+                .filter(|shared| {
+                    !params.iter().any(|own| {
+                        param_name(own).is_some() && param_name(own) == param_name(shared)
+                    })
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            item.attrs = attrs;
+            analyse_and_expand_struct(shared_params.into_iter().chain(params), item).unwrap_or_else(|err| err.to_compile_error())
+        })
+        .collect()
+}
@@ -0,0 +1,168 @@
+use super::{
+    config::Config,
+    BitfieldStruct,
+};
+use crate::errors::CombineError;
+use proc_macro2::Span;
+use syn::{
+    self,
+    ext::IdentExt as _,
+    parse::Result,
+    spanned::Spanned as _,
+};
+
+/// Derive macros of other crates that are known to generate inherent methods.
+///
+/// Derive macros retained by `#[bitfield]` only see the underlying `bytes` field
+/// of the generated struct. Therefore their generated methods are known up front.
+const METHOD_GENERATING_DERIVES: &[(&str, &[&str])] = &[
+    // The `getset` crate.
+    ("Getters", &["bytes"]),
+    ("CopyGetters", &["bytes"]),
+    ("MutGetters", &["bytes_mut"]),
+    ("Setters", &["set_bytes"]),
+    // The `derive_more` crate.
+    ("Constructor", &["new"]),
+    // The `derive-new` crate.
+    ("new", &["new"]),
+];
+
+impl BitfieldStruct {
+    /// Registers the methods generated by a retained derive macro if it is known to generate any.
+    pub(super) fn register_methods_of_derive(path: &syn::Path, config: &mut Config) {
+        let name = match path.segments.last() {
+            Some(segment) => segment.ident.to_string(),
+            None => return,
+        };
+        let origin = path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::");
+        let methods = METHOD_GENERATING_DERIVES
+            .iter()
+            .find(|(derive, _)| *derive == name)
+            .map(|(_, methods)| *methods)
+            .unwrap_or_default();
+        for method in methods {
+            config.push_foreign_method(
+                method.to_string(),
+                format!("#[derive({})]", origin),
+                path.span(),
+            );
+        }
+    }
+
+    /// Returns the identifiers and originating spans of all methods generated by `#[bitfield]`.
+    ///
+    /// The methods are collected from the inherent impl blocks of the expanded code itself
+    /// so that every method of every generator is taken into account.
+    fn generated_methods(&self, config: &Config) -> Vec<(syn::Ident, Span)> {
+        let mut methods = Vec::new();
+        if let Ok(file) = syn::parse2::<syn::File>(self.expand(config)) {
+            for item in &file.items {
+                Self::collect_inherent_methods(&self.item_struct.ident, item, &mut methods);
+            }
+        }
+        methods
+    }
+
+    /// Collects the methods of the item if it is an inherent impl block of the struct with
+    /// the given identifier.
+    ///
+    /// Impl blocks within anonymous constants, e.g. `const _: () = { .. };`, are included.
+    fn collect_inherent_methods(
+        ident: &syn::Ident,
+        item: &syn::Item,
+        methods: &mut Vec<(syn::Ident, Span)>,
+    ) {
+        match item {
+            syn::Item::Impl(item_impl) if item_impl.trait_.is_none() => {
+                let is_struct = matches!(
+                    &*item_impl.self_ty,
+                    syn::Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident(ident)
+                );
+                if !is_struct {
+                    return
+                }
+                for impl_item in &item_impl.items {
+                    if let syn::ImplItem::Method(method) = impl_item {
+                        let method_ident = &method.sig.ident;
+                        methods.push((method_ident.clone(), method_ident.span()));
+                    }
+                }
+            }
+            syn::Item::Const(item_const) => {
+                if let syn::Expr::Block(block) = &*item_const.expr {
+                    for stmt in &block.block.stmts {
+                        if let syn::Stmt::Item(item) = stmt {
+                            Self::collect_inherent_methods(ident, item, methods);
+                        }
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Ensures that no method generated by `#[bitfield]` collides with a method
+    /// generated by a retained attribute or listed in the `external_methods` parameter.
+    ///
+    /// With `rename_collisions` the colliding methods generated by `#[bitfield]`
+    /// are renamed instead.
+    ///
+    /// # Errors
+    ///
+    /// If a collision is found and `rename_collisions` has not been set.
+    pub(super) fn ensure_no_method_collisions(&self, config: &mut Config) -> Result<()> {
+        if let Some(external_methods) = config.external_methods.clone() {
+            for name in external_methods.value {
+                config.push_foreign_method(
+                    name,
+                    "the `external_methods` parameter".to_string(),
+                    external_methods.span,
+                );
+            }
+        }
+        if config.foreign_methods.is_empty() {
+            return Ok(())
+        }
+        let generated = self.generated_methods(config);
+        let mut error: Option<syn::Error> = None;
+        let mut renamed = Vec::new();
+        for foreign in &config.foreign_methods {
+            for (ident, span) in &generated {
+                let name = ident.unraw().to_string();
+                if name != foreign.name {
+                    continue
+                }
+                if config.rename_collisions.is_some() {
+                    renamed.push(name);
+                    continue
+                }
+                let collision = format_err!(
+                    foreign.span,
+                    "encountered method `{}` generated by {} that collides with a method generated by #[bitfield]",
+                    foreign.name,
+                    foreign.origin,
+                )
+                .into_combine(format_err!(
+                    *span,
+                    "colliding method `{}` generated by #[bitfield] here, consider using #[bitfield(rename_collisions)] to rename it to `{}_bitfield`",
+                    name,
+                    name,
+                ));
+                error = Some(match error {
+                    Some(error) => error.into_combine(collision),
+                    None => collision,
+                });
+            }
+        }
+        if let Some(error) = error {
+            return Err(error)
+        }
+        config.renamed_methods.extend(renamed);
+        Ok(())
+    }
+}
@@ -4,9 +4,11 @@ use super::field_config::FieldConfig;
 use crate::errors::CombineError;
 use core::any::TypeId;
 use proc_macro2::Span;
+use quote::format_ident;
 use std::collections::{
     hash_map::Entry,
     HashMap,
+    HashSet,
 };
 use syn::parse::Result;
 
@@ -16,8 +18,93 @@ pub struct Config {
     pub bytes: Option<ConfigValue<usize>>,
     pub bits: Option<ConfigValue<usize>>,
     pub filled: Option<ConfigValue<bool>>,
+    pub uninit: Option<ConfigValue<bool>>,
+    /// The name of the zero initializing constructor set by `new = "name"`.
+    pub new: Option<ConfigValue<String>>,
+    /// Constructors initializing the bitfield from packed values set by `preset(..)` parameters.
+    pub presets: Vec<ConfigValue<Preset>>,
+    /// Read-only getters derived from other fields set by `#[computed(..)]` attributes.
+    pub computed: Vec<ConfigValue<Computed>>,
+    pub emit_layout: Option<ConfigValue<String>>,
+    pub layout_guard: Option<ConfigValue<String>>,
+    /// The `#[cfg(..)]` predicate of the layout variant that is being expanded.
+    ///
+    /// Only set for structs with `#[cfg(..)]` or `#[cfg_attr(..)]` annotated fields.
+    pub layout_variant: Option<ConfigValue<String>>,
+    pub split_at: Option<ConfigValue<String>>,
+    /// The name of the methods returning the wrappers that provide the accessors of the fields.
+    pub accessors_mod: Option<ConfigValue<String>>,
+    /// The module path of the struct used by the doctests of `doc_examples`.
+    ///
+    /// This is `crate` if the struct is found at the crate root.
+    pub doc_examples: Option<ConfigValue<String>>,
+    /// The prefix of the names of the `extern "C"` functions generated by `ffi_export`.
+    pub ffi_export: Option<ConfigValue<String>>,
+    pub unchecked_setters: Option<ConfigValue<()>>,
+    pub bit_queries: Option<ConfigValue<()>>,
+    pub bounds: Option<ConfigValue<()>>,
+    pub masks: Option<ConfigValue<()>>,
+    pub lints: Option<ConfigValue<()>>,
+    pub const_accessors: Option<ConfigValue<()>>,
+    pub fault_injection: Option<ConfigValue<()>>,
+    pub neighbor_check_tests: Option<ConfigValue<()>>,
+    pub dual_order: Option<ConfigValue<()>>,
+    pub keep_phantom_fields: Option<ConfigValue<()>>,
+    pub dirty_tracking: Option<ConfigValue<()>>,
+    pub c_embed: Option<ConfigValue<()>>,
+    pub transparent: Option<ConfigValue<()>>,
+    pub minimal: Option<ConfigValue<()>>,
+    pub dump: Option<ConfigValue<()>>,
+    pub bitstring: Option<ConfigValue<()>>,
+    pub no_straddle: Option<ConfigValue<()>>,
+    pub auto_pad: Option<ConfigValue<()>>,
+    pub contiguous: Option<ConfigValue<()>>,
+    pub register_layout: Option<ConfigValue<()>>,
+    pub mmio: Option<ConfigValue<()>>,
+    /// The representation of the `Serialize` and `Deserialize` impls set by `serde`.
+    pub serde: Option<ConfigValue<SerdeRepr>>,
+    pub iter_fields: Option<ConfigValue<()>>,
+    /// The derives of the fields struct given by `fields_struct(derive(..))`.
+    pub fields_struct: Option<ConfigValue<Vec<syn::Path>>>,
+    pub access_stats: Option<ConfigValue<()>>,
+    pub hash: Option<ConfigValue<()>>,
+    pub copy: Option<ConfigValue<bool>>,
+    pub bit_order: Option<ConfigValue<BitOrder>>,
+    /// The bit order set by the `order = lsb | msb` alias of `bit_order`.
+    pub order: Option<ConfigValue<BitOrder>>,
+    pub compat: Option<ConfigValue<Compat>>,
+    pub doc_bit_numbering: Option<ConfigValue<BitNumbering>>,
+    pub rename_collisions: Option<ConfigValue<()>>,
+    pub external_methods: Option<ConfigValue<Vec<String>>>,
+    /// The trailing `Rest` field marking the start of the payload after the bitfield.
+    pub rest: Option<ConfigValue<syn::Field>>,
+    /// Bitfields that the `#[bitfield]` struct can be cast to via `cast_to(..)` parameters.
+    pub cast_to: Vec<ConfigValue<syn::Path>>,
+    /// The word types of the word array conversions set by `words(..)`.
+    pub words: Vec<ConfigValue<WordKind>>,
+    /// The byte order of the words of the word array conversions set by `word_endian`.
+    pub word_endian: Option<ConfigValue<ReprEndian>>,
+    /// Derives that are implemented field-wise due to `intercept(..)` parameters.
+    pub intercept: Vec<ConfigValue<InterceptableDerive>>,
+    /// Derives that are applied to the underlying bytes due to `passthrough(..)` parameters.
+    pub passthrough: Vec<ConfigValue<InterceptableDerive>>,
+    /// Methods that are generated by retained attributes of the `#[bitfield]` struct.
+    pub foreign_methods: Vec<ForeignMethod>,
+    /// Names of generated methods that are renamed due to `rename_collisions`.
+    pub renamed_methods: HashSet<String>,
     pub repr: Option<ConfigValue<ReprKind>>,
+    /// The byte order of the `#[repr(uN)]` conversions set by `#[repr_endian = ".."]`.
+    pub repr_endian: Option<ConfigValue<ReprEndian>>,
     pub derive_debug: Option<ConfigValue<()>>,
+    /// Set by an intercepted `#[derive(defmt::Format)]` with the `defmt` crate feature.
+    pub derive_defmt: Option<ConfigValue<()>>,
+    pub derive_partial_ord: Option<ConfigValue<()>>,
+    pub derive_ord: Option<ConfigValue<()>>,
+    pub derive_copy: Option<ConfigValue<()>>,
+    pub derive_hash: Option<ConfigValue<()>>,
+    pub derive_default: Option<ConfigValue<()>>,
+    /// Set if any field has a `#[default = EXPR]` attribute.
+    pub field_defaults: bool,
     pub derive_specifier: Option<ConfigValue<()>>,
     pub retained_attributes: Vec<syn::Attribute>,
     pub field_configs: HashMap<usize, ConfigValue<FieldConfig>>,
@@ -57,6 +144,307 @@ impl core::fmt::Debug for ReprKind {
     }
 }
 
+/// A constructor generated by a `preset(name = value)` parameter.
+pub struct Preset {
+    /// The name of the constructor.
+    pub name: syn::Ident,
+    /// The packed value the constructor initializes the bitfield with.
+    pub value: u128,
+}
+
+/// A read-only getter generated by a `#[computed(..)]` struct attribute.
+pub struct Computed {
+    /// The name of the getter.
+    pub name: syn::Ident,
+    /// The fields whose values are bound to their names when evaluating `expr`.
+    pub from: Vec<syn::Ident>,
+    /// The return type of the getter.
+    pub ty: syn::Type,
+    /// The expression computing the value of the getter.
+    pub expr: syn::Expr,
+}
+
+/// The byte order used by the `From` conversions of a `#[repr(uN)]` bitfield.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ReprEndian {
+    /// The first byte of the bitfield is the least significant byte of the integer.
+    Little,
+    /// The first byte of the bitfield is the most significant byte of the integer.
+    Big,
+}
+
+impl ReprEndian {
+    /// Returns the name of the byte order as used by the `#[repr_endian = ".."]` attribute.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Little => "little",
+            Self::Big => "big",
+        }
+    }
+}
+
+impl core::fmt::Debug for ReprEndian {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "#[repr_endian = \"{}\"]", self.name())
+    }
+}
+
+/// The word types of the word array conversions generated by `words(..)`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum WordKind {
+    U16,
+    U32,
+    U64,
+}
+
+impl WordKind {
+    /// All supported word types.
+    pub const ALL: [Self; 3] = [Self::U16, Self::U32, Self::U64];
+
+    /// Returns the name of the primitive type of the words.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+        }
+    }
+
+    /// Returns the number of bytes of a single word.
+    pub fn bytes(self) -> usize {
+        match self {
+            Self::U16 => 2,
+            Self::U32 => 4,
+            Self::U64 => 8,
+        }
+    }
+
+    /// Returns the word type with the given path if any.
+    pub fn from_path(path: &syn::Path) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|kind| path.is_ident(kind.name()))
+    }
+}
+
+/// The order in which the bits of a `#[bitfield]` struct are packed into its bytes.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Fields start at the least significant bit of the first byte.
+    ///
+    /// This is the default bit order.
+    Le,
+    /// Fields start at the most significant bit of the first byte.
+    Be,
+}
+
+impl BitOrder {
+    /// Returns the name of the bit order as used by the `bit_order` parameter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Le => "le",
+            Self::Be => "be",
+        }
+    }
+}
+
+impl BitOrder {
+    /// Returns the name of the bit order as used by the `order` parameter.
+    pub fn order_name(self) -> &'static str {
+        match self {
+            Self::Le => "lsb",
+            Self::Be => "msb",
+        }
+    }
+}
+
+impl core::fmt::Debug for BitOrder {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The representation of a `#[bitfield]` struct used by its `serde` impls.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum SerdeRepr {
+    /// Serializes the bitfield as its array of bytes.
+    ///
+    /// This is the default representation.
+    Bytes,
+    /// Serializes the bitfield as a struct with the values of its fields.
+    Fields,
+}
+
+impl SerdeRepr {
+    /// Returns the name of the representation as used by the `serde` parameter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bytes => "bytes",
+            Self::Fields => "fields",
+        }
+    }
+}
+
+impl core::fmt::Debug for SerdeRepr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Crates whose bit layout is replicated by the `compat` parameter.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Compat {
+    /// The layout of `#[packed_struct(bit_numbering = "msb0", endian = "msb")]` structs.
+    PackedStruct,
+}
+
+impl Compat {
+    /// Returns the name of the crate as used by the `compat` parameter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::PackedStruct => "packed_struct",
+        }
+    }
+
+    /// Returns the bit order that replicates the layout of the crate.
+    pub fn bit_order(self) -> BitOrder {
+        match self {
+            Self::PackedStruct => BitOrder::Be,
+        }
+    }
+}
+
+impl core::fmt::Debug for Compat {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The bit numbering used by the generated layout documentation.
+///
+/// Does not affect how the bits of a `#[bitfield]` struct are stored.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum BitNumbering {
+    /// Bit 0 is the least significant bit of the bitfield.
+    Lsb0,
+    /// Bit 0 is the most significant bit of the bitfield.
+    Msb0,
+}
+
+impl BitNumbering {
+    /// Returns the name of the bit numbering as used by the `doc_bit_numbering` parameter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Lsb0 => "lsb0",
+            Self::Msb0 => "msb0",
+        }
+    }
+
+    /// Returns the bit numbers of a field with the given offset and bit width as `(first, last)`.
+    ///
+    /// The bitfield is numbered as a number of `total_bits` bits that starts with the first
+    /// field at its least significant bit for the `le` and at its most significant bit for
+    /// the `be` bit order. Ranges are ordered as `high:low` for `lsb0` and as `low:high`
+    /// for `msb0` numbering matching the conventions of datasheets.
+    pub fn bounds(
+        self,
+        offset: usize,
+        bits: usize,
+        total_bits: usize,
+        bit_order: BitOrder,
+    ) -> (usize, usize) {
+        let (low, high) = match bit_order {
+            BitOrder::Le => (offset, offset + bits - 1),
+            BitOrder::Be => (total_bits - offset - bits, total_bits - offset - 1),
+        };
+        match self {
+            Self::Lsb0 => (high, low),
+            Self::Msb0 => (total_bits - 1 - high, total_bits - 1 - low),
+        }
+    }
+
+    /// Returns the bit range of a field with the given offset and bit width as `first:last`.
+    ///
+    /// Single bit ranges are written as one number. See [`Self::bounds`] for the numbering.
+    pub fn range(
+        self,
+        offset: usize,
+        bits: usize,
+        total_bits: usize,
+        bit_order: BitOrder,
+    ) -> String {
+        match self.bounds(offset, bits, total_bits, bit_order) {
+            (first, last) if first == last => format!("{}", first),
+            (first, last) => format!("{}:{}", first, last),
+        }
+    }
+}
+
+impl core::fmt::Debug for BitNumbering {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A method that is generated by a retained attribute of the `#[bitfield]` struct.
+pub struct ForeignMethod {
+    /// The name of the generated method.
+    pub name: String,
+    /// A description of the attribute generating the method, e.g. `#[derive(Getters)]`.
+    pub origin: String,
+    /// The span of the attribute generating the method.
+    pub span: Span,
+}
+
+/// Derives whose implementation can either be intercepted by `#[bitfield]` or passed through.
+///
+/// Intercepted derives are implemented in terms of the fields of the bitfield whereas passed
+/// through derives are applied to the generated struct and thus to its underlying bytes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InterceptableDerive {
+    Debug,
+    Default,
+    Hash,
+    PartialOrd,
+    Ord,
+}
+
+impl InterceptableDerive {
+    /// All derives that can be intercepted.
+    pub const ALL: [Self; 5] = [
+        Self::Debug,
+        Self::Default,
+        Self::Hash,
+        Self::PartialOrd,
+        Self::Ord,
+    ];
+
+    /// Returns the name of the derived trait.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Debug => "Debug",
+            Self::Default => "Default",
+            Self::Hash => "Hash",
+            Self::PartialOrd => "PartialOrd",
+            Self::Ord => "Ord",
+        }
+    }
+
+    /// Returns the interceptable derive with the given path if any.
+    pub fn from_path(path: &syn::Path) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|derive| path.is_ident(derive.name()))
+    }
+
+    /// Returns `true` if the derive is intercepted unless configured otherwise.
+    fn intercepted_by_default(self) -> bool {
+        matches!(self, Self::Debug | Self::PartialOrd | Self::Ord)
+    }
+}
+
 /// A configuration value and its originating span.
 #[derive(Clone)]
 pub struct ConfigValue<T> {
@@ -82,6 +470,110 @@ impl Config {
             .unwrap_or(true)
     }
 
+    /// Returns the value of the `uninit` parameter if provided and otherwise `false`.
+    pub fn uninit_enabled(&self) -> bool {
+        self.uninit
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(false)
+    }
+
+    /// Returns `true` if the given derive is implemented field-wise by `#[bitfield]`.
+    ///
+    /// This is the case if the derive has been given to `intercept(..)` or if it is
+    /// intercepted by default and has not been given to `passthrough(..)`. `Default` is
+    /// intercepted by default if any field has a `#[default = EXPR]` attribute.
+    pub fn intercepts(&self, derive: InterceptableDerive) -> bool {
+        if self.intercept.iter().any(|config| config.value == derive) {
+            return true
+        }
+        if self.passthrough.iter().any(|config| config.value == derive) {
+            return false
+        }
+        derive.intercepted_by_default()
+            || derive == InterceptableDerive::Default && self.field_defaults
+    }
+
+    /// Returns the value of the `copy` parameter if provided.
+    pub fn copy_enabled(&self) -> Option<bool> {
+        self.copy.as_ref().map(|config| config.value)
+    }
+
+    /// Returns the value of the `bit_order` or `order` parameter if provided and otherwise
+    /// `BitOrder::Le`.
+    ///
+    /// Without a `bit_order` parameter the bit order of the `compat` parameter is used if provided.
+    pub fn bit_order(&self) -> BitOrder {
+        self.bit_order
+            .as_ref()
+            .or(self.order.as_ref())
+            .map(|config| config.value)
+            .or_else(|| self.compat.as_ref().map(|config| config.value.bit_order()))
+            .unwrap_or(BitOrder::Le)
+    }
+
+    /// Returns the byte order of the words of the `words(..)` conversions.
+    ///
+    /// Defaults to the byte order matching the bit order of the bitfield.
+    pub fn word_endian(&self) -> ReprEndian {
+        match &self.word_endian {
+            Some(word_endian) => word_endian.value,
+            None => {
+                match self.bit_order() {
+                    BitOrder::Le => ReprEndian::Little,
+                    BitOrder::Be => ReprEndian::Big,
+                }
+            }
+        }
+    }
+
+    /// Returns the byte order of the `#[repr(uN)]` conversions.
+    ///
+    /// Defaults to the byte order matching the bit order of the bitfield.
+    pub fn repr_endian(&self) -> ReprEndian {
+        match &self.repr_endian {
+            Some(repr_endian) => repr_endian.value,
+            None => {
+                match self.bit_order() {
+                    BitOrder::Le => ReprEndian::Little,
+                    BitOrder::Be => ReprEndian::Big,
+                }
+            }
+        }
+    }
+
+    /// Returns the value of the `doc_bit_numbering` parameter if provided.
+    pub fn doc_bit_numbering(&self) -> Option<BitNumbering> {
+        self.doc_bit_numbering.as_ref().map(|config| config.value)
+    }
+
+    /// Returns the identifier of the zero initializing constructor.
+    ///
+    /// This is `new` unless overridden by the `new = "name"` parameter.
+    pub fn new_ident(&self) -> syn::Ident {
+        let ident = match &self.new {
+            Some(new) => {
+                let mut ident = syn::parse_str::<syn::Ident>(&new.value)
+                    .expect("encountered unvalidated `new` parameter");
+                ident.set_span(new.span);
+                ident
+            }
+            None => format_ident!("new"),
+        };
+        self.method_ident(ident)
+    }
+
+    /// Returns the identifier of a generated method taking `rename_collisions` into account.
+    ///
+    /// Generated methods that collide with methods of retained attributes are suffixed with `_bitfield`.
+    pub fn method_ident(&self, ident: syn::Ident) -> syn::Ident {
+        use syn::ext::IdentExt as _;
+        if self.renamed_methods.contains(&ident.unraw().to_string()) {
+            return format_ident!("{}_bitfield", ident.unraw(), span = ident.span())
+        }
+        ident
+    }
+
     fn ensure_no_bits_and_repr_conflict(&self) -> Result<()> {
         if let (Some(bits), Some(repr)) = (self.bits.as_ref(), self.repr.as_ref()) {
             if bits.value != repr.value.bits() {
@@ -155,108 +647,1296 @@ impl Config {
         Ok(())
     }
 
-    /// Ensures that there are no conflicting configuration parameters.
-    pub fn ensure_no_conflicts(&self) -> Result<()> {
-        self.ensure_no_bits_and_repr_conflict()?;
-        self.ensure_no_bits_and_bytes_conflict()?;
-        self.ensure_no_repr_and_filled_conflict()?;
+    fn ensure_no_hash_and_derive_hash_conflict(&self) -> Result<()> {
+        if let (Some(hash), Some(derive_hash)) =
+            (self.hash.as_ref(), self.derive_hash.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `hash` parameter and #[derive(Hash)]",
+            )
+            .into_combine(format_err!(hash.span, "conflicting `hash` parameter here"))
+            .into_combine(format_err!(
+                derive_hash.span,
+                "conflicting #[derive(Hash)] here"
+            )))
+        }
         Ok(())
     }
 
-    /// Returns an error showing both the duplicate as well as the previous parameters.
-    fn raise_duplicate_error<T>(
-        name: &str,
-        span: Span,
-        previous: &ConfigValue<T>,
-    ) -> syn::Error
-    where
-        T: core::fmt::Debug + 'static,
-    {
-        if TypeId::of::<T>() == TypeId::of::<()>() {
-            format_err!(span, "encountered duplicate `{}` parameter", name,)
-        } else {
-            format_err!(
-                span,
-                "encountered duplicate `{}` parameter: duplicate set to {:?}",
-                name,
-                previous.value
+    fn ensure_no_dirty_tracking_and_bytes_conflict(&self) -> Result<()> {
+        if let (Some(dirty_tracking), Some(bytes)) =
+            (self.dirty_tracking.as_ref(), self.bytes.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `dirty_tracking` and `bytes = {}` parameters",
+                bytes.value,
             )
+            .into_combine(format_err!(
+                dirty_tracking.span,
+                "conflicting `dirty_tracking` here"
+            ))
+            .into_combine(format_err!(
+                bytes.span,
+                "conflicting `bytes = {}` here",
+                bytes.value
+            )))
         }
-        .into_combine(format_err!(
-            previous.span,
-            "previous `{}` parameter here",
-            name
-        ))
+        Ok(())
     }
 
-    /// Sets the `bytes: int` #[bitfield] parameter to the given value.
+    /// Returns an error if both `c_embed` and `dirty_tracking` are set.
     ///
-    /// # Errors
-    ///
-    /// If the specifier has already been set.
-    pub fn bytes(&mut self, value: usize, span: Span) -> Result<()> {
-        match &self.bytes {
-            Some(previous) => {
-                return Err(Self::raise_duplicate_error("bytes", span, previous))
-            }
-            None => self.bytes = Some(ConfigValue::new(value, span)),
+    /// The set of modified fields would be part of the memory layout of the struct.
+    fn ensure_no_c_embed_and_dirty_tracking_conflict(&self) -> Result<()> {
+        if let (Some(c_embed), Some(dirty_tracking)) =
+            (self.c_embed.as_ref(), self.dirty_tracking.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `c_embed` and `dirty_tracking` parameters",
+            )
+            .into_combine(format_err!(c_embed.span, "conflicting `c_embed` here"))
+            .into_combine(format_err!(
+                dirty_tracking.span,
+                "conflicting `dirty_tracking` here"
+            )))
         }
         Ok(())
     }
 
-    /// Sets the `bits: int` #[bitfield] parameter to the given value.
+    /// Returns an error if `accessors_mod` is set together with `dirty_tracking` or `doc_examples`.
     ///
-    /// # Errors
-    ///
-    /// If the specifier has already been set.
-    pub fn bits(&mut self, value: usize, span: Span) -> Result<()> {
-        match &self.bits {
-            Some(previous) => {
-                return Err(Self::raise_duplicate_error("bits", span, previous))
+    /// The accessor wrappers only borrow the bytes of the bitfield and not the set of modified
+    /// fields while the doc examples call the accessors on the bitfield directly.
+    fn ensure_no_accessors_mod_conflict(&self) -> Result<()> {
+        let accessors_mod = match &self.accessors_mod {
+            Some(accessors_mod) => accessors_mod,
+            None => return Ok(()),
+        };
+        let conflicts = [
+            ("dirty_tracking", self.dirty_tracking.as_ref().map(|config| config.span)),
+            ("doc_examples", self.doc_examples.as_ref().map(|config| config.span)),
+        ];
+        for (name, conflict) in conflicts {
+            if let Some(conflict) = conflict {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `accessors_mod` and `{}` parameters",
+                    name,
+                )
+                .into_combine(format_err!(accessors_mod.span, "conflicting `accessors_mod` here"))
+                .into_combine(format_err!(conflict, "conflicting `{}` here", name)))
             }
-            None => self.bits = Some(ConfigValue::new(value, span)),
         }
         Ok(())
     }
 
-    /// Sets the `filled: bool` #[bitfield] parameter to the given value.
+    /// Returns an error if `transparent` is set together with `dirty_tracking` or `c_embed`.
     ///
-    /// # Errors
-    ///
-    /// If the specifier has already been set.
-    pub fn filled(&mut self, value: bool, span: Span) -> Result<()> {
-        match &self.filled {
-            Some(previous) => {
-                return Err(Self::raise_duplicate_error("filled", span, previous))
+    /// The set of modified fields would be part of the memory layout of the struct and
+    /// `c_embed` already requests `#[repr(C)]` which conflicts with `#[repr(transparent)]`.
+    fn ensure_no_transparent_conflict(&self) -> Result<()> {
+        let transparent = match &self.transparent {
+            Some(transparent) => transparent,
+            None => return Ok(()),
+        };
+        let conflicts = [
+            ("dirty_tracking", self.dirty_tracking.as_ref()),
+            ("c_embed", self.c_embed.as_ref()),
+        ];
+        for (name, conflict) in conflicts {
+            if let Some(conflict) = conflict {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `transparent` and `{}` parameters",
+                    name,
+                )
+                .into_combine(format_err!(transparent.span, "conflicting `transparent` here"))
+                .into_combine(format_err!(conflict.span, "conflicting `{}` here", name)))
             }
-            None => self.filled = Some(ConfigValue::new(value, span)),
         }
         Ok(())
     }
 
-    /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
+    /// Returns an error if `emit_layout` or `layout_guard` is used for a layout variant.
     ///
-    /// # Errors
-    ///
-    /// If a `#[repr(uN)]` attribute has already been found.
-    pub fn repr(&mut self, value: ReprKind, span: Span) -> Result<()> {
-        match &self.repr {
-            Some(previous) => {
-                return Err(Self::raise_duplicate_error("#[repr(uN)]", span, previous))
+    /// All layout variants would share the same layout file.
+    fn ensure_no_layout_variant_conflict(&self) -> Result<()> {
+        if self.layout_variant.is_none() {
+            return Ok(())
+        }
+        let params = [("emit_layout", &self.emit_layout), ("layout_guard", &self.layout_guard)];
+        for (name, param) in params.iter() {
+            if let Some(param) = param {
+                return Err(format_err!(
+                    param.span,
+                    "encountered `{}` parameter for a bitfield with #[cfg(..)] fields",
+                    name,
+                ))
             }
-            None => self.repr = Some(ConfigValue::new(value, span)),
         }
         Ok(())
     }
 
-    /// Registers the `#[derive(Debug)]` attribute for the #[bitfield] macro.
-    ///
-    /// # Errors
+    /// Returns an error if `#[repr_endian = ".."]` is used without a `#[repr(uN)]` attribute.
+    fn ensure_repr_endian_has_repr(&self) -> Result<()> {
+        if let (Some(repr_endian), None) = (self.repr_endian.as_ref(), self.repr.as_ref()) {
+            return Err(format_err!(
+                repr_endian.span,
+                "encountered {:?} attribute without a #[repr(uN)] attribute",
+                repr_endian.value,
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the `mmio` parameter is used without a `#[repr(uN)]` attribute.
     ///
-    /// If a `#[derive(Debug)]` attribute has already been found.
-    pub fn derive_debug(&mut self, span: Span) -> Result<()> {
-        match &self.derive_debug {
-            Some(previous) => {
+    /// The register is accessed as a whole with the width of the `#[repr(uN)]` primitive.
+    fn ensure_mmio_has_repr(&self) -> Result<()> {
+        if let (Some(mmio), None) = (self.mmio.as_ref(), self.repr.as_ref()) {
+            return Err(format_err!(
+                mmio.span,
+                "encountered `mmio` parameter without a #[repr(uN)] attribute",
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the `word_endian` parameter is set without a `words(..)` parameter.
+    fn ensure_word_endian_has_words(&self) -> Result<()> {
+        if let (Some(word_endian), true) = (self.word_endian.as_ref(), self.words.is_empty()) {
+            return Err(format_err!(
+                word_endian.span,
+                "encountered `word_endian` parameter without a `words(..)` parameter",
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if both the `bit_order` parameter and its `order` alias are set.
+    fn ensure_no_bit_order_and_order_conflict(&self) -> Result<()> {
+        if let (Some(bit_order), Some(order)) = (self.bit_order.as_ref(), self.order.as_ref()) {
+            return Err(format_err!(
+                order.span,
+                "encountered duplicate bit order: `order = {}` is an alias of `bit_order = {:?}`",
+                order.value.order_name(),
+                order.value,
+            )
+            .into_combine(format_err!(
+                bit_order.span,
+                "duplicate `bit_order` parameter here"
+            )))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the `bit_order` or `order` parameter differs from the bit order
+    /// of `compat`.
+    fn ensure_no_compat_and_bit_order_conflict(&self) -> Result<()> {
+        let compat = match self.compat.as_ref() {
+            Some(compat) => compat,
+            None => return Ok(()),
+        };
+        let (name, value, span) = match (self.bit_order.as_ref(), self.order.as_ref()) {
+            (Some(bit_order), _) => ("bit_order", bit_order.value.name(), bit_order.span),
+            (None, Some(order)) => ("order", order.value.order_name(), order.span),
+            (None, None) => return Ok(()),
+        };
+        if compat.value.bit_order() != self.bit_order() {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `compat = {:?}` and `{} = {}` parameters",
+                compat.value,
+                name,
+                value,
+            )
+            .into_combine(format_err!(
+                compat.span,
+                "conflicting `compat = {:?}` here",
+                compat.value
+            ))
+            .into_combine(format_err!(span, "conflicting `{} = {}` here", name, value)))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if both `split_at` and `dirty_tracking` are set.
+    ///
+    /// Both halves would have to mutably borrow the set of modified fields.
+    fn ensure_no_split_at_and_dirty_tracking_conflict(&self) -> Result<()> {
+        if let (Some(split_at), Some(dirty_tracking)) =
+            (self.split_at.as_ref(), self.dirty_tracking.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `split_at` and `dirty_tracking` parameters",
+            )
+            .into_combine(format_err!(split_at.span, "conflicting `split_at` here"))
+            .into_combine(format_err!(
+                dirty_tracking.span,
+                "conflicting `dirty_tracking` here"
+            )))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if exactly one of `PartialOrd` and `Ord` is intercepted.
+    ///
+    /// Otherwise the orderings of both traits would be inconsistent with each other.
+    fn ensure_no_ord_policy_conflict(&self) -> Result<()> {
+        let partial_ord = self.intercepts(InterceptableDerive::PartialOrd);
+        let ord = self.intercepts(InterceptableDerive::Ord);
+        if partial_ord == ord {
+            return Ok(())
+        }
+        let explicit = self
+            .intercept
+            .iter()
+            .chain(&self.passthrough)
+            .filter(|config| {
+                matches!(
+                    config.value,
+                    InterceptableDerive::PartialOrd | InterceptableDerive::Ord
+                )
+            })
+            .fold(None, |error: Option<syn::Error>, config| {
+                let note = format_err!(config.span, "`{}` configured here", config.value.name());
+                Some(match error {
+                    Some(error) => error.into_combine(note),
+                    None => note,
+                })
+            });
+        let error = format_err!(
+            Span::call_site(),
+            "encountered inconsistent `intercept` and `passthrough` parameters: \
+             `PartialOrd` and `Ord` must either both be intercepted or both be passed through",
+        );
+        Err(match explicit {
+            Some(explicit) => error.into_combine(explicit),
+            None => error,
+        })
+    }
+
+    /// Ensures that there are no conflicting configuration parameters.
+    pub fn ensure_no_conflicts(&self) -> Result<()> {
+        self.ensure_no_bits_and_repr_conflict()?;
+        self.ensure_no_bits_and_bytes_conflict()?;
+        self.ensure_no_repr_and_filled_conflict()?;
+        self.ensure_repr_endian_has_repr()?;
+        self.ensure_mmio_has_repr()?;
+        self.ensure_word_endian_has_words()?;
+        self.ensure_no_layout_variant_conflict()?;
+        self.ensure_no_hash_and_derive_hash_conflict()?;
+        self.ensure_no_dirty_tracking_and_bytes_conflict()?;
+        self.ensure_no_c_embed_and_dirty_tracking_conflict()?;
+        self.ensure_no_split_at_and_dirty_tracking_conflict()?;
+        self.ensure_no_transparent_conflict()?;
+        self.ensure_no_ord_policy_conflict()?;
+        self.ensure_no_bit_order_and_order_conflict()?;
+        self.ensure_no_compat_and_bit_order_conflict()?;
+        self.ensure_no_accessors_mod_conflict()?;
+        self.ensure_no_preset_and_new_conflict()?;
+        self.ensure_no_const_accessors_conflict()?;
+        self.ensure_no_serde_fields_and_minimal_conflict()?;
+        Ok(())
+    }
+
+    /// Returns an error if `serde = "fields"` is set together with `minimal`.
+    ///
+    /// The `Deserialize` impl writes the fields through their checked setters which are
+    /// not generated with `minimal`.
+    fn ensure_no_serde_fields_and_minimal_conflict(&self) -> Result<()> {
+        if let (Some(serde), Some(minimal)) = (&self.serde, &self.minimal) {
+            if serde.value == SerdeRepr::Fields {
+                return Err(format_err!(
+                    Span::call_site(),
+                    "encountered conflicting `serde = \"fields\"` and `minimal` parameters"
+                )
+                .into_combine(format_err!(serde.span, "conflicting `serde = \"fields\"` here"))
+                .into_combine(format_err!(minimal.span, "conflicting `minimal` here")))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `const_accessors` is set together with `access_stats` or
+    /// `accessors_mod`.
+    ///
+    /// The access counters cannot be updated in `const` contexts while the accessor wrappers
+    /// only borrow the bytes of the bitfield.
+    fn ensure_no_const_accessors_conflict(&self) -> Result<()> {
+        let const_accessors = match &self.const_accessors {
+            Some(const_accessors) => const_accessors,
+            None => return Ok(()),
+        };
+        let (name, span) = match (&self.access_stats, &self.accessors_mod) {
+            (Some(access_stats), _) => ("access_stats", access_stats.span),
+            (None, Some(accessors_mod)) => ("accessors_mod", accessors_mod.span),
+            (None, None) => return Ok(()),
+        };
+        Err(format_err!(
+            Span::call_site(),
+            "encountered conflicting `const_accessors` and `{}` parameters",
+            name,
+        )
+        .into_combine(format_err!(
+            const_accessors.span,
+            "conflicting `const_accessors` here"
+        ))
+        .into_combine(format_err!(span, "conflicting `{}` here", name)))
+    }
+
+    /// Ensures that no `preset(..)` constructor has the name of the zero initializing constructor.
+    fn ensure_no_preset_and_new_conflict(&self) -> Result<()> {
+        let new = self.new_ident();
+        if let Some(preset) = self.presets.iter().find(|preset| preset.value.name == new) {
+            return Err(format_err!(
+                preset.span,
+                "encountered `preset({} = ..)` parameter that collides with the `{}` constructor",
+                new,
+                new,
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error showing both the duplicate as well as the previous parameters.
+    fn raise_duplicate_error<T>(
+        name: &str,
+        span: Span,
+        previous: &ConfigValue<T>,
+    ) -> syn::Error
+    where
+        T: core::fmt::Debug + 'static,
+    {
+        if TypeId::of::<T>() == TypeId::of::<()>() {
+            format_err!(span, "encountered duplicate `{}` parameter", name,)
+        } else {
+            format_err!(
+                span,
+                "encountered duplicate `{}` parameter: duplicate set to {:?}",
+                name,
+                previous.value
+            )
+        }
+        .into_combine(format_err!(
+            previous.span,
+            "previous `{}` parameter here",
+            name
+        ))
+    }
+
+    /// Sets the `bytes: int` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bytes(&mut self, value: usize, span: Span) -> Result<()> {
+        match &self.bytes {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bytes", span, previous))
+            }
+            None => self.bytes = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bits: int` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bits(&mut self, value: usize, span: Span) -> Result<()> {
+        match &self.bits {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bits", span, previous))
+            }
+            None => self.bits = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `filled: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn filled(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.filled {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("filled", span, previous))
+            }
+            None => self.filled = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `uninit: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn uninit(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.uninit {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("uninit", span, previous))
+            }
+            None => self.uninit = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `new: str` #[bitfield] parameter to the given constructor name.
+    ///
+    /// # Errors
+    ///
+    /// - If the name is not an identifier.
+    /// - If the specifier has already been set.
+    pub fn set_new(&mut self, name: String, span: Span) -> Result<()> {
+        if syn::parse_str::<syn::Ident>(&name).is_err() {
+            return Err(format_err!(
+                span,
+                "encountered invalid value argument for #[bitfield] `new` parameter: expected an identifier",
+            ))
+        }
+        match &self.new {
+            Some(previous) => return Err(Self::raise_duplicate_error("new", span, previous)),
+            None => self.new = Some(ConfigValue::new(name, span)),
+        }
+        Ok(())
+    }
+
+    /// Registers a `preset(name = value)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If a preset with the same name has already been registered.
+    pub fn preset(&mut self, name: syn::Ident, value: u128, span: Span) -> Result<()> {
+        if let Some(previous) = self.presets.iter().find(|preset| preset.value.name == name) {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `preset({} = ..)` parameter",
+                name,
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous `preset({} = ..)` parameter here",
+                name,
+            )))
+        }
+        self.presets.push(ConfigValue::new(Preset { name, value }, span));
+        Ok(())
+    }
+
+    /// Registers a `#[computed(..)]` attribute of the #[bitfield] struct.
+    ///
+    /// # Errors
+    ///
+    /// If a computed getter with the same name has already been registered.
+    pub fn computed(&mut self, computed: Computed, span: Span) -> Result<()> {
+        if let Some(previous) = self
+            .computed
+            .iter()
+            .find(|previous| previous.value.name == computed.name)
+        {
+            return Err(format_err!(
+                span,
+                "encountered duplicate #[computed(name = \"{}\", ..)] attribute",
+                computed.name,
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous #[computed(name = \"{}\", ..)] attribute here",
+                computed.name,
+            )))
+        }
+        self.computed.push(ConfigValue::new(computed, span));
+        Ok(())
+    }
+
+    /// Sets the `emit_layout: str` #[bitfield] parameter to the given path.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn emit_layout(&mut self, path: String, span: Span) -> Result<()> {
+        match &self.emit_layout {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("emit_layout", span, previous))
+            }
+            None => self.emit_layout = Some(ConfigValue::new(path, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `layout_guard: str` #[bitfield] parameter to the given path.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn layout_guard(&mut self, path: String, span: Span) -> Result<()> {
+        match &self.layout_guard {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("layout_guard", span, previous))
+            }
+            None => self.layout_guard = Some(ConfigValue::new(path, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `doc_examples` #[bitfield] parameter to the given module path.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn doc_examples(&mut self, module: String, span: Span) -> Result<()> {
+        match &self.doc_examples {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("doc_examples", span, previous))
+            }
+            None => self.doc_examples = Some(ConfigValue::new(module, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `ffi_export(prefix = str)` #[bitfield] parameter to the given prefix.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn ffi_export(&mut self, prefix: String, span: Span) -> Result<()> {
+        match &self.ffi_export {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("ffi_export", span, previous))
+            }
+            None => self.ffi_export = Some(ConfigValue::new(prefix, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `split_at: str` #[bitfield] parameter to the given field name.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn split_at(&mut self, field: String, span: Span) -> Result<()> {
+        match &self.split_at {
+            Some(previous) => return Err(Self::raise_duplicate_error("split_at", span, previous)),
+            None => self.split_at = Some(ConfigValue::new(field, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `accessors_mod: str` #[bitfield] parameter to the given name.
+    ///
+    /// # Errors
+    ///
+    /// - If the name is not an identifier.
+    /// - If the specifier has already been set.
+    pub fn accessors_mod(&mut self, name: String, span: Span) -> Result<()> {
+        if syn::parse_str::<syn::Ident>(&name).is_err() {
+            return Err(format_err!(
+                span,
+                "encountered invalid value argument for #[bitfield] `accessors_mod` parameter: expected an identifier",
+            ))
+        }
+        match &self.accessors_mod {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("accessors_mod", span, previous))
+            }
+            None => self.accessors_mod = Some(ConfigValue::new(name, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `copy: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn copy(&mut self, value: bool, span: Span) -> Result<()> {
+        match &self.copy {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("copy", span, previous))
+            }
+            None => self.copy = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bit_order: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn set_bit_order(&mut self, value: BitOrder, span: Span) -> Result<()> {
+        match &self.bit_order {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bit_order", span, previous))
+            }
+            None => self.bit_order = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `order: str` #[bitfield] parameter, an alias of `bit_order`, to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn set_order(&mut self, value: BitOrder, span: Span) -> Result<()> {
+        match &self.order {
+            Some(previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `order` parameter: duplicate set to {}",
+                    previous.value.order_name(),
+                )
+                .into_combine(format_err!(previous.span, "previous `order` parameter here")))
+            }
+            None => self.order = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `serde` #[bitfield] parameter to the given representation.
+    ///
+    /// # Errors
+    ///
+    /// - If the `serde` crate feature has not been enabled.
+    /// - If the specifier has already been set.
+    pub fn serde(&mut self, value: SerdeRepr, span: Span) -> Result<()> {
+        if cfg!(not(feature = "serde")) {
+            return Err(format_err!(
+                span,
+                "the `serde` parameter requires the `serde` crate feature"
+            ))
+        }
+        match &self.serde {
+            Some(previous) => return Err(Self::raise_duplicate_error("serde", span, previous)),
+            None => self.serde = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `compat: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn set_compat(&mut self, value: Compat, span: Span) -> Result<()> {
+        match &self.compat {
+            Some(previous) => return Err(Self::raise_duplicate_error("compat", span, previous)),
+            None => self.compat = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `doc_bit_numbering: str` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn set_doc_bit_numbering(&mut self, value: BitNumbering, span: Span) -> Result<()> {
+        match &self.doc_bit_numbering {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("doc_bit_numbering", span, previous))
+            }
+            None => self.doc_bit_numbering = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `rename_collisions` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn rename_collisions(&mut self, span: Span) -> Result<()> {
+        match &self.rename_collisions {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "rename_collisions",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.rename_collisions = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `external_methods: str` #[bitfield] parameter to the given method names.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn external_methods(&mut self, names: Vec<String>, span: Span) -> Result<()> {
+        match &self.external_methods {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "external_methods",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.external_methods = Some(ConfigValue::new(names, span)),
+        }
+        Ok(())
+    }
+
+    /// Registers a method that is generated by a retained attribute of the `#[bitfield]` struct.
+    pub fn push_foreign_method(&mut self, name: String, origin: String, span: Span) {
+        self.foreign_methods
+            .push(ForeignMethod { name, origin, span });
+    }
+
+    /// Registers a derive given by an `intercept(..)` or a `passthrough(..)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the derive has already been given to either of both parameters.
+    pub fn derive_policy(
+        &mut self,
+        derive: InterceptableDerive,
+        intercept: bool,
+        span: Span,
+    ) -> Result<()> {
+        let param = |intercept: bool| if intercept { "intercept" } else { "passthrough" };
+        let previous = self
+            .intercept
+            .iter()
+            .map(|config| (config, true))
+            .chain(self.passthrough.iter().map(|config| (config, false)))
+            .find(|(config, _)| config.value == derive);
+        if let Some((previous, previous_intercept)) = previous {
+            let error = if previous_intercept == intercept {
+                format_err!(
+                    span,
+                    "encountered duplicate `{}` in #[bitfield] `{}` parameter",
+                    derive.name(),
+                    param(intercept),
+                )
+            } else {
+                format_err!(
+                    span,
+                    "encountered `{}` in both #[bitfield] `intercept` and `passthrough` parameters",
+                    derive.name(),
+                )
+            };
+            return Err(error.into_combine(format_err!(
+                previous.span,
+                "previous `{}` in `{}` parameter here",
+                derive.name(),
+                param(previous_intercept),
+            )))
+        }
+        let config = ConfigValue::new(derive, span);
+        match intercept {
+            true => self.intercept.push(config),
+            false => self.passthrough.push(config),
+        }
+        Ok(())
+    }
+
+    /// Registers a bitfield given by a `cast_to(Other)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the bitfield has already been registered.
+    pub fn cast_to(&mut self, path: syn::Path, span: Span) -> Result<()> {
+        let name = Self::path_to_string(&path);
+        if let Some(previous) = self
+            .cast_to
+            .iter()
+            .find(|previous| Self::path_to_string(&previous.value) == name)
+        {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `cast_to({})` parameter",
+                name,
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous `cast_to({})` parameter here",
+                name,
+            )))
+        }
+        self.cast_to.push(ConfigValue::new(path, span));
+        Ok(())
+    }
+
+    /// Registers a word type of the `words(..)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the word type has already been registered.
+    pub fn words(&mut self, kind: WordKind, span: Span) -> Result<()> {
+        if let Some(previous) = self.words.iter().find(|previous| previous.value == kind) {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `{}` in #[bitfield] `words` parameter",
+                kind.name(),
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous `{}` in `words` parameter here",
+                kind.name(),
+            )))
+        }
+        self.words.push(ConfigValue::new(kind, span));
+        Ok(())
+    }
+
+    /// Sets the `word_endian = "little" | "big"` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn set_word_endian(&mut self, value: ReprEndian, span: Span) -> Result<()> {
+        if let Some(previous) = &self.word_endian {
+            return Err(format_err!(span, "encountered duplicate `word_endian` parameter")
+                .into_combine(format_err!(previous.span, "previous `word_endian` parameter here")))
+        }
+        self.word_endian = Some(ConfigValue::new(value, span));
+        Ok(())
+    }
+
+    /// Returns the given path as `String` without whitespace.
+    fn path_to_string(path: &syn::Path) -> String {
+        quote::quote!(#path).to_string().replace(' ', "")
+    }
+
+    /// Sets the `hash` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn hash(&mut self, span: Span) -> Result<()> {
+        match &self.hash {
+            Some(previous) => return Err(Self::raise_duplicate_error("hash", span, previous)),
+            None => self.hash = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `unchecked_setters` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn unchecked_setters(&mut self, span: Span) -> Result<()> {
+        match &self.unchecked_setters {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "unchecked_setters",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.unchecked_setters = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bit_queries` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bit_queries(&mut self, span: Span) -> Result<()> {
+        match &self.bit_queries {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bit_queries", span, previous))
+            }
+            None => self.bit_queries = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bounds` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bounds(&mut self, span: Span) -> Result<()> {
+        match &self.bounds {
+            Some(previous) => return Err(Self::raise_duplicate_error("bounds", span, previous)),
+            None => self.bounds = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `masks` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn masks(&mut self, span: Span) -> Result<()> {
+        match &self.masks {
+            Some(previous) => return Err(Self::raise_duplicate_error("masks", span, previous)),
+            None => self.masks = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `lints` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn lints(&mut self, span: Span) -> Result<()> {
+        match &self.lints {
+            Some(previous) => return Err(Self::raise_duplicate_error("lints", span, previous)),
+            None => self.lints = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `const_accessors` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn const_accessors(&mut self, span: Span) -> Result<()> {
+        match &self.const_accessors {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("const_accessors", span, previous))
+            }
+            None => self.const_accessors = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `fault_injection` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn fault_injection(&mut self, span: Span) -> Result<()> {
+        match &self.fault_injection {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("fault_injection", span, previous))
+            }
+            None => self.fault_injection = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `neighbor_check_tests` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn neighbor_check_tests(&mut self, span: Span) -> Result<()> {
+        match &self.neighbor_check_tests {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("neighbor_check_tests", span, previous))
+            }
+            None => self.neighbor_check_tests = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `dual_order` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn dual_order(&mut self, span: Span) -> Result<()> {
+        match &self.dual_order {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("dual_order", span, previous))
+            }
+            None => self.dual_order = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `dirty_tracking` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn dirty_tracking(&mut self, span: Span) -> Result<()> {
+        match &self.dirty_tracking {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("dirty_tracking", span, previous))
+            }
+            None => self.dirty_tracking = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `c_embed` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn c_embed(&mut self, span: Span) -> Result<()> {
+        match &self.c_embed {
+            Some(previous) => return Err(Self::raise_duplicate_error("c_embed", span, previous)),
+            None => self.c_embed = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `transparent` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn transparent(&mut self, span: Span) -> Result<()> {
+        match &self.transparent {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("transparent", span, previous))
+            }
+            None => self.transparent = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `iter_fields` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn iter_fields(&mut self, span: Span) -> Result<()> {
+        match &self.iter_fields {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("iter_fields", span, previous))
+            }
+            None => self.iter_fields = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `dump` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// - If the `alloc` crate feature has not been enabled.
+    /// - If the specifier has already been set.
+    pub fn dump(&mut self, span: Span) -> Result<()> {
+        if cfg!(not(feature = "alloc")) {
+            return Err(format_err!(
+                span,
+                "the `dump` parameter requires the `alloc` crate feature"
+            ))
+        }
+        match &self.dump {
+            Some(previous) => return Err(Self::raise_duplicate_error("dump", span, previous)),
+            None => self.dump = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `bitstring` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn bitstring(&mut self, span: Span) -> Result<()> {
+        match &self.bitstring {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("bitstring", span, previous))
+            }
+            None => self.bitstring = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `no_straddle` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn no_straddle(&mut self, span: Span) -> Result<()> {
+        match &self.no_straddle {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("no_straddle", span, previous))
+            }
+            None => self.no_straddle = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `register_layout` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// - If the `registry` crate feature has not been enabled.
+    /// - If the specifier has already been set.
+    pub fn register_layout(&mut self, span: Span) -> Result<()> {
+        if cfg!(not(feature = "registry")) {
+            return Err(format_err!(
+                span,
+                "the `register_layout` parameter requires the `registry` crate feature"
+            ))
+        }
+        match &self.register_layout {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("register_layout", span, previous))
+            }
+            None => self.register_layout = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `mmio` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn mmio(&mut self, span: Span) -> Result<()> {
+        match &self.mmio {
+            Some(previous) => return Err(Self::raise_duplicate_error("mmio", span, previous)),
+            None => self.mmio = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `auto_pad` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn auto_pad(&mut self, span: Span) -> Result<()> {
+        match &self.auto_pad {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("auto_pad", span, previous))
+            }
+            None => self.auto_pad = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `contiguous` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn contiguous(&mut self, span: Span) -> Result<()> {
+        match &self.contiguous {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("contiguous", span, previous))
+            }
+            None => self.contiguous = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `fields_struct` #[bitfield] parameter with the derives of the fields struct.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn fields_struct(&mut self, derives: Vec<syn::Path>, span: Span) -> Result<()> {
+        match &self.fields_struct {
+            Some(previous) => {
+                return Err(format_err!(span, "encountered duplicate `fields_struct` parameter")
+                    .into_combine(format_err!(
+                        previous.span,
+                        "previous `fields_struct` parameter here"
+                    )))
+            }
+            None => self.fields_struct = Some(ConfigValue::new(derives, span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `access_stats` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn access_stats(&mut self, span: Span) -> Result<()> {
+        match &self.access_stats {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("access_stats", span, previous))
+            }
+            None => self.access_stats = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `minimal` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn minimal(&mut self, span: Span) -> Result<()> {
+        match &self.minimal {
+            Some(previous) => return Err(Self::raise_duplicate_error("minimal", span, previous)),
+            None => self.minimal = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Sets the `keep_phantom_fields` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the specifier has already been set.
+    pub fn keep_phantom_fields(&mut self, span: Span) -> Result<()> {
+        match &self.keep_phantom_fields {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "keep_phantom_fields",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.keep_phantom_fields = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[repr(uN)]` attribute has already been found.
+    pub fn repr(&mut self, value: ReprKind, span: Span) -> Result<()> {
+        match &self.repr {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("#[repr(uN)]", span, previous))
+            }
+            None => self.repr = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[repr_endian = ".."]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[repr_endian = ".."]` attribute has already been found.
+    pub fn set_repr_endian(&mut self, value: ReprEndian, span: Span) -> Result<()> {
+        match &self.repr_endian {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[repr_endian = \"..\"]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.repr_endian = Some(ConfigValue::new(value, span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(Debug)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Debug)]` attribute has already been found.
+    pub fn derive_debug(&mut self, span: Span) -> Result<()> {
+        match &self.derive_debug {
+            Some(previous) => {
                 return Err(Self::raise_duplicate_error(
                     "#[derive(Debug)]",
                     span,
@@ -268,6 +1948,108 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(defmt::Format)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(defmt::Format)]` attribute has already been found.
+    pub fn derive_defmt(&mut self, span: Span) -> Result<()> {
+        match &self.derive_defmt {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(defmt::Format)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_defmt = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(PartialOrd)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(PartialOrd)]` attribute has already been found.
+    pub fn derive_partial_ord(&mut self, span: Span) -> Result<()> {
+        match &self.derive_partial_ord {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(PartialOrd)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_partial_ord = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(Ord)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Ord)]` attribute has already been found.
+    pub fn derive_ord(&mut self, span: Span) -> Result<()> {
+        match &self.derive_ord {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("#[derive(Ord)]", span, previous))
+            }
+            None => self.derive_ord = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(Copy)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Copy)]` attribute has already been found.
+    pub fn derive_copy(&mut self, span: Span) -> Result<()> {
+        match &self.derive_copy {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("#[derive(Copy)]", span, previous))
+            }
+            None => self.derive_copy = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(Hash)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Hash)]` attribute has already been found.
+    pub fn derive_hash(&mut self, span: Span) -> Result<()> {
+        match &self.derive_hash {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error("#[derive(Hash)]", span, previous))
+            }
+            None => self.derive_hash = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers an intercepted `#[derive(Default)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Default)]` attribute has already been found.
+    pub fn derive_default(&mut self, span: Span) -> Result<()> {
+        match &self.derive_default {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(Default)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_default = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[derive(BitfieldSpecifier)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
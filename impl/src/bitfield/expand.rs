@@ -1,7 +1,19 @@
 use super::{
     config::{
+        BitOrder,
+        Computed,
         Config,
+        ConfigValue,
+        InterceptableDerive,
+        ReprEndian,
         ReprKind,
+        SerdeRepr,
+    },
+    field_config::{
+        camel_case,
+        snake_case,
+        Quantity,
+        Validate,
     },
     field_info::FieldInfo,
     BitfieldStruct,
@@ -10,7 +22,7 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::{
     format_ident,
     quote,
-    quote_spanned,
+    ToTokens as _,
 };
 use syn::{
     self,
@@ -24,26 +36,96 @@ impl BitfieldStruct {
     pub fn expand(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let check_filled = self.generate_check_for_filled(config);
+        let check_contiguous = self.generate_check_for_contiguous(config);
         let struct_definition = self.generate_struct(config);
         let constructor_definition = self.generate_constructor(config);
         let specifier_impl = self.generate_specifier_impl(config);
+        let copy_check = self.generate_copy_check(config);
 
         let byte_conversion_impls = self.expand_byte_conversion_impls(config);
+        let word_conversions = self.generate_word_conversions(config);
         let getters_and_setters = self.expand_getters_and_setters(config);
         let bytes_check = self.expand_optional_bytes_check(config);
         let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
         let debug_impl = self.generate_debug_impl(config);
+        let defmt_impl = self.generate_defmt_impl(config);
+        let partial_ord_impl = self.generate_partial_ord_impl(config);
+        let ord_impl = self.generate_ord_impl(config);
+        let hash_impl = self.generate_hash_impl(config);
+        let default_impl = self.generate_default_impl(config);
+        let cast_impls = self.generate_cast_impls(config);
+        let distribution_impl = self.generate_distribution_impl(config);
+        let rest_methods = self.generate_rest_methods(config);
+        let dirty_tracking = self.generate_dirty_tracking(config);
+        let c_embed = self.generate_c_embed(config);
+        let dump = self.generate_dump(config);
+        let bitstring = self.generate_bitstring(config);
+        let serde_impls = self.generate_serde_impls(config);
+        let iter_fields = self.generate_iter_fields(config);
+        let fields_struct = self.generate_fields_struct(config);
+        let mmio_register = self.generate_mmio_register(config);
+        let access_stats = self.generate_access_stats(config);
+        let groups = self.generate_groups(config);
+        let computed_getters = self.generate_computed_getters(config);
+        let bitvec_methods = self.generate_bitvec_methods(config);
+        let split_mut = self.generate_split_mut(config);
+        let from_bytes_checked = self.generate_from_bytes_checked(config);
+        let payload_accessors = self.generate_payload_accessors(config);
+        let ffi_exports = self.generate_ffi_exports(config);
+        let fault_injection = self.generate_fault_injection(config);
+        let neighbor_check_tests = self.generate_neighbor_check_tests(config);
+        let modify_fields = self.generate_modify_fields(config);
+        let layout_version = self.generate_layout_version(config);
+        let layout_variant = self.generate_layout_variant(config);
+        let layout_registration = self.generate_layout_registration(config);
+        let lints = self.generate_lints(config);
+        let no_straddle_checks = self.generate_no_straddle_checks(config);
 
         quote_spanned!(span=>
             #struct_definition
             #check_filled
+            #check_contiguous
             #constructor_definition
             #byte_conversion_impls
+            #word_conversions
             #getters_and_setters
             #specifier_impl
+            #copy_check
             #bytes_check
             #repr_impls_and_checks
             #debug_impl
+            #defmt_impl
+            #partial_ord_impl
+            #ord_impl
+            #hash_impl
+            #default_impl
+            #cast_impls
+            #distribution_impl
+            #rest_methods
+            #dirty_tracking
+            #c_embed
+            #dump
+            #bitstring
+            #serde_impls
+            #iter_fields
+            #fields_struct
+            #mmio_register
+            #access_stats
+            #groups
+            #computed_getters
+            #bitvec_methods
+            #split_mut
+            #from_bytes_checked
+            #payload_accessors
+            #ffi_exports
+            #fault_injection
+            #neighbor_check_tests
+            #modify_fields
+            #layout_version
+            #layout_variant
+            #layout_registration
+            #lints
+            #no_straddle_checks
         )
     }
 
@@ -54,9 +136,36 @@ impl BitfieldStruct {
     pub fn generate_specifier_impl(&self, config: &Config) -> Option<TokenStream2> {
         config.derive_specifier.as_ref()?;
         let span = self.item_struct.span();
+        let extra_field_inits = self.extra_field_inits(config);
         let ident = &self.item_struct.ident;
         let bits = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&bits);
+        let (into_bytes, from_bytes) = match config.bit_order() {
+            BitOrder::Le => {
+                (
+                    quote_spanned!(span=>
+                        <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::array_into_bytes(
+                            value.bytes
+                        )
+                    ),
+                    quote_spanned!(span=>
+                        <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
+                    ),
+                )
+            }
+            BitOrder::Be => {
+                (
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::read_specifier_be::<Self>(&value.bytes[..], 0usize)
+                    ),
+                    quote_spanned!(span=> {
+                        let mut __bf_array = [0x00_u8; #next_divisible_by_8 / 8usize];
+                        ::modular_bitfield::private::write_specifier_be::<Self>(&mut __bf_array[..], 0usize, bytes);
+                        __bf_array
+                    }),
+                )
+            }
+        };
         Some(quote_spanned!(span =>
             #[allow(clippy::identity_op)]
             const _: () = {
@@ -77,11 +186,7 @@ impl BitfieldStruct {
                 fn into_bytes(
                     value: Self::InOut,
                 ) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
-                    ::core::result::Result::Ok(
-                        <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::array_into_bytes(
-                            value.bytes
-                        )
-                    )
+                    ::core::result::Result::Ok(#into_bytes)
                 }
 
                 #[inline]
@@ -97,39 +202,88 @@ impl BitfieldStruct {
                     }
                     let __bf_bytes = bytes.to_le_bytes();
                     ::core::result::Result::Ok(Self {
-                        bytes: <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
+                        bytes: #from_bytes,
+                        #extra_field_inits
                     })
                 }
             }
         ))
     }
 
+    /// Generates a check that a `#[derive(Copy)]` bitfield requires at most 64 bytes.
+    ///
+    /// Larger bitfields are reported as a deprecation warning via `Lint<true>`.
+    ///
+    /// Returns `None` if there is no `#[derive(Copy)]` or if the `copy` parameter is set.
+    fn generate_copy_check(&self, config: &Config) -> Option<TokenStream2> {
+        let derive_copy = config.derive_copy.as_ref()?;
+        if config.copy_enabled().is_some() {
+            return None
+        }
+        let span = derive_copy.span;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        Some(quote_spanned!(span=>
+            #[allow(unused_parens, clippy::identity_op)]
+            const _: () = {
+                ::modular_bitfield::private::Lint::<{
+                    (#next_divisible_by_8) / 8usize > 64usize
+                }>::large_copy_bitfield();
+            };
+        ))
+    }
+
     /// Generates the core::fmt::Debug impl if `#[derive(Debug)]` is included.
     pub fn generate_debug_impl(&self, config: &Config) -> Option<TokenStream2> {
         config.derive_debug.as_ref()?;
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let fields = self.field_infos(config).map(|info| {
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let fields = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let field_value = self.checked_field_value(&offset, &info, config, &quote!(self));
+            let element_values = info
+                .elements(&offset)
+                .into_iter()
+                .map(|(offset, _)| self.expand_checked_read(&offset, &info, config, &quote!(self)))
+                .collect::<Vec<_>>();
             let FieldInfo {
                 index: _,
                 field,
                 config,
             } = &info;
+            let field_span = field.span();
+            let field_name = info.name();
+            if config.is_reserved() {
+                // Reserved fields are shown with their raw bits.
+                let ty = &field.ty;
+                return Some(quote_spanned!(field_span=>
+                    .field(
+                        #field_name,
+                        &::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
+                    )
+                ))
+            }
             if config.skip_getters() {
                 return None
             }
-            let field_span = field.span();
-            let field_name = info.name();
-            let field_ident = info.ident_frag();
-            let field_getter = field
-                .ident
-                .as_ref()
-                .map(|_| format_ident!("{}_or_err", field_ident))
-                .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+            if config.repeat.is_some() {
+                // Repeated fields are shown as the list of the values of their elements.
+                let elements = element_values.iter().map(|element_value| {
+                    quote_spanned!(field_span=>
+                        #element_value
+                            .as_ref()
+                            .map(|__bf_field| __bf_field as &dyn (::core::fmt::Debug))
+                            .unwrap_or_else(|__bf_err| __bf_err as &dyn (::core::fmt::Debug))
+                    )
+                });
+                return Some(quote_spanned!(field_span=>
+                    .field(#field_name, &[ #( #elements ),* ])
+                ))
+            }
             Some(quote_spanned!(field_span=>
                 .field(
                     #field_name,
-                    self.#field_getter()
+                    #field_value
                         .as_ref()
                         .map(|__bf_field| __bf_field as &dyn (::core::fmt::Debug))
                         .unwrap_or_else(|__bf_err| __bf_err as &dyn (::core::fmt::Debug))
@@ -138,6 +292,7 @@ impl BitfieldStruct {
         });
         Some(quote_spanned!(span=>
             impl ::core::fmt::Debug for #ident {
+                #[allow(clippy::identity_op)]
                 fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                     __bf_f.debug_struct(::core::stringify!(#ident))
                         #( #fields )*
@@ -147,392 +302,4082 @@ impl BitfieldStruct {
         ))
     }
 
-    /// Generates the expression denoting the sum of all field bit specifier sizes.
-    ///
-    /// # Example
-    ///
-    /// For the following struct:
-    ///
-    /// ```
-    /// # use modular_bitfield::prelude::*;
-    /// #[bitfield]
-    /// pub struct Color {
-    ///     r: B8,
-    ///     g: B8,
-    ///     b: B8,
-    ///     a: bool,
-    ///     rest: B7,
-    /// }
-    /// ```
-    ///
-    /// We generate the following tokens:
-    ///
-    /// ```
-    /// # use modular_bitfield::prelude::*;
-    /// {
-    ///     0usize +
-    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
-    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
-    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
-    ///     <bool as ::modular_bitfield::Specifier>::BITS +
-    ///     <B7 as ::modular_bitfield::Specifier>::BITS
-    /// }
-    /// # ;
-    /// ```
+    /// Generates the `defmt::Format` impl if `#[derive(defmt::Format)]` is intercepted.
     ///
-    /// Which is a compile time evaluatable expression.
-    fn generate_bitfield_size(&self) -> TokenStream2 {
+    /// Mirrors the `Debug` impl: fields are written one by one with their decoded values
+    /// or their invalid bit patterns, and reserved fields with their raw bits.
+    pub fn generate_defmt_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_defmt.as_ref()?;
         let span = self.item_struct.span();
-        let sum = self
-            .item_struct
-            .fields
-            .iter()
-            .map(|field| {
-                let span = field.span();
-                let ty = &field.ty;
-                quote_spanned!(span=>
-                    <#ty as ::modular_bitfield::Specifier>::BITS
+        let ident = &self.item_struct.ident;
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let mut separator = "";
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.is_reserved() || !info.config.skip_getters())
+            .map(|(info, offset)| {
+                let field_span = info.field.span();
+                if info.config.repeat.is_some() {
+                    // Repeated fields are written as the list of the values of their elements.
+                    let open = format!("{}{}: [", separator, info.name());
+                    separator = ", ";
+                    let elements = info.elements(&offset).into_iter().enumerate().map(
+                        |(index, (offset, _))| {
+                            let format = match index {
+                                0 => "{}",
+                                _ => ", {}",
+                            };
+                            let element_value =
+                                self.expand_checked_read(&offset, &info, config, &quote!(self));
+                            quote_spanned!(field_span=>
+                                match #element_value {
+                                    ::core::result::Result::Ok(__bf_field) => {
+                                        ::defmt::write!(__bf_f, #format, __bf_field)
+                                    }
+                                    ::core::result::Result::Err(__bf_err) => {
+                                        ::defmt::write!(__bf_f, #format, __bf_err)
+                                    }
+                                }
+                            )
+                        },
+                    );
+                    return quote_spanned!(field_span=>
+                        ::defmt::write!(__bf_f, #open);
+                        #( #elements )*
+                        ::defmt::write!(__bf_f, "]");
+                    )
+                }
+                let format = format!("{}{}: {{}}", separator, info.name());
+                separator = ", ";
+                if info.config.is_reserved() {
+                    let ty = &info.field.ty;
+                    return quote_spanned!(field_span=>
+                        ::defmt::write!(
+                            __bf_f,
+                            #format,
+                            ::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
+                        );
+                    )
+                }
+                let field_value = self.checked_field_value(&offset, &info, config, &quote!(self));
+                quote_spanned!(field_span=>
+                    match #field_value {
+                        ::core::result::Result::Ok(__bf_field) => {
+                            ::defmt::write!(__bf_f, #format, __bf_field)
+                        }
+                        ::core::result::Result::Err(__bf_err) => {
+                            ::defmt::write!(__bf_f, #format, __bf_err)
+                        }
+                    }
                 )
             })
-            .fold(quote_spanned!(span=> 0usize), |lhs, rhs| {
-                quote_spanned!(span =>
-                    #lhs + #rhs
-                )
-            });
-        quote_spanned!(span=>
-            { #sum }
-        )
+            .collect::<Vec<_>>();
+        let open = format!("{} {{{{ ", ident);
+        Some(quote_spanned!(span=>
+            impl ::defmt::Format for #ident {
+                #[allow(clippy::identity_op)]
+                fn format(&self, __bf_f: ::defmt::Formatter<'_>) {
+                    ::defmt::write!(__bf_f, #open);
+                    #( #fields )*
+                    ::defmt::write!(__bf_f, " }}");
+                }
+            }
+        ))
     }
 
-    /// Generates the expression denoting the actual configured or implied bit width.
-    fn generate_target_or_actual_bitfield_size(&self, config: &Config) -> TokenStream2 {
-        config
-            .bits
-            .as_ref()
-            .map(|bits_config| {
-                let span = bits_config.span;
-                let value = bits_config.value;
-                quote_spanned!(span=>
-                    #value
-                )
+    /// Returns the checked values of all fields taking part in comparisons
+    /// for both `self` and `__bf_other`.
+    ///
+    /// Fields with skipped getters do not take part. Their bits are only compared by the
+    /// raw bytes that break ties between otherwise equal bitfields.
+    /// Fields with `#[repeat = N]` take part with the values of their elements in order.
+    fn comparable_field_values<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> impl Iterator<Item = (proc_macro2::Span, TokenStream2, TokenStream2)> + 'a {
+        self.field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_getters())
+            .flat_map(move |(info, offset)| {
+                let span = info.field.span();
+                if info.config.repeat.is_none() {
+                    return vec![(
+                        span,
+                        self.checked_field_value(&offset, &info, config, &quote!(self)),
+                        self.checked_field_value(&offset, &info, config, &quote!(__bf_other)),
+                    )]
+                }
+                info.elements(&offset)
+                    .into_iter()
+                    .map(|(offset, _)| {
+                        (
+                            span,
+                            self.expand_checked_read(&offset, &info, config, &quote!(self)),
+                            self.expand_checked_read(&offset, &info, config, &quote!(__bf_other)),
+                        )
+                    })
+                    .collect()
             })
-            .unwrap_or_else(|| self.generate_bitfield_size())
     }
 
-    /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
+    /// Returns the given field with its offset, its name and its checked value or,
+    /// for a field with `#[repeat = N]`, each of its elements with theirs.
     ///
-    /// - ... equal to `N`, if `filled = true` or
-    /// - ... smaller than `N`, if `filled = false`
-    fn generate_filled_check_for_unaligned_bits(
+    /// Used by `dump` and `iter_fields` which show the elements of repeated fields
+    /// as if they were individual fields named `f[i]`.
+    fn field_or_element_values<'a>(
         &self,
+        info: FieldInfo<'a>,
+        offset: Punctuated<syn::Expr, syn::Token![+]>,
         config: &Config,
-        required_bits: usize,
+    ) -> Vec<(FieldInfo<'a>, Punctuated<syn::Expr, syn::Token![+]>, String, TokenStream2)> {
+        let elements = info.elements(&offset);
+        if elements.is_empty() {
+            let value = self.checked_field_value(&offset, &info, config, &quote!(self));
+            let name = info.name();
+            return vec![(info, offset, name, value)]
+        }
+        elements
+            .into_iter()
+            .map(|(offset, name)| {
+                let value = self.expand_checked_read(&offset, &info, config, &quote!(self));
+                (info.clone(), offset, name, value)
+            })
+            .collect()
+    }
+
+    /// Returns an expression evaluating to the checked value of the field of `receiver`.
+    ///
+    /// Uses the checked getter unless it is not generated due to `minimal`.
+    fn checked_field_value(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+        receiver: &TokenStream2,
+    ) -> TokenStream2 {
+        if config.minimal.is_some() {
+            return self.expand_checked_read(offset, info, config, receiver)
+        }
+        let span = info.field.span();
+        let field_getter = config.method_ident(info.checked_getter_ident());
+        let receiver = Self::accessors_receiver(config, receiver, false);
+        quote_spanned!(span=> #receiver.#field_getter())
+    }
+
+    /// Returns an expression reading the field of `receiver` and decoding it
+    /// into a `Result` of its value or the invalid bit pattern.
+    fn expand_checked_read(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+        receiver: &TokenStream2,
     ) -> TokenStream2 {
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let from_bytes = Self::field_value_from_bytes(info);
+        let record_read = self.record_access(info, config, "record_read");
+        quote_spanned!(span=> {
+            #record_read
+            let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                ::modular_bitfield::private::#read_specifier::<#ty>(&#receiver.bytes[..], #offset)
+            };
+            #from_bytes
+        })
+    }
+
+    /// Generates `to_bitstring()` rendering the bytes of the bitfield as binary literals if
+    /// the `bitstring` parameter is set.
+    pub fn generate_bitstring(&self, config: &Config) -> Option<TokenStream2> {
+        config.bitstring.as_ref()?;
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
-        let check_ident = match config.filled_enabled() {
-            true => quote_spanned!(span => CheckFillsUnalignedBits),
-            false => quote_spanned!(span => CheckDoesNotFillUnalignedBits),
-        };
-        let comparator = match config.filled_enabled() {
-            true => quote! { == },
-            false => quote! { > },
-        };
-        quote_spanned!(span=>
-            #[allow(clippy::identity_op)]
-            const _: () = {
-                impl ::modular_bitfield::private::checks::#check_ident for #ident {
-                    type CheckType = [(); (#required_bits #comparator #actual_bits) as usize];
+        let to_bitstring_ident = config.method_ident(format_ident!("to_bitstring"));
+        let offsets = self
+            .field_infos_with_offsets(config)
+            .map(|(_, offset)| quote_spanned!(span=> #offset));
+        let msb0 = matches!(config.bit_order(), BitOrder::Be);
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns the bytes of the bitfield rendered as binary literals,
+                /// e.g. `0b0101_0011 0b1110_0000`.
+                ///
+                /// With the alternate flag `{:#}` the boundaries between the fields
+                /// are marked with `|` instead of separating the nibbles with `_`.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn #to_bitstring_ident(&self) -> impl ::core::fmt::Display + '_ {
+                    ::modular_bitfield::private::Bitstring::new(
+                        &self.bytes[..],
+                        [#( #offsets ),*],
+                        #msb0,
+                    )
                 }
-            };
-        )
+            }
+        ))
     }
 
-    /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
+    /// Generates the `Serialize` and `Deserialize` impls if the `serde` parameter is set.
     ///
-    /// - ... divisible by 8, if `filled = true` or
-    /// - ... not divisible by 8, if `filled = false`
-    fn generate_filled_check_for_aligned_bits(&self, config: &Config) -> TokenStream2 {
-        let span = self.item_struct.span();
+    /// With `serde = "bytes"` the bitfield is represented by its bytes and with
+    /// `serde = "fields"` by a struct with the values of all fields that have both
+    /// getters and setters.
+    pub fn generate_serde_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let serde = config.serde.as_ref()?;
+        let span = serde.span;
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
-        let check_ident = match config.filled_enabled() {
-            true => quote_spanned!(span => CheckTotalSizeMultipleOf8),
-            false => quote_spanned!(span => CheckTotalSizeIsNotMultipleOf8),
+        let (serialize, deserialize) = match serde.value {
+            SerdeRepr::Bytes => self.expand_serde_bytes(config),
+            SerdeRepr::Fields => self.expand_serde_fields(config),
         };
-        quote_spanned!(span=>
-            #[allow(clippy::identity_op)]
-            const _: () = {
-                impl ::modular_bitfield::private::checks::#check_ident for #ident {
-                    type Size = ::modular_bitfield::private::checks::TotalSize<[(); #actual_bits % 8usize]>;
+        Some(quote_spanned!(span=>
+            impl ::modular_bitfield::private::serde::Serialize for #ident {
+                #[allow(clippy::identity_op)]
+                fn serialize<__BfS>(
+                    &self,
+                    __bf_serializer: __BfS,
+                ) -> ::core::result::Result<__BfS::Ok, __BfS::Error>
+                where
+                    __BfS: ::modular_bitfield::private::serde::Serializer,
+                {
+                    #serialize
                 }
-            };
-        )
-    }
+            }
 
-    /// Generate check for either of the following two cases:
-    ///
-    /// - `filled = true`: Check if the total number of required bits is
-    ///         - ... the same as `N` if `bits = N` was provided or
-    ///         - ... a multiple of 8, otherwise
-    /// - `filled = false`: Check if the total number of required bits is
-    ///         - ... smaller than `N` if `bits = N` was provided or
-    ///         - ... NOT a multiple of 8, otherwise
-    fn generate_check_for_filled(&self, config: &Config) -> TokenStream2 {
-        match config.bits.as_ref() {
-            Some(bits_config) => {
-                self.generate_filled_check_for_unaligned_bits(config, bits_config.value)
+            impl<'de> ::modular_bitfield::private::serde::Deserialize<'de> for #ident {
+                #[allow(clippy::identity_op)]
+                fn deserialize<__BfD>(
+                    __bf_deserializer: __BfD,
+                ) -> ::core::result::Result<Self, __BfD::Error>
+                where
+                    __BfD: ::modular_bitfield::private::serde::Deserializer<'de>,
+                {
+                    #deserialize
+                }
             }
-            None => self.generate_filled_check_for_aligned_bits(config),
-        }
+        ))
     }
 
-    /// Returns a token stream representing the next greater value divisible by 8.
-    fn next_divisible_by_8(value: &TokenStream2) -> TokenStream2 {
-        let span = value.span();
-        quote_spanned!(span=> {
-            (((#value - 1) / 8) + 1) * 8
-        })
+    /// Returns the bodies of `serialize` and `deserialize` for `serde = "bytes"`.
+    ///
+    /// The bytes are checked the same as by `from_bytes` and `from_bytes_checked`.
+    fn expand_serde_bytes(&self, config: &Config) -> (TokenStream2, TokenStream2) {
+        let span = self.item_struct.span();
+        let from_bytes_ident = config.method_ident(format_ident!("from_bytes"));
+        let from_bytes_checked_ident = config.method_ident(format_ident!("from_bytes_checked"));
+        let serialize = quote_spanned!(span=>
+            ::modular_bitfield::private::serialize_bytes(&self.bytes, __bf_serializer)
+        );
+        let custom = quote_spanned!(span=>
+            <__BfD::Error as ::modular_bitfield::private::serde::de::Error>::custom
+        );
+        let construct = match (self.has_validated_fields(config), config.filled_enabled()) {
+            (true, _) => {
+                quote_spanned!(span=> Self::#from_bytes_checked_ident(__bf_bytes).map_err(#custom))
+            }
+            (false, true) => {
+                quote_spanned!(span=> ::core::result::Result::Ok(Self::#from_bytes_ident(__bf_bytes)))
+            }
+            (false, false) => {
+                quote_spanned!(span=> Self::#from_bytes_ident(__bf_bytes).map_err(#custom))
+            }
+        };
+        let deserialize = quote_spanned!(span=>
+            let __bf_bytes = ::modular_bitfield::private::deserialize_bytes(__bf_deserializer)?;
+            #construct
+        );
+        (serialize, deserialize)
     }
 
-    /// Generates the actual item struct definition for the `#[bitfield]`.
+    /// Returns the bodies of `serialize` and `deserialize` for `serde = "fields"`.
     ///
-    /// Internally it only contains a byte array equal to the minimum required
-    /// amount of bytes to compactly store the information of all its bit fields.
-    fn generate_struct(&self, config: &Config) -> TokenStream2 {
+    /// The fields are serialized as a struct named after the bitfield. Deserialization
+    /// starts from a zeroed bitfield and writes the fields through their checked setters.
+    fn expand_serde_fields(&self, config: &Config) -> (TokenStream2, TokenStream2) {
         let span = self.item_struct.span();
-        let attrs = &config.retained_attributes;
-        let vis = &self.item_struct.vis;
         let ident = &self.item_struct.ident;
-        let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
-        quote_spanned!(span=>
-            #( #attrs )*
-            #[allow(clippy::identity_op)]
-            #vis struct #ident
-            {
-                bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+        let struct_name = ident.to_string();
+        let new_ident = config.new_ident();
+        let receiver = Self::accessors_receiver(config, &quote!(__bf_value), true);
+        let infos = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_getters() && !info.config.skip_setters())
+            .collect::<Vec<_>>();
+        let len = infos.len();
+        let serialize_fields = infos.iter().map(|(info, offset)| {
+            let field_span = info.field.span();
+            let name = info.name();
+            let message = format!(
+                "value contains invalid bit pattern for field {}.{}",
+                ident, name
+            );
+            let checked_read = |offset| {
+                let checked_read = self.expand_checked_read(offset, info, config, &quote!(self));
+                quote_spanned!(field_span=>
+                    #checked_read.map_err(|_| {
+                        <__BfS::Error as ::modular_bitfield::private::serde::ser::Error>::custom(
+                            #message,
+                        )
+                    })?
+                )
+            };
+            let field_value = match info.config.repeat {
+                Some(_) => {
+                    // Repeated fields are serialized as a tuple of the values of their elements.
+                    let elements = info.elements(offset);
+                    let elements = elements.iter().map(|(offset, _)| checked_read(offset));
+                    quote_spanned!(field_span=>
+                        ::modular_bitfield::private::SerdeArray([ #( #elements ),* ])
+                    )
+                }
+                None => checked_read(offset),
+            };
+            quote_spanned!(field_span=>
+                let __bf_field = #field_value;
+                ::modular_bitfield::private::serde::ser::SerializeStruct::serialize_field(
+                    &mut __bf_state,
+                    #name,
+                    &__bf_field,
+                )?;
+            )
+        });
+        let serialize = quote_spanned!(span=>
+            let mut __bf_state = ::modular_bitfield::private::serde::Serializer::serialize_struct(
+                __bf_serializer,
+                #struct_name,
+                #len,
+            )?;
+            #( #serialize_fields )*
+            ::modular_bitfield::private::serde::ser::SerializeStruct::end(__bf_state)
+        );
+        let helper_idents = infos
+            .iter()
+            .map(|(info, _)| format_ident!("__bf_field_{}", info.index))
+            .collect::<Vec<_>>();
+        let helper_fields = infos.iter().zip(&helper_idents).map(|((info, _), helper_ident)| {
+            let field_span = info.field.span();
+            let name = info.name();
+            let in_out = Self::field_in_out_type(info);
+            let ty = match &info.config.repeat {
+                Some(repeat) => {
+                    let repetitions = repeat.value;
+                    quote_spanned!(field_span=>
+                        ::modular_bitfield::private::SerdeArray<#in_out, #repetitions>
+                    )
+                }
+                None => quote_spanned!(field_span=> #in_out),
+            };
+            quote_spanned!(field_span=>
+                #[serde(rename = #name)]
+                #helper_ident: #ty
+            )
+        });
+        let writes = infos.iter().zip(&helper_idents).map(|((info, _), helper_ident)| {
+            let field_span = info.field.span();
+            let message = format!("value out of bounds for field {}.{}", ident, info.name());
+            let map_err = quote_spanned!(field_span=>
+                map_err(|_| {
+                    <__BfD::Error as ::modular_bitfield::private::serde::de::Error>::custom(#message)
+                })
+            );
+            if info.config.repeat.is_some() {
+                let set_at_ident = match info.config.array {
+                    true => format_ident!("set_{}_checked", info.ident_frag()),
+                    false => format_ident!("set_{}_at", info.ident_frag()),
+                };
+                let set_at_ident = config.method_ident(set_at_ident);
+                return quote_spanned!(field_span=>
+                    for (__bf_index, __bf_element) in
+                        ::core::iter::Iterator::enumerate(
+                            ::core::iter::IntoIterator::into_iter(__bf_fields.#helper_ident.0),
+                        )
+                    {
+                        #receiver.#set_at_ident(__bf_index, __bf_element).#map_err?;
+                    }
+                )
             }
-        )
+            let set_checked_ident =
+                config.method_ident(format_ident!("set_{}_checked", info.ident_frag()));
+            quote_spanned!(field_span=>
+                #receiver.#set_checked_ident(__bf_fields.#helper_ident).#map_err?;
+            )
+        });
+        let deserialize = quote_spanned!(span=>
+            #[derive(::modular_bitfield::private::serde::Deserialize)]
+            #[serde(
+                crate = "::modular_bitfield::private::serde",
+                rename = #struct_name,
+                deny_unknown_fields,
+            )]
+            struct __BfFields {
+                #( #helper_fields ),*
+            }
+
+            let __bf_fields =
+                <__BfFields as ::modular_bitfield::private::serde::Deserialize>::deserialize(
+                    __bf_deserializer,
+                )?;
+            let mut __bf_value = Self::#new_ident();
+            #( #writes )*
+            ::core::result::Result::Ok(__bf_value)
+        );
+        (serialize, deserialize)
     }
 
-    /// Generates the constructor for the bitfield that initializes all bytes to zero.
-    fn generate_constructor(&self, config: &Config) -> TokenStream2 {
+    /// Generates the `dump_to` and `dump` methods if the `dump` parameter is set.
+    ///
+    /// Fields with skipped getters are not dumped while reserved fields are dumped
+    /// with their raw bits as their decoded value. The elements of fields with
+    /// `#[repeat = N]` are dumped as fields of their own.
+    pub fn generate_dump(&self, config: &Config) -> Option<TokenStream2> {
+        config.dump.as_ref()?;
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
-        quote_spanned!(span=>
-            impl #ident
-            {
-                /// Returns an instance with zero initialized data.
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let dump_to_ident = config.method_ident(format_ident!("dump_to"));
+        let dump_ident = config.method_ident(format_ident!("dump"));
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.is_reserved() || !info.config.skip_getters())
+            .flat_map(|(info, offset)| self.field_or_element_values(info, offset, config))
+            .map(|(info, offset, name, value)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let bits = quote_spanned!(field_span=> <#ty as ::modular_bitfield::Specifier>::BITS);
+                let raw = quote_spanned!(field_span=>
+                    ::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
+                );
+                let decoded = match info.config.is_reserved() {
+                    true => quote_spanned!(field_span=> &#raw),
+                    false => {
+                        quote_spanned!(field_span=>
+                            #value
+                                .as_ref()
+                                .map(|__bf_field| __bf_field as &dyn ::core::fmt::Debug)
+                                .unwrap_or_else(|__bf_err| __bf_err as &dyn ::core::fmt::Debug)
+                        )
+                    }
+                };
+                let column = quote_spanned!(field_span=> (#name, #offset, #bits));
+                let row = quote_spanned!(field_span=>
+                    ::modular_bitfield::private::dump_row(
+                        __bf_w,
+                        &__bf_widths,
+                        #name,
+                        #offset,
+                        #bits,
+                        ::core::convert::Into::<::core::primitive::u128>::into(#raw),
+                        #decoded,
+                    )?;
+                );
+                (column, row)
+            })
+            .collect::<Vec<_>>();
+        let columns = fields.iter().map(|(column, _)| column);
+        let rows = fields.iter().map(|(_, row)| row);
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Writes an aligned table of the fields of the bitfield to the given writer.
+                ///
+                /// Every field is written on its own line with its name, its bit range,
+                /// its raw value in hexadecimal and its decoded value.
+                ///
+                /// # Errors
+                ///
+                /// If writing to the given writer fails.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn #dump_to_ident<__BfW>(&self, __bf_w: &mut __BfW) -> ::core::fmt::Result
+                where
+                    __BfW: ::core::fmt::Write + ?::core::marker::Sized,
+                {
+                    let __bf_widths = ::modular_bitfield::private::DumpWidths::new(&[
+                        #( #columns ),*
+                    ]);
+                    #( #rows )*
+                    ::core::result::Result::Ok(())
+                }
+
+                /// Returns an aligned table of the fields of the bitfield.
+                ///
+                /// Every field is written on its own line with its name, its bit range,
+                /// its raw value in hexadecimal and its decoded value.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #dump_ident(&self) -> ::modular_bitfield::private::String {
+                    let mut __bf_dump = ::modular_bitfield::private::String::new();
+                    self.#dump_to_ident(&mut __bf_dump)
+                        .expect("writing to a String cannot fail");
+                    __bf_dump
+                }
+            }
+        ))
+    }
+
+    /// Generates the `iter_fields` method and the `IntoIterator` impl for references
+    /// if the `iter_fields` parameter is set.
+    ///
+    /// Like for `dump` fields with skipped getters are not yielded while reserved fields
+    /// are yielded with their raw bits as their decoded value and the elements of fields
+    /// with `#[repeat = N]` are yielded as fields of their own.
+    pub fn generate_iter_fields(&self, config: &Config) -> Option<TokenStream2> {
+        config.iter_fields.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let iter_fields_ident = config.method_ident(format_ident!("iter_fields"));
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.is_reserved() || !info.config.skip_getters())
+            .flat_map(|(info, offset)| self.field_or_element_values(info, offset, config))
+            .map(|(info, offset, name, value)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let raw = quote_spanned!(field_span=>
+                    ::core::convert::Into::<::core::primitive::u128>::into(
+                        ::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
+                    )
+                );
+                let value = match info.config.is_reserved() {
+                    true => quote_spanned!(field_span=> ::core::option::Option::Some(#raw)),
+                    false => {
+                        quote_spanned!(field_span=>
+                            #value.ok().and_then(|__bf_value| {
+                                use ::modular_bitfield::private::{
+                                    IntoNumericValue as _,
+                                    NoNumericValue as _,
+                                };
+                                let __bf_value = ::modular_bitfield::private::NumericValue(__bf_value);
+                                (&__bf_value).numeric_value()
+                            })
+                        )
+                    }
+                };
+                quote_spanned!(field_span=>
+                    ::modular_bitfield::FieldValue {
+                        name: #name,
+                        bits: (#offset)..(#offset + <#ty as ::modular_bitfield::Specifier>::BITS),
+                        raw: #raw,
+                        value: #value,
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        let len = fields.len();
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns an iterator over the names, bit ranges and values of the fields.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #iter_fields_ident(
+                    &self,
+                ) -> impl ::core::iter::Iterator<Item = ::modular_bitfield::FieldValue> {
+                    ::core::iter::IntoIterator::into_iter(self)
+                }
+            }
+
+            impl ::core::iter::IntoIterator for &#ident {
+                type Item = ::modular_bitfield::FieldValue;
+                type IntoIter = ::core::array::IntoIter<::modular_bitfield::FieldValue, #len>;
+
+                #[inline]
                 #[allow(clippy::identity_op)]
-                pub const fn new() -> Self {
-                    Self {
-                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                fn into_iter(self) -> Self::IntoIter {
+                    ::core::iter::IntoIterator::into_iter([
+                        #( #fields ),*
+                    ])
+                }
+            }
+        ))
+    }
+
+    /// Generates the volatile register reference type if the `mmio` parameter is set.
+    ///
+    /// The register is read and written as a whole through a pointer to the `#[repr(uN)]`
+    /// primitive so that the access width matches the width of the hardware register.
+    pub fn generate_mmio_register(&self, config: &Config) -> Option<TokenStream2> {
+        let mmio = config.mmio.as_ref()?;
+        let repr = config.repr.as_ref()?;
+        let span = mmio.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let register_ident = format_ident!("{}RegisterRef", ident);
+        let prim = match repr.value {
+            ReprKind::U8 => quote! { ::core::primitive::u8 },
+            ReprKind::U16 => quote! { ::core::primitive::u16 },
+            ReprKind::U32 => quote! { ::core::primitive::u32 },
+            ReprKind::U64 => quote! { ::core::primitive::u64 },
+            ReprKind::U128 => quote! { ::core::primitive::u128 },
+        };
+        let register_docs = format!(
+            "A reference to a memory-mapped [`{}`] register that is read and written as a whole \
+             with volatile accesses.",
+            ident,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #register_docs]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+            #vis struct #register_ident {
+                ptr: *mut #prim,
+            }
+
+            impl #register_ident {
+                /// Creates a reference to the register at the given address.
+                ///
+                /// # Safety
+                ///
+                /// The pointer must be non-null, properly aligned and valid for volatile reads
+                /// and writes for as long as the returned reference or any of its copies is used.
+                #[inline]
+                pub const unsafe fn new(ptr: *mut #prim) -> Self {
+                    Self { ptr }
+                }
+
+                /// Returns the address of the register.
+                #[inline]
+                pub const fn as_ptr(self) -> *mut #prim {
+                    self.ptr
+                }
+
+                /// Reads the register with a single volatile read.
+                #[inline]
+                pub fn read_volatile(self) -> #ident {
+                    // SAFETY: The pointer is valid for volatile reads as guaranteed by `new`.
+                    let value = unsafe { ::core::ptr::read_volatile(self.ptr) };
+                    <#ident as ::core::convert::From<#prim>>::from(value)
+                }
+
+                /// Writes the register with a single volatile write.
+                #[inline]
+                pub fn write_volatile(self, value: #ident) {
+                    let value = <#prim as ::core::convert::From<#ident>>::from(value);
+                    // SAFETY: The pointer is valid for volatile writes as guaranteed by `new`.
+                    unsafe { ::core::ptr::write_volatile(self.ptr, value) }
+                }
+
+                /// Reads the register, updates the read value with `f` and writes it back.
+                ///
+                /// Note that the read and the write are two separate volatile accesses.
+                #[inline]
+                pub fn modify<F>(self, f: F)
+                where
+                    F: ::core::ops::FnOnce(&mut #ident),
+                {
+                    let mut value = self.read_volatile();
+                    f(&mut value);
+                    self.write_volatile(value);
+                }
+            }
+        ))
+    }
+
+    /// Generates the fields struct and the `fields` method if the `fields_struct` parameter is set.
+    ///
+    /// The fields struct holds the decoded values of all fields with getters so that
+    /// all of them can be read with a single call. Field arrays and fields with
+    /// `#[repeat = N]` are held as arrays of the values of their elements.
+    pub fn generate_fields_struct(&self, config: &Config) -> Option<TokenStream2> {
+        let fields_struct = config.fields_struct.as_ref()?;
+        let span = fields_struct.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let fields_ident = format_ident!("{}Fields", ident);
+        let fields_method_ident = config.method_ident(format_ident!("fields"));
+        let derives = (!fields_struct.value.is_empty()).then(|| {
+            let derives = &fields_struct.value;
+            quote_spanned!(span=> #[derive( #( #derives ),* )])
+        });
+        let (definitions, reads): (Vec<_>, Vec<_>) = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_getters())
+            .filter_map(|(info, offset)| {
+                let field_ident = info.field.ident.as_ref()?;
+                let field_span = info.field.span();
+                let field_vis = &info.field.vis;
+                let in_out = Self::field_in_out_type(&info);
+                let assert_msg = format!(
+                    "value contains invalid bit pattern for field {}.{}",
+                    ident, field_ident
+                );
+                let checked_read = |offset| {
+                    let checked_read =
+                        self.expand_checked_read(offset, &info, config, &quote!(self));
+                    quote_spanned!(field_span=> #checked_read.expect(#assert_msg))
+                };
+                let (definition, read) = match &info.config.repeat {
+                    Some(repeat) => {
+                        let repetitions = repeat.value;
+                        let docs = format!("The values of the elements of `{}`.", field_ident);
+                        let elements = info.elements(&offset);
+                        let elements = elements.iter().map(|(offset, _)| checked_read(offset));
+                        (
+                            quote_spanned!(field_span=>
+                                #[doc = #docs]
+                                #field_vis #field_ident: [#in_out; #repetitions]
+                            ),
+                            quote_spanned!(field_span=>
+                                #field_ident: [ #( #elements ),* ]
+                            ),
+                        )
+                    }
+                    None => {
+                        let docs = format!("The value of `{}`.", field_ident);
+                        let read = checked_read(&offset);
+                        (
+                            quote_spanned!(field_span=>
+                                #[doc = #docs]
+                                #field_vis #field_ident: #in_out
+                            ),
+                            quote_spanned!(field_span=> #field_ident: #read),
+                        )
+                    }
+                };
+                Some((definition, read))
+            })
+            .unzip();
+        let fields_docs = format!(
+            "The decoded values of the fields of [`{}`] as returned by [`{}::{}`].",
+            ident, ident, fields_method_ident,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #fields_docs]
+            #derives
+            #vis struct #fields_ident {
+                #( #definitions ),*
+            }
+
+            impl #ident {
+                /// Returns the values of all fields of the bitfield at once.
+                ///
+                /// # Panics
+                ///
+                /// If the value of any field contains an invalid bit pattern.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #fields_method_ident(&self) -> #fields_ident {
+                    #fields_ident {
+                        #( #reads ),*
+                    }
+                }
+            }
+        ))
+    }
+
+    /// Generates the access counters and the `access_stats` and `reset_access_stats` functions
+    /// if the `access_stats` parameter is set.
+    ///
+    /// Every field has its own counter that is incremented by its getters and setters.
+    /// All of this is generated for debug builds only.
+    pub fn generate_access_stats(&self, config: &Config) -> Option<TokenStream2> {
+        let access_stats = config.access_stats.as_ref()?;
+        let span = access_stats.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let access_stats_ident = config.method_ident(format_ident!("access_stats"));
+        let reset_access_stats_ident = config.method_ident(format_ident!("reset_access_stats"));
+        let infos = self.field_infos(config).collect::<Vec<_>>();
+        let len = infos.len();
+        let counter_idents = infos
+            .iter()
+            .map(|info| Self::access_counter_ident(info))
+            .collect::<Vec<_>>();
+        let counters = infos.iter().zip(&counter_idents).map(|(info, counter_ident)| {
+            let docs = format!("Returns the access counter of `{}`.", info.name());
+            quote_spanned!(info.field.span()=>
+                #[doc = #docs]
+                #[doc(hidden)]
+                #[inline]
+                fn #counter_ident() -> &'static ::modular_bitfield::private::AccessCounter {
+                    static __BF_COUNTER: ::modular_bitfield::private::AccessCounter =
+                        ::modular_bitfield::private::AccessCounter::new();
+                    &__BF_COUNTER
+                }
+            )
+        });
+        let stats = infos.iter().zip(&counter_idents).map(|(info, counter_ident)| {
+            let name = info.name();
+            quote_spanned!(info.field.span()=> Self::#counter_ident().stats(#name))
+        });
+        Some(quote_spanned!(span=>
+            #[cfg(debug_assertions)]
+            impl #ident {
+                #( #counters )*
+
+                /// Returns the name, the number of reads and the number of writes of every field.
+                ///
+                /// Accesses of all instances of the bitfield are counted together.
+                /// Only available in debug builds.
+                #[allow(dead_code)]
+                #vis fn #access_stats_ident(
+                ) -> [(&'static ::core::primitive::str, ::core::primitive::u64, ::core::primitive::u64); #len] {
+                    [ #( #stats ),* ]
+                }
+
+                /// Resets the numbers of reads and writes of all fields to zero.
+                ///
+                /// Only available in debug builds.
+                #[allow(dead_code)]
+                #vis fn #reset_access_stats_ident() {
+                    #( Self::#counter_idents().reset(); )*
+                }
+            }
+        ))
+    }
+
+    /// Returns the fields of every `#[group = "name"]` in the order of their declaration.
+    pub(super) fn field_groups<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> Vec<(syn::Ident, Vec<FieldInfo<'a>>)> {
+        let mut groups: Vec<(syn::Ident, Vec<FieldInfo<'a>>)> = Vec::new();
+        for info in self.field_infos(config) {
+            let group = match &info.config.group {
+                Some(group) => group.value.clone(),
+                None => continue,
+            };
+            match groups.last_mut() {
+                Some((name, fields)) if *name == group => fields.push(info),
+                _ => groups.push((group, vec![info])),
+            }
+        }
+        groups
+    }
+
+    /// Generates the read-only getter of every `#[computed(..)]` attribute.
+    ///
+    /// The getter binds the values of the `from` fields to their names and evaluates `expr`.
+    pub fn generate_computed_getters(&self, config: &Config) -> Option<TokenStream2> {
+        if config.computed.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let receiver = Self::accessors_receiver(config, &quote!(self), false);
+        let getters = config.computed.iter().map(|computed| {
+            let span = computed.span;
+            let Computed { name, from, ty, expr } = &computed.value;
+            let name = config.method_ident(name.clone());
+            let getters = from.iter().map(|field| config.method_ident(field.clone()));
+            let docs = match from.is_empty() {
+                true => format!("Returns the value computed as `{}`.", expr.to_token_stream()),
+                false => {
+                    let fields = from
+                        .iter()
+                        .map(|field| format!("`{}`", field))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(
+                        "Returns the value computed as `{}` from the fields {}.",
+                        expr.to_token_stream(),
+                        fields,
+                    )
+                }
+            };
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #name(&self) -> #ty {
+                    #( let #from = #receiver.#getters(); )*
+                    #expr
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #( #getters )*
+            }
+        ))
+    }
+
+    /// Generates the flags struct as well as its getter and setter for every `#[group = "name"]`.
+    ///
+    /// The flags struct of a group named `irq` is called `IrqFlags` and has a `bool` field
+    /// for every field of the group.
+    pub fn generate_groups(&self, config: &Config) -> Option<TokenStream2> {
+        let groups = self.field_groups(config);
+        if groups.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let groups = groups.iter().map(|(group, infos)| {
+            let span = group.span();
+            let flags_ident = format_ident!("{}Flags", camel_case(&group.to_string()));
+            let get_ident = config.method_ident(group.clone());
+            let set_ident = config.method_ident(format_ident!("set_{}", group));
+            let field_idents = infos
+                .iter()
+                .map(|info| info.field.ident.as_ref().expect("grouped fields are named"))
+                .collect::<Vec<_>>();
+            let field_docs = field_idents
+                .iter()
+                .map(|field_ident| format!("The value of `{}`.", field_ident));
+            let getter_receiver = Self::accessors_receiver(config, &quote!(self), false);
+            let setter_receiver = Self::accessors_receiver(config, &quote!(self), true);
+            let getters = infos.iter().map(|info| {
+                let getter = config.method_ident(info.getter_ident());
+                quote_spanned!(span=> #getter_receiver.#getter())
+            });
+            let setters = infos.iter().map(|info| {
+                let setter = config.method_ident(format_ident!("set_{}", info.ident_frag()));
+                quote_spanned!(span=> #setter_receiver.#setter)
+            });
+            let flags_docs = format!(
+                "The flags of the `{}` group of [`{}`] as returned by [`{}::{}`].",
+                group, ident, ident, get_ident,
+            );
+            let get_docs = format!("Returns the values of the fields of the `{}` group.", group);
+            let set_docs = format!("Sets the values of the fields of the `{}` group.", group);
+            quote_spanned!(span=>
+                #[doc = #flags_docs]
+                #[derive(
+                    ::core::fmt::Debug,
+                    ::core::default::Default,
+                    ::core::clone::Clone,
+                    ::core::marker::Copy,
+                    ::core::cmp::PartialEq,
+                    ::core::cmp::Eq,
+                    ::core::hash::Hash,
+                )]
+                #vis struct #flags_ident {
+                    #(
+                        #[doc = #field_docs]
+                        pub #field_idents: ::core::primitive::bool,
+                    )*
+                }
+
+                impl #ident {
+                    #[doc = #get_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #vis fn #get_ident(&self) -> #flags_ident {
+                        #flags_ident {
+                            #( #field_idents: #getters, )*
+                        }
+                    }
+
+                    #[doc = #set_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #vis fn #set_ident(&mut self, flags: #flags_ident) {
+                        #( #setters(flags.#field_idents); )*
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #groups )*
+        ))
+    }
+
+    /// Generates the `core::cmp::PartialOrd` impl if `#[derive(PartialOrd)]` is included.
+    ///
+    /// Bitfields are compared lexicographically by the decoded values of their
+    /// fields in the order of their declaration. Ties are broken by the raw bytes so that
+    /// the ordering agrees with the derived `PartialEq` which compares all bytes.
+    pub fn generate_partial_ord_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_partial_ord.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let fields = self
+            .comparable_field_values(config)
+            .map(|(field_span, self_value, other_value)| {
+                quote_spanned!(field_span=>
+                    match ::core::cmp::PartialOrd::partial_cmp(
+                        &#self_value,
+                        &#other_value,
+                    ) {
+                        ::core::option::Option::Some(::core::cmp::Ordering::Equal) => (),
+                        __bf_ordering => return __bf_ordering,
+                    }
+                )
+            });
+        Some(quote_spanned!(span=>
+            impl ::core::cmp::PartialOrd for #ident {
+                fn partial_cmp(
+                    &self,
+                    __bf_other: &Self,
+                ) -> ::core::option::Option<::core::cmp::Ordering> {
+                    #( #fields )*
+                    ::core::cmp::PartialOrd::partial_cmp(&self.bytes, &__bf_other.bytes)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `core::cmp::Ord` impl if `#[derive(Ord)]` is included.
+    ///
+    /// Bitfields are compared lexicographically by the decoded values of their
+    /// fields in the order of their declaration. Ties are broken by the raw bytes so that
+    /// the ordering agrees with the derived `Eq` which compares all bytes.
+    pub fn generate_ord_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_ord.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let fields = self
+            .comparable_field_values(config)
+            .map(|(field_span, self_value, other_value)| {
+                quote_spanned!(field_span=>
+                    match ::core::cmp::Ord::cmp(
+                        &#self_value,
+                        &#other_value,
+                    ) {
+                        ::core::cmp::Ordering::Equal => (),
+                        __bf_ordering => return __bf_ordering,
+                    }
+                )
+            });
+        Some(quote_spanned!(span=>
+            impl ::core::cmp::Ord for #ident {
+                fn cmp(&self, __bf_other: &Self) -> ::core::cmp::Ordering {
+                    #( #fields )*
+                    ::core::cmp::Ord::cmp(&self.bytes, &__bf_other.bytes)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `core::hash::Hash` impl if the `hash` parameter is set or if
+    /// `#[derive(Hash)]` is intercepted via `intercept(Hash)`.
+    ///
+    /// Only the raw bits of the fields taking part in comparisons are hashed so that
+    /// the bits of skipped fields and undefined trailing bits do not affect the hash.
+    pub fn generate_hash_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let intercepted =
+            config.derive_hash.is_some() && config.intercepts(InterceptableDerive::Hash);
+        if config.hash.is_none() && !intercepted {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| !info.config.skip_getters())
+            .flat_map(|(info, offset)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let offsets = match info.config.repeat {
+                    Some(_) => {
+                        info.elements(&offset)
+                            .into_iter()
+                            .map(|(offset, _)| offset)
+                            .collect()
+                    }
+                    None => vec![offset],
+                };
+                offsets
+                    .into_iter()
+                    .map(|offset| {
+                        quote_spanned!(field_span=>
+                            ::core::hash::Hash::hash(
+                                &::modular_bitfield::private::#read_specifier::<#ty>(
+                                    &self.bytes[..],
+                                    #offset,
+                                ),
+                                __bf_state,
+                            );
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            });
+        Some(quote_spanned!(span=>
+            impl ::core::hash::Hash for #ident {
+                #[allow(clippy::identity_op)]
+                fn hash<__BfH: ::core::hash::Hasher>(&self, __bf_state: &mut __BfH) {
+                    #( #fields )*
+                }
+            }
+        ))
+    }
+
+    /// Generates the `core::default::Default` impl if `#[derive(Default)]` is intercepted.
+    ///
+    /// The default value is the instance returned by `new_with_defaults` if any field has
+    /// a `#[default = EXPR]` attribute and the zero initialized instance otherwise.
+    pub fn generate_default_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let derive_default = config.derive_default.as_ref()?;
+        let ident = &self.item_struct.ident;
+        let new_ident = match config.field_defaults {
+            true => config.method_ident(format_ident!("new_with_defaults")),
+            false => config.new_ident(),
+        };
+        Some(quote_spanned!(derive_default.span=>
+            impl ::core::default::Default for #ident {
+                #[inline]
+                fn default() -> Self {
+                    Self::#new_ident()
+                }
+            }
+        ))
+    }
+
+    /// Generates the `SameLayout` impls and the `cast` method for all `cast_to(..)` parameters.
+    ///
+    /// The target bitfields must have the same number of bytes and must be filled
+    /// so that every bit pattern of the bytes is a valid value of the target.
+    pub fn generate_cast_impls(&self, config: &Config) -> Option<TokenStream2> {
+        if config.cast_to.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let cast_ident = config.method_ident(format_ident!("cast"));
+        let impls = config.cast_to.iter().map(|cast_to| {
+            let target = &cast_to.value;
+            quote_spanned!(cast_to.span=>
+                impl ::modular_bitfield::SameLayout<#ident> for #target {
+                    #[inline]
+                    fn from_same_layout(from: #ident) -> Self {
+                        <#target>::from_bytes(from.bytes)
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #impls )*
+
+            impl #ident {
+                /// Reinterprets the bytes of the bitfield as the bitfield `T` with the same layout.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #cast_ident<T>(self) -> T
+                where
+                    T: ::modular_bitfield::SameLayout<Self>,
+                {
+                    <T as ::modular_bitfield::SameLayout<Self>>::from_same_layout(self)
+                }
+            }
+        ))
+    }
+
+    /// Generates the expression denoting the sum of all field bit specifier sizes.
+    ///
+    /// # Example
+    ///
+    /// For the following struct:
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Color {
+    ///     r: B8,
+    ///     g: B8,
+    ///     b: B8,
+    ///     a: bool,
+    ///     rest: B7,
+    /// }
+    /// ```
+    ///
+    /// We generate the following tokens:
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// {
+    ///     0usize +
+    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
+    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
+    ///     <B8 as ::modular_bitfield::Specifier>::BITS +
+    ///     <bool as ::modular_bitfield::Specifier>::BITS +
+    ///     <B7 as ::modular_bitfield::Specifier>::BITS
+    /// }
+    /// # ;
+    /// ```
+    ///
+    /// Which is a compile time evaluatable expression.
+    /// Generates the methods locating the payload after the bitfield if its last field
+    /// is of the `Rest` pseudo-type.
+    ///
+    /// Otherwise returns `None`.
+    pub fn generate_rest_methods(&self, config: &Config) -> Option<TokenStream2> {
+        use syn::ext::IdentExt as _;
+        let rest = config.rest.as_ref()?;
+        let span = rest.span;
+        let ident = &self.item_struct.ident;
+        let field = &rest.value;
+        let vis = &field.vis;
+        let docs = &field.attrs;
+        let name = field.ident.as_ref()?.unraw();
+        let offset_bits_ident = config.method_ident(format_ident!("{}_offset_bits", name));
+        let of_ident = config.method_ident(format_ident!("{}_of", name));
+        let size = self.generate_bitfield_size(config);
+        let offset_bits_docs = format!(
+            "Returns the offset in bits at which `{}` starts after the fields of the bitfield.",
+            name
+        );
+        let of_docs = format!(
+            "Returns the bytes of `{}` within the given record starting with the bitfield.\n\n\
+             If the offset of `{}` is not a multiple of 8 the first returned byte also \
+             contains the last bits of the bitfield.\n\n\
+             # Panics\n\n\
+             If the record is shorter than the offset of `{}`.",
+            name, name, name,
+        );
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #( #docs )*
+                #[doc = #offset_bits_docs]
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis const fn #offset_bits_ident() -> ::core::primitive::usize {
+                    #size
+                }
+
+                #( #docs )*
+                #[doc = #of_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #of_ident<'a>(&self, buf: &'a [::core::primitive::u8]) -> &'a [::core::primitive::u8] {
+                    &buf[Self::#offset_bits_ident() / 8usize..]
+                }
+            }
+        ))
+    }
+
+    /// Generates the `Distribution` impl of the `Standard` distribution for the bitfield
+    /// if the `rand` crate feature is enabled.
+    ///
+    /// Every field that is not skipped entirely is assigned uniformly random bits
+    /// that form a valid bit pattern for its specifier. Skipped fields are zero.
+    pub fn generate_distribution_impl(&self, config: &Config) -> Option<TokenStream2> {
+        if cfg!(not(feature = "rand")) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let extra_field_inits = self.extra_field_inits(config);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let write_specifier = Self::bit_order_routine("write_specifier", config);
+        let fields = self
+            .field_infos_with_offsets(config)
+            .filter(|(info, _)| info.config.has_scalar_getter() || info.config.has_scalar_setter())
+            .map(|(info, offset)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                quote_spanned!(field_span=>
+                    ::modular_bitfield::private::#write_specifier::<#ty>(
+                        &mut __bf_value.bytes[..],
+                        #offset,
+                        ::modular_bitfield::private::random_specifier::<#ty, __BfR>(__bf_rng),
+                    );
+                )
+            });
+        Some(quote_spanned!(span=>
+            impl ::modular_bitfield::rand::distributions::Distribution<#ident>
+                for ::modular_bitfield::rand::distributions::Standard
+            {
+                #[allow(clippy::identity_op)]
+                fn sample<__BfR>(&self, __bf_rng: &mut __BfR) -> #ident
+                where
+                    __BfR: ::modular_bitfield::rand::Rng + ?::core::marker::Sized,
+                {
+                    let mut __bf_value = #ident {
+                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                        #extra_field_inits
+                    };
+                    #( #fields )*
+                    __bf_value
+                }
+            }
+        ))
+    }
+
+    /// Generates the `as_bitslice`, `as_mut_bitslice` and `from_bitslice` methods
+    /// if the `bitvec` crate feature is enabled.
+    ///
+    /// The bit slices cover the bits of the bitfield in the order of its `bit_order`
+    /// which is `Lsb0` for `le` and `Msb0` for `be` bit order.
+    pub fn generate_bitvec_methods(&self, config: &Config) -> Option<TokenStream2> {
+        if cfg!(not(feature = "bitvec")) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let extra_field_inits = self.extra_field_inits(config);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let as_bitslice_ident = config.method_ident(format_ident!("as_bitslice"));
+        let as_mut_bitslice_ident = config.method_ident(format_ident!("as_mut_bitslice"));
+        let from_bitslice_ident = config.method_ident(format_ident!("from_bitslice"));
+        let order = match config.bit_order() {
+            BitOrder::Le => quote_spanned!(span=> ::modular_bitfield::bitvec::order::Lsb0),
+            BitOrder::Be => quote_spanned!(span=> ::modular_bitfield::bitvec::order::Msb0),
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns a view of the bits of the bitfield as bit slice.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #as_bitslice_ident(
+                    &self,
+                ) -> &::modular_bitfield::bitvec::slice::BitSlice<::core::primitive::u8, #order> {
+                    &::modular_bitfield::bitvec::view::BitView::view_bits::<#order>(&self.bytes)[..#size]
+                }
+
+                /// Returns a mutable view of the bits of the bitfield as bit slice.
+                ///
+                /// Bits written through the bit slice may form invalid bit patterns for
+                /// the fields of the bitfield, the same as with `from_bytes`.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #as_mut_bitslice_ident(
+                    &mut self,
+                ) -> &mut ::modular_bitfield::bitvec::slice::BitSlice<::core::primitive::u8, #order> {
+                    &mut ::modular_bitfield::bitvec::view::BitView::view_bits_mut::<#order>(&mut self.bytes)[..#size]
+                }
+
+                /// Creates a new bitfield from the bits of the given bit slice.
+                ///
+                /// # Errors
+                ///
+                /// If the length of the bit slice does not match the bit width of the bitfield.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #from_bitslice_ident<__BfT, __BfO>(
+                    bits: &::modular_bitfield::bitvec::slice::BitSlice<__BfT, __BfO>,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds>
+                where
+                    __BfT: ::modular_bitfield::bitvec::store::BitStore,
+                    __BfO: ::modular_bitfield::bitvec::order::BitOrder,
+                {
+                    if bits.len() != #size {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let mut __bf_value = Self {
+                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                        #extra_field_inits
+                    };
+                    __bf_value.#as_mut_bitslice_ident().clone_from_bitslice(bits);
+                    ::core::result::Result::Ok(__bf_value)
+                }
+            }
+        ))
+    }
+
+    /// Generates the two half types and the `split_mut` method if `split_at` is set.
+    ///
+    /// The first half provides the getters and setters of the fields before the `split_at`
+    /// field and the second half those of the `split_at` field and all fields after it.
+    /// Since the halves borrow disjoint bytes the `split_at` field must start at a byte boundary.
+    pub fn generate_split_mut(&self, config: &Config) -> Option<TokenStream2> {
+        let split_at = config.split_at.as_ref()?;
+        let span = split_at.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let half_a_ident = format_ident!("{}HalfA", ident);
+        let half_b_ident = format_ident!("{}HalfB", ident);
+        let split_mut_ident = config.method_ident(format_ident!("split_mut"));
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        offset.push(syn::parse_quote! { 0usize });
+        let mut boundary = None;
+        let mut half_a = Vec::new();
+        let mut half_b = Vec::new();
+        for info in self.field_infos(config) {
+            if boundary.is_none() && info.name() == split_at.value {
+                boundary = Some(offset.clone());
+                offset = Punctuated::new();
+                offset.push(syn::parse_quote! { 0usize });
+            }
+            let getters = self.expand_getters_for_field(&offset, &info, config);
+            let setters = self.expand_setters_for_field(&offset, &info, config);
+            let accessors = quote_spanned!(info.field.span()=>
+                #getters
+                #setters
+            );
+            match boundary {
+                None => half_a.push(accessors),
+                Some(_) => half_b.push(accessors),
+            }
+            info.add_bits_to_offset(&mut offset);
+        }
+        let boundary = boundary?;
+        let split_at_name = &split_at.value;
+        let half_a_docs = format!(
+            "The fields of [`{}`] before `{}` as returned by [`{}::{}`].",
+            ident, split_at_name, ident, split_mut_ident,
+        );
+        let half_b_docs = format!(
+            "The fields of [`{}`] starting at `{}` as returned by [`{}::{}`].",
+            ident, split_at_name, ident, split_mut_ident,
+        );
+        let split_mut_docs = format!(
+            "Splits the bitfield at the field `{}` into two halves that can be mutated independently.",
+            split_at_name,
+        );
+        let boundary_msg = format!(
+            "the `split_at` field `{}` of `{}` must start at a byte boundary",
+            split_at_name, ident,
+        );
+        Some(quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = ::core::assert!((#boundary) % 8usize == 0usize, #boundary_msg);
+
+            #[doc = #half_a_docs]
+            #vis struct #half_a_ident<'a> {
+                bytes: &'a mut [::core::primitive::u8],
+            }
+
+            #[doc = #half_b_docs]
+            #vis struct #half_b_ident<'a> {
+                bytes: &'a mut [::core::primitive::u8],
+            }
+
+            impl #ident {
+                #[doc = #split_mut_docs]
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #split_mut_ident(&mut self) -> (#half_a_ident<'_>, #half_b_ident<'_>) {
+                    let (__bf_a, __bf_b) = self.bytes.split_at_mut((#boundary) / 8usize);
+                    (#half_a_ident { bytes: __bf_a }, #half_b_ident { bytes: __bf_b })
+                }
+            }
+
+            #[allow(clippy::identity_op)]
+            impl #half_a_ident<'_> {
+                #( #half_a )*
+            }
+
+            #[allow(clippy::identity_op)]
+            impl #half_b_ident<'_> {
+                #( #half_b )*
+            }
+        ))
+    }
+
+    fn generate_bitfield_size(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let sum = self
+            .field_infos(config)
+            .map(|info| info.bits_expr())
+            .fold(quote_spanned!(span=> 0usize), |lhs, rhs| {
+                quote_spanned!(span =>
+                    #lhs + #rhs
+                )
+            });
+        quote_spanned!(span=>
+            { #sum }
+        )
+    }
+
+    /// Generates the expression denoting the actual configured or implied bit width.
+    pub fn generate_target_or_actual_bitfield_size(&self, config: &Config) -> TokenStream2 {
+        config
+            .bits
+            .as_ref()
+            .map(|bits_config| {
+                let span = bits_config.span;
+                let value = bits_config.value;
+                quote_spanned!(span=>
+                    #value
+                )
+            })
+            .unwrap_or_else(|| self.generate_bitfield_size(config))
+    }
+
+    /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
+    ///
+    /// - ... equal to `N`, if `filled = true` or
+    /// - ... smaller than `N`, if `filled = false`
+    fn generate_filled_check_for_unaligned_bits(
+        &self,
+        config: &Config,
+        required_bits: usize,
+    ) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let actual_bits = self.generate_bitfield_size(config);
+        let check_ident = match config.filled_enabled() {
+            true => quote_spanned!(span => CheckFillsUnalignedBits),
+            false => quote_spanned!(span => CheckDoesNotFillUnalignedBits),
+        };
+        let comparator = match config.filled_enabled() {
+            true => quote! { == },
+            false => quote! { > },
+        };
+        // The bit widths are compared as `u32` so that the check does not depend
+        // on the width of `usize` of the compilation target.
+        let required_bits = proc_macro2::Literal::u32_suffixed(required_bits as u32);
+        quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = {
+                impl ::modular_bitfield::private::checks::#check_ident for #ident {
+                    type CheckType = [(); (#required_bits #comparator (#actual_bits) as ::core::primitive::u32) as ::core::primitive::usize];
+                }
+            };
+        )
+    }
+
+    /// Generates a check in case `bits = N` is unset to verify that the actual amount of bits is either
+    ///
+    /// - ... divisible by 8, if `filled = true` or
+    /// - ... not divisible by 8, if `filled = false`
+    fn generate_filled_check_for_aligned_bits(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let actual_bits = self.generate_bitfield_size(config);
+        let check_ident = match config.filled_enabled() {
+            true => quote_spanned!(span => CheckTotalSizeMultipleOf8),
+            false => quote_spanned!(span => CheckTotalSizeIsNotMultipleOf8),
+        };
+        quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = {
+                impl ::modular_bitfield::private::checks::#check_ident for #ident {
+                    type Size = ::modular_bitfield::private::checks::TotalSize<[(); #actual_bits % 8usize]>;
+                }
+            };
+        )
+    }
+
+    /// Generate check for either of the following two cases:
+    ///
+    /// - `filled = true`: Check if the total number of required bits is
+    ///         - ... the same as `N` if `bits = N` was provided or
+    ///         - ... a multiple of 8, otherwise
+    /// - `filled = false`: Check if the total number of required bits is
+    ///         - ... smaller than `N` if `bits = N` was provided or
+    ///         - ... NOT a multiple of 8, otherwise
+    fn generate_check_for_filled(&self, config: &Config) -> TokenStream2 {
+        match config.bits.as_ref() {
+            Some(bits_config) => {
+                self.generate_filled_check_for_unaligned_bits(config, bits_config.value)
+            }
+            None => self.generate_filled_check_for_aligned_bits(config),
+        }
+    }
+
+    /// Generates a check that the fields of a `contiguous` bitfield with `bits = N`
+    /// cover all of its `N` bits.
+    ///
+    /// Gaps in between `#[bits(start..end)]` fields are already rejected during analysis
+    /// so that only the bits after the last field remain to be checked. These are left
+    /// uncovered by `filled = false`.
+    ///
+    /// Returns `None` if `contiguous` or `bits = N` is unset.
+    fn generate_check_for_contiguous(&self, config: &Config) -> Option<TokenStream2> {
+        let contiguous = config.contiguous.as_ref()?;
+        let required_bits = config.bits.as_ref()?.value;
+        let span = contiguous.span;
+        let actual_bits = self.generate_bitfield_size(config);
+        let message = format!(
+            " after the last field of `contiguous` bitfield `{}` with `bits = {}`",
+            self.item_struct.ident, required_bits,
+        );
+        Some(quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = ::modular_bitfield::private::checks::check_contiguous(
+                #actual_bits,
+                #required_bits,
+                #message,
+            );
+        ))
+    }
+
+    /// Returns a token stream representing the next greater value divisible by 8.
+    fn next_divisible_by_8(value: &TokenStream2) -> TokenStream2 {
+        let span = value.span();
+        quote_spanned!(span=> {
+            (((#value - 1) / 8) + 1) * 8
+        })
+    }
+
+    /// Generates the actual item struct definition for the `#[bitfield]`.
+    ///
+    /// Internally it only contains a byte array equal to the minimum required
+    /// amount of bytes to compactly store the information of all its bit fields.
+    fn generate_struct(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let attrs = &config.retained_attributes;
+        let vis = &self.item_struct.vis;
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let phantom_fields = self.phantom_field_infos(config).map(|info| {
+            let FieldInfo { field, config, .. } = &info;
+            let phantom_attrs = &config.phantom_attrs;
+            let field_vis = &field.vis;
+            let field_ident = &field.ident;
+            let ty = &field.ty;
+            quote_spanned!(field.span()=>
+                #( #phantom_attrs )*
+                #field_vis #field_ident: ::core::marker::PhantomData<#ty>,
+            )
+        });
+        let dirty_field = config.dirty_tracking.as_ref().map(|_| {
+            let field_set_ident = self.field_set_ident();
+            quote! { __bf_dirty: #field_set_ident, }
+        });
+        let repr_c = config.c_embed.as_ref().map(|c_embed| {
+            quote_spanned!(c_embed.span=> #[repr(C)])
+        });
+        let repr_transparent = config.transparent.as_ref().map(|transparent| {
+            quote_spanned!(transparent.span=> #[repr(transparent)])
+        });
+        let transparent_check = config.transparent.as_ref().map(|transparent| {
+            quote_spanned!(transparent.span=>
+                const _: () = {
+                    // The bitfield has the same layout as its underlying byte array.
+                    ::core::assert!(
+                        ::core::mem::size_of::<#ident>()
+                            == ::core::mem::size_of::<[::core::primitive::u8; #next_divisible_by_8 / 8usize]>()
+                            && ::core::mem::align_of::<#ident>()
+                                == ::core::mem::align_of::<[::core::primitive::u8; #next_divisible_by_8 / 8usize]>()
+                    );
+                };
+            )
+        });
+        quote_spanned!(span=>
+            #( #attrs )*
+            #repr_c
+            #repr_transparent
+            #[allow(clippy::identity_op)]
+            #vis struct #ident
+            {
+                bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+                #( #phantom_fields )*
+                #dirty_field
+            }
+
+            #transparent_check
+        )
+    }
+
+    /// Returns the field infos of the phantom fields if `keep_phantom_fields` is set.
+    ///
+    /// Fields named `__` are excluded since this name may be used by multiple skipped fields.
+    fn phantom_field_infos<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> impl Iterator<Item = FieldInfo<'a>> + 'a {
+        self.field_infos(config).filter(move |info| {
+            config.keep_phantom_fields.is_some()
+                && matches!(&info.field.ident, Some(ident) if ident != "__")
+        })
+    }
+
+    /// Generates the initializers of the fields besides the bytes of the bitfield.
+    ///
+    /// These are the phantom fields if `keep_phantom_fields` is set and the set of
+    /// modified fields if `dirty_tracking` is set.
+    fn extra_field_inits(&self, config: &Config) -> TokenStream2 {
+        let inits = self.phantom_field_infos(config).map(|info| {
+            let ident = &info.field.ident;
+            quote_spanned!(info.field.span()=> #ident: ::core::marker::PhantomData,)
+        });
+        let dirty_init = config.dirty_tracking.as_ref().map(|_| {
+            let field_set_ident = self.field_set_ident();
+            quote! { __bf_dirty: #field_set_ident::empty(), }
+        });
+        quote! {
+            #( #inits )*
+            #dirty_init
+        }
+    }
+
+    /// Returns the identifier of the field set type generated for `dirty_tracking`.
+    fn field_set_ident(&self) -> syn::Ident {
+        format_ident!("{}FieldSet", self.item_struct.ident)
+    }
+
+    /// Returns the smallest unsigned primitive with a bit for every field of the bitfield.
+    fn field_set_prim(&self) -> TokenStream2 {
+        match self.item_struct.fields.len() {
+            0..=8 => quote! { ::core::primitive::u8 },
+            9..=16 => quote! { ::core::primitive::u16 },
+            17..=32 => quote! { ::core::primitive::u32 },
+            33..=64 => quote! { ::core::primitive::u64 },
+            _ => quote! { ::core::primitive::u128 },
+        }
+    }
+
+    /// Generates the statement marking the given field as modified if `dirty_tracking` is set.
+    ///
+    /// Otherwise returns `None`.
+    fn mark_dirty(&self, info: &FieldInfo<'_>, config: &Config) -> Option<TokenStream2> {
+        config.dirty_tracking.as_ref()?;
+        let field_set_ident = self.field_set_ident();
+        let mask_ident = Self::field_set_mask_ident(info);
+        Some(quote_spanned!(info.field.span()=>
+            self.__bf_dirty.bits |= #field_set_ident::#mask_ident;
+        ))
+    }
+
+    /// Returns the identifier of the hidden constant holding the bit of the field in the field set.
+    fn field_set_mask_ident(info: &FieldInfo<'_>) -> syn::Ident {
+        format_ident!("__bf_{}", info.ident_frag())
+    }
+
+    /// Generates the field set type and the methods to query modified fields
+    /// if `dirty_tracking` is set.
+    ///
+    /// Otherwise returns `None`.
+    pub fn generate_dirty_tracking(&self, config: &Config) -> Option<TokenStream2> {
+        let dirty_tracking = config.dirty_tracking.as_ref()?;
+        let span = dirty_tracking.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let field_set_ident = self.field_set_ident();
+        let prim = self.field_set_prim();
+        let take_dirty_ident = config.method_ident(format_ident!("take_dirty"));
+        let is_dirty_ident = config.method_ident(format_ident!("is_dirty"));
+        let field_set_docs = format!(
+            "The set of fields of [`{}`] that have been modified by its setters.",
+            ident
+        );
+        let masks = self.field_infos(config).map(|info| {
+            let mask_ident = Self::field_set_mask_ident(&info);
+            let index = proc_macro2::Literal::usize_unsuffixed(info.index);
+            quote_spanned!(info.field.span()=>
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals, dead_code)]
+                const #mask_ident: #prim = (0x01 as #prim) << #index;
+            )
+        });
+        let contains = self
+            .field_infos(config)
+            .filter(|info| info.config.has_scalar_setter())
+            .map(|info| {
+                let field_span = info.field.span();
+                let field_vis = &info.field.vis;
+                let getter_ident = info.getter_ident();
+                let mask_ident = Self::field_set_mask_ident(&info);
+                let docs = format!("Returns `true` if the set contains `{}`.", info.name());
+                quote_spanned!(field_span=>
+                    #[doc = #docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #field_vis const fn #getter_ident(&self) -> ::core::primitive::bool {
+                        self.bits & Self::#mask_ident != 0
+                    }
+                )
+            });
+        Some(quote_spanned!(span=>
+            #[doc = #field_set_docs]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::default::Default,
+                ::core::marker::Copy,
+                ::core::clone::Clone,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis struct #field_set_ident {
+                bits: #prim,
+            }
+
+            impl #field_set_ident {
+                #( #masks )*
+
+                /// Returns the set without any fields.
+                #[inline]
+                #[allow(dead_code)]
+                #vis const fn empty() -> Self {
+                    Self { bits: 0 }
+                }
+
+                /// Returns `true` if the set does not contain any fields.
+                #[inline]
+                #[allow(dead_code)]
+                #vis const fn is_empty(&self) -> ::core::primitive::bool {
+                    self.bits == 0
+                }
+
+                /// Returns the number of fields in the set.
+                #[inline]
+                #[allow(dead_code)]
+                #vis const fn len(&self) -> ::core::primitive::usize {
+                    self.bits.count_ones() as ::core::primitive::usize
+                }
+
+                #( #contains )*
+            }
+
+            impl #ident {
+                /// Returns the set of fields modified by setters since the last call
+                /// and clears it.
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #take_dirty_ident(&mut self) -> #field_set_ident {
+                    ::core::mem::replace(&mut self.__bf_dirty, #field_set_ident::empty())
+                }
+
+                /// Returns `true` if any field has been modified by a setter since the
+                /// last call to `take_dirty`.
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #is_dirty_ident(&self) -> ::core::primitive::bool {
+                    !self.__bf_dirty.is_empty()
+                }
+            }
+        ))
+    }
+
+    /// Generates the helpers to embed the bitfield within a larger `#[repr(C)]` struct
+    /// if `c_embed` is set.
+    ///
+    /// Otherwise returns `None`.
+    pub fn generate_c_embed(&self, config: &Config) -> Option<TokenStream2> {
+        config.c_embed.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let assert_embedded_at_ident = config.method_ident(format_ident!("assert_embedded_at"));
+        let from_outer_ident = config.method_ident(format_ident!("from_outer"));
+        let write_outer_ident = config.method_ident(format_ident!("write_outer"));
+        let from_bytes_ident = config.method_ident(format_ident!("from_bytes"));
+        let assert_msg = format!("{} does not fit into the outer struct at the given offset", ident);
+        let from_bytes = match config.filled_enabled() {
+            true => quote_spanned!(span=> ::core::result::Result::Ok(Self::#from_bytes_ident(__bf_array))),
+            false => {
+                quote_spanned!(span=>
+                    Self::#from_bytes_ident(__bf_array).map_err(::core::convert::From::from)
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            const _: () = {
+                // The bitfield consists of its bytes only so that it can be embedded directly.
+                ::core::assert!(
+                    ::core::mem::size_of::<#ident>() == #next_divisible_by_8 / 8usize
+                        && ::core::mem::align_of::<#ident>() == 1usize
+                );
+            };
+
+            impl #ident {
+                /// Asserts that the bitfield fits into an outer struct of `outer_size` bytes
+                /// when embedded at the byte `offset`.
+                ///
+                /// Use this in a `const` item together with `core::mem::size_of` and
+                /// `core::mem::offset_of` to check the layout of the outer struct at compile time.
+                ///
+                /// # Panics
+                ///
+                /// If the bitfield does not fit into the outer struct at the given offset.
+                #[inline]
+                #[allow(dead_code)]
+                pub const fn #assert_embedded_at_ident(
+                    outer_size: ::core::primitive::usize,
+                    offset: ::core::primitive::usize,
+                ) {
+                    ::core::assert!(
+                        offset <= outer_size && #next_divisible_by_8 / 8usize <= outer_size - offset,
+                        #assert_msg
+                    );
+                }
+
+                /// Reads the bitfield from the bytes of an outer struct starting at the byte `offset`.
+                ///
+                /// # Errors
+                ///
+                /// - If the bitfield does not fit into `bytes` at the given offset.
+                /// - If the bytes contain bits at positions that are undefined for `Self`.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #from_outer_ident<const M: ::core::primitive::usize>(
+                    bytes: &[::core::primitive::u8; M],
+                    offset: ::core::primitive::usize,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    let __bf_bytes = offset
+                        .checked_add(#next_divisible_by_8 / 8usize)
+                        .and_then(|__bf_end| bytes.get(offset..__bf_end))
+                        .ok_or(::modular_bitfield::error::OutOfBounds)?;
+                    let mut __bf_array = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    __bf_array.copy_from_slice(__bf_bytes);
+                    #from_bytes
+                }
+
+                /// Writes the bitfield to the bytes of an outer struct starting at the byte `offset`.
+                ///
+                /// # Errors
+                ///
+                /// If the bitfield does not fit into `bytes` at the given offset.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn #write_outer_ident<const M: ::core::primitive::usize>(
+                    &self,
+                    bytes: &mut [::core::primitive::u8; M],
+                    offset: ::core::primitive::usize,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    let __bf_bytes = offset
+                        .checked_add(#next_divisible_by_8 / 8usize)
+                        .and_then(|__bf_end| bytes.get_mut(offset..__bf_end))
+                        .ok_or(::modular_bitfield::error::OutOfBounds)?;
+                    __bf_bytes.copy_from_slice(&self.bytes);
+                    ::core::result::Result::Ok(())
+                }
+            }
+        ))
+    }
+
+    /// Generates the constructor for the bitfield that initializes all bytes to zero.
+    fn generate_constructor(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let extra_field_inits = self.extra_field_inits(config);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let uninit_constructors = self.generate_uninit_constructors(config);
+        let preset_constructors = self.generate_preset_constructors(config);
+        let defaults_constructor = self.generate_defaults_constructor(config);
+        let new_ident = config.new_ident();
+        quote_spanned!(span=>
+            impl #ident
+            {
+                /// Returns an instance with zero initialized data.
+                #[allow(clippy::identity_op)]
+                pub const fn #new_ident() -> Self {
+                    Self {
+                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                        #extra_field_inits
+                    }
+                }
+
+                #uninit_constructors
+                #( #preset_constructors )*
+                #defaults_constructor
+            }
+        )
+    }
+
+    /// Generates the `new_with_defaults` constructor if any field has a `#[default = EXPR]`.
+    ///
+    /// The default values are written through the setters of their fields and thus panic
+    /// if they are out of bounds.
+    fn generate_defaults_constructor(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.field_defaults {
+            return None
+        }
+        let span = self.item_struct.span();
+        let new_ident = config.new_ident();
+        let new_with_defaults_ident = config.method_ident(format_ident!("new_with_defaults"));
+        let receiver = Self::accessors_receiver(config, &quote!(__bf_value), true);
+        let writes = self.field_infos(config).filter_map(|info| {
+            let default = info.config.default.as_ref()?;
+            let expr = &default.value;
+            let set_ident = config.method_ident(format_ident!("set_{}", info.ident_frag()));
+            Some(quote_spanned!(default.span=>
+                #receiver.#set_ident(#expr);
+            ))
+        });
+        Some(quote_spanned!(span=>
+            /// Returns an instance with every field set to its `#[default = EXPR]` value
+            /// and all other bits zero initialized.
+            ///
+            /// # Panics
+            ///
+            /// If a default value is out of bounds for its field.
+            #[inline]
+            pub fn #new_with_defaults_ident() -> Self {
+                let mut __bf_value = Self::#new_ident();
+                #( #writes )*
+                __bf_value
+            }
+        ))
+    }
+
+    /// Generates a constructor for every `preset(name = value)` parameter.
+    ///
+    /// The first bit of the bitfield is the least significant bit of the packed value or
+    /// its most significant bit for `bit_order = be`. Packed values that do not fit into
+    /// the bits of the bitfield fail to compile.
+    fn generate_preset_constructors<'a>(
+        &'a self,
+        config: &'a Config,
+    ) -> impl Iterator<Item = TokenStream2> + 'a {
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let (mask_bytes, offset) = match config.bit_order() {
+            BitOrder::Le => (quote! { mask_bytes }, quote! { 0usize }),
+            BitOrder::Be => (quote! { mask_bytes_be }, quote! { __bf_bits - __bf_value_bits }),
+        };
+        config.presets.iter().map(move |preset| {
+            let span = preset.span;
+            let name = config.method_ident(preset.value.name.clone());
+            let value = preset.value.value;
+            let extra_field_inits = self.extra_field_inits(config);
+            let docs = format!(
+                "Returns an instance initialized from the packed value `{:#X}`.",
+                value,
+            );
+            let message = format!(
+                "preset `{}` of `{}` exceeds the bits of the bitfield",
+                preset.value.name, ident,
+            );
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[allow(clippy::identity_op)]
+                pub const fn #name() -> Self {
+                    const __BF_BYTES: [::core::primitive::u8; #next_divisible_by_8 / 8usize] = {
+                        let __bf_bits = #size;
+                        // Packed values have at most 128 bits.
+                        let __bf_value_bits = if __bf_bits < 128 { __bf_bits } else { 128 };
+                        match ::modular_bitfield::private::#mask_bytes(#value, #offset, __bf_value_bits) {
+                            ::core::option::Option::Some(bytes) => bytes,
+                            ::core::option::Option::None => ::core::panic!(#message),
+                        }
+                    };
+                    Self {
+                        bytes: __BF_BYTES,
+                        #extra_field_inits
+                    }
+                }
+            )
+        })
+    }
+
+    /// Generates the constructors operating on uninitialized data if `uninit = true`.
+    ///
+    /// Otherwise returns `None`.
+    fn generate_uninit_constructors(&self, config: &Config) -> Option<TokenStream2> {
+        if !config.uninit_enabled() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let extra_field_inits = self.extra_field_inits(config);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let new_uninit_ident = config.method_ident(format_ident!("new_uninit"));
+        let from_uninit_with_ident = config.method_ident(format_ident!("from_uninit_with"));
+        Some(quote_spanned!(span=>
+            /// Returns an instance with uninitialized data.
+            #[inline]
+            pub const fn #new_uninit_ident() -> ::core::mem::MaybeUninit<Self> {
+                ::core::mem::MaybeUninit::uninit()
+            }
+
+            /// Returns an instance whose data has been initialized by `f`.
+            ///
+            /// This avoids zero initializing the underlying bytes for bitfields
+            /// that are going to be entirely overwritten right after creation.
+            ///
+            /// # Safety
+            ///
+            /// The caller must guarantee that `f` initializes all of the underlying bytes.
+            #[inline]
+            #[allow(clippy::identity_op)]
+            pub unsafe fn #from_uninit_with_ident<F>(f: F) -> Self
+            where
+                F: ::core::ops::FnOnce(
+                    &mut ::core::mem::MaybeUninit<[::core::primitive::u8; #next_divisible_by_8 / 8usize]>
+                ),
+            {
+                let mut __bf_bytes = ::core::mem::MaybeUninit::uninit();
+                f(&mut __bf_bytes);
+                Self {
+                    bytes: unsafe { __bf_bytes.assume_init() },
+                    #extra_field_inits
+                }
+            }
+        ))
+    }
+
+    /// Generates the compile-time assertion if the optional `byte` parameter has been set.
+    fn expand_optional_bytes_check(&self, config: &Config) -> Option<TokenStream2> {
+        let ident = &self.item_struct.ident;
+        config.bytes.as_ref().map(|config| {
+            let bytes = config.value;
+            quote_spanned!(config.span=>
+                const _: () = {
+                    struct ExpectedBytes { __bf_unused: [::core::primitive::u8; #bytes] }
+
+                    ::modular_bitfield::private::static_assertions::assert_eq_size!(
+                        ExpectedBytes,
+                        #ident
+                    );
+                };
+            )
+        })
+    }
+
+    /// Generates the `into_words_uN` and `from_words_uN` methods for every word type of the
+    /// `words(..)` parameter.
+    ///
+    /// The number of bytes of the bitfield must be a multiple of the size of the words
+    /// which is checked at compile time. The bytes of every word are ordered by `word_endian`.
+    pub fn generate_word_conversions(&self, config: &Config) -> Option<TokenStream2> {
+        if config.words.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let from_bytes_ident = config.method_ident(format_ident!("from_bytes"));
+        let (to_bytes, from_bytes) = match config.word_endian() {
+            ReprEndian::Little => (quote! { to_le_bytes }, quote! { from_le_bytes }),
+            ReprEndian::Big => (quote! { to_be_bytes }, quote! { from_be_bytes }),
+        };
+        let (constness, from_words_output) = match config.filled_enabled() {
+            true => (quote! { const }, quote! { Self }),
+            false => {
+                (
+                    quote! {},
+                    quote! {
+                        ::core::result::Result<Self, ::modular_bitfield::error::PaddingBitsSet>
+                    },
+                )
+            }
+        };
+        let conversions = config.words.iter().map(|words| {
+            let span = words.span;
+            let name = words.value.name();
+            let size = words.value.bytes();
+            let prim = format_ident!("{}", name);
+            let into_words_ident = config.method_ident(format_ident!("into_words_{}", name));
+            let from_words_ident = config.method_ident(format_ident!("from_words_{}", name));
+            let word_bytes = (0..size)
+                .map(|index| quote_spanned!(span=> self.bytes[__bf_i * #size + #index]));
+            let message = format!(
+                "the bytes of `{}` are not a multiple of the size of `{}` for the `words` parameter",
+                ident, name,
+            );
+            let into_words_docs = format!(
+                "Returns the underlying bytes as `{}` words in the byte order of `word_endian`.",
+                name,
+            );
+            let from_words_docs = format!(
+                "Converts the given `{}` words into the bitfield the same as `from_bytes`.",
+                name,
+            );
+            quote_spanned!(span=>
+                const _: () = ::core::assert!(#ident::BYTES % #size == 0, #message);
+
+                impl #ident {
+                    #[doc = #into_words_docs]
+                    #[inline]
+                    pub const fn #into_words_ident(self) -> [::core::primitive::#prim; #ident::BYTES / #size] {
+                        let mut __bf_words = [0; #ident::BYTES / #size];
+                        let mut __bf_i = 0;
+                        while __bf_i < __bf_words.len() {
+                            __bf_words[__bf_i] = <::core::primitive::#prim>::#from_bytes([#( #word_bytes ),*]);
+                            __bf_i += 1;
+                        }
+                        __bf_words
+                    }
+
+                    #[doc = #from_words_docs]
+                    #[inline]
+                    pub #constness fn #from_words_ident(
+                        words: [::core::primitive::#prim; #ident::BYTES / #size],
+                    ) -> #from_words_output {
+                        let mut __bf_bytes = [0; #ident::BYTES];
+                        let mut __bf_i = 0;
+                        while __bf_i < __bf_bytes.len() {
+                            __bf_bytes[__bf_i] = <::core::primitive::#prim>::#to_bytes(words[__bf_i / #size])[__bf_i % #size];
+                            __bf_i += 1;
+                        }
+                        Self::#from_bytes_ident(__bf_bytes)
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #conversions )*
+        ))
+    }
+
+    /// Generates `From` impls for a `#[repr(uN)]` annotated #[bitfield] struct.
+    fn expand_repr_from_impls_and_checks(&self, config: &Config) -> Option<TokenStream2> {
+        let ident = &self.item_struct.ident;
+        let extra_field_inits = self.extra_field_inits(config);
+        config.repr.as_ref().map(|repr| {
+            let kind = &repr.value;
+            let span = repr.span;
+            let prim = match kind {
+                ReprKind::U8 => quote! { ::core::primitive::u8 },
+                ReprKind::U16 => quote! { ::core::primitive::u16 },
+                ReprKind::U32 => quote! { ::core::primitive::u32 },
+                ReprKind::U64 => quote! { ::core::primitive::u64 },
+                ReprKind::U128 => quote! { ::core::primitive::u128 },
+            };
+            let actual_bits = self.generate_target_or_actual_bitfield_size(config);
+            let (to_bytes, from_bytes) = match config.repr_endian() {
+                ReprEndian::Little => (quote! { to_le_bytes }, quote! { from_le_bytes }),
+                ReprEndian::Big => (quote! { to_be_bytes }, quote! { from_be_bytes }),
+            };
+            let from_le_ident = config.method_ident(format_ident!("from_le"));
+            let from_be_ident = config.method_ident(format_ident!("from_be"));
+            let trait_check_ident = match kind {
+                ReprKind::U8 => quote! { IsU8Compatible },
+                ReprKind::U16 => quote! { IsU16Compatible },
+                ReprKind::U32 => quote! { IsU32Compatible },
+                ReprKind::U64 => quote! { IsU64Compatible },
+                ReprKind::U128 => quote! { IsU128Compatible },
+            };
+            quote_spanned!(span=>
+                impl ::core::convert::From<#prim> for #ident
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    #[inline]
+                    fn from(__bf_prim: #prim) -> Self {
+                        Self { bytes: <#prim>::#to_bytes(__bf_prim), #extra_field_inits }
+                    }
+                }
+
+                impl ::core::convert::From<#ident> for #prim
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    #[inline]
+                    fn from(__bf_bitfield: #ident) -> Self {
+                        <Self>::#from_bytes(__bf_bitfield.bytes)
+                    }
+                }
+
+                impl #ident
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    /// Converts the integer into the bitfield with its least significant byte
+                    /// becoming the first byte of the bitfield.
+                    #[inline]
+                    pub fn #from_le_ident(__bf_prim: #prim) -> Self {
+                        Self { bytes: <#prim>::to_le_bytes(__bf_prim), #extra_field_inits }
+                    }
+
+                    /// Converts the integer into the bitfield with its most significant byte
+                    /// becoming the first byte of the bitfield.
+                    #[inline]
+                    pub fn #from_be_ident(__bf_prim: #prim) -> Self {
+                        Self { bytes: <#prim>::to_be_bytes(__bf_prim), #extra_field_inits }
+                    }
+                }
+            )
+        })
+    }
+
+    /// Returns `true` if any field of the bitfield is annotated with `#[validate(..)]`.
+    pub fn has_validated_fields(&self, config: &Config) -> bool {
+        self.field_infos(config)
+            .any(|info| info.config.validate.is_some())
+    }
+
+    /// Generates the `from_bytes_checked` constructor if any field is annotated with
+    /// `#[validate(..)]`.
+    ///
+    /// Otherwise returns `None`.
+    pub fn generate_from_bytes_checked(&self, config: &Config) -> Option<TokenStream2> {
+        if !self.has_validated_fields(config) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let from_bytes_ident = config.method_ident(format_ident!("from_bytes"));
+        let from_bytes_checked_ident = config.method_ident(format_ident!("from_bytes_checked"));
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let from_bytes = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self::#from_bytes_ident(bytes)),
+            false => quote_spanned!(span=> Self::#from_bytes_ident(bytes)?),
+        };
+        let checks = self
+            .field_infos_with_offsets(config)
+            .filter_map(|(info, offset)| {
+                let validate = info.config.validate.as_ref()?;
+                let ty = &info.field.ty;
+                let name = info.name();
+                let expected = match validate.value {
+                    Validate::Zero => quote_spanned!(validate.span=> 0),
+                    Validate::Ones => {
+                        quote_spanned!(validate.span=> {
+                            let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                            !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                        })
+                    }
+                    Validate::Any => return None,
+                };
+                Some(quote_spanned!(validate.span=>
+                    if ::modular_bitfield::private::#read_specifier::<#ty>(&__bf_bitfield.bytes[..], #offset) != #expected {
+                        return ::core::result::Result::Err(
+                            ::modular_bitfield::error::InvalidBytes::InvalidField(#name)
+                        )
+                    }
+                ))
+            });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Converts the given bytes into the bitfield struct and validates them.
+                ///
+                /// # Errors
+                ///
+                /// - If the given bytes contain bits at positions that are undefined for `Self`.
+                /// - If the bits of a field annotated with `#[validate(..)]` violate its rule.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn #from_bytes_checked_ident(
+                    bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::InvalidBytes> {
+                    let __bf_bitfield = #from_bytes;
+                    #( #checks )*
+                    ::core::result::Result::Ok(__bf_bitfield)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `f_as_variant()` and `set_f_as_variant(new_val)` accessors for every
+    /// field `f` annotated with `#[payload_of(..)]` and every variant of its mapping.
+    ///
+    /// The getters only decode the payload if the tag field holds the mapped variant while
+    /// the setters write the mapped variant to the tag field together with the payload.
+    pub fn generate_payload_accessors(&self, config: &Config) -> Option<TokenStream2> {
+        use syn::ext::IdentExt as _;
+        let infos = self.field_infos_with_offsets(config).collect::<Vec<_>>();
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let write_specifier = Self::bit_order_routine("write_specifier", config);
+        let struct_ident = &self.item_struct.ident;
+        let accessors = infos
+            .iter()
+            .filter_map(|(info, offset)| {
+                let payload_of = info.config.payload_of.as_ref()?;
+                let (tag_info, tag_offset) = infos
+                    .iter()
+                    .find(|(tag_info, _)| {
+                        tag_info.field.ident.is_some() && tag_info.name() == payload_of.value.tag
+                    })
+                    .expect("the tag field has been checked to exist during analysis");
+                let tag_path = match &tag_info.field.ty {
+                    syn::Type::Path(path) => &path.path,
+                    _ => unreachable!("the tag field type has been checked to be a path during analysis"),
+                };
+                let span = payload_of.span;
+                let ty = &info.field.ty;
+                let tag_ty = &tag_info.field.ty;
+                let vis = &info.field.vis;
+                let name = info.name();
+                let tag_name = tag_info.name();
+                let checked_read_tag =
+                    self.expand_checked_read(tag_offset, tag_info, config, &quote!(self));
+                let write_raw_val = self.expand_write_raw_val(offset, info, config);
+                let write_tag_raw_val = self.expand_write_raw_val(tag_offset, tag_info, config);
+                let mark_dirty = self.mark_dirty(info, config);
+                let mark_tag_dirty = self.mark_dirty(tag_info, config);
+                let get_assert_msg = format!(
+                    "value contains invalid bit pattern for field {}.{}",
+                    struct_ident, name
+                );
+                let set_assert_msg =
+                    format!("value out of bounds for field {}.{}", struct_ident, name);
+                let set_tag_assert_msg =
+                    format!("value out of bounds for field {}.{}", struct_ident, tag_name);
+                let methods = payload_of.value.map.iter().map(|(variant, packed)| {
+                    let variant_name = snake_case(&variant.unraw().to_string());
+                    let packed_name = quote!(#packed).to_string().replace(' ', "");
+                    let get_ident = config.method_ident(format_ident!(
+                        "{}_as_{}",
+                        info.getter_ident(),
+                        variant_name
+                    ));
+                    let set_ident = config.method_ident(format_ident!(
+                        "set_{}_as_{}",
+                        info.ident_frag(),
+                        variant_name
+                    ));
+                    let bits_msg = format!(
+                        "payload type `{}` of variant `{}` does not fit into field `{}.{}`",
+                        packed_name, variant, struct_ident,
+                        name,
+                    );
+                    let getter_docs = format!(
+                        "Returns the value of `{}` as `{}` if `{}` is `{}`.\n\n\
+                         # Errors\n\n\
+                         If `{}` is not `{}`.\n\n\
+                         # Panics\n\n\
+                         If the value contains an invalid bit pattern for `{}`.",
+                        name, packed_name, tag_name, variant, tag_name, variant, packed_name,
+                    );
+                    let setter_docs = format!(
+                        "Sets the value of `{}` to the given `{}` and `{}` to `{}`.\n\n\
+                         # Panics\n\n\
+                         If the given value is out of bounds for `{}`.",
+                        name, packed_name, tag_name, variant, packed_name,
+                    );
+                    let bits_check = quote_spanned!(span=>
+                        const _: () = ::core::assert!(
+                            <#packed as ::modular_bitfield::Specifier>::BITS
+                                <= <#ty as ::modular_bitfield::Specifier>::BITS,
+                            #bits_msg,
+                        );
+                    );
+                    let accessors = quote_spanned!(span=>
+                        #[doc = #getter_docs]
+                        #[inline]
+                        #[allow(dead_code)]
+                        #vis fn #get_ident(
+                            &self,
+                        ) -> ::core::result::Result<
+                            <#packed as ::modular_bitfield::Specifier>::InOut,
+                            ::modular_bitfield::error::WrongVariant
+                        > {
+                            if !::core::matches!(#checked_read_tag, ::core::result::Result::Ok(#tag_path::#variant)) {
+                                return ::core::result::Result::Err(::modular_bitfield::error::WrongVariant)
+                            }
+                            let __bf_read: <#packed as ::modular_bitfield::Specifier>::Bytes = {
+                                ::modular_bitfield::private::#read_specifier::<#packed>(&self.bytes[..], #offset)
+                            };
+                            ::core::result::Result::Ok(
+                                <#packed as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                                    .expect(#get_assert_msg)
+                            )
+                        }
+
+                        #[doc = #setter_docs]
+                        #[inline]
+                        #[allow(dead_code)]
+                        #vis fn #set_ident(
+                            &mut self,
+                            new_val: <#packed as ::modular_bitfield::Specifier>::InOut
+                        ) {
+                            let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#packed as ::modular_bitfield::Specifier>::Bytes>();
+                            let __bf_max_value: <#packed as ::modular_bitfield::Specifier>::Bytes = {
+                                !0 >> (__bf_base_bits - <#packed as ::modular_bitfield::Specifier>::BITS)
+                            };
+                            let __bf_packed: <#packed as ::modular_bitfield::Specifier>::Bytes = {
+                                <#packed as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                            }
+                            .expect(#set_assert_msg);
+                            ::core::assert!(__bf_packed <= __bf_max_value, #set_assert_msg);
+                            {
+                                // Clears the bits of the payload that are not covered by the payload type.
+                                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = 0;
+                                #write_raw_val
+                            }
+                            ::modular_bitfield::private::#write_specifier::<#packed>(&mut self.bytes[..], #offset, __bf_packed);
+                            {
+                                let __bf_raw_val: <#tag_ty as ::modular_bitfield::Specifier>::Bytes = {
+                                    <#tag_ty as ::modular_bitfield::Specifier>::into_bytes(#tag_path::#variant)
+                                }
+                                .expect(#set_tag_assert_msg);
+                                #write_tag_raw_val
+                            }
+                            #mark_dirty
+                            #mark_tag_dirty
+                        }
+                    );
+                    (bits_check, accessors)
+                });
+                Some(methods.collect::<Vec<_>>())
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+        if accessors.is_empty() {
+            return None
+        }
+        let (bits_checks, accessors): (Vec<_>, Vec<_>) = accessors.into_iter().unzip();
+        Some(quote_spanned!(self.item_struct.span()=>
+            #( #bits_checks )*
+
+            impl #struct_ident {
+                #( #accessors )*
+            }
+        ))
+    }
+
+    /// Generates `extern "C"` functions reading and writing the raw bits of every field
+    /// through a pointer to the bytes of the bitfield if `ffi_export` is set.
+    ///
+    /// For a field `f` and prefix `p_` these are `p_get_f(ptr)` and `p_set_f(ptr, raw)`.
+    pub fn generate_ffi_exports(&self, config: &Config) -> Option<TokenStream2> {
+        let ffi_export = config.ffi_export.as_ref()?;
+        let span = ffi_export.span;
+        let prefix = &ffi_export.value;
+        let struct_ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let write_specifier = Self::bit_order_routine("write_specifier", config);
+        let safety_docs = format!(
+            "# Safety\n\n\
+             The pointer must point to the bytes of a valid `{}`.",
+            struct_ident,
+        );
+        let functions = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let ty = &info.field.ty;
+            let name = info.name();
+            let getter = info.config.has_scalar_getter().then(|| {
+                let get_ident = format_ident!("{}get_{}", prefix, info.ident_frag());
+                let getter_docs = format!(
+                    "Returns the raw bits of `{}.{}` without decoding them.",
+                    struct_ident, name,
+                );
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    ///
+                    #[doc = #safety_docs]
+                    #[no_mangle]
+                    pub unsafe extern "C" fn #get_ident(
+                        ptr: *const ::core::primitive::u8,
+                    ) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                        let bytes = unsafe {
+                            &*(ptr as *const [::core::primitive::u8; #next_divisible_by_8 / 8usize])
+                        };
+                        ::modular_bitfield::private::#read_specifier::<#ty>(&bytes[..], #offset)
+                    }
+                )
+            });
+            let setter = info.config.has_scalar_setter().then(|| {
+                let set_ident = format_ident!("{}set_{}", prefix, info.ident_frag());
+                let setter_docs = format!(
+                    "Sets the raw bits of `{}.{}` without encoding them.\n\n\
+                     Returns `false` and leaves the bits unchanged if the given bits do not fit \
+                     into the bit width of the field.",
+                    struct_ident, name,
+                );
+                quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    ///
+                    #[doc = #safety_docs]
+                    #[no_mangle]
+                    pub unsafe extern "C" fn #set_ident(
+                        ptr: *mut ::core::primitive::u8,
+                        raw: <#ty as ::modular_bitfield::Specifier>::Bytes,
+                    ) -> ::core::primitive::bool {
+                        let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                        let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                            !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                        };
+                        if raw > __bf_max_value {
+                            return false
+                        }
+                        let bytes = unsafe {
+                            &mut *(ptr as *mut [::core::primitive::u8; #next_divisible_by_8 / 8usize])
+                        };
+                        ::modular_bitfield::private::#write_specifier::<#ty>(&mut bytes[..], #offset, raw);
+                        true
+                    }
+                )
+            });
+            quote_spanned!(span=>
+                #getter
+                #setter
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #functions )*
+        ))
+    }
+
+    /// Generates the `flip_bit` and `corrupt_f` test helpers if the `fault_injection`
+    /// parameter is set.
+    ///
+    /// The helpers are only compiled with `cfg(test)` and `corrupt_f` additionally
+    /// requires the `rand` crate feature.
+    pub fn generate_fault_injection(&self, config: &Config) -> Option<TokenStream2> {
+        let fault_injection = config.fault_injection.as_ref()?;
+        let span = fault_injection.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let toggle_bit = Self::bit_order_routine("toggle_bit", config);
+        let flip_bit_ident = config.method_ident(format_ident!("flip_bit"));
+        let out_of_bounds_msg = format!("bit index out of bounds for bitfield {}", ident);
+        let corrupters = self
+            .field_infos_with_offsets(config)
+            .filter(|_| cfg!(feature = "rand"))
+            .filter(|(info, _)| info.config.has_scalar_getter() || info.config.has_scalar_setter())
+            .map(|(info, offset)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let corrupt_ident =
+                    config.method_ident(format_ident!("corrupt_{}", info.ident_frag()));
+                let docs = format!(
+                    "Flips a random non-empty subset of the bits of `{}`.\n\n\
+                     The resulting value might be an invalid bit pattern for `{}`.",
+                    info.name(),
+                    info.name(),
+                );
+                quote_spanned!(field_span=>
+                    #[doc = #docs]
+                    #[must_use]
+                    #[allow(dead_code, clippy::identity_op)]
+                    #vis fn #corrupt_ident<__BfR>(mut self, __bf_rng: &mut __BfR) -> Self
+                    where
+                        __BfR: ::modular_bitfield::rand::Rng + ?::core::marker::Sized,
+                    {
+                        let __bf_bits = <#ty as ::modular_bitfield::Specifier>::BITS;
+                        let __bf_flipped = __bf_rng.gen_range(0..__bf_bits);
+                        for __bf_bit in 0..__bf_bits {
+                            let __bf_flip = __bf_rng.gen::<::core::primitive::bool>();
+                            if __bf_bit == __bf_flipped || __bf_flip {
+                                ::modular_bitfield::private::#toggle_bit(
+                                    &mut self.bytes[..],
+                                    #offset + __bf_bit,
+                                );
+                            }
+                        }
+                        self
+                    }
+                )
+            });
+        Some(quote_spanned!(span=>
+            #[cfg(test)]
+            impl #ident {
+                /// Flips the bit at the given bit index of the bitfield.
+                ///
+                /// # Panics
+                ///
+                /// If the bit index is out of bounds for the bitfield.
+                #[must_use]
+                #[allow(dead_code, clippy::identity_op)]
+                #vis fn #flip_bit_ident(mut self, index: ::core::primitive::usize) -> Self {
+                    ::core::assert!(index < (#size), #out_of_bounds_msg);
+                    ::modular_bitfield::private::#toggle_bit(&mut self.bytes[..], index);
+                    self
+                }
+
+                #( #corrupters )*
+            }
+        ))
+    }
+
+    /// Generates a unit test checking that the setters of every field leave all other
+    /// fields unchanged if the `neighbor_check_tests` parameter is set.
+    ///
+    /// The test draws random bytes for the bitfield and random valid values for the field
+    /// from a deterministic generator so that failures are reproducible.
+    pub fn generate_neighbor_check_tests(&self, config: &Config) -> Option<TokenStream2> {
+        let neighbor_check_tests = config.neighbor_check_tests.as_ref()?;
+        let span = neighbor_check_tests.span;
+        let ident = &self.item_struct.ident;
+        let test_ident = format_ident!("__bf_neighbor_check_{}", snake_case(&ident.to_string()));
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let extra_field_inits = self.extra_field_inits(config);
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let infos = self.field_infos_with_offsets(config).collect::<Vec<_>>();
+        let raw_values = |skipped: usize| {
+            let raw_values = infos
+                .iter()
+                .filter(|(info, _)| info.index != skipped)
+                .map(|(info, offset)| {
+                    let ty = &info.field.ty;
+                    quote_spanned!(info.field.span()=>
+                        ::core::convert::Into::<::core::primitive::u128>::into(
+                            ::modular_bitfield::private::#read_specifier::<#ty>(&__bf_value.bytes[..], #offset)
+                        )
+                    )
+                });
+            quote_spanned!(span=> [ #( #raw_values ),* ])
+        };
+        let checks = infos
+            .iter()
+            .filter(|(info, _)| info.config.has_scalar_setter())
+            .map(|(info, offset)| {
+                let field_span = info.field.span();
+                let set_ident =
+                    config.method_ident(format_ident!("set_{}", info.ident_frag()));
+                let receiver = Self::accessors_receiver(config, &quote!(__bf_value), true);
+                let new_value =
+                    self.expand_checked_read(offset, info, config, &quote!(__bf_source));
+                let raw_values = raw_values(info.index);
+                let message = format!(
+                    "setting `{}` of `{}` changed the other fields",
+                    info.name(),
+                    ident,
+                );
+                quote_spanned!(field_span=>
+                    let mut __bf_value = __bf_random(&mut __bf_rng);
+                    let __bf_source = __bf_random(&mut __bf_rng);
+                    if let ::core::result::Result::Ok(__bf_new_value) = #new_value {
+                        let __bf_before = #raw_values;
+                        #receiver.#set_ident(__bf_new_value);
+                        ::core::assert_eq!(__bf_before, #raw_values, #message);
+                    }
+                )
+            });
+        Some(quote_spanned!(span=>
+            #[cfg(test)]
+            #[test]
+            #[allow(unused, clippy::identity_op)]
+            fn #test_ident() {
+                fn __bf_random(__bf_rng: &mut ::modular_bitfield::private::TestRng) -> #ident {
+                    let mut __bf_bytes = [0u8; #next_divisible_by_8 / 8usize];
+                    __bf_rng.fill_bytes(&mut __bf_bytes);
+                    #ident {
+                        bytes: __bf_bytes,
+                        #extra_field_inits
+                    }
+                }
+
+                let mut __bf_rng = ::modular_bitfield::private::TestRng::new(
+                    #ident::LAYOUT_VERSION,
+                );
+                for _ in 0..256 {
+                    #( #checks )*
+                }
+            }
+        ))
+    }
+
+    /// Generates the `ModifyFields` impl used by the `modify_fields!` macro.
+    ///
+    /// The associated `Fields` type provides a chaining setter named after every field
+    /// with a setter so that the macro can map its field initializers to method calls.
+    fn generate_modify_fields(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let setters = self.field_infos(config).filter_map(|info| {
+            let field_ident = info.field.ident.as_ref()?;
+            if !info.config.has_scalar_setter() {
+                return None
+            }
+            let span = info.field.span();
+            let vis = &info.field.vis;
+            let in_out = Self::field_in_out_type(&info);
+            let set_ident = config.method_ident(format_ident!("set_{}", info.ident_frag()));
+            let receiver = Self::accessors_receiver(config, &quote!(self.0), true);
+            Some(quote_spanned!(span=>
+                #[inline]
+                #vis fn #field_ident(mut self, new_val: #in_out) -> Self {
+                    #receiver.#set_ident(new_val);
+                    self
+                }
+            ))
+        });
+        quote_spanned!(span=>
+            const _: () = {
+                #[doc(hidden)]
+                pub struct __BfModifyFields(#ident);
+
+                #[allow(dead_code, clippy::wrong_self_convention)]
+                impl __BfModifyFields {
+                    #( #setters )*
+                }
+
+                impl ::modular_bitfield::private::ModifyFields for #ident {
+                    type Fields = __BfModifyFields;
+
+                    #[inline]
+                    fn into_fields(self) -> Self::Fields {
+                        __BfModifyFields(self)
+                    }
+
+                    #[inline]
+                    fn from_fields(fields: Self::Fields) -> Self {
+                        fields.0
+                    }
+                }
+            };
+        )
+    }
+
+    /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
+    fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let extra_field_inits = self.extra_field_inits(config);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let into_bytes_ident = config.method_ident(format_ident!("into_bytes"));
+        let from_bytes_ident = config.method_ident(format_ident!("from_bytes"));
+        let is_zeroed_ident = config.method_ident(format_ident!("is_zeroed"));
+        // Masks out the undefined bits of the last byte for bitfields that are not filled.
+        let last_byte_mask = match config.bit_order() {
+            BitOrder::Le => {
+                quote_spanned!(span=>
+                    (((0x01_u16 << (8usize - (#next_divisible_by_8 - #size))) - 1) as ::core::primitive::u8)
+                )
+            }
+            BitOrder::Be => {
+                quote_spanned!(span=>
+                    !(((0x01_u16 << (#next_divisible_by_8 - #size)) - 1) as ::core::primitive::u8)
+                )
+            }
+        };
+        let from_bytes = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub const fn #from_bytes_ident(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
+                        Self { bytes, #extra_field_inits }
+                    }
+                )
+            }
+            false => {
+                let undefined_bits = match config.bit_order() {
+                    BitOrder::Le => {
+                        quote_spanned!(span=>
+                            (0xFF_u8 << (8 - (#next_divisible_by_8 - #size)))
+                        )
+                    }
+                    BitOrder::Be => {
+                        quote_spanned!(span=>
+                            ((0x01_u8 << (#next_divisible_by_8 - #size)) - 1)
+                        )
+                    }
+                };
+                quote_spanned!(span=>
+                    /// Converts the given bytes directly into the bitfield struct.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// The error names the byte and the undefined bits of it that are set.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn #from_bytes_ident(
+                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::PaddingBitsSet> {
+                        let __bf_byte_index = (#next_divisible_by_8 / 8usize) - 1;
+                        let __bf_mask = bytes[__bf_byte_index] & #undefined_bits;
+                        if __bf_mask != 0 {
+                            return ::core::result::Result::Err(::modular_bitfield::error::PaddingBitsSet {
+                                byte_index: __bf_byte_index,
+                                mask: __bf_mask,
+                            })
+                        }
+                        ::core::result::Result::Ok(Self { bytes, #extra_field_inits })
+                    }
+                )
+            }
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                /// The number of bytes of the bitfield as returned by `into_bytes`.
+                #[allow(clippy::identity_op)]
+                pub const BYTES: ::core::primitive::usize = #next_divisible_by_8 / 8usize;
+
+                /// Returns the underlying bits.
+                ///
+                /// # Layout
+                ///
+                /// The returned byte array is layed out in the same way as described
+                /// [here](https://docs.rs/modular-bitfield/#generated-structure).
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub const fn #into_bytes_ident(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    self.bytes
+                }
+
+                /// Returns `true` if all defined bits of the bitfield are zero.
+                ///
+                /// This compares the underlying bytes directly without decoding any fields.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub const fn #is_zeroed_ident(&self) -> ::core::primitive::bool {
+                    let __bf_last = (#next_divisible_by_8 / 8usize) - 1;
+                    let mut __bf_index = 0usize;
+                    while __bf_index < __bf_last {
+                        if self.bytes[__bf_index] != 0 {
+                            return false
+                        }
+                        __bf_index += 1;
+                    }
+                    self.bytes[__bf_last] & #last_byte_mask == 0
+                }
+
+                #from_bytes
+            }
+        )
+    }
+
+    /// Generates code to check for the bit size arguments of bitfields.
+    fn expand_bits_checks_for_field(&self, field_info: FieldInfo<'_>) -> TokenStream2 {
+        let name = field_info.name();
+        let actual_bits = field_info.bits_expr();
+        let folded_bits = field_info.folded_bits();
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = field_info;
+        let span = field.span();
+        let bits_check = match &config.bits {
+            Some(bits) => {
+                let ty = &field.ty;
+                let expected_bits = bits.value;
+                let span = bits.span;
+                let message = format!(
+                    "field `{}.{}` is annotated with #[bits = {}] but its specifier `{}` has ",
+                    self.item_struct.ident,
+                    name,
+                    expected_bits,
+                    quote!(#ty).to_string().replace(' ', ""),
+                );
+                Some(quote_spanned!(span =>
+                    let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]> =
+                        ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]>{
+                            arr: [(); #actual_bits]
+                        };
+                    const _: () = ::modular_bitfield::private::checks::check_bits_attribute(
+                        #actual_bits,
+                        #expected_bits,
+                        #message,
+                    );
+                ))
+            }
+            None => None,
+        };
+        // Asserts that folded bit widths match the actual ones, e.g. for shadowed `BN` types.
+        let folded_bits_check = folded_bits.map(|folded_bits| {
+            quote_spanned!(span=>
+                let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #folded_bits]> =
+                    ::modular_bitfield::private::checks::BitsCheck::<[(); #folded_bits]>{
+                        arr: [(); #actual_bits]
+                    };
+            )
+        });
+        quote_spanned!(span=>
+            const _: () = {
+                #bits_check
+                #folded_bits_check
+            };
+        )
+    }
+
+    /// Returns `true` if the getter, setter and `with_*` setter of the field are generated
+    /// as `const fn` due to the `const_accessors` parameter.
+    ///
+    /// These are fields of the predefined unsigned integer and `bool` specifier types whose
+    /// values are not mapped or checked by `#[offset_by = K]`, `#[validate(..)]` or
+    /// `#[payload_of(..)]`.
+    pub(super) fn has_const_accessors(info: &FieldInfo<'_>, config: &Config) -> bool {
+        config.const_accessors.is_some()
+            && (info.is_integer() || info.is_bool())
+            && info.config.offset_by.is_none()
+            && info.config.validate.is_none()
+            && info.config.payload_of.is_none()
+    }
+
+    /// Returns an expression reading the value of the field in a `const` context.
+    fn expand_const_read(
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let in_out = Self::field_in_out_type(info);
+        let read_bits = Self::bit_order_routine("read_bits", config);
+        let raw = quote_spanned!(span=>
+            ::modular_bitfield::private::#read_bits(
+                &self.bytes,
+                #offset,
+                <#ty as ::modular_bitfield::Specifier>::BITS,
+            )
+        );
+        match info.is_bool() {
+            true => quote_spanned!(span=> #raw != 0),
+            false => quote_spanned!(span=> #raw as #in_out),
+        }
+    }
+
+    /// Expands to the statements writing `new_val` to the bits of the field in a `const` context.
+    ///
+    /// # Panics
+    ///
+    /// The generated code panics if `new_val` is out of bounds for the bit width of the field.
+    fn expand_const_write(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let write_bits = Self::bit_order_routine("write_bits", config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let set_assert_msg = format!(
+            "value out of bounds for field {}.{}",
+            self.item_struct.ident,
+            info.name(),
+        );
+        quote_spanned!(span=>
+            let __bf_spec_bits: ::core::primitive::usize =
+                <#ty as ::modular_bitfield::Specifier>::BITS;
+            let __bf_raw_val = new_val as ::core::primitive::u128;
+            ::core::assert!(
+                __bf_spec_bits >= 128 || __bf_raw_val >> __bf_spec_bits == 0,
+                #set_assert_msg,
+            );
+            self.bytes = ::modular_bitfield::private::#write_bits(
+                self.bytes,
+                #offset,
+                __bf_spec_bits,
+                __bf_raw_val,
+            );
+            #mark_dirty
+        )
+    }
+
+    /// Returns the identifier of the `private` routine with the given name for the bit order of the bitfield.
+    fn bit_order_routine(name: &str, config: &Config) -> syn::Ident {
+        match config.bit_order() {
+            BitOrder::Le => format_ident!("{}", name),
+            BitOrder::Be => format_ident!("{}_be", name),
+        }
+    }
+
+    /// Expands to the statement writing `__bf_raw_val` to the bits of the field.
+    ///
+    /// Fields that cover whole bytes are written by copying the bytes of the value directly
+    /// which avoids the generic bit-by-bit write path in unoptimized builds.
+    fn expand_write_raw_val(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let write_specifier = Self::bit_order_routine("write_specifier", config);
+        let write_aligned_specifier = Self::bit_order_routine("write_aligned_specifier", config);
+        let record_write = self.record_access(info, config, "record_write");
+        quote_spanned!(span=>
+            #record_write
+            const __BF_BYTE_ALIGNED: ::core::primitive::bool =
+                ::modular_bitfield::private::is_byte_aligned::<#ty>(#offset);
+            if __BF_BYTE_ALIGNED {
+                ::modular_bitfield::private::#write_aligned_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
+            } else {
+                ::modular_bitfield::private::#write_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
+            }
+        )
+    }
+
+    /// Expands to the statement counting an access of the field if `access_stats` is set.
+    ///
+    /// The given method of the access counter of the field is called in debug builds only.
+    fn record_access(
+        &self,
+        info: &FieldInfo<'_>,
+        config: &Config,
+        method: &str,
+    ) -> Option<TokenStream2> {
+        config.access_stats.as_ref()?;
+        let span = info.field.span();
+        let ident = &self.item_struct.ident;
+        let counter_ident = Self::access_counter_ident(info);
+        let method = format_ident!("{}", method);
+        Some(quote_spanned!(span=>
+            #[cfg(debug_assertions)]
+            #ident::#counter_ident().#method();
+        ))
+    }
+
+    /// Returns the identifier of the hidden function returning the access counter of the field.
+    fn access_counter_ident(info: &FieldInfo<'_>) -> syn::Ident {
+        format_ident!("__bf_access_counter_{}", info.ident_frag())
+    }
+
+    /// Returns the `# Examples` section appended to the docs of an accessor of the field
+    /// if `doc_examples` is set.
+    ///
+    /// The doctest imports the bitfield struct from its crate and then runs the given lines.
+    /// Returns an empty string if `doc_examples` is not set or if the struct or the accessors
+    /// of the field are not public since doctests cannot access them in that case.
+    fn doc_example(&self, info: &FieldInfo<'_>, config: &Config, lines: &[String]) -> String {
+        let module = match &config.doc_examples {
+            Some(doc_examples) => &doc_examples.value,
+            None => return String::new(),
+        };
+        let is_public = |vis: &syn::Visibility| matches!(vis, syn::Visibility::Public(_));
+        if !is_public(&self.item_struct.vis) || !is_public(&info.field.vis) {
+            return String::new()
+        }
+        let krate = match std::env::var("CARGO_CRATE_NAME") {
+            Ok(krate) => krate,
+            Err(_) => return String::new(),
+        };
+        let module = match module.as_str() {
+            "crate" => "",
+            module => module.strip_prefix("crate::").unwrap_or(module),
+        };
+        let mut path = krate;
+        if !module.is_empty() {
+            path.push_str("::");
+            path.push_str(module);
+        }
+        format!(
+            "\n\n# Examples\n\n```no_run\n# use {}::{};\n{}\n```",
+            path,
+            self.item_struct.ident,
+            lines.join("\n"),
+        )
+    }
+
+    /// Returns the doctest line binding a `value` of the field for the examples of its setters.
+    ///
+    /// The value is read from a new instance. Returns `None` if the field has no getters
+    /// since the value cannot be named in a doctest then.
+    fn doc_example_value(&self, info: &FieldInfo<'_>, config: &Config) -> Option<String> {
+        if !info.config.has_scalar_getter() {
+            return None
+        }
+        Some(format!(
+            "let value = {}::{}().{}();",
+            self.item_struct.ident,
+            config.new_ident(),
+            config.method_ident(info.getter_ident()),
+        ))
+    }
+
+    /// Returns the type that the accessors of the field operate on.
+    ///
+    /// This is the `InOut` type of the field's specifier unless the field is annotated
+    /// with `#[quantity(..)]` or `#[try_from = Type]`, in which case it is the `uom`
+    /// quantity or `Type` respectively.
+    fn field_in_out_type(info: &FieldInfo<'_>) -> TokenStream2 {
+        let ty = &info.field.ty;
+        if let Some(try_from) = &info.config.try_from {
+            let target = &try_from.value;
+            return quote_spanned!(try_from.span=> #target)
+        }
+        match &info.config.quantity {
+            Some(quantity) => {
+                let Quantity { kind, storage, .. } = &quantity.value;
+                quote_spanned!(quantity.span=>
+                    ::modular_bitfield::uom::si::#storage::#kind
+                )
+            }
+            None => {
+                quote_spanned!(info.field.span()=> <#ty as ::modular_bitfield::Specifier>::InOut)
+            }
+        }
+    }
+
+    /// Returns the path of the unit of the raw value of a `#[quantity(..)]` field.
+    fn quantity_unit_path(quantity: &ConfigValue<Quantity>) -> TokenStream2 {
+        let module = format_ident!("{}", quantity.value.module_name());
+        let unit = &quantity.value.unit;
+        quote_spanned!(quantity.span=> ::modular_bitfield::uom::si::#module::#unit)
+    }
+
+    /// Returns the error type of the checked getters of the field.
+    ///
+    /// This is the error of the `TryFrom` conversion of `#[try_from = Type]` fields.
+    fn field_error_type(info: &FieldInfo<'_>) -> TokenStream2 {
+        let ty = &info.field.ty;
+        match &info.config.try_from {
+            Some(try_from) => {
+                let target = &try_from.value;
+                quote_spanned!(try_from.span=>
+                    <#target as ::core::convert::TryFrom<<#ty as ::modular_bitfield::Specifier>::InOut>>::Error
+                )
+            }
+            None => {
+                quote_spanned!(info.field.span()=>
+                    ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+                )
+            }
+        }
+    }
+
+    /// Decodes the raw bytes `__bf_read` of the field into a `Result` of the value returned
+    /// by its getters or the error returned by its checked getters.
+    ///
+    /// The raw bytes of `#[try_from = Type]` fields are their `InOut` values since these
+    /// fields are of unsigned integer types.
+    fn field_value_from_bytes(info: &FieldInfo<'_>) -> TokenStream2 {
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        if let Some(try_from) = &info.config.try_from {
+            let target = &try_from.value;
+            return quote_spanned!(try_from.span=>
+                <#target as ::core::convert::TryFrom<<#ty as ::modular_bitfield::Specifier>::InOut>>::try_from(__bf_read)
+            )
+        }
+        let from_raw = Self::field_value_from_raw(info)
+            .map(|from_raw| quote_spanned!(span=> .map(|__bf_value| #from_raw)));
+        quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)#from_raw)
+    }
+
+    /// Converts the raw value `__bf_value` of the field into the value returned by its getters.
+    fn field_value_from_raw(info: &FieldInfo<'_>) -> Option<TokenStream2> {
+        if let Some(offset_by) = &info.config.offset_by {
+            let offset = syn::LitInt::new(&offset_by.value.to_string(), offset_by.span);
+            return Some(quote_spanned!(offset_by.span=> __bf_value + #offset))
+        }
+        let quantity = info.config.quantity.as_ref()?;
+        let in_out = Self::field_in_out_type(info);
+        let unit = Self::quantity_unit_path(quantity);
+        let storage = &quantity.value.storage;
+        Some(quote_spanned!(quantity.span=>
+            <#in_out>::new::<#unit>(__bf_value as ::core::primitive::#storage)
+        ))
+    }
+
+    /// Converts the `new_val` passed to the setters of the field into its raw value.
+    ///
+    /// Quantities are rounded to the nearest raw value in the unit of the field.
+    /// Values of `#[offset_by = K]` fields that are smaller than `K` wrap around and are
+    /// thus rejected by the bounds checks of the setters.
+    fn field_value_into_raw(info: &FieldInfo<'_>) -> Option<TokenStream2> {
+        if let Some(try_from) = &info.config.try_from {
+            let ty = &info.field.ty;
+            return Some(quote_spanned!(try_from.span=>
+                let new_val: <#ty as ::modular_bitfield::Specifier>::InOut =
+                    ::core::convert::Into::into(new_val);
+            ))
+        }
+        if let Some(offset_by) = &info.config.offset_by {
+            let ty = &info.field.ty;
+            let offset = syn::LitInt::new(&offset_by.value.to_string(), offset_by.span);
+            return Some(quote_spanned!(offset_by.span=>
+                let new_val: <#ty as ::modular_bitfield::Specifier>::InOut = new_val.wrapping_sub(#offset);
+            ))
+        }
+        let quantity = info.config.quantity.as_ref()?;
+        let ty = &info.field.ty;
+        let unit = Self::quantity_unit_path(quantity);
+        Some(quote_spanned!(quantity.span=>
+            let new_val: <#ty as ::modular_bitfield::Specifier>::InOut = {
+                let __bf_value = new_val.get::<#unit>();
+                let __bf_value = if __bf_value < 0.0 {
+                    __bf_value - 0.5
+                } else {
+                    __bf_value + 0.5
+                };
+                __bf_value as <#ty as ::modular_bitfield::Specifier>::InOut
+            };
+        ))
+    }
+
+    fn expand_getters_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        let checked_read = self.expand_checked_read(offset, info, config, &quote!(self));
+        let const_read = Self::has_const_accessors(info, config)
+            .then(|| Self::expand_const_read(offset, info, config));
+        let minimal = config.minimal.is_some();
+        let get_ident = config.method_ident(info.getter_ident());
+        let get_from_bytes_ident =
+            config.method_ident(format_ident!("{}_from_bytes", info.getter_ident()));
+        let extra_field_inits = self.extra_field_inits(config);
+        let get_checked_ident = config.method_ident(info.checked_getter_ident());
+        let new_call = format!(
+            "let bitfield = {}::{}();",
+            self.item_struct.ident,
+            config.new_ident(),
+        );
+        let getter_example = self.doc_example(
+            info,
+            config,
+            &[new_call.clone(), format!("let value = bitfield.{}();", get_ident)],
+        );
+        let checked_getter_example = self.doc_example(
+            info,
+            config,
+            &[new_call, format!("let result = bitfield.{}();", get_checked_ident)],
+        );
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        if !config.has_scalar_getter() {
+            return None
+        }
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let name = info.name();
+
+        let retained_attrs = &config.retained_attrs;
+        let vis = &field.vis;
+        let get_assert_msg = match config.try_from {
+            Some(_) => format!("failed to convert value of field {}.{}", struct_ident, name),
+            None => {
+                format!(
+                    "value contains invalid bit pattern for field {}.{}",
+                    struct_ident, name
+                )
+            }
+        };
+
+        let getter_docs = format!("Returns the value of `{}`.{}", name, getter_example);
+        let error_docs = match config.try_from {
+            Some(_) => format!("If the raw value of `{}` cannot be converted.", name),
+            None => {
+                format!(
+                    "If the returned value contains an invalid bit pattern for `{}`.",
+                    name
+                )
+            }
+        };
+        let checked_getter_docs = format!(
+            "Returns the value of `{}`.\n\n\
+             # Errors\n\n\
+             {}{}",
+            name, error_docs, checked_getter_example,
+        );
+        let in_out = Self::field_in_out_type(info);
+        let error = Self::field_error_type(info);
+        let getter = match const_read {
+            Some(const_read) => {
+                let from_bytes_docs = format!(
+                    "Returns the value of `{}` stored in the given bytes.\n\n\
+                     This allows to decode constant bytes, e.g. embedded configuration data, \
+                     in `const` contexts.",
+                    name,
+                );
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis const fn #get_ident(&self) -> #in_out {
+                        #const_read
+                    }
+
+                    #[doc = #from_bytes_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis const fn #get_from_bytes_ident(
+                        bytes: [::core::primitive::u8; Self::BYTES],
+                    ) -> #in_out {
+                        Self { bytes, #extra_field_inits }.#get_ident()
+                    }
+                )
+            }
+            None if minimal => {
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self) -> #in_out {
+                        #checked_read.expect(#get_assert_msg)
+                    }
+                )
+            }
+            None => {
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self) -> #in_out {
+                        self.#get_checked_ident().expect(#get_assert_msg)
+                    }
+                )
+            }
+        };
+        if minimal {
+            return Some(getter)
+        }
+        let getters = quote_spanned!(span=>
+            #getter
+
+            #[doc = #checked_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_checked_ident(
+                &self,
+            ) -> ::core::result::Result<#in_out, #error> {
+                #checked_read
+            }
+        );
+        Some(getters)
+    }
+
+    fn expand_setters_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        let write_raw_val = self.expand_write_raw_val(offset, info, config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let const_write = Self::has_const_accessors(info, config)
+            .then(|| self.expand_const_write(offset, info, config));
+        let minimal = config.minimal.is_some();
+        let method_ident = |ident| config.method_ident(ident);
+        let example = |call: String| {
+            let new_call = format!(
+                "let mut bitfield = {}::{}();",
+                self.item_struct.ident,
+                config.new_ident(),
+            );
+            match self.doc_example_value(info, config) {
+                Some(value) => self.doc_example(info, config, &[value, new_call, call]),
+                None => String::new(),
+            }
+        };
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        if !config.has_scalar_setter() {
+            return None
+        }
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
+
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+
+        let set_ident = method_ident(format_ident!("set_{}", ident));
+        if minimal {
+            let set_assert_msg =
+                format!("value out of bounds for field {}.{}", struct_ident, name);
+            let setter_docs = format!(
+                "Sets the value of `{}` to the given value.\n\n\
+                 # Panics\n\n\
+                 If the given value is out of bounds for `{}`.{}",
+                name,
+                name,
+                example(format!("bitfield.{}(value);", set_ident)),
+            );
+            let in_out = Self::field_in_out_type(info);
+            let into_raw = Self::field_value_into_raw(info);
+            if let Some(const_write) = const_write {
+                return Some(quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis const fn #set_ident(&mut self, new_val: #in_out) {
+                        #const_write
+                    }
+                ))
+            }
+            return Some(quote_spanned!(span=>
+                #[doc = #setter_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_ident(&mut self, new_val: #in_out) {
+                    #into_raw
+                    let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                    let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                    };
+                    let __bf_spec_bits: ::core::primitive::usize =
+                <#ty as ::modular_bitfield::Specifier>::BITS;
+                    let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                    }
+                    .expect(#set_assert_msg);
+                    ::core::assert!(
+                        __bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value,
+                        #set_assert_msg,
+                    );
+                    #write_raw_val
+                    #mark_dirty
+                }
+            ))
+        }
+        let set_checked_ident = method_ident(format_ident!("set_{}_checked", ident));
+        let with_ident = method_ident(format_ident!("with_{}", ident));
+        let with_checked_ident = method_ident(format_ident!("with_{}_checked", ident));
+        let set_chain_ident = method_ident(format_ident!("set_{}_chain", ident));
+
+        let set_assert_msg =
+            format!("value out of bounds for field {}.{}", struct_ident, name);
+        let setter_docs = format!(
+            "Sets the value of `{}` to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for `{}`.{}",
+            name,
+            name,
+            example(format!("bitfield.{}(value);", set_ident)),
+        );
+        let checked_setter_docs = format!(
+            "Sets the value of `{}` to the given value.\n\n\
+             # Errors\n\n\
+             If the given value is out of bounds for `{}`.{}",
+            name,
+            name,
+            example(format!("let result = bitfield.{}(value);", set_checked_ident)),
+        );
+        let with_docs = format!(
+            "Returns a copy of the bitfield with the value of `{}` \
+             set to the given value.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for `{}`.{}",
+            name,
+            name,
+            example(format!("let bitfield = bitfield.{}(value);", with_ident)),
+        );
+        let checked_with_docs = format!(
+            "Returns a copy of the bitfield with the value of `{}` \
+             set to the given value.\n\n\
+             # Errors\n\n\
+             If the given value is out of bounds for `{}`.{}",
+            name,
+            name,
+            example(format!("let result = bitfield.{}(value);", with_checked_ident)),
+        );
+        let chain_docs = format!(
+            "Sets the value of `{}` to the given value and returns a mutable \
+             reference to the bitfield.\n\n\
+             # Panics\n\n\
+             If the given value is out of bounds for `{}`.{}",
+            name,
+            name,
+            example(format!("bitfield.{}(value).{}(value);", set_chain_ident, set_chain_ident)),
+        );
+        let in_out = Self::field_in_out_type(info);
+        let into_raw = Self::field_value_into_raw(info);
+        let (with_setter, setter) = match const_write {
+            Some(const_write) => {
+                let with_setter = quote_spanned!(span=>
+                    #[doc = #with_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis const fn #with_ident(
+                        mut self,
+                        new_val: #in_out
+                    ) -> Self {
+                        #const_write
+                        self
+                    }
+                );
+                let setter = quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis const fn #set_ident(&mut self, new_val: #in_out) {
+                        #const_write
+                    }
+                );
+                (with_setter, setter)
+            }
+            None => {
+                let with_setter = quote_spanned!(span=>
+                    #[doc = #with_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #with_ident(
+                        mut self,
+                        new_val: #in_out
+                    ) -> Self {
+                        self.#set_ident(new_val);
+                        self
+                    }
+                );
+                let setter = quote_spanned!(span=>
+                    #[doc = #setter_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(&mut self, new_val: #in_out) {
+                        self.#set_checked_ident(new_val).expect(#set_assert_msg)
+                    }
+                );
+                (with_setter, setter)
+            }
+        };
+        let setters = quote_spanned!(span=>
+            #with_setter
+
+            #[doc = #checked_with_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #with_checked_ident(
+                mut self,
+                new_val: #in_out,
+            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                self.#set_checked_ident(new_val)?;
+                ::core::result::Result::Ok(self)
+            }
+
+            #setter
+
+            #[doc = #chain_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_chain_ident(
+                &mut self,
+                new_val: #in_out
+            ) -> &mut Self {
+                self.#set_ident(new_val);
+                self
+            }
+
+            #[doc = #checked_setter_docs]
+            #[inline]
+            #( #retained_attrs )*
+            #vis fn #set_checked_ident(
+                &mut self,
+                new_val: #in_out
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                #into_raw
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                };
+                let __bf_spec_bits: ::core::primitive::usize =
+                <#ty as ::modular_bitfield::Specifier>::BITS;
+                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                }?;
+                // We compare base bits with spec bits to drop this condition
+                // if there cannot be invalid inputs.
+                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+                #write_raw_val
+                #mark_dirty
+                ::core::result::Result::Ok(())
+            }
+        );
+        Some(setters)
+    }
+
+    fn expand_unchecked_setter_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        config.unchecked_setters.as_ref()?;
+        let write_raw_val = self.expand_write_raw_val(offset, info, config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let method_ident = |ident| config.method_ident(ident);
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        if !config.has_scalar_setter() {
+            return None
+        }
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
+
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+
+        let set_unchecked_ident = method_ident(format_ident!("set_{}_unchecked", ident));
+        let in_out = Self::field_in_out_type(info);
+        let into_raw = Self::field_value_into_raw(info);
+        let set_assert_msg =
+            format!("value out of bounds for field {}.{}", struct_ident, name);
+        let unchecked_setter_docs = format!(
+            "Sets the value of `{}` to the given value without checking its bounds.\n\n\
+             # Safety\n\n\
+             The caller must guarantee that the given value is within bounds for `{}`.\n\n\
+             # Panics\n\n\
+             If debug assertions are enabled and the given value is out of bounds for `{}`.",
+            name, name, name,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #unchecked_setter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis unsafe fn #set_unchecked_ident(
+                &mut self,
+                new_val: #in_out
+            ) {
+                #into_raw
+                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    match <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val) {
+                        ::core::result::Result::Ok(__bf_raw_val) => __bf_raw_val,
+                        ::core::result::Result::Err(_) => {
+                            if ::core::cfg!(debug_assertions) {
+                                ::core::panic!(#set_assert_msg)
+                            }
+                            // Safety: The caller guarantees that the value is within bounds.
+                            unsafe { ::core::hint::unreachable_unchecked() }
+                        }
                     }
+                };
+                if ::core::cfg!(debug_assertions) {
+                    let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                    let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                    };
+                    ::core::assert!(__bf_raw_val <= __bf_max_value, #set_assert_msg);
                 }
+                #write_raw_val
+                #mark_dirty
             }
-        )
+        ))
     }
 
-    /// Generates the compile-time assertion if the optional `byte` parameter has been set.
-    fn expand_optional_bytes_check(&self, config: &Config) -> Option<TokenStream2> {
-        let ident = &self.item_struct.ident;
-        config.bytes.as_ref().map(|config| {
-            let bytes = config.value;
-            quote_spanned!(config.span=>
-                const _: () = {
-                    struct ExpectedBytes { __bf_unused: [::core::primitive::u8; #bytes] }
+    fn expand_raw_accessors_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if config.minimal.is_some() {
+            return None
+        }
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let write_raw_val = self.expand_write_raw_val(offset, info, config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let method_ident = |ident| config.method_ident(ident);
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
 
-                    ::modular_bitfield::private::static_assertions::assert_eq_size!(
-                        ExpectedBytes,
-                        #ident
-                    );
-                };
-            )
-        })
-    }
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
 
-    /// Generates `From` impls for a `#[repr(uN)]` annotated #[bitfield] struct.
-    fn expand_repr_from_impls_and_checks(&self, config: &Config) -> Option<TokenStream2> {
-        let ident = &self.item_struct.ident;
-        config.repr.as_ref().map(|repr| {
-            let kind = &repr.value;
-            let span = repr.span;
-            let prim = match kind {
-                ReprKind::U8 => quote! { ::core::primitive::u8 },
-                ReprKind::U16 => quote! { ::core::primitive::u16 },
-                ReprKind::U32 => quote! { ::core::primitive::u32 },
-                ReprKind::U64 => quote! { ::core::primitive::u64 },
-                ReprKind::U128 => quote! { ::core::primitive::u128 },
-            };
-            let actual_bits = self.generate_target_or_actual_bitfield_size(config);
-            let trait_check_ident = match kind {
-                ReprKind::U8 => quote! { IsU8Compatible },
-                ReprKind::U16 => quote! { IsU16Compatible },
-                ReprKind::U32 => quote! { IsU32Compatible },
-                ReprKind::U64 => quote! { IsU64Compatible },
-                ReprKind::U128 => quote! { IsU128Compatible },
-            };
+        let raw_getter = config.has_scalar_getter().then(|| {
+            let get_raw_ident = method_ident(format_ident!("{}_raw", info.getter_ident()));
+            let get_raw_docs = format!(
+                "Returns the raw bits of `{}` without decoding them.\n\n\
+                 The returned bits might not represent a valid value of `{}`.",
+                name, name,
+            );
             quote_spanned!(span=>
-                impl ::core::convert::From<#prim> for #ident
-                where
-                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
-                {
-                    #[inline]
-                    fn from(__bf_prim: #prim) -> Self {
-                        Self { bytes: <#prim>::to_le_bytes(__bf_prim) }
-                    }
+                #[doc = #get_raw_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #get_raw_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                    ::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
                 }
-
-                impl ::core::convert::From<#ident> for #prim
-                where
-                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
-                {
-                    #[inline]
-                    fn from(__bf_bitfield: #ident) -> Self {
-                        <Self>::from_le_bytes(__bf_bitfield.bytes)
-                    }
+            )
+        });
+        let raw_setter = config.has_scalar_setter().then(|| {
+            let set_raw_ident = method_ident(format_ident!("set_{}_raw", ident));
+            let set_assert_msg =
+                format!("value out of bounds for field {}.{}", struct_ident, name);
+            let set_raw_docs = format!(
+                "Sets the raw bits of `{}` without encoding them.\n\n\
+                 The given bits are not required to represent a valid value of `{}`.\n\n\
+                 # Panics\n\n\
+                 If the given bits do not fit into the bit width of `{}`.",
+                name, name, name,
+            );
+            quote_spanned!(span=>
+                #[doc = #set_raw_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_raw_ident(
+                    &mut self,
+                    raw: <#ty as ::modular_bitfield::Specifier>::Bytes
+                ) {
+                    let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                    let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                    };
+                    ::core::assert!(raw <= __bf_max_value, #set_assert_msg);
+                    let __bf_raw_val = raw;
+                    #write_raw_val
+                    #mark_dirty
                 }
             )
-        })
+        });
+        Some(quote_spanned!(span=>
+            #raw_getter
+            #raw_setter
+        ))
     }
 
-    /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
-    fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
-        let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
-        let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
-        let from_bytes = match config.filled_enabled() {
-            true => {
+    /// Generates the associated constants and setters of a `#[const_values(..)]` field.
+    ///
+    /// For a field `f` and a value named `NAME` these are `F_NAME` and `set_f_to_name()`.
+    /// The constants are of the type that the accessors of the field operate on.
+    fn expand_const_values_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        use syn::ext::IdentExt as _;
+        let const_values = info.config.const_values.as_ref()?;
+        let set_ident = config.method_ident(format_ident!("set_{}", info.ident_frag()));
+        let field_prefix = info.getter_ident().unraw().to_string().to_uppercase();
+        let name = info.name();
+        let in_out = Self::field_in_out_type(info);
+        let vis = &info.field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let values = const_values.value.iter().map(|const_value| {
+            let span = const_value.name.span();
+            let const_ident = format_ident!("{}_{}", field_prefix, const_value.name, span = span);
+            let value = syn::LitInt::new(
+                const_value.value.base10_digits(),
+                const_value.value.span(),
+            );
+            let const_docs = format!("The `{}` value of `{}`.", const_value.name, name);
+            let setter = info.config.has_scalar_setter().then(|| {
+                let set_to_ident = config.method_ident(format_ident!(
+                    "set_{}_to_{}",
+                    info.ident_frag(),
+                    const_value.name.to_string().to_lowercase(),
+                    span = span,
+                ));
+                let setter_docs = format!(
+                    "Sets the value of `{}` to [`Self::{}`].",
+                    name, const_ident,
+                );
                 quote_spanned!(span=>
-                    /// Converts the given bytes directly into the bitfield struct.
+                    #[doc = #setter_docs]
                     #[inline]
-                    #[allow(clippy::identity_op)]
-                    pub const fn from_bytes(bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]) -> Self {
-                        Self { bytes }
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #set_to_ident(&mut self) {
+                        self.#set_ident(Self::#const_ident)
                     }
                 )
-            }
-            false => {
+            });
+            quote_spanned!(span=>
+                #[doc = #const_docs]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis const #const_ident: #in_out = #value;
+
+                #setter
+            )
+        });
+        Some(quote_spanned!(const_values.span=>
+            #( #values )*
+        ))
+    }
+
+    fn expand_integer_setters_for_field(
+        &self,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if !info.is_integer()
+            || !info.config.has_scalar_setter()
+            || info.config.offset_by.is_some()
+            || config.minimal.is_some()
+        {
+            return None
+        }
+        let method_ident = |ident| config.method_ident(ident);
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
+
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+
+        let set_ident = method_ident(format_ident!("set_{}", ident));
+        let set_truncated_ident = method_ident(format_ident!("set_{}_truncated", ident));
+        let set_saturating_ident = method_ident(format_ident!("set_{}_saturating", ident));
+        let truncated_docs = format!(
+            "Sets the value of `{}` to the given value truncated to the bit width of `{}`.\n\n\
+             The bits of the given value that do not fit into `{}` are discarded.",
+            name, name, name,
+        );
+        let saturating_docs = format!(
+            "Sets the value of `{}` to the given value saturated to the maximum value of `{}`.",
+            name, name,
+        );
+        let bitwise_ops = config.has_scalar_getter().then(|| {
+            let get_ident = method_ident(info.getter_ident());
+            let ops = [("or", quote! { | }), ("and", quote! { & }), ("xor", quote! { ^ })];
+            let ops = ops.iter().map(|(op, operator)| {
+                let op_ident = method_ident(format_ident!("{}_{}", op, ident));
+                let docs = format!(
+                    "Applies a bitwise `{}` with the given bits to the value of `{}`.\n\n\
+                     The bits of the given value that do not fit into `{}` are discarded.",
+                    op.to_uppercase(),
+                    name,
+                    name,
+                );
                 quote_spanned!(span=>
-                    /// Converts the given bytes directly into the bitfield struct.
-                    ///
-                    /// # Errors
-                    ///
-                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    #[doc = #docs]
                     #[inline]
-                    #[allow(clippy::identity_op)]
-                    pub fn from_bytes(
-                        bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize]
-                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                        if bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size))) {
-                            return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
-                        }
-                        ::core::result::Result::Ok(Self { bytes })
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #op_ident(
+                        &mut self,
+                        bits: <#ty as ::modular_bitfield::Specifier>::InOut
+                    ) {
+                        let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::InOut>();
+                        let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::InOut = {
+                            !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                        };
+                        self.#set_ident(self.#get_ident() #operator (bits & __bf_max_value))
                     }
                 )
+            });
+            quote_spanned!(span=> #( #ops )*)
+        });
+        Some(quote_spanned!(span=>
+            #[doc = #truncated_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_truncated_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) {
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::InOut>();
+                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::InOut = {
+                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                };
+                self.#set_ident(new_val & __bf_max_value)
             }
-        };
-        quote_spanned!(span=>
-            impl #ident {
-                /// Returns the underlying bits.
-                ///
-                /// # Layout
-                ///
-                /// The returned byte array is layed out in the same way as described
-                /// [here](https://docs.rs/modular-bitfield/#generated-structure).
-                #[inline]
-                #[allow(clippy::identity_op)]
-                pub const fn into_bytes(self) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
-                    self.bytes
-                }
 
-                #from_bytes
+            #[doc = #saturating_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_saturating_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) {
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::InOut>();
+                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::InOut = {
+                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                };
+                self.#set_ident(::core::cmp::min(new_val, __bf_max_value))
             }
-        )
+
+            #bitwise_ops
+        ))
     }
 
-    /// Generates code to check for the bit size arguments of bitfields.
-    fn expand_bits_checks_for_field(&self, field_info: FieldInfo<'_>) -> TokenStream2 {
+    fn expand_bit_queries_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        config.bit_queries.as_ref()?;
+        let is_zero_specifier = Self::bit_order_routine("is_zero_specifier", config);
+        let significant_bits_specifier =
+            Self::bit_order_routine("significant_bits_specifier", config);
+        let method_ident = |ident| config.method_ident(ident);
         let FieldInfo {
             index: _,
             field,
             config,
-        } = field_info;
+        } = &info;
+        if !config.has_scalar_getter() {
+            return None
+        }
         let span = field.span();
-        let bits_check = match &config.bits {
-            Some(bits) => {
-                let ty = &field.ty;
-                let expected_bits = bits.value;
-                let span = bits.span;
-                Some(quote_spanned!(span =>
-                    let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]> =
-                        ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]>{
-                            arr: [(); <#ty as ::modular_bitfield::Specifier>::BITS]
-                        };
-                ))
+        let retained_attrs = &config.retained_attrs;
+
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+
+        let is_zero_ident = method_ident(format_ident!("{}_is_zero", info.getter_ident()));
+        let significant_bits_ident =
+            method_ident(format_ident!("{}_significant_bits", info.getter_ident()));
+        let is_zero_docs = format!("Returns `true` if all bits of `{}` are zero.", name);
+        let significant_bits_docs = format!(
+            "Returns the number of significant bits of `{}`.\n\n\
+             This is the position of the highest set bit of `{}` plus one \
+             or zero if no bit is set.",
+            name, name,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #is_zero_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #is_zero_ident(&self) -> ::core::primitive::bool {
+                ::modular_bitfield::private::#is_zero_specifier::<#ty>(&self.bytes[..], #offset)
             }
-            None => None,
-        };
-        quote_spanned!(span=>
-            const _: () = {
-                #bits_check
-            };
-        )
+
+            #[doc = #significant_bits_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #significant_bits_ident(&self) -> ::core::primitive::u32 {
+                ::modular_bitfield::private::#significant_bits_specifier::<#ty>(&self.bytes[..], #offset)
+            }
+        ))
     }
 
-    fn expand_getters_for_field(
+    /// Returns the suffix of the diagnostic getters generated by the `dual_order` parameter.
+    ///
+    /// These getters interpret the bytes with the bit order opposite to the bitfield's.
+    pub(super) fn dual_order_suffix(config: &Config) -> &'static str {
+        match config.bit_order() {
+            BitOrder::Le => "msb0",
+            BitOrder::Be => "lsb0",
+        }
+    }
+
+    fn expand_dual_order_getters_for_field(
         &self,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        config: &Config,
     ) -> Option<TokenStream2> {
+        config.dual_order.as_ref()?;
+        let read_specifier = match config.bit_order() {
+            BitOrder::Le => format_ident!("read_specifier_be"),
+            BitOrder::Be => format_ident!("read_specifier"),
+        };
+        let suffix = Self::dual_order_suffix(config);
+        let get_ident = config.method_ident(format_ident!("{}_{}", info.getter_ident(), suffix));
+        let get_checked_ident = config.method_ident(format_ident!(
+            "{}_{}_or_err",
+            info.getter_ident(),
+            suffix
+        ));
         let FieldInfo {
             index: _,
             field,
             config,
         } = &info;
-        if config.skip_getters() {
+        if !config.has_scalar_getter() {
             return None
         }
         let struct_ident = &self.item_struct.ident;
         let span = field.span();
-        let ident = info.ident_frag();
         let name = info.name();
 
         let retained_attrs = &config.retained_attrs;
-        let get_ident = field
-            .ident
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| format_ident!("get_{}", ident));
-        let get_checked_ident = field
-            .ident
-            .as_ref()
-            .map(|_| format_ident!("{}_or_err", ident))
-            .unwrap_or_else(|| format_ident!("get_{}_or_err", ident));
         let ty = &field.ty;
         let vis = &field.vis;
+        let in_out = Self::field_in_out_type(info);
+        let error = Self::field_error_type(info);
+        let from_bytes = Self::field_value_from_bytes(info);
         let get_assert_msg = format!(
-            "value contains invalid bit pattern for field {}.{}",
-            struct_ident, name
+            "value contains invalid bit pattern for field {}.{} in {} bit order",
+            struct_ident, name, suffix,
+        );
+        let getter_docs = format!(
+            "Returns the value of `{}` as if the bitfield used the {} bit order.\n\n\
+             This is meant for diagnostics during layout migrations.",
+            name, suffix,
         );
-
-        let getter_docs = format!("Returns the value of `{}`.", name);
         let checked_getter_docs = format!(
-            "Returns the value of `{}`.\n\n\
+            "Returns the value of `{}` as if the bitfield used the {} bit order.\n\n\
              # Errors\n\n\
              If the returned value contains an invalid bit pattern for `{}`.",
-            name, name,
+            name, suffix, name,
         );
-        let getters = quote_spanned!(span=>
+        Some(quote_spanned!(span=>
             #[doc = #getter_docs]
             #[inline]
+            #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+            #vis fn #get_ident(&self) -> #in_out {
                 self.#get_checked_ident().expect(#get_assert_msg)
             }
 
@@ -542,151 +4387,419 @@ impl BitfieldStruct {
             #( #retained_attrs )*
             #vis fn #get_checked_ident(
                 &self,
-            ) -> ::core::result::Result<
-                <#ty as ::modular_bitfield::Specifier>::InOut,
-                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
-            > {
+            ) -> ::core::result::Result<#in_out, #error> {
                 let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    ::modular_bitfield::private::read_specifier::<#ty>(&self.bytes[..], #offset)
+                    ::modular_bitfield::private::#read_specifier::<#ty>(&self.bytes[..], #offset)
                 };
-                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                #from_bytes
             }
-        );
-        Some(getters)
+        ))
     }
 
-    fn expand_setters_for_field(
+    fn expand_bounds_for_field(
         &self,
-        offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
+        config: &Config,
     ) -> Option<TokenStream2> {
+        config.bounds.as_ref()?;
+        let method_ident = |ident| config.method_ident(ident);
         let FieldInfo {
             index: _,
             field,
             config,
         } = &info;
-        if config.skip_setters() {
+        if !config.has_scalar_setter() {
             return None
         }
-        let struct_ident = &self.item_struct.ident;
         let span = field.span();
         let retained_attrs = &config.retained_attrs;
 
-        let ident = info.ident_frag();
         let name = info.name();
         let ty = &field.ty;
         let vis = &field.vis;
 
-        let set_ident = format_ident!("set_{}", ident);
-        let set_checked_ident = format_ident!("set_{}_checked", ident);
-        let with_ident = format_ident!("with_{}", ident);
-        let with_checked_ident = format_ident!("with_{}_checked", ident);
-
-        let set_assert_msg =
-            format!("value out of bounds for field {}.{}", struct_ident, name);
-        let setter_docs = format!(
-            "Sets the value of `{}` to the given value.\n\n\
-             # Panics\n\n\
-             If the given value is out of bounds for `{}`.",
-            name, name,
-        );
-        let checked_setter_docs = format!(
-            "Sets the value of `{}` to the given value.\n\n\
-             # Errors\n\n\
-             If the given value is out of bounds for `{}`.",
-            name, name,
-        );
-        let with_docs = format!(
-            "Returns a copy of the bitfield with the value of `{}` \
-             set to the given value.\n\n\
-             # Panics\n\n\
-             If the given value is out of bounds for `{}`.",
-            name, name,
-        );
-        let checked_with_docs = format!(
-            "Returns a copy of the bitfield with the value of `{}` \
-             set to the given value.\n\n\
-             # Errors\n\n\
-             If the given value is out of bounds for `{}`.",
-            name, name,
-        );
-        let setters = quote_spanned!(span=>
-            #[doc = #with_docs]
+        let min_ident = method_ident(format_ident!("{}_min", info.getter_ident()));
+        let max_ident = method_ident(format_ident!("{}_max", info.getter_ident()));
+        let min_docs = format!("Returns the smallest raw value that can be stored in `{}`.", name);
+        let max_docs = format!("Returns the largest raw value that can be stored in `{}`.", name);
+        Some(quote_spanned!(span=>
+            #[doc = #min_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> Self {
-                self.#set_ident(new_val);
-                self
+            #vis const fn #min_ident() -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                0
             }
 
-            #[doc = #checked_with_docs]
+            #[doc = #max_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
-            #vis fn #with_checked_ident(
-                mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
-            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                self.#set_checked_ident(new_val)?;
-                ::core::result::Result::Ok(self)
+            #vis const fn #max_ident() -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
             }
+        ))
+    }
 
-            #[doc = #setter_docs]
+    /// Expands to the `f_mask_for(value)` function of the field if `masks` is set.
+    ///
+    /// The function returns the bytes of the bitfield with only the bits of the field set
+    /// to the given raw value which is useful for set and clear companion registers.
+    fn expand_masks_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        config.masks.as_ref()?;
+        if !info.config.has_scalar_getter() && !info.config.has_scalar_setter() {
+            return None
+        }
+        let span = info.field.span();
+        let ty = &info.field.ty;
+        let vis = &info.field.vis;
+        let retained_attrs = &info.config.retained_attrs;
+        let name = info.name();
+        let mask_bytes = Self::bit_order_routine("mask_bytes", config);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let mask_ident = config.method_ident(format_ident!("{}_mask_for", info.getter_ident()));
+        let mask_docs = format!(
+            "Returns the bytes of the bitfield with only the bits of `{}` set to the given raw value.",
+            name,
+        );
+        let panic_msg = format!(
+            "value out of bounds for field {}.{}",
+            self.item_struct.ident, name
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #mask_docs]
+            ///
+            /// # Panics
+            ///
+            /// If the given value is out of bounds for the bit width of the field.
             #[inline]
-            #[allow(dead_code)]
+            #[allow(dead_code, clippy::identity_op)]
             #( #retained_attrs )*
-            #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
-                self.#set_checked_ident(new_val).expect(#set_assert_msg)
+            #vis const fn #mask_ident(
+                value: <#ty as ::modular_bitfield::Specifier>::Bytes,
+            ) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                match ::modular_bitfield::private::#mask_bytes(
+                    value as ::core::primitive::u128,
+                    #offset,
+                    <#ty as ::modular_bitfield::Specifier>::BITS,
+                ) {
+                    ::core::option::Option::Some(bytes) => bytes,
+                    ::core::option::Option::None => ::core::panic!(#panic_msg),
+                }
             }
+        ))
+    }
 
-            #[doc = #checked_setter_docs]
-            #[inline]
-            #( #retained_attrs )*
-            #vis fn #set_checked_ident(
-                &mut self,
-                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
-                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
-                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
-                };
-                let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
-                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
-                }?;
-                // We compare base bits with spec bits to drop this condition
-                // if there cannot be invalid inputs.
-                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
-                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+    fn expand_bool_methods_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        if !info.is_bool() || config.minimal.is_some() {
+            return None
+        }
+        let toggle_bit = Self::bit_order_routine("toggle_bit", config);
+        let set_bit_if = Self::bit_order_routine("set_bit_if", config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let method_ident = |ident| config.method_ident(ident);
+        let get_ident = method_ident(info.getter_ident());
+        let then_ident = method_ident(format_ident!("{}_then", info.getter_ident()));
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
+
+        let ident = info.ident_frag();
+        let name = info.name();
+        let vis = &field.vis;
+
+        let toggle_ident = method_ident(format_ident!("toggle_{}", ident));
+        let set_if_ident = method_ident(format_ident!("set_{}_if", ident));
+        let toggle_docs = format!("Flips the value of `{}`.", name);
+        let set_if_docs = format!(
+            "Sets `{}` to `true` if `condition` is `true` and leaves it unchanged otherwise.",
+            name,
+        );
+        let then_docs = format!(
+            "Returns `Some(f())` if `{}` is `true` and `None` otherwise.",
+            name,
+        );
+        let mark_dirty_if = mark_dirty.as_ref().map(|mark_dirty| {
+            quote_spanned!(span=>
+                if condition {
+                    #mark_dirty
                 }
-                ::modular_bitfield::private::write_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
-                ::core::result::Result::Ok(())
+            )
+        });
+        let setters = config.has_scalar_setter().then(|| {
+            quote_spanned!(span=>
+                #[doc = #toggle_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #toggle_ident(&mut self) {
+                    ::modular_bitfield::private::#toggle_bit(&mut self.bytes[..], #offset);
+                    #mark_dirty
+                }
+
+                #[doc = #set_if_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_if_ident(&mut self, condition: ::core::primitive::bool) {
+                    ::modular_bitfield::private::#set_bit_if(&mut self.bytes[..], #offset, condition);
+                    #mark_dirty_if
+                }
+            )
+        });
+        let getters = config.has_scalar_getter().then(|| {
+            quote_spanned!(span=>
+                #[doc = #then_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #then_ident<__BfR, __BfF>(&self, f: __BfF) -> ::core::option::Option<__BfR>
+                where
+                    __BfF: ::core::ops::FnOnce() -> __BfR,
+                {
+                    if self.#get_ident() {
+                        ::core::option::Option::Some(f())
+                    } else {
+                        ::core::option::Option::None
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #setters
+            #getters
+        ))
+    }
+
+    /// Generates the `f_at(index)` and `set_f_at(index, new_value)` accessors
+    /// for a field `f` annotated with `#[repeat = N]`.
+    ///
+    /// For a field array `f: [T; N]` these are named `f_or_err(index)` and
+    /// `set_f_checked(index, new_value)` instead and accompanied by the panicking
+    /// `f(index)` and `set_f(index, new_value)` accessors.
+    fn expand_repeated_accessors_for_field(
+        &self,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        let repeat = info.config.repeat.as_ref()?;
+        let read_specifier = Self::bit_order_routine("read_specifier", config);
+        let write_specifier = Self::bit_order_routine("write_specifier", config);
+        let mark_dirty = self.mark_dirty(info, config);
+        let method_ident = |ident| config.method_ident(ident);
+        let get_ident = method_ident(info.getter_ident());
+        let set_ident = method_ident(format_ident!("set_{}", info.ident_frag()));
+        let (get_at_ident, set_at_ident) = match info.config.array {
+            true => {
+                (
+                    method_ident(info.checked_getter_ident()),
+                    method_ident(format_ident!("set_{}_checked", info.ident_frag())),
+                )
+            }
+            false => {
+                (
+                    method_ident(format_ident!("{}_at", info.getter_ident())),
+                    method_ident(format_ident!("set_{}_at", info.ident_frag())),
+                )
             }
+        };
+        let FieldInfo {
+            index: _,
+            field,
+            config,
+        } = &info;
+        let struct_ident = &self.item_struct.ident;
+        let span = field.span();
+        let retained_attrs = &config.retained_attrs;
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let repetitions = repeat.value;
+
+        let get_assert_msg = format!(
+            "value contains invalid bit pattern for field {}.{}",
+            struct_ident, name
         );
-        Some(setters)
+        let get_at_docs = format!(
+            "Returns the value of `{}` at the given index.\n\n\
+             # Errors\n\n\
+             If the index is not less than {}.\n\n\
+             # Panics\n\n\
+             If the value contains an invalid bit pattern for `{}`.",
+            name, repetitions, name,
+        );
+        let set_at_docs = format!(
+            "Sets the value of `{}` at the given index to the given value.\n\n\
+             # Errors\n\n\
+             If the index is not less than {} or the given value is out of bounds for `{}`.",
+            name, repetitions, name,
+        );
+        let in_out = Self::field_in_out_type(info);
+        let from_bytes = Self::field_value_from_bytes(info);
+        let into_raw = Self::field_value_into_raw(info);
+        let panicking_accessors = config.array.then(|| {
+            let get_docs = format!(
+                "Returns the value of `{}` at the given index.\n\n\
+                 # Panics\n\n\
+                 If the index is not less than {} or the value contains an invalid bit pattern.",
+                name, repetitions,
+            );
+            let set_docs = format!(
+                "Sets the value of `{}` at the given index to the given value.\n\n\
+                 # Panics\n\n\
+                 If the index is not less than {} or the given value is out of bounds.",
+                name, repetitions,
+            );
+            let get_assert_msg = format!("index out of bounds for field {}.{}", struct_ident, name);
+            let set_assert_msg = format!(
+                "index or value out of bounds for field {}.{}",
+                struct_ident, name
+            );
+            let getter = (!config.skip_getters()).then(|| {
+                quote_spanned!(span=>
+                    #[doc = #get_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self, index: ::core::primitive::usize) -> #in_out {
+                        self.#get_at_ident(index).expect(#get_assert_msg)
+                    }
+                )
+            });
+            let setter = (!config.skip_setters()).then(|| {
+                quote_spanned!(span=>
+                    #[doc = #set_docs]
+                    #[inline]
+                    #[allow(dead_code)]
+                    #( #retained_attrs )*
+                    #vis fn #set_ident(
+                        &mut self,
+                        index: ::core::primitive::usize,
+                        new_val: #in_out,
+                    ) {
+                        self.#set_at_ident(index, new_val).expect(#set_assert_msg)
+                    }
+                )
+            });
+            quote_spanned!(span=>
+                #getter
+                #setter
+            )
+        });
+        let getter = (!config.skip_getters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #get_at_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #get_at_ident(
+                    &self,
+                    index: ::core::primitive::usize,
+                ) -> ::core::result::Result<#in_out, ::modular_bitfield::error::OutOfBounds> {
+                    if index >= #repetitions {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        ::modular_bitfield::access::#read_specifier::<#ty>(
+                            &self.bytes[..],
+                            #offset + index * <#ty as ::modular_bitfield::Specifier>::BITS,
+                        )?
+                    };
+                    let __bf_value = #from_bytes;
+                    ::core::result::Result::Ok(__bf_value.expect(#get_assert_msg))
+                }
+            )
+        });
+        let setter = (!config.skip_setters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #set_at_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_at_ident(
+                    &mut self,
+                    index: ::core::primitive::usize,
+                    new_val: #in_out,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    if index >= #repetitions {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    #into_raw
+                    let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                    }?;
+                    ::modular_bitfield::access::#write_specifier::<#ty>(
+                        &mut self.bytes[..],
+                        #offset + index * <#ty as ::modular_bitfield::Specifier>::BITS,
+                        __bf_raw_val,
+                    )?;
+                    #mark_dirty
+                    ::core::result::Result::Ok(())
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #panicking_accessors
+            #getter
+            #setter
+        ))
     }
 
     fn expand_getters_and_setters_for_field(
         &self,
         offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
         info: FieldInfo<'_>,
+        config: &Config,
     ) -> Option<TokenStream2> {
         let FieldInfo {
             index: _, field, ..
         } = &info;
         let span = field.span();
-        let ty = &field.ty;
-        let getters = self.expand_getters_for_field(offset, &info);
-        let setters = self.expand_setters_for_field(offset, &info);
+        let getters = self.expand_getters_for_field(offset, &info, config);
+        let setters = self.expand_setters_for_field(offset, &info, config);
+        let unchecked_setter = self.expand_unchecked_setter_for_field(offset, &info, config);
+        let raw_accessors = self.expand_raw_accessors_for_field(offset, &info, config);
+        let bit_queries = self.expand_bit_queries_for_field(offset, &info, config);
+        let bounds = self.expand_bounds_for_field(&info, config);
+        let masks = self.expand_masks_for_field(offset, &info, config);
+        let bool_methods = self.expand_bool_methods_for_field(offset, &info, config);
+        let integer_setters = self.expand_integer_setters_for_field(&info, config);
+        let const_values = self.expand_const_values_for_field(&info, config);
+        let dual_order_getters =
+            self.expand_dual_order_getters_for_field(offset, &info, config);
+        let repeated_accessors = self.expand_repeated_accessors_for_field(offset, &info, config);
         let getters_and_setters = quote_spanned!(span=>
             #getters
             #setters
+            #unchecked_setter
+            #raw_accessors
+            #bit_queries
+            #bounds
+            #masks
+            #bool_methods
+            #integer_setters
+            #const_values
+            #dual_order_getters
+            #repeated_accessors
         );
-        offset.push(syn::parse_quote! { <#ty as ::modular_bitfield::Specifier>::BITS });
+        info.add_bits_to_offset(offset);
         Some(getters_and_setters)
     }
 
@@ -701,17 +4814,141 @@ impl BitfieldStruct {
         let bits_checks = self
             .field_infos(config)
             .map(|field_info| self.expand_bits_checks_for_field(field_info));
-        let setters_and_getters = self.field_infos(config).map(|field_info| {
-            self.expand_getters_and_setters_for_field(&mut offset, field_info)
-        });
+        let setters_and_getters = self
+            .field_infos(config)
+            .map(|field_info| {
+                self.expand_getters_and_setters_for_field(&mut offset, field_info, config)
+            })
+            .collect::<Vec<_>>();
+        let accessors = match self.generate_accessors_mod(&setters_and_getters, config) {
+            Some(accessors_mod) => accessors_mod,
+            None => {
+                quote_spanned!(span=>
+                    impl #ident {
+                        #( #setters_and_getters )*
+                    }
+                )
+            }
+        };
         quote_spanned!(span=>
             const _: () = {
                 #( #bits_checks )*
             };
 
+            #accessors
+        )
+    }
+
+    /// Returns the identifiers of the shared and exclusive accessor methods
+    /// if the `accessors_mod` parameter is set.
+    fn accessors_mod_idents(config: &Config) -> Option<(syn::Ident, syn::Ident)> {
+        let accessors_mod = config.accessors_mod.as_ref()?;
+        let name = &accessors_mod.value;
+        let span = accessors_mod.span;
+        Some((
+            config.method_ident(format_ident!("{}", name, span = span)),
+            config.method_ident(format_ident!("{}_mut", name, span = span)),
+        ))
+    }
+
+    /// Returns an expression evaluating to the value on which the field accessors
+    /// of `receiver` are invoked.
+    ///
+    /// With `accessors_mod` these are the generated wrappers instead of `receiver` itself.
+    fn accessors_receiver(
+        config: &Config,
+        receiver: &TokenStream2,
+        mutable: bool,
+    ) -> TokenStream2 {
+        match Self::accessors_mod_idents(config) {
+            Some((_, mut_ident)) if mutable => quote!(#receiver.#mut_ident()),
+            Some((ref_ident, _)) => quote!(#receiver.#ref_ident()),
+            None => receiver.clone(),
+        }
+    }
+
+    /// Generates the wrapper types holding the field accessors if the `accessors_mod`
+    /// parameter is set.
+    ///
+    /// The exclusive wrapper holds all `accessors` while the shared wrapper only
+    /// holds the getters and bit queries.
+    fn generate_accessors_mod(
+        &self,
+        accessors: &[Option<TokenStream2>],
+        config: &Config,
+    ) -> Option<TokenStream2> {
+        let (ref_method_ident, mut_method_ident) = Self::accessors_mod_idents(config)?;
+        let accessors_mod = config.accessors_mod.as_ref()?;
+        let span = accessors_mod.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let camel = camel_case(&accessors_mod.value);
+        let ref_ident = format_ident!("{}{}Ref", ident, camel);
+        let mut_ident = format_ident!("{}{}Mut", ident, camel);
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        offset.push(syn::parse_quote! { 0usize });
+        let getters = self
+            .field_infos(config)
+            .map(|info| {
+                let getters = self.expand_getters_for_field(&offset, &info, config);
+                let bit_queries = self.expand_bit_queries_for_field(&offset, &info, config);
+                let dual_order_getters =
+                    self.expand_dual_order_getters_for_field(&offset, &info, config);
+                info.add_bits_to_offset(&mut offset);
+                quote_spanned!(info.field.span()=>
+                    #getters
+                    #bit_queries
+                    #dual_order_getters
+                )
+            })
+            .collect::<Vec<_>>();
+        let ref_docs = format!(
+            "Shared access to the fields of [`{}`] as returned by [`{}::{}`].",
+            ident, ident, ref_method_ident,
+        );
+        let mut_docs = format!(
+            "Exclusive access to the fields of [`{}`] as returned by [`{}::{}`].",
+            ident, ident, mut_method_ident,
+        );
+        let ref_method_docs = format!("Returns the getters of the fields of `{}`.", ident);
+        let mut_method_docs =
+            format!("Returns the getters and setters of the fields of `{}`.", ident);
+        Some(quote_spanned!(span=>
+            #[doc = #ref_docs]
+            #vis struct #ref_ident<'a> {
+                bytes: &'a [::core::primitive::u8],
+            }
+
+            #[doc = #mut_docs]
+            #vis struct #mut_ident<'a> {
+                bytes: &'a mut [::core::primitive::u8],
+            }
+
             impl #ident {
-                #( #setters_and_getters )*
+                #[doc = #ref_method_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #ref_method_ident(&self) -> #ref_ident<'_> {
+                    #ref_ident { bytes: &self.bytes[..] }
+                }
+
+                #[doc = #mut_method_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #vis fn #mut_method_ident(&mut self) -> #mut_ident<'_> {
+                    #mut_ident { bytes: &mut self.bytes[..] }
+                }
             }
-        )
+
+            #[allow(clippy::identity_op)]
+            impl #ref_ident<'_> {
+                #( #getters )*
+            }
+
+            #[allow(clippy::identity_op)]
+            impl #mut_ident<'_> {
+                #( #accessors )*
+            }
+        ))
     }
 }
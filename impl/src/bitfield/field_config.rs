@@ -6,10 +6,120 @@ use proc_macro2::Span;
 pub struct FieldConfig {
     /// Attributes that are re-expanded and going to be ignored by the rest of the `#[bitfield]` invocation.
     pub retained_attrs: Vec<syn::Attribute>,
+    /// Attributes that are re-expanded on the phantom field if `keep_phantom_fields` is set.
+    pub phantom_attrs: Vec<syn::Attribute>,
     /// An encountered `#[bits = N]` attribute on a field.
     pub bits: Option<ConfigValue<usize>>,
     /// An encountered `#[skip]` attribute on a field.
     pub skip: Option<ConfigValue<SkipWhich>>,
+    /// An encountered `#[reserved]` attribute on a field.
+    pub reserved: Option<ConfigValue<()>>,
+    /// An encountered `#[quantity(Kind, unit)]` attribute on a field.
+    pub quantity: Option<ConfigValue<Quantity>>,
+    /// An encountered `#[try_from = Type]` attribute on a field.
+    pub try_from: Option<ConfigValue<syn::Type>>,
+    /// An encountered `#[repeat = N]` attribute on a field.
+    pub repeat: Option<ConfigValue<usize>>,
+    /// `true` if the field is declared as a field array `[T; N]`.
+    ///
+    /// Field arrays are repeated like `#[repeat = N]` fields but their indexed accessors
+    /// are named like the plain getters and setters.
+    pub array: bool,
+    /// An encountered `#[validate(..)]` attribute on a field.
+    pub validate: Option<ConfigValue<Validate>>,
+    /// An encountered `#[payload_of(..)]` attribute on a field.
+    pub payload_of: Option<ConfigValue<PayloadOf>>,
+    /// An encountered `#[const_values(NAME = value, ..)]` attribute on a field.
+    pub const_values: Option<ConfigValue<Vec<ConstValue>>>,
+    /// An encountered `#[offset_by = K]` attribute on a field.
+    pub offset_by: Option<ConfigValue<u128>>,
+    /// An encountered `#[group = "name"]` attribute on a field.
+    pub group: Option<ConfigValue<syn::Ident>>,
+    /// An encountered `#[default = EXPR]` attribute on a field.
+    pub default: Option<ConfigValue<syn::Expr>>,
+}
+
+/// A named value of a `#[const_values(NAME = value, ..)]` field.
+#[derive(Clone)]
+pub struct ConstValue {
+    /// The name of the value, e.g. `FAST`.
+    pub name: syn::Ident,
+    /// The value itself.
+    pub value: syn::LitInt,
+}
+
+/// The tag field and the mapping of its variants to payload types of a `#[payload_of(..)]` field.
+#[derive(Clone)]
+pub struct PayloadOf {
+    /// The name of the tag field whose value selects the type of the payload.
+    pub tag: String,
+    /// The span of the name of the tag field.
+    pub tag_span: Span,
+    /// The variants of the tag field together with the payload types they select.
+    pub map: Vec<(syn::Ident, syn::Type)>,
+}
+
+/// The rule of a `#[validate(..)]` field that is enforced by `from_bytes_checked`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Validate {
+    /// All bits of the field must be zero.
+    Zero,
+    /// All bits of the field must be one.
+    Ones,
+    /// The bits of the field may have any value.
+    Any,
+}
+
+/// The `uom` quantity that the accessors of a field operate on.
+#[derive(Clone)]
+pub struct Quantity {
+    /// The kind of the quantity, e.g. `ElectricPotential`.
+    pub kind: syn::Ident,
+    /// The unit of the stored raw value, e.g. `millivolt`.
+    pub unit: syn::Ident,
+    /// The underlying storage type of the quantity, either `f32` or `f64`.
+    pub storage: syn::Ident,
+}
+
+impl Quantity {
+    /// Returns the name of the `uom::si` module that defines the units of the quantity.
+    ///
+    /// This is the snake case version of the quantity kind, e.g. `electric_potential`
+    /// for `ElectricPotential`.
+    pub fn module_name(&self) -> String {
+        snake_case(&self.kind.to_string())
+    }
+}
+
+/// Returns the snake case version of the given camel case name, e.g. `type_one` for `TypeOne`.
+pub fn snake_case(camel_case: &str) -> String {
+    let mut name = String::new();
+    for (index, c) in camel_case.chars().enumerate() {
+        if c.is_uppercase() {
+            if index != 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+/// Returns the camel case version of the given snake case name, e.g. `TypeOne` for `type_one`.
+pub fn camel_case(snake_case: &str) -> String {
+    snake_case
+        .split('_')
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars)
+        })
+        .collect()
 }
 
 /// Controls which parts of the code generation to skip.
@@ -30,6 +140,7 @@ pub enum SkipWhich {
     ///
     /// - `set_f`
     /// - `set_f_checked`
+    /// - `set_f_chain`
     /// - `with_f`
     /// - `with_f_checked`
     Setters,
@@ -53,6 +164,15 @@ impl FieldConfig {
         self.retained_attrs.push(attr);
     }
 
+    /// Moves the retained attributes onto the phantom field of the field.
+    ///
+    /// Only documentation attributes are kept for the generated getters and setters
+    /// since the other attributes are expected to operate on fields.
+    pub fn retain_attrs_for_phantom_field(&mut self) {
+        self.phantom_attrs = self.retained_attrs.clone();
+        self.retained_attrs.retain(|attr| attr.path.is_ident("doc"));
+    }
+
     /// Sets the `#[bits = N]` if found for a `#[bitfield]` annotated field.
     ///
     /// # Errors
@@ -93,6 +213,13 @@ impl FieldConfig {
     /// E.g. when skipping getters or setters twice. Note that skipping getters followed
     /// by skipping setters is fine.
     pub fn skip(&mut self, which: SkipWhich, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref reserved) = self.reserved {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[skip]` and `#[reserved]` attributes for field"
+            )
+            .into_combine(format_err!(reserved.span, "conflicting `#[reserved]` here")))
+        }
         fn raise_skip_error(
             skip_params: &str,
             span: Span,
@@ -138,21 +265,343 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Sets the `#[reserved]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[reserved]` or a `#[skip]` attribute.
+    pub fn reserved(&mut self, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.reserved {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[reserved]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[reserved]` here")))
+        }
+        if let Some(ref skip) = self.skip {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[reserved]` and `#[skip]` attributes for field"
+            )
+            .into_combine(format_err!(skip.span, "conflicting `#[skip]` here")))
+        }
+        if let Some(ref repeat) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[reserved]` and `#[repeat = N]` attributes for field"
+            )
+            .into_combine(format_err!(repeat.span, "conflicting `#[repeat = N]` here")))
+        }
+        self.reserved = Some(ConfigValue { value: (), span });
+        Ok(())
+    }
+
+    /// Sets the `#[quantity(Kind, unit)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[quantity(..)]` attribute.
+    pub fn quantity(&mut self, quantity: Quantity, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.quantity {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[quantity(..)]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[quantity(..)]` here")))
+        }
+        self.quantity = Some(ConfigValue {
+            value: quantity,
+            span,
+        });
+        Ok(())
+    }
+
+    /// Sets the `#[try_from = Type]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[try_from = Type]` attribute.
+    pub fn try_from(&mut self, ty: syn::Type, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.try_from {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[try_from = Type]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[try_from = Type]` here")))
+        }
+        self.try_from = Some(ConfigValue { value: ty, span });
+        Ok(())
+    }
+
+    /// Sets the `#[default = EXPR]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[default = EXPR]` attribute.
+    pub fn default_value(&mut self, expr: syn::Expr, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.default {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[default = EXPR]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[default = EXPR]` here")))
+        }
+        self.default = Some(ConfigValue { value: expr, span });
+        Ok(())
+    }
+
+    /// Sets the `#[repeat = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// - If `N` is zero.
+    /// - If previously already registered a `#[repeat = M]`, a `#[reserved]`, a
+    ///   `#[validate(..)]` or a `#[payload_of(..)]` attribute.
+    pub fn repeat(&mut self, amount: usize, span: Span) -> Result<(), syn::Error> {
+        if amount == 0 {
+            return Err(format_err!(
+                span,
+                "encountered invalid repetition factor of 0 for #[repeat = N], expected at least 1"
+            ))
+        }
+        if let Some(ref previous) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[repeat = N]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[repeat = M]` here")))
+        }
+        if let Some(ref reserved) = self.reserved {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[repeat = N]` and `#[reserved]` attributes for field"
+            )
+            .into_combine(format_err!(reserved.span, "conflicting `#[reserved]` here")))
+        }
+        if let Some(ref validate) = self.validate {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[repeat = N]` and `#[validate(..)]` attributes for field"
+            )
+            .into_combine(format_err!(validate.span, "conflicting `#[validate(..)]` here")))
+        }
+        if let Some(ref payload_of) = self.payload_of {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[repeat = N]` and `#[payload_of(..)]` attributes for field"
+            )
+            .into_combine(format_err!(payload_of.span, "conflicting `#[payload_of(..)]` here")))
+        }
+        if let Some(ref const_values) = self.const_values {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[repeat = N]` and `#[const_values(..)]` attributes for field"
+            )
+            .into_combine(format_err!(const_values.span, "conflicting `#[const_values(..)]` here")))
+        }
+        self.repeat = Some(ConfigValue {
+            value: amount,
+            span,
+        });
+        Ok(())
+    }
+
+    /// Registers the length of a field array `[T; N]` as its repetition factor.
+    ///
+    /// # Errors
+    ///
+    /// If the field has a `#[repeat = N]` attribute or any other attribute conflicting with it.
+    pub fn array(&mut self, len: usize, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref repeat) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered conflicting field array type and `#[repeat = N]` attribute for field"
+            )
+            .into_combine(format_err!(repeat.span, "conflicting `#[repeat = N]` here")))
+        }
+        self.repeat(len, span)?;
+        self.array = true;
+        Ok(())
+    }
+
+    /// Sets the `#[validate(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[validate(..)]` or a `#[repeat = N]` attribute.
+    pub fn validate(&mut self, rule: Validate, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.validate {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[validate(..)]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[validate(..)]` here")))
+        }
+        if let Some(ref repeat) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[validate(..)]` and `#[repeat = N]` attributes for field"
+            )
+            .into_combine(format_err!(repeat.span, "conflicting `#[repeat = N]` here")))
+        }
+        self.validate = Some(ConfigValue { value: rule, span });
+        Ok(())
+    }
+
+    /// Sets the `#[payload_of(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[payload_of(..)]` or a `#[repeat = N]` attribute.
+    pub fn payload_of(&mut self, payload_of: PayloadOf, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.payload_of {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[payload_of(..)]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[payload_of(..)]` here")))
+        }
+        if let Some(ref repeat) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[payload_of(..)]` and `#[repeat = N]` attributes for field"
+            )
+            .into_combine(format_err!(repeat.span, "conflicting `#[repeat = N]` here")))
+        }
+        self.payload_of = Some(ConfigValue {
+            value: payload_of,
+            span,
+        });
+        Ok(())
+    }
+
+    /// Sets the `#[const_values(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[const_values(..)]` or a `#[repeat = N]` attribute.
+    pub fn const_values(
+        &mut self,
+        const_values: Vec<ConstValue>,
+        span: Span,
+    ) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.const_values {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[const_values(..)]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[const_values(..)]` here")))
+        }
+        if let Some(ref repeat) = self.repeat {
+            return Err(format_err!(
+                span,
+                "encountered conflicting `#[const_values(..)]` and `#[repeat = N]` attributes for field"
+            )
+            .into_combine(format_err!(repeat.span, "conflicting `#[repeat = N]` here")))
+        }
+        self.const_values = Some(ConfigValue {
+            value: const_values,
+            span,
+        });
+        Ok(())
+    }
+
+    /// Sets the `#[offset_by = K]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// - If `K` is zero.
+    /// - If previously already registered an `#[offset_by = L]` attribute.
+    pub fn offset_by(&mut self, offset: u128, span: Span) -> Result<(), syn::Error> {
+        if offset == 0 {
+            return Err(format_err!(
+                span,
+                "encountered invalid offset of 0 for #[offset_by = K], expected at least 1"
+            ))
+        }
+        if let Some(ref previous) = self.offset_by {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[offset_by = K]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[offset_by = L]` here")))
+        }
+        self.offset_by = Some(ConfigValue {
+            value: offset,
+            span,
+        });
+        Ok(())
+    }
+
+    /// Sets the `#[group = "name"]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[group = "name"]` attribute.
+    pub fn group(&mut self, name: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.group {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `#[group = \"name\"]` attribute for field"
+            )
+            .into_combine(format_err!(previous.span, "duplicate `#[group = \"name\"]` here")))
+        }
+        self.group = Some(ConfigValue { value: name, span });
+        Ok(())
+    }
+
+    /// Returns the offset of the values of the field, which is 0 unless it is annotated with `#[offset_by = K]`.
+    pub fn offset(&self) -> u128 {
+        self.offset_by.as_ref().map(|offset_by| offset_by.value).unwrap_or(0)
+    }
+
+    /// Returns the repetition factor of the field, which is 1 unless it is annotated with `#[repeat = N]`.
+    pub fn repetitions(&self) -> usize {
+        self.repeat.as_ref().map(|repeat| repeat.value).unwrap_or(1)
+    }
+
+    /// Returns `true` if the field has been marked as `#[reserved]`.
+    pub fn is_reserved(&self) -> bool {
+        self.reserved.is_some()
+    }
+
     /// Returns `true` if the config demands that code generation for setters should be skipped.
+    ///
+    /// This is always the case for `#[reserved]` fields.
     pub fn skip_setters(&self) -> bool {
-        self.skip
-            .as_ref()
-            .map(|config| config.value)
-            .map(SkipWhich::skip_setters)
-            .unwrap_or(false)
+        self.is_reserved()
+            || self
+                .skip
+                .as_ref()
+                .map(|config| config.value)
+                .map(SkipWhich::skip_setters)
+                .unwrap_or(false)
     }
 
     /// Returns `true` if the config demands that code generation for getters should be skipped.
+    ///
+    /// This is always the case for `#[reserved]` fields.
     pub fn skip_getters(&self) -> bool {
-        self.skip
-            .as_ref()
-            .map(|config| config.value)
-            .map(SkipWhich::skip_getters)
-            .unwrap_or(false)
+        self.is_reserved()
+            || self
+                .skip
+                .as_ref()
+                .map(|config| config.value)
+                .map(SkipWhich::skip_getters)
+                .unwrap_or(false)
+    }
+
+    /// Returns `true` if the field has a getter returning its whole value.
+    ///
+    /// Fields with `#[repeat = N]` and field arrays only have indexed accessors.
+    pub fn has_scalar_getter(&self) -> bool {
+        self.repeat.is_none() && !self.skip_getters()
+    }
+
+    /// Returns `true` if the field has a setter taking its whole value.
+    ///
+    /// Fields with `#[repeat = N]` and field arrays only have indexed accessors.
+    pub fn has_scalar_setter(&self) -> bool {
+        self.repeat.is_none() && !self.skip_setters()
     }
 }
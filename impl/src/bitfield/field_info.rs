@@ -3,8 +3,15 @@ use super::{
     BitfieldStruct,
     Config,
 };
+use quote::format_ident;
+use syn::{
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    Token,
+};
 
 /// Compactly stores all shared and useful information about a single `#[bitfield]` field.
+#[derive(Clone)]
 pub struct FieldInfo<'a> {
     /// The index of the field.
     pub index: usize,
@@ -32,6 +39,326 @@ impl<'a> FieldInfo<'a> {
         }
     }
 
+    /// Returns the identifier of the getter for this field.
+    ///
+    /// For field `f` this is `f` and for unnamed field `0` this is `get_0`.
+    pub fn getter_ident(&self) -> syn::Ident {
+        let ident = self.ident_frag();
+        self.field
+            .ident
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("get_{}", ident))
+    }
+
+    /// Returns the identifier of the checked getter for this field.
+    ///
+    /// For field `f` this is `f_or_err` and for unnamed field `0` this is `get_0_or_err`.
+    pub fn checked_getter_ident(&self) -> syn::Ident {
+        let ident = self.ident_frag();
+        self.field
+            .ident
+            .as_ref()
+            .map(|_| format_ident!("{}_or_err", ident))
+            .unwrap_or_else(|| format_ident!("get_{}_or_err", ident))
+    }
+
+    /// Returns `true` if the field is of the `bool` specifier type.
+    ///
+    /// These are fields of type `bool`, `core::primitive::bool` or `std::primitive::bool`
+    /// that are not annotated with `#[quantity(..)]`.
+    pub fn is_bool(&self) -> bool {
+        if self.config.quantity.is_some() {
+            return false
+        }
+        let path = match &self.field.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return false,
+        };
+        let segments = path
+            .segments
+            .iter()
+            .map(|segment| {
+                segment
+                    .arguments
+                    .is_empty()
+                    .then(|| segment.ident.to_string())
+            })
+            .collect::<Option<Vec<_>>>();
+        match segments.as_deref() {
+            Some([name]) => name == "bool" && path.leading_colon.is_none(),
+            Some([krate, module, name]) => {
+                (krate == "core" || krate == "std") && module == "primitive" && name == "bool"
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the field is of an unsigned integer specifier type.
+    ///
+    /// These are fields of the predefined `B1`, .., `B128` and `u8`, .., `u128` specifier
+    /// types that are annotated with neither `#[quantity(..)]` nor `#[try_from = Type]`.
+    pub fn is_integer(&self) -> bool {
+        if self.config.quantity.is_some() || self.config.try_from.is_some() {
+            return false
+        }
+        Self::is_integer_type(&self.field.ty)
+    }
+
+    /// Returns `true` if the type is one of the predefined `B1`, .., `B128` and `u8`, .., `u128`
+    /// specifier types.
+    pub fn is_integer_type(ty: &syn::Type) -> bool {
+        let path = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return false,
+        };
+        let segment = match path.segments.last() {
+            Some(segment) if path.segments.len() == 1 && segment.arguments.is_empty() => {
+                segment
+            }
+            _ => return false,
+        };
+        let name = segment.ident.to_string();
+        match name.as_str() {
+            "u8" | "u16" | "u32" | "u64" | "u128" => true,
+            _ => {
+                name.strip_prefix('B')
+                    .filter(|bits| !bits.starts_with('0'))
+                    .and_then(|bits| bits.parse::<usize>().ok())
+                    .filter(|bits| (1..=128).contains(bits))
+                    .is_some()
+            }
+        }
+    }
+
+    /// Returns the expression denoting the bit width of the field.
+    ///
+    /// This is the bit width of its specifier type times its `#[repeat = N]` factor, if any.
+    pub fn bits_expr(&self) -> proc_macro2::TokenStream {
+        let span = self.field.ty.span();
+        let ty = &self.field.ty;
+        match &self.config.repeat {
+            Some(repeat) => {
+                let repetitions = repeat.value;
+                quote_spanned!(span=>
+                    (<#ty as ::modular_bitfield::Specifier>::BITS * #repetitions)
+                )
+            }
+            None => quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::BITS),
+        }
+    }
+
+    /// Returns the bit width of the field that is folded into the offsets of the following fields.
+    ///
+    /// This is the bit width of its specifier type if it is known without type information
+    /// times its `#[repeat = N]` factor. In contrast to [`Self::static_bits`] this ignores
+    /// `#[bits = N]` attributes since they might be wrong and are only checked after expansion.
+    pub fn folded_bits(&self) -> Option<usize> {
+        Self::static_type_bits(&self.field.ty).map(|bits| bits * self.config.repetitions())
+    }
+
+    /// Adds the bit width of the field to the given offset expression.
+    ///
+    /// Statically known bit widths are folded into the leading `Nusize` literal of the offset
+    /// so that the offsets of bitfields with many fields do not grow into long chains of
+    /// `<T as Specifier>::BITS` terms which are slow to evaluate for the compiler.
+    /// Only the bit widths of types that are opaque to the macro are added symbolically.
+    pub fn add_bits_to_offset(&self, offset: &mut Punctuated<syn::Expr, Token![+]>) {
+        Self::add_to_offset(offset, self.folded_bits(), self.bits_expr())
+    }
+
+    /// Returns the offsets and names of the elements of a field with `#[repeat = N]`.
+    ///
+    /// The elements are named `f[i]` after the field `f`.
+    /// Returns an empty `Vec` for fields without `#[repeat = N]`.
+    pub fn elements(
+        &self,
+        offset: &Punctuated<syn::Expr, Token![+]>,
+    ) -> Vec<(Punctuated<syn::Expr, Token![+]>, String)> {
+        let repetitions = match &self.config.repeat {
+            Some(repeat) => repeat.value,
+            None => return Vec::new(),
+        };
+        let span = self.field.ty.span();
+        let ty = &self.field.ty;
+        let element_bits = Self::static_type_bits(ty);
+        let mut element_offset = offset.clone();
+        (0..repetitions)
+            .map(|index| {
+                let element = (element_offset.clone(), format!("{}[{}]", self.name(), index));
+                Self::add_to_offset(
+                    &mut element_offset,
+                    element_bits,
+                    quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::BITS),
+                );
+                element
+            })
+            .collect()
+    }
+
+    /// Adds the given bit width to the given offset expression.
+    ///
+    /// Folds the bit width into the leading `Nusize` literal of the offset if it is known.
+    fn add_to_offset(
+        offset: &mut Punctuated<syn::Expr, Token![+]>,
+        folded_bits: Option<usize>,
+        bits_expr: proc_macro2::TokenStream,
+    ) {
+        let bits = match folded_bits {
+            Some(bits) => bits,
+            None => {
+                offset.push(syn::parse_quote! { #bits_expr });
+                return
+            }
+        };
+        match offset.first_mut() {
+            Some(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            })) => {
+                let folded = lit.base10_parse::<usize>().unwrap_or_default() + bits;
+                *lit = syn::LitInt::new(&format!("{}usize", folded), lit.span());
+            }
+            _ => {
+                let bits = proc_macro2::Literal::usize_suffixed(bits);
+                offset.push(syn::parse_quote! { #bits });
+            }
+        }
+    }
+
+    /// Returns the bit width of the field if it is known without type information.
+    ///
+    /// This is the case for fields annotated with `#[bits = N]` as well as for fields
+    /// of the predefined `B1`, .., `B128`, `bool`, `u8`, .., `u128`, `ActiveLow<bool>`,
+    /// `AsciiChar<N>`, `Flags<N>` and signed encoding specifier types. Fields annotated
+    /// with `#[repeat = N]` span `N` times the bit width of their specifier type.
+    pub fn static_bits(&self) -> Option<usize> {
+        if let Some(bits) = &self.config.bits {
+            return Some(bits.value)
+        }
+        Self::static_type_bits(&self.field.ty)
+            .map(|bits| bits * self.config.repetitions())
+    }
+
+    /// Returns the bit width of the given specifier type if it is known without type information.
+    ///
+    /// The signed encodings `TwosComplement<T>`, `SignMagnitude<T>` and `ExcessK<T, K>`
+    /// span the bit width of their specifier type `T` and `Option<T>` spans one more bit.
+    fn static_type_bits(ty: &syn::Type) -> Option<usize> {
+        let path = match ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        let segment = path.segments.last()?;
+        if segment.ident == "ActiveLow" {
+            return Some(1)
+        }
+        if segment.ident == "Flags" {
+            return Self::flags_bits(&segment.arguments)
+        }
+        if segment.ident == "AsciiChar" {
+            if segment.arguments.is_empty() {
+                return Some(8)
+            }
+            return Self::flags_bits(&segment.arguments).filter(|bits| (7..=8).contains(bits))
+        }
+        if segment.ident == "TwosComplement"
+            || segment.ident == "SignMagnitude"
+            || segment.ident == "ExcessK"
+        {
+            return Self::signed_encoding_bits(&segment.arguments)
+        }
+        if segment.ident == "Option" {
+            if let Some(bits) = Self::option_niche_bits(&segment.arguments) {
+                return Some(bits)
+            }
+            return Self::signed_encoding_bits(&segment.arguments).map(|bits| bits + 1)
+        }
+        if !segment.arguments.is_empty() {
+            return None
+        }
+        let name = segment.ident.to_string();
+        match name.as_str() {
+            "bool" => Some(1),
+            "u8" => Some(8),
+            "u16" => Some(16),
+            "u32" => Some(32),
+            "u64" => Some(64),
+            "u128" => Some(128),
+            _ => {
+                if let Some(bits) = Self::non_zero_bits(&name) {
+                    return Some(bits)
+                }
+                name.strip_prefix('B')
+                    .or_else(|| name.strip_prefix('S'))
+                    .filter(|bits| !bits.starts_with('0'))
+                    .and_then(|bits| bits.parse::<usize>().ok())
+                    .filter(|bits| (1..=128).contains(bits))
+            }
+        }
+    }
+
+    /// Returns the bit width of a `NonZeroB1`, .. `NonZeroB64` specifier with the given name.
+    fn non_zero_bits(name: &str) -> Option<usize> {
+        name.strip_prefix("NonZeroB")
+            .filter(|bits| !bits.starts_with('0'))
+            .and_then(|bits| bits.parse::<usize>().ok())
+            .filter(|bits| (1..=64).contains(bits))
+    }
+
+    /// Returns the bit width of `T` for the generic arguments `<T>` of an `Option<T>` field
+    /// whose `T` stores `None` as all zeros, i.e. `NonZeroB1`, .. `NonZeroB64`.
+    fn option_niche_bits(arguments: &syn::PathArguments) -> Option<usize> {
+        let arguments = match arguments {
+            syn::PathArguments::AngleBracketed(arguments) => &arguments.args,
+            _ => return None,
+        };
+        match arguments.first()? {
+            syn::GenericArgument::Type(syn::Type::Path(type_path))
+                if type_path.qself.is_none() =>
+            {
+                let segment = type_path.path.segments.last()?;
+                if !segment.arguments.is_empty() {
+                    return None
+                }
+                Self::non_zero_bits(&segment.ident.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the bit width of `T` for the generic arguments `<T>` or `<T, K>` of a
+    /// signed encoding or `Option<T>` field with `1 <= T::BITS <= 64`.
+    fn signed_encoding_bits(arguments: &syn::PathArguments) -> Option<usize> {
+        let arguments = match arguments {
+            syn::PathArguments::AngleBracketed(arguments) => &arguments.args,
+            _ => return None,
+        };
+        match arguments.first()? {
+            syn::GenericArgument::Type(ty) => {
+                Self::static_type_bits(ty).filter(|bits| (1..=64).contains(bits))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns `N` for the generic arguments `<N>` of a `Flags<N>` field with `1 <= N <= 128`.
+    fn flags_bits(arguments: &syn::PathArguments) -> Option<usize> {
+        let arguments = match arguments {
+            syn::PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+                &arguments.args
+            }
+            _ => return None,
+        };
+        match arguments.first()? {
+            syn::GenericArgument::Const(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(bits),
+                ..
+            })) => bits.base10_parse::<usize>().ok().filter(|bits| (1..=128).contains(bits)),
+            _ => None,
+        }
+    }
+
     /// Returns the field's identifier as `String`.
     pub fn name(&self) -> String {
         Self::ident_as_string(self.field, self.index)
@@ -78,4 +405,18 @@ impl BitfieldStruct {
             FieldInfo::new(n, field, field_config)
         })
     }
+
+    /// Returns an iterator over the fields together with the expressions denoting their bit offsets.
+    pub fn field_infos_with_offsets<'a, 'b: 'a>(
+        &'a self,
+        config: &'b Config,
+    ) -> impl Iterator<Item = (FieldInfo<'a>, Punctuated<syn::Expr, Token![+]>)> {
+        let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
+        offset.push(syn::parse_quote! { 0usize });
+        self.field_infos(config).map(move |info| {
+            let field_offset = offset.clone();
+            info.add_bits_to_offset(&mut offset);
+            (info, field_offset)
+        })
+    }
 }
@@ -0,0 +1,572 @@
+use super::{
+    config::{
+        BitNumbering,
+        BitOrder,
+        Config,
+    },
+    field_config::snake_case,
+    field_info::FieldInfo,
+    BitfieldStruct,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::format_ident;
+use std::{
+    fmt::Write as _,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+use syn::{
+    parse::Result,
+    spanned::Spanned as _,
+};
+
+/// The computed layout of a single `#[bitfield]` field.
+struct FieldLayout {
+    /// The name of the field.
+    name: String,
+    /// The type of the field as written by the user.
+    ty: String,
+    /// The bit offset of the field within the bitfield.
+    offset: usize,
+    /// The bit width of the field.
+    ///
+    /// For field arrays and fields with `#[repeat = N]` this is the bit width of all elements.
+    bits: usize,
+    /// `true` if getters are generated for the field.
+    getters: bool,
+    /// `true` if setters are generated for the field.
+    setters: bool,
+    /// The repetition of a field array `[T; N]` or a field with `#[repeat = N]`.
+    repeat: Option<RepeatLayout>,
+}
+
+/// The computed layout of the elements of a field array or a field with `#[repeat = N]`.
+struct RepeatLayout {
+    /// The number of elements.
+    len: usize,
+    /// `true` if the field is a field array `[T; N]`.
+    array: bool,
+    /// The names of the generated indexed getters.
+    getters: Vec<String>,
+    /// The names of the generated indexed setters.
+    setters: Vec<String>,
+}
+
+/// Escapes the given string so that it can be used as a JSON string literal.
+fn escape_json(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                write!(escaped, "\\u{:04x}", c as u32).expect("writing to string")
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl BitfieldStruct {
+    /// Writes a JSON description of the computed layout if `emit_layout = "path"` is set.
+    ///
+    /// Returns compile-time checks asserting that the field bit widths written to the
+    /// layout file match the actual bit widths of the field types.
+    ///
+    /// # Errors
+    ///
+    /// - If the bit width of a field is not known without type information.
+    /// - If the layout file cannot be written.
+    pub fn emit_layout(&self, config: &Config) -> Result<Option<TokenStream2>> {
+        let emit_layout = match &config.emit_layout {
+            Some(emit_layout) => emit_layout,
+            None => return Ok(None),
+        };
+        let (bits, fields, checks) = self.compute_layout(config, "emit_layout")?;
+        let json = self.layout_to_json(config, bits, &fields);
+        let mut path = PathBuf::from(&emit_layout.value);
+        if path.is_relative() {
+            if let Some(manifest_dir) = std::env::var_os("CARGO_MANIFEST_DIR") {
+                path = PathBuf::from(manifest_dir).join(path);
+            }
+        }
+        let write_layout = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            // Avoid touching the layout file if it is already up to date.
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(json.as_str()) {
+                std::fs::write(&path, &json)?;
+            }
+            Ok(())
+        };
+        write_layout().map_err(|err| {
+            format_err!(
+                emit_layout.span,
+                "encountered error while writing layout to {}: {}",
+                path.display(),
+                err
+            )
+        })?;
+        let span = emit_layout.span;
+        Ok(Some(quote_spanned!(span=>
+            const _: () = {
+                #( #checks )*
+            };
+        )))
+    }
+
+    /// Generates the `LAYOUT_VERSION` constant hashing the layout of the bitfield.
+    ///
+    /// The hash is computed at compile time from the bit order and the name, bit offset and
+    /// bit width of every field so that it also covers fields whose bit widths are only known
+    /// after expansion.
+    pub fn generate_layout_version(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let bit_order = config.bit_order().name();
+        let fields = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let field_span = info.field.span();
+            let name = info.name();
+            let bits = info.bits_expr();
+            quote_spanned!(field_span=>
+                .write_field(#name, #offset, #bits)
+            )
+        });
+        quote_spanned!(span=>
+            impl #ident {
+                /// Hash of the names, bit offsets and bit widths of the fields of the bitfield.
+                ///
+                /// Two builds agree on the layout of the bitfield if they agree on this value.
+                #[allow(unused_parens, clippy::identity_op)]
+                pub const LAYOUT_VERSION: ::core::primitive::u64 =
+                    ::modular_bitfield::private::LayoutHasher::new(#bit_order)
+                        #( #fields )*
+                        .finish();
+            }
+        )
+    }
+
+    /// Generates the `LAYOUT_VARIANT` constant if the struct is expanded as a layout variant.
+    pub fn generate_layout_variant(&self, config: &Config) -> Option<TokenStream2> {
+        let layout_variant = config.layout_variant.as_ref()?;
+        let span = layout_variant.span;
+        let ident = &self.item_struct.ident;
+        let predicate = &layout_variant.value;
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// The `#[cfg(..)]` predicate under which this layout variant is compiled.
+                pub const LAYOUT_VARIANT: &'static ::core::primitive::str = #predicate;
+            }
+        ))
+    }
+
+    /// Submits the layout of the bitfield to the global registry if `register_layout` is set.
+    ///
+    /// The bit offsets and bit widths of the fields are computed at compile time so that
+    /// fields of any specifier type are supported.
+    pub fn generate_layout_registration(&self, config: &Config) -> Option<TokenStream2> {
+        let register_layout = config.register_layout.as_ref()?;
+        let span = register_layout.span;
+        let name = self.item_struct.ident.to_string();
+        let bits = self.generate_target_or_actual_bitfield_size(config);
+        let big_endian = config.bit_order() == BitOrder::Be;
+        let fields = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let field_span = info.field.span();
+            let name = info.name();
+            let bits = info.bits_expr();
+            quote_spanned!(field_span=>
+                ::modular_bitfield::registry::FieldLayout::new(#name, #offset, #bits)
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[allow(unused_parens, clippy::identity_op)]
+            const _: () = {
+                ::modular_bitfield::private::inventory::submit! {
+                    ::modular_bitfield::registry::BitfieldLayout::new(
+                        #name,
+                        ::core::module_path!(),
+                        #bits,
+                        #big_endian,
+                        &[ #( #fields ),* ],
+                    )
+                }
+            };
+        ))
+    }
+
+    /// Checks the total bit width of a layout variant if the bit widths of all fields are known.
+    ///
+    /// This reports invalid layout variants even if their `#[cfg(..)]` predicate does not hold
+    /// for the current build.
+    ///
+    /// # Errors
+    ///
+    /// - If `filled = true` and the total bit width differs from `bits = N` or `#[repr(uN)]`
+    ///   or is not divisible by 8 if neither is given.
+    /// - If `filled = false` and the total bit width is not smaller than `bits = N` or is
+    ///   divisible by 8 if `bits = N` is not given.
+    pub fn check_layout_variant(&self, config: &Config) -> Result<()> {
+        let layout_variant = match &config.layout_variant {
+            Some(layout_variant) => layout_variant,
+            None => return Ok(()),
+        };
+        let bits = match self
+            .field_infos(config)
+            .map(|info| info.static_bits())
+            .sum::<Option<usize>>()
+        {
+            Some(bits) => bits,
+            None => return Ok(()),
+        };
+        let required = config
+            .bits
+            .as_ref()
+            .map(|config| config.value)
+            .or_else(|| config.repr.as_ref().map(|config| config.value.bits()));
+        let expected = match (config.filled_enabled(), required) {
+            (true, Some(required)) if bits != required => format!("exactly {} bits", required),
+            (true, None) if bits % 8 != 0 => "a multiple of 8 bits".to_string(),
+            (false, Some(required)) if bits >= required => {
+                format!("less than {} bits", required)
+            }
+            (false, None) if bits % 8 == 0 => "a bit width not divisible by 8".to_string(),
+            _ => return Ok(()),
+        };
+        Err(format_err!(
+            layout_variant.span,
+            "encountered a total of {} bits but expected {}",
+            bits,
+            expected,
+        ))
+    }
+
+    /// Generates a unit test comparing the layout against a snapshot file if `layout_guard = "path"` is set.
+    ///
+    /// The snapshot lists the total bit width of the bitfield followed by the bit offset and
+    /// bit width of every field, one field per line. Relative paths are resolved against the
+    /// directory of the manifest of the crate that is being tested.
+    pub fn layout_guard(&self, config: &Config) -> Option<TokenStream2> {
+        let layout_guard = config.layout_guard.as_ref()?;
+        let span = layout_guard.span;
+        let ident = &self.item_struct.ident;
+        let test_ident = format_ident!("__bf_layout_guard_{}", snake_case(&ident.to_string()));
+        let path = &layout_guard.value;
+        let path = match Path::new(path).is_absolute() {
+            true => quote_spanned!(span=> #path),
+            false => quote_spanned!(span=> ::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", #path)),
+        };
+        let header = format!("struct {} bits {{}}", ident);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let fields = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let field_span = info.field.span();
+            let bits = info.bits_expr();
+            let line = format!("field {} offset {{}} width {{}}", info.name());
+            quote_spanned!(field_span=>
+                ::core::writeln!(__bf_actual, #line, #offset, #bits).unwrap();
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[cfg(test)]
+            #[test]
+            #[allow(non_snake_case, clippy::identity_op)]
+            fn #test_ident() {
+                extern crate std;
+                use ::core::fmt::Write as _;
+                let __bf_path = #path;
+                let mut __bf_actual = std::string::String::new();
+                ::core::writeln!(__bf_actual, #header, #size).unwrap();
+                #( #fields )*
+                let __bf_expected = std::fs::read_to_string(__bf_path).ok();
+                ::modular_bitfield::private::check_layout_snapshot(
+                    __bf_path,
+                    __bf_expected.as_deref(),
+                    &__bf_actual,
+                );
+            }
+        ))
+    }
+
+    /// Appends a layout table to the documentation of the struct if `doc_bit_numbering` is set.
+    ///
+    /// The bit ranges in the table are numbered according to the `doc_bit_numbering` parameter
+    /// while the storage of the bitfield is unaffected. Returns compile-time checks asserting
+    /// that the documented field bit widths match the actual bit widths of the field types.
+    ///
+    /// # Errors
+    ///
+    /// If the bit width of a field is not known without type information.
+    pub fn doc_layout(&self, config: &mut Config) -> Result<Option<TokenStream2>> {
+        let (numbering, span) = match &config.doc_bit_numbering {
+            Some(numbering) => (numbering.value, numbering.span),
+            None => return Ok(None),
+        };
+        let bit_order = config.bit_order();
+        let (bits, fields, checks) = self.compute_layout(config, "doc_bit_numbering")?;
+        let mut rows = fields
+            .iter()
+            .map(|field| {
+                format!(
+                    " | `{}` | `{}` | `{}` |",
+                    numbering.range(field.offset, field.bits, bits, bit_order),
+                    field.name,
+                    field.ty,
+                )
+            })
+            .collect::<Vec<_>>();
+        if (numbering == BitNumbering::Msb0) != (bit_order == BitOrder::Be) {
+            // List the rows in ascending order of their bit numbers.
+            rows.reverse();
+        }
+        let constants = self.expand_doc_bit_constants(config, &fields, bits);
+        let header = [
+            String::new(),
+            " # Layout".to_string(),
+            String::new(),
+            format!(" | Bits ({}) | Field | Type |", numbering.name()),
+            " |------|-------|------|".to_string(),
+        ];
+        config.retained_attributes.extend(
+            header
+                .iter()
+                .chain(&rows)
+                .map(|line| syn::parse_quote_spanned!(span=> #[doc = #line])),
+        );
+        Ok(Some(quote_spanned!(span=>
+            const _: () = {
+                #( #checks )*
+            };
+            #constants
+        )))
+    }
+
+    /// Generates the bit offset constants of the fields named after their documented bits.
+    ///
+    /// For a field `f` documented at bits `31:28` this is `F_BIT_31_28` holding the bit offset
+    /// of `f` as used by the bitfield. Only named fields that have getters or setters get a
+    /// constant so that the usual `#[skip] __` padding fields do not collide.
+    fn expand_doc_bit_constants(
+        &self,
+        config: &Config,
+        fields: &[FieldLayout],
+        bits: usize,
+    ) -> Option<TokenStream2> {
+        let numbering = config.doc_bit_numbering()?;
+        let bit_order = config.bit_order();
+        let ident = &self.item_struct.ident;
+        let constants = self
+            .field_infos(config)
+            .zip(fields)
+            .filter(|(info, _)| !(info.config.skip_getters() && info.config.skip_setters()))
+            .filter_map(|(info, field)| {
+                let field_ident = info.field.ident.as_ref()?;
+                let span = field_ident.span();
+                let vis = &info.field.vis;
+                let (first, last) = numbering.bounds(field.offset, field.bits, bits, bit_order);
+                let name = syn::ext::IdentExt::unraw(field_ident).to_string().to_uppercase();
+                let const_ident = match first == last {
+                    true => format_ident!("{}_BIT_{}", name, first, span = span),
+                    false => format_ident!("{}_BIT_{}_{}", name, first, last, span = span),
+                };
+                let offset = field.offset;
+                let docs = format!(
+                    "The bit offset of `{}` which is documented at bits `{}` ({}).",
+                    field.name,
+                    numbering.range(field.offset, field.bits, bits, bit_order),
+                    numbering.name(),
+                );
+                Some(quote_spanned!(span=>
+                    #[doc = #docs]
+                    #[allow(dead_code)]
+                    #vis const #const_ident: ::core::primitive::usize = #offset;
+                ))
+            });
+        Some(quote_spanned!(self.item_struct.span()=>
+            impl #ident {
+                #( #constants )*
+            }
+        ))
+    }
+
+    /// Computes the bit offsets and bit widths of all fields for the given layout parameter.
+    ///
+    /// Returns the total bit width, the field layouts and compile-time checks asserting that
+    /// the computed field bit widths match the actual bit widths of the field types.
+    ///
+    /// # Errors
+    ///
+    /// If the bit width of a field is not known without type information.
+    fn compute_layout(
+        &self,
+        config: &Config,
+        param: &str,
+    ) -> Result<(usize, Vec<FieldLayout>, Vec<TokenStream2>)> {
+        let mut offset = 0;
+        let mut fields = Vec::new();
+        let mut checks = Vec::new();
+        for info in self.field_infos(config) {
+            let bits = info.static_bits().ok_or_else(|| {
+                format_err!(
+                    info.field,
+                    "encountered field with unknown bit width for `{}`, \
+                     specify its bit width via #[bits = N]",
+                    param,
+                )
+            })?;
+            checks.push(Self::expand_emit_layout_check(&info, bits));
+            let repeat = info.config.repeat.as_ref().map(|repeat| {
+                Self::compute_repeat_layout(&info, config, repeat.value)
+            });
+            fields.push(FieldLayout {
+                name: info.name(),
+                ty: quote::ToTokens::to_token_stream(&info.field.ty)
+                    .to_string()
+                    .replace(' ', ""),
+                offset,
+                bits,
+                getters: info.config.has_scalar_getter(),
+                setters: info.config.has_scalar_setter(),
+                repeat,
+            });
+            offset += bits;
+        }
+        let bits = config
+            .bits
+            .as_ref()
+            .map(|config| config.value)
+            .unwrap_or(offset);
+        Ok((bits, fields, checks))
+    }
+
+    /// Computes the element count and the names of the indexed accessors of a field
+    /// array `[T; N]` or a field with `#[repeat = N]`.
+    fn compute_repeat_layout(info: &FieldInfo<'_>, config: &Config, len: usize) -> RepeatLayout {
+        use syn::ext::IdentExt as _;
+        let method_name = |ident| config.method_ident(ident).unraw().to_string();
+        let frag = info.ident_frag();
+        let (getters, setters) = match info.config.array {
+            true => {
+                (
+                    vec![info.getter_ident(), info.checked_getter_ident()],
+                    vec![
+                        format_ident!("set_{}", frag),
+                        format_ident!("set_{}_checked", frag),
+                    ],
+                )
+            }
+            false => {
+                (
+                    vec![format_ident!("{}_at", info.getter_ident())],
+                    vec![format_ident!("set_{}_at", frag)],
+                )
+            }
+        };
+        let getters = match info.config.skip_getters() {
+            true => Vec::new(),
+            false => getters.into_iter().map(method_name).collect(),
+        };
+        let setters = match info.config.skip_setters() {
+            true => Vec::new(),
+            false => setters.into_iter().map(method_name).collect(),
+        };
+        RepeatLayout {
+            len,
+            array: info.config.array,
+            getters,
+            setters,
+        }
+    }
+
+    /// Generates a check asserting that the field type has the given bit width.
+    fn expand_emit_layout_check(info: &FieldInfo<'_>, bits: usize) -> TokenStream2 {
+        let span = info.field.span();
+        let actual_bits = info.bits_expr();
+        quote_spanned!(span=>
+            let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #bits]> =
+                ::modular_bitfield::private::checks::BitsCheck::<[(); #bits]>{
+                    arr: [(); #actual_bits]
+                };
+        )
+    }
+
+    /// Returns the JSON description of the computed bitfield layout.
+    fn layout_to_json(&self, config: &Config, bits: usize, fields: &[FieldLayout]) -> String {
+        let mut json = String::new();
+        let mut push = |line: String| {
+            json.push_str(&line);
+            json.push('\n');
+        };
+        push("{".to_string());
+        push(format!(
+            "  \"name\": \"{}\",",
+            escape_json(&self.item_struct.ident.to_string())
+        ));
+        push(format!("  \"bits\": {},", bits));
+        push(format!("  \"bytes\": {},", (bits.saturating_sub(1) / 8) + 1));
+        push(format!("  \"filled\": {},", config.filled_enabled()));
+        push(format!("  \"bit_order\": \"{}\",", config.bit_order().name()));
+        let numbering = config.doc_bit_numbering();
+        if let Some(numbering) = numbering {
+            push(format!("  \"doc_bit_numbering\": \"{}\",", numbering.name()));
+        }
+        push("  \"fields\": [".to_string());
+        for (n, field) in fields.iter().enumerate() {
+            let separator = if n + 1 == fields.len() { "" } else { "," };
+            let doc_bits = numbering
+                .map(|numbering| {
+                    format!(
+                        ", \"doc_bits\": \"{}\"",
+                        numbering.range(field.offset, field.bits, bits, config.bit_order())
+                    )
+                })
+                .unwrap_or_default();
+            let repeat = match &field.repeat {
+                Some(repeat) => repeat,
+                None => {
+                    push(format!(
+                        "    {{ \"name\": \"{}\", \"type\": \"{}\", \"offset\": {}, \"bits\": {}{}, \
+                         \"getters\": {}, \"setters\": {} }}{}",
+                        escape_json(&field.name),
+                        escape_json(&field.ty),
+                        field.offset,
+                        field.bits,
+                        doc_bits,
+                        field.getters,
+                        field.setters,
+                        separator,
+                    ));
+                    continue
+                }
+            };
+            let names = |names: &[String]| {
+                names
+                    .iter()
+                    .map(|name| format!("\"{}\"", escape_json(name)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            push(format!(
+                "    {{ \"name\": \"{}\", \"type\": \"{}\", \"offset\": {}, \"bits\": {}, \"{}\": {}{}, \
+                 \"getters\": {}, \"setters\": {}, \"indexed_getters\": [{}], \"indexed_setters\": [{}] }}{}",
+                escape_json(&field.name),
+                escape_json(&field.ty),
+                field.offset,
+                field.bits / repeat.len,
+                if repeat.array { "len" } else { "repeat" },
+                repeat.len,
+                doc_bits,
+                !repeat.getters.is_empty(),
+                !repeat.setters.is_empty(),
+                names(&repeat.getters),
+                names(&repeat.setters),
+                separator,
+            ));
+        }
+        push("  ]".to_string());
+        push("}".to_string());
+        json
+    }
+}
@@ -0,0 +1,105 @@
+use super::{
+    config::Config,
+    field_info::FieldInfo,
+    BitfieldStruct,
+};
+use proc_macro2::TokenStream as TokenStream2;
+use syn::spanned::Spanned as _;
+
+impl BitfieldStruct {
+    /// Generates the compile-time checks of the `lints` parameter.
+    ///
+    /// Every check calls a function of `Lint<WARN>` which is deprecated for `WARN = true`
+    /// so that suspicious layouts are reported as warnings pointing at the offending field.
+    /// The conditions are evaluated at compile time since they depend on the bit widths
+    /// of the field types.
+    pub fn generate_lints(&self, config: &Config) -> Option<TokenStream2> {
+        let lints = config.lints.as_ref()?;
+        let span = lints.span;
+        let infos = self.field_infos_with_offsets(config).collect::<Vec<_>>();
+        let crosses_byte_boundary = infos
+            .iter()
+            .filter(|(info, _)| !Self::is_padding(info) && info.config.repeat.is_none())
+            .map(|(info, offset)| {
+                let field_span = info.field.span();
+                let bits = info.bits_expr();
+                quote_spanned!(field_span=>
+                    ::modular_bitfield::private::Lint::<{
+                        ::modular_bitfield::private::crosses_byte_boundary(#offset, #bits)
+                    }>::field_crosses_byte_boundary();
+                )
+            });
+        let oversized_enum = infos
+            .iter()
+            .filter(|(info, _)| !Self::is_padding(info) && info.folded_bits().is_none())
+            .map(|(info, _)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                quote_spanned!(field_span=>
+                    ::modular_bitfield::private::Lint::<{
+                        <#ty as ::modular_bitfield::Specifier>::REQUIRED_BITS
+                            < <#ty as ::modular_bitfield::Specifier>::BITS
+                    }>::oversized_enum_field();
+                )
+            });
+        let padding_before_last_field = match infos.as_slice() {
+            [.., (padding, _), (last, _)] if Self::is_padding(padding) && !Self::is_padding(last) => {
+                let field_span = padding.field.span();
+                Some(quote_spanned!(field_span=>
+                    ::modular_bitfield::private::Lint::<true>::padding_before_last_field();
+                ))
+            }
+            _ => None,
+        };
+        Some(quote_spanned!(span=>
+            #[allow(unused_parens, clippy::identity_op)]
+            const _: () = {
+                #( #crosses_byte_boundary )*
+                #( #oversized_enum )*
+                #padding_before_last_field
+            };
+        ))
+    }
+
+    /// Generates the compile-time checks of the `no_straddle` parameter.
+    ///
+    /// Compilation fails for every field that neither lies within a single byte nor
+    /// starts and ends at byte boundaries. Elements of `#[repeat = N]` fields are
+    /// checked one by one.
+    pub fn generate_no_straddle_checks(&self, config: &Config) -> Option<TokenStream2> {
+        let no_straddle = config.no_straddle.as_ref()?;
+        let span = no_straddle.span;
+        let ident = &self.item_struct.ident;
+        let checks = self.field_infos_with_offsets(config).map(|(info, offset)| {
+            let field_span = info.field.span();
+            let ty = &info.field.ty;
+            let repetitions = info.config.repetitions();
+            let message = format!(
+                "field `{}.{}` straddles a byte boundary which is not allowed by `no_straddle`",
+                ident,
+                info.name(),
+            );
+            quote_spanned!(field_span=>
+                ::core::assert!(
+                    !::modular_bitfield::private::straddles_byte_boundary(
+                        #offset,
+                        <#ty as ::modular_bitfield::Specifier>::BITS,
+                        #repetitions,
+                    ),
+                    #message,
+                );
+            )
+        });
+        Some(quote_spanned!(span=>
+            #[allow(unused_parens, clippy::identity_op)]
+            const _: () = {
+                #( #checks )*
+            };
+        ))
+    }
+
+    /// Returns `true` if the field is padding without getters and setters.
+    fn is_padding(info: &FieldInfo) -> bool {
+        info.config.repeat.is_none() && info.config.skip_getters() && info.config.skip_setters()
+    }
+}
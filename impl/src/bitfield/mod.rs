@@ -1,16 +1,28 @@
 mod analyse;
+mod bitfields;
+mod collisions;
 mod config;
 mod expand;
 mod field_config;
 mod field_info;
+mod layout;
+mod lints;
 mod params;
+mod variants;
 
+pub use self::bitfields::analyse_and_expand_all;
 use self::{
-    config::Config,
+    config::{
+        Config,
+        ConfigValue,
+    },
     params::ParamArgs,
+    variants::LayoutVariant,
 };
+use crate::errors::CombineError;
 use core::convert::TryFrom;
 use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
 use syn::{
     self,
     parse::Result,
@@ -35,10 +47,87 @@ fn analyse_and_expand_or_error(
 ) -> Result<TokenStream2> {
     let input = syn::parse::<syn::ItemStruct>(input.into())?;
     let params = syn::parse::<ParamArgs>(args.into())?;
-    let mut config = Config::default();
+    analyse_and_expand_struct(params, input)
+}
+
+/// Analyzes the given `#[bitfield]` struct with the given parameters and expands code if valid.
+///
+/// # Errors
+///
+/// If the given parameters and struct do not yield a valid `#[bitfield]` specifier.
+///
+/// Structs with `#[cfg(..)]` or `#[cfg_attr(..)]` annotated fields are expanded once for every
+/// layout variant. Errors of all layout variants are reported together.
+fn analyse_and_expand_struct<P>(params: P, input: syn::ItemStruct) -> Result<TokenStream2>
+where
+    P: IntoIterator<Item = syn::NestedMeta>,
+{
+    let variants = match variants::layout_variants(&input)? {
+        Some(variants) => variants,
+        None => return analyse_and_expand_variant(params, input, None),
+    };
+    let params = params.into_iter().collect::<Vec<_>>();
+    let mut expansions = Vec::new();
+    let mut errors: Option<syn::Error> = None;
+    for variant in &variants {
+        let expanded =
+            analyse_and_expand_variant(params.clone(), variant.item.clone(), Some(variant))
+                .and_then(|expanded| variants::gate_expansion(variant, expanded))
+                .map_err(|err| {
+                    err.into_combine(format_err!(
+                        input.ident,
+                        "in layout variant `{}` of `{}`",
+                        variant.name(),
+                        input.ident,
+                    ))
+                });
+        match expanded {
+            Ok(expanded) => expansions.push(expanded),
+            Err(err) => {
+                errors = Some(match errors {
+                    Some(errors) => errors.into_combine(err),
+                    None => err,
+                })
+            }
+        }
+    }
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(quote! { #( #expansions )* }),
+    }
+}
+
+/// Analyzes and expands the given `#[bitfield]` struct or one of its layout variants.
+///
+/// # Errors
+///
+/// If the given parameters and struct do not yield a valid `#[bitfield]` specifier.
+fn analyse_and_expand_variant<P>(
+    params: P,
+    input: syn::ItemStruct,
+    variant: Option<&LayoutVariant>,
+) -> Result<TokenStream2>
+where
+    P: IntoIterator<Item = syn::NestedMeta>,
+{
+    let mut config = Config {
+        layout_variant: variant
+            .map(|variant| ConfigValue::new(variant.name(), input.ident.span())),
+        ..Config::default()
+    };
     config.feed_params(params)?;
     let bitfield = BitfieldStruct::try_from((&mut config, input))?;
-    Ok(bitfield.expand(&config))
+    bitfield.check_layout_variant(&config)?;
+    let layout_checks = bitfield.emit_layout(&config)?;
+    let doc_layout_checks = bitfield.doc_layout(&mut config)?;
+    let layout_guard = bitfield.layout_guard(&config);
+    let expanded = bitfield.expand(&config);
+    Ok(quote! {
+        #expanded
+        #layout_checks
+        #doc_layout_checks
+        #layout_guard
+    })
 }
 
 /// Type used to guide analysis and expansion of `#[bitfield]` structs.
@@ -1,4 +1,13 @@
-use super::config::Config;
+use super::config::{
+    BitNumbering,
+    BitOrder,
+    Compat,
+    Config,
+    InterceptableDerive,
+    ReprEndian,
+    SerdeRepr,
+    WordKind,
+};
 use proc_macro2::Span;
 use syn::{
     parse::Result,
@@ -33,14 +42,37 @@ pub struct ParamArgs {
 
 impl syn::parse::Parse for ParamArgs {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let punctuated =
-            <syn::punctuated::Punctuated<_, syn::Token![,]>>::parse_terminated(input)?;
+        let punctuated = <syn::punctuated::Punctuated<_, syn::Token![,]>>::parse_terminated_with(
+            input,
+            Self::parse_nested_meta,
+        )?;
         Ok(Self {
             args: punctuated.into_iter().collect::<Vec<_>>(),
         })
     }
 }
 
+impl ParamArgs {
+    /// Parses a single parameter.
+    ///
+    /// In addition to the usual meta items this also accepts identifiers as values,
+    /// e.g. `bit_order = be`, which are then treated as if they were string literals.
+    fn parse_nested_meta(input: syn::parse::ParseStream) -> Result<syn::NestedMeta> {
+        if input.peek(syn::Ident) && input.peek2(syn::Token![=]) && input.peek3(syn::Ident) {
+            let path = input.parse::<syn::Ident>()?;
+            let eq_token = input.parse::<syn::Token![=]>()?;
+            let value = input.parse::<syn::Ident>()?;
+            let lit = syn::Lit::Str(syn::LitStr::new(&value.to_string(), value.span()));
+            return Ok(syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                path: path.into(),
+                eq_token,
+                lit,
+            })))
+        }
+        input.parse()
+    }
+}
+
 impl IntoIterator for ParamArgs {
     type Item = syn::NestedMeta;
     type IntoIter = std::vec::IntoIter<syn::NestedMeta>;
@@ -85,6 +117,31 @@ impl Config {
         Ok(())
     }
 
+    /// Feeds a parameter that takes a string value to the `#[bitfield]` configuration.
+    fn feed_str_param<F>(
+        name_value: syn::MetaNameValue,
+        name: &str,
+        on_success: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(String, Span) -> Result<()>,
+    {
+        assert!(name_value.path.is_ident(name));
+        match &name_value.lit {
+            syn::Lit::Str(lit_str) => {
+                on_success(lit_str.value(), name_value.span())?;
+            }
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
+            }
+        }
+        Ok(())
+    }
+
     /// Feeds a `bytes: int` parameter to the `#[bitfield]` configuration.
     fn feed_bytes_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
         Self::feed_int_param(name_value, "bytes", |value, span| self.bytes(value, span))
@@ -95,23 +152,427 @@ impl Config {
         Self::feed_int_param(name_value, "bits", |value, span| self.bits(value, span))
     }
 
-    /// Feeds a `filled: bool` parameter to the `#[bitfield]` configuration.
-    fn feed_filled_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
-        assert!(name_value.path.is_ident("filled"));
+    /// Feeds a parameter that takes a boolean value to the `#[bitfield]` configuration.
+    fn feed_bool_param<F>(
+        name_value: syn::MetaNameValue,
+        name: &str,
+        on_success: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(bool, Span) -> Result<()>,
+    {
+        assert!(name_value.path.is_ident(name));
         match &name_value.lit {
             syn::Lit::Bool(lit_bool) => {
-                self.filled(lit_bool.value, name_value.span())?;
+                on_success(lit_bool.value, name_value.span())?;
             }
             invalid => {
                 return Err(format_err!(
-                invalid,
-                "encountered invalid value argument for #[bitfield] `filled` parameter",
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `{}` parameter",
+                    name
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Feeds a `filled: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_filled_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "filled", |value, span| {
+            self.filled(value, span)
+        })
+    }
+
+    /// Feeds an `uninit: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_uninit_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "uninit", |value, span| {
+            self.uninit(value, span)
+        })
+    }
+
+    /// Feeds a `copy: bool` parameter to the `#[bitfield]` configuration.
+    fn feed_copy_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_bool_param(name_value, "copy", |value, span| self.copy(value, span))
+    }
+
+    /// Feeds an `emit_layout: str` parameter to the `#[bitfield]` configuration.
+    fn feed_emit_layout_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "emit_layout", |value, span| {
+            self.emit_layout(value, span)
+        })
+    }
+
+    /// Feeds a `layout_guard: str` parameter to the `#[bitfield]` configuration.
+    fn feed_layout_guard_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "layout_guard", |value, span| {
+            self.layout_guard(value, span)
+        })
+    }
+
+    /// Feeds a `doc_examples: str` parameter to the `#[bitfield]` configuration.
+    fn feed_doc_examples_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "doc_examples", |value, span| {
+            self.doc_examples(value, span)
+        })
+    }
+
+    /// Feeds a `split_at: str` parameter to the `#[bitfield]` configuration.
+    fn feed_split_at_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "split_at", |value, span| {
+            self.split_at(value, span)
+        })
+    }
+
+    /// Feeds a `new: str` parameter to the `#[bitfield]` configuration.
+    fn feed_new_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "new", |value, span| self.set_new(value, span))
+    }
+
+    /// Feeds a `preset(name = value, ..)` parameter to the `#[bitfield]` configuration.
+    ///
+    /// The packed values are given as integer literals or as strings containing them.
+    fn feed_preset_param(&mut self, meta_list: syn::MetaList) -> Result<()> {
+        if meta_list.nested.is_empty() {
+            return Err(format_err!(
+                meta_list,
+                "encountered missing presets for #[bitfield] `preset` parameter, expected `preset(name = \"value\")`"
             ))
+        }
+        for nested_meta in meta_list.nested {
+            let name_value = match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(name_value)) => name_value,
+                invalid => {
+                    return Err(format_err!(
+                        invalid,
+                        "encountered invalid format for #[bitfield] `preset` parameter, expected `preset(name = \"value\")`"
+                    ))
+                }
+            };
+            let name = match name_value.path.get_ident() {
+                Some(name) => name.clone(),
+                None => {
+                    return Err(format_err!(
+                        name_value.path,
+                        "encountered invalid name for #[bitfield] `preset` parameter: expected an identifier"
+                    ))
+                }
+            };
+            let value = match &name_value.lit {
+                syn::Lit::Int(lit_int) => lit_int.base10_parse::<u128>().ok(),
+                syn::Lit::Str(lit_str) => {
+                    lit_str
+                        .parse::<syn::LitInt>()
+                        .and_then(|lit_int| lit_int.base10_parse::<u128>())
+                        .ok()
+                }
+                _ => None,
+            };
+            let value = match value {
+                Some(value) => value,
+                None => {
+                    return Err(format_err!(
+                        name_value.lit,
+                        "encountered invalid value for preset `{}` of #[bitfield] `preset` parameter: expected an unsigned integer",
+                        name,
+                    ))
+                }
+            };
+            self.preset(name, value, name_value.span())?;
+        }
+        Ok(())
+    }
+
+    /// Feeds an `accessors_mod: str` parameter to the `#[bitfield]` configuration.
+    fn feed_accessors_mod_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        Self::feed_str_param(name_value, "accessors_mod", |value, span| {
+            self.accessors_mod(value, span)
+        })
+    }
+
+    /// Feeds a `bit_order: le | be` parameter to the `#[bitfield]` configuration.
+    fn feed_bit_order_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "bit_order", |value, span| {
+            let bit_order = match value.as_str() {
+                "le" => BitOrder::Le,
+                "be" => BitOrder::Be,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `bit_order` parameter: expected `le` or `be`",
+                    ))
+                }
+            };
+            self.set_bit_order(bit_order, span)
+        })
+    }
+
+    /// Feeds an `order: lsb | msb` parameter, an alias of `bit_order`, to the `#[bitfield]`
+    /// configuration.
+    fn feed_order_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "order", |value, span| {
+            let bit_order = match value.as_str() {
+                "lsb" => BitOrder::Le,
+                "msb" => BitOrder::Be,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `order` parameter: expected `lsb` or `msb`",
+                    ))
+                }
+            };
+            self.set_order(bit_order, span)
+        })
+    }
+
+    /// Feeds a `serde: bytes | fields` parameter to the `#[bitfield]` configuration.
+    fn feed_serde_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "serde", |value, span| {
+            let repr = match value.as_str() {
+                "bytes" => SerdeRepr::Bytes,
+                "fields" => SerdeRepr::Fields,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `serde` parameter: expected `bytes` or `fields`",
+                    ))
+                }
+            };
+            self.serde(repr, span)
+        })
+    }
+
+    /// Feeds a `word_endian = "little" | "big"` parameter to the `#[bitfield]` configuration.
+    fn feed_word_endian_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "word_endian", |value, span| {
+            let word_endian = match value.as_str() {
+                "little" => ReprEndian::Little,
+                "big" => ReprEndian::Big,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `word_endian` parameter: expected `little` or `big`",
+                    ))
+                }
+            };
+            self.set_word_endian(word_endian, span)
+        })
+    }
+
+    /// Feeds a `compat: packed_struct` parameter to the `#[bitfield]` configuration.
+    fn feed_compat_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "compat", |value, span| {
+            let compat = match value.as_str() {
+                "packed_struct" => Compat::PackedStruct,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `compat` parameter: expected `packed_struct`",
+                    ))
+                }
+            };
+            self.set_compat(compat, span)
+        })
+    }
+
+    /// Feeds a `doc_bit_numbering: lsb0 | msb0` parameter to the `#[bitfield]` configuration.
+    fn feed_doc_bit_numbering_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "doc_bit_numbering", |value, span| {
+            let numbering = match value.as_str() {
+                "lsb0" => BitNumbering::Lsb0,
+                "msb0" => BitNumbering::Msb0,
+                _ => {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid value argument for #[bitfield] `doc_bit_numbering` parameter: expected `lsb0` or `msb0`",
+                    ))
+                }
+            };
+            self.set_doc_bit_numbering(numbering, span)
+        })
+    }
+
+    /// Feeds an `external_methods: str` parameter to the `#[bitfield]` configuration.
+    ///
+    /// The value is a comma separated list of method names.
+    fn feed_external_methods_param(&mut self, name_value: syn::MetaNameValue) -> Result<()> {
+        let lit = name_value.lit.clone();
+        Self::feed_str_param(name_value, "external_methods", |value, span| {
+            let mut names = Vec::new();
+            for name in value.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+                if syn::parse_str::<syn::Ident>(name).is_err() {
+                    return Err(format_err!(
+                        lit,
+                        "encountered invalid method name `{}` for #[bitfield] `external_methods` parameter",
+                        name,
+                    ))
+                }
+                names.push(name.to_string());
+            }
+            self.external_methods(names, span)
+        })
+    }
+
+    /// Feeds a `cast_to(A, B, ..)` parameter to the `#[bitfield]` configuration.
+    fn feed_cast_to_param(&mut self, meta_list: syn::MetaList) -> Result<()> {
+        if meta_list.nested.is_empty() {
+            return Err(format_err!(
+                meta_list,
+                "encountered missing bitfield types for #[bitfield] `cast_to` parameter"
+            ))
+        }
+        for nested_meta in meta_list.nested {
+            match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
+                    let span = path.span();
+                    self.cast_to(path, span)?;
+                }
+                invalid => {
+                    return Err(format_err!(
+                        invalid,
+                        "encountered invalid bitfield type for #[bitfield] `cast_to` parameter"
+                    ))
+                }
             }
         }
         Ok(())
     }
 
+    /// Feeds a `fields_struct(derive(A, B, ..))` parameter to the `#[bitfield]` configuration.
+    fn feed_fields_struct_param(&mut self, meta_list: syn::MetaList) -> Result<()> {
+        let span = meta_list.span();
+        let mut derives = None;
+        for nested_meta in meta_list.nested {
+            match nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::List(list))
+                    if list.path.is_ident("derive") && derives.is_none() =>
+                {
+                    let mut paths = Vec::new();
+                    for nested_meta in list.nested {
+                        match nested_meta {
+                            syn::NestedMeta::Meta(syn::Meta::Path(path)) => paths.push(path),
+                            invalid => {
+                                return Err(format_err!(
+                                    invalid,
+                                    "encountered invalid derive for #[bitfield] `fields_struct` parameter"
+                                ))
+                            }
+                        }
+                    }
+                    derives = Some(paths);
+                }
+                unsupported => {
+                    return Err(format_err!(
+                        unsupported,
+                        "encountered unsupported argument for #[bitfield] `fields_struct` parameter, \
+                         expected `derive(..)`",
+                    ))
+                }
+            }
+        }
+        self.fields_struct(derives.unwrap_or_default(), span)
+    }
+
+    /// Feeds a `words(u16, u32, u64)` parameter to the `#[bitfield]` configuration.
+    fn feed_words_param(&mut self, meta_list: syn::MetaList) -> Result<()> {
+        if meta_list.nested.is_empty() {
+            return Err(format_err!(
+                meta_list,
+                "encountered missing word types for #[bitfield] `words` parameter"
+            ))
+        }
+        for nested_meta in meta_list.nested {
+            let kind = match &nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => WordKind::from_path(path),
+                _ => None,
+            };
+            match kind {
+                Some(kind) => self.words(kind, nested_meta.span())?,
+                None => {
+                    return Err(format_err!(
+                        nested_meta,
+                        "encountered unsupported word type for #[bitfield] `words` parameter, \
+                         expected one of `u16`, `u32` or `u64`",
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Feeds an `intercept(A, B, ..)` or a `passthrough(A, B, ..)` parameter to the
+    /// `#[bitfield]` configuration.
+    fn feed_derive_policy_param(&mut self, meta_list: syn::MetaList, intercept: bool) -> Result<()> {
+        let param = if intercept { "intercept" } else { "passthrough" };
+        if meta_list.nested.is_empty() {
+            return Err(format_err!(
+                meta_list,
+                "encountered missing derives for #[bitfield] `{}` parameter",
+                param,
+            ))
+        }
+        for nested_meta in meta_list.nested {
+            let derive = match &nested_meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => InterceptableDerive::from_path(path),
+                _ => None,
+            };
+            match derive {
+                Some(derive) => self.derive_policy(derive, intercept, nested_meta.span())?,
+                None => {
+                    return Err(format_err!(
+                        nested_meta,
+                        "encountered unsupported derive for #[bitfield] `{}` parameter, \
+                         expected one of `Debug`, `Default`, `Hash`, `PartialOrd` or `Ord`",
+                        param,
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Feeds an `ffi_export(prefix = str)` parameter to the `#[bitfield]` configuration.
+    fn feed_ffi_export_param(&mut self, meta_list: syn::MetaList) -> Result<()> {
+        let span = meta_list.span();
+        let mut nested = meta_list.nested.iter();
+        let name_value = match (nested.next(), nested.next()) {
+            (Some(syn::NestedMeta::Meta(syn::Meta::NameValue(name_value))), None)
+                if name_value.path.is_ident("prefix") =>
+            {
+                name_value
+            }
+            _ => {
+                return Err(format_err!(
+                    meta_list,
+                    "encountered invalid format for #[bitfield] `ffi_export` parameter, expected `ffi_export(prefix = \"..\")`"
+                ))
+            }
+        };
+        let prefix = match &name_value.lit {
+            syn::Lit::Str(lit_str) => lit_str.value(),
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid value argument for #[bitfield] `ffi_export` parameter"
+                ))
+            }
+        };
+        if syn::parse_str::<syn::Ident>(&format!("{}get", prefix)).is_err() {
+            return Err(format_err!(
+                name_value.lit,
+                "encountered invalid function name prefix `{}` for #[bitfield] `ffi_export` parameter",
+                prefix,
+            ))
+        }
+        self.ffi_export(prefix, span)
+    }
+
     /// Feeds the given parameters to the `#[bitfield]` configuration.
     ///
     /// # Errors
@@ -132,11 +593,120 @@ impl Config {
                                 self.feed_bits_param(name_value)?;
                             } else if name_value.path.is_ident("filled") {
                                 self.feed_filled_param(name_value)?;
+                            } else if name_value.path.is_ident("uninit") {
+                                self.feed_uninit_param(name_value)?;
+                            } else if name_value.path.is_ident("copy") {
+                                self.feed_copy_param(name_value)?;
+                            } else if name_value.path.is_ident("emit_layout") {
+                                self.feed_emit_layout_param(name_value)?;
+                            } else if name_value.path.is_ident("layout_guard") {
+                                self.feed_layout_guard_param(name_value)?;
+                            } else if name_value.path.is_ident("doc_examples") {
+                                self.feed_doc_examples_param(name_value)?;
+                            } else if name_value.path.is_ident("split_at") {
+                                self.feed_split_at_param(name_value)?;
+                            } else if name_value.path.is_ident("new") {
+                                self.feed_new_param(name_value)?;
+                            } else if name_value.path.is_ident("accessors_mod") {
+                                self.feed_accessors_mod_param(name_value)?;
+                            } else if name_value.path.is_ident("bit_order") {
+                                self.feed_bit_order_param(name_value)?;
+                            } else if name_value.path.is_ident("order") {
+                                self.feed_order_param(name_value)?;
+                            } else if name_value.path.is_ident("compat") {
+                                self.feed_compat_param(name_value)?;
+                            } else if name_value.path.is_ident("serde") {
+                                self.feed_serde_param(name_value)?;
+                            } else if name_value.path.is_ident("word_endian") {
+                                self.feed_word_endian_param(name_value)?;
+                            } else if name_value.path.is_ident("doc_bit_numbering") {
+                                self.feed_doc_bit_numbering_param(name_value)?;
+                            } else if name_value.path.is_ident("external_methods") {
+                                self.feed_external_methods_param(name_value)?;
                             } else {
                                 return Err(unsupported_argument(name_value))
                             }
                         }
-                        unsupported => return Err(unsupported_argument(unsupported)),
+                        syn::Meta::Path(path) => {
+                            if path.is_ident("unchecked_setters") {
+                                self.unchecked_setters(path.span())?;
+                            } else if path.is_ident("bit_queries") {
+                                self.bit_queries(path.span())?;
+                            } else if path.is_ident("bounds") {
+                                self.bounds(path.span())?;
+                            } else if path.is_ident("masks") {
+                                self.masks(path.span())?;
+                            } else if path.is_ident("lints") {
+                                self.lints(path.span())?;
+                            } else if path.is_ident("const_accessors") {
+                                self.const_accessors(path.span())?;
+                            } else if path.is_ident("fault_injection") {
+                                self.fault_injection(path.span())?;
+                            } else if path.is_ident("neighbor_check_tests") {
+                                self.neighbor_check_tests(path.span())?;
+                            } else if path.is_ident("dual_order") {
+                                self.dual_order(path.span())?;
+                            } else if path.is_ident("dirty_tracking") {
+                                self.dirty_tracking(path.span())?;
+                            } else if path.is_ident("dump") {
+                                self.dump(path.span())?;
+                            } else if path.is_ident("bitstring") {
+                                self.bitstring(path.span())?;
+                            } else if path.is_ident("no_straddle") {
+                                self.no_straddle(path.span())?;
+                            } else if path.is_ident("auto_pad") {
+                                self.auto_pad(path.span())?;
+                            } else if path.is_ident("contiguous") {
+                                self.contiguous(path.span())?;
+                            } else if path.is_ident("register_layout") {
+                                self.register_layout(path.span())?;
+                            } else if path.is_ident("mmio") {
+                                self.mmio(path.span())?;
+                            } else if path.is_ident("serde") {
+                                self.serde(SerdeRepr::Bytes, path.span())?;
+                            } else if path.is_ident("iter_fields") {
+                                self.iter_fields(path.span())?;
+                            } else if path.is_ident("fields_struct") {
+                                self.fields_struct(Vec::new(), path.span())?;
+                            } else if path.is_ident("access_stats") {
+                                self.access_stats(path.span())?;
+                            } else if path.is_ident("doc_examples") {
+                                self.doc_examples("crate".to_string(), path.span())?;
+                            } else if path.is_ident("minimal") {
+                                self.minimal(path.span())?;
+                            } else if path.is_ident("transparent") {
+                                self.transparent(path.span())?;
+                            } else if path.is_ident("c_embed") {
+                                self.c_embed(path.span())?;
+                            } else if path.is_ident("keep_phantom_fields") {
+                                self.keep_phantom_fields(path.span())?;
+                            } else if path.is_ident("hash") {
+                                self.hash(path.span())?;
+                            } else if path.is_ident("rename_collisions") {
+                                self.rename_collisions(path.span())?;
+                            } else {
+                                return Err(unsupported_argument(path))
+                            }
+                        }
+                        syn::Meta::List(meta_list) => {
+                            if meta_list.path.is_ident("cast_to") {
+                                self.feed_cast_to_param(meta_list)?;
+                            } else if meta_list.path.is_ident("fields_struct") {
+                                self.feed_fields_struct_param(meta_list)?;
+                            } else if meta_list.path.is_ident("words") {
+                                self.feed_words_param(meta_list)?;
+                            } else if meta_list.path.is_ident("intercept") {
+                                self.feed_derive_policy_param(meta_list, true)?;
+                            } else if meta_list.path.is_ident("passthrough") {
+                                self.feed_derive_policy_param(meta_list, false)?;
+                            } else if meta_list.path.is_ident("preset") {
+                                self.feed_preset_param(meta_list)?;
+                            } else if meta_list.path.is_ident("ffi_export") {
+                                self.feed_ffi_export_param(meta_list)?;
+                            } else {
+                                return Err(unsupported_argument(meta_list))
+                            }
+                        }
                     }
                 }
                 unsupported => return Err(unsupported_argument(unsupported)),
@@ -0,0 +1,308 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    quote,
+    ToTokens as _,
+};
+use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+        Result,
+    },
+    punctuated::Punctuated,
+    spanned::Spanned as _,
+    Token,
+};
+
+/// The maximum number of distinct `#[cfg(..)]` predicates on the fields of a bitfield.
+///
+/// Every combination of the predicates yields its own layout variant.
+const MAX_PREDICATES: usize = 4;
+
+/// A layout variant of a `#[bitfield]` struct with `#[cfg(..)]` or `#[cfg_attr(..)]` fields.
+pub struct LayoutVariant {
+    /// The predicate under which the layout variant is compiled, e.g. `all(feature = "ext")`.
+    pub predicate: TokenStream2,
+    /// The struct with the field attributes resolved for the layout variant.
+    pub item: syn::ItemStruct,
+}
+
+impl LayoutVariant {
+    /// Returns the predicate of the layout variant as written in a `#[cfg(..)]` attribute.
+    pub fn name(&self) -> String {
+        self.predicate.to_string()
+    }
+}
+
+/// A field attribute that depends on a `#[cfg(..)]` predicate.
+enum CfgAttr {
+    /// A `#[cfg(predicate)]` attribute.
+    Cfg(syn::Meta),
+    /// A `#[cfg_attr(predicate, attrs..)]` attribute.
+    CfgAttr(syn::Meta, Vec<syn::Attribute>),
+}
+
+/// The arguments of a `#[cfg_attr(predicate, attrs..)]` attribute.
+struct CfgAttrArgs {
+    predicate: syn::Meta,
+    attrs: Vec<syn::Attribute>,
+}
+
+impl Parse for CfgAttrArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let predicate = input.parse::<syn::Meta>()?;
+        input.parse::<Token![,]>()?;
+        let mut attrs = Vec::new();
+        while !input.is_empty() {
+            let path = input.call(syn::Path::parse_mod_style)?;
+            let mut tokens = TokenStream2::new();
+            while !input.is_empty() && !input.peek(Token![,]) {
+                tokens.extend(Some(input.parse::<proc_macro2::TokenTree>()?));
+            }
+            attrs.push(syn::Attribute {
+                pound_token: Default::default(),
+                style: syn::AttrStyle::Outer,
+                bracket_token: Default::default(),
+                path,
+                tokens,
+            });
+            if input.is_empty() {
+                break
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(Self { predicate, attrs })
+    }
+}
+
+impl CfgAttr {
+    /// Returns the `#[cfg(..)]` or `#[cfg_attr(..)]` attribute or `None` for other attributes.
+    fn parse(attr: &syn::Attribute) -> Result<Option<Self>> {
+        if attr.path.is_ident("cfg") {
+            return attr.parse_args::<syn::Meta>().map(|meta| Some(Self::Cfg(meta)))
+        }
+        if attr.path.is_ident("cfg_attr") {
+            let args = attr.parse_args::<CfgAttrArgs>()?;
+            return Ok(Some(Self::CfgAttr(args.predicate, args.attrs)))
+        }
+        Ok(None)
+    }
+}
+
+/// Splits off a leading `not(..)` of the predicate.
+///
+/// Returns the positive predicate and `true` if it was negated.
+fn split_negation(predicate: &syn::Meta) -> (TokenStream2, bool) {
+    if let syn::Meta::List(list) = predicate {
+        if list.path.is_ident("not") && list.nested.len() == 1 {
+            return (list.nested.to_token_stream(), true)
+        }
+    }
+    (predicate.to_token_stream(), false)
+}
+
+/// The distinct positive predicates of all field attributes of a bitfield struct.
+#[derive(Default)]
+struct Predicates {
+    predicates: Vec<TokenStream2>,
+}
+
+impl Predicates {
+    /// Registers the positive predicate of the given `#[cfg(..)]` predicate.
+    fn push(&mut self, predicate: &syn::Meta) {
+        let (positive, _) = split_negation(predicate);
+        if !self.contains(&positive) {
+            self.predicates.push(positive);
+        }
+    }
+
+    /// Returns the index of the given positive predicate.
+    fn position(&self, positive: &TokenStream2) -> Option<usize> {
+        let positive = positive.to_string();
+        self.predicates
+            .iter()
+            .position(|predicate| predicate.to_string() == positive)
+    }
+
+    /// Returns `true` if the positive predicate has already been registered.
+    fn contains(&self, positive: &TokenStream2) -> bool {
+        self.position(positive).is_some()
+    }
+
+    /// Registers all predicates of the given field attributes.
+    fn collect(&mut self, attrs: &[syn::Attribute]) -> Result<()> {
+        for attr in attrs {
+            match CfgAttr::parse(attr)? {
+                Some(CfgAttr::Cfg(predicate)) => self.push(&predicate),
+                Some(CfgAttr::CfgAttr(predicate, attrs)) => {
+                    self.push(&predicate);
+                    self.collect(&attrs)?;
+                }
+                None => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates the given predicate for the given truth values of the positive predicates.
+    fn eval(&self, predicate: &syn::Meta, values: &[bool]) -> bool {
+        let (positive, negated) = split_negation(predicate);
+        let index = self
+            .position(&positive)
+            .expect("encountered unregistered cfg predicate");
+        values[index] != negated
+    }
+
+    /// Resolves the given field attributes for the given truth values.
+    ///
+    /// Returns `None` if the field is not part of the layout variant.
+    fn resolve(
+        &self,
+        attrs: &[syn::Attribute],
+        values: &[bool],
+    ) -> Result<Option<Vec<syn::Attribute>>> {
+        let mut resolved = Vec::new();
+        for attr in attrs {
+            match CfgAttr::parse(attr)? {
+                Some(CfgAttr::Cfg(predicate)) => {
+                    if !self.eval(&predicate, values) {
+                        return Ok(None)
+                    }
+                }
+                Some(CfgAttr::CfgAttr(predicate, attrs)) => {
+                    if self.eval(&predicate, values) {
+                        match self.resolve(&attrs, values)? {
+                            Some(attrs) => resolved.extend(attrs),
+                            None => return Ok(None),
+                        }
+                    }
+                }
+                None => resolved.push(attr.clone()),
+            }
+        }
+        Ok(Some(resolved))
+    }
+
+    /// Returns the `all(..)` predicate that holds for the given truth values.
+    fn variant_predicate(&self, values: &[bool]) -> TokenStream2 {
+        let predicates = self
+            .predicates
+            .iter()
+            .zip(values)
+            .map(|(predicate, value)| {
+                match value {
+                    true => quote! { #predicate },
+                    false => quote! { not(#predicate) },
+                }
+            });
+        quote! { all(#( #predicates ),*) }
+    }
+}
+
+/// Returns the fields of the struct regardless of whether they are named or unnamed.
+fn fields_mut(item: &mut syn::ItemStruct) -> Option<&mut Punctuated<syn::Field, Token![,]>> {
+    match &mut item.fields {
+        syn::Fields::Named(fields) => Some(&mut fields.named),
+        syn::Fields::Unnamed(fields) => Some(&mut fields.unnamed),
+        syn::Fields::Unit => None,
+    }
+}
+
+/// Returns the layout variants of the struct if any of its fields has a `#[cfg(..)]` or
+/// `#[cfg_attr(..)]` attribute.
+///
+/// Every combination of the distinct predicates yields a layout variant. A predicate
+/// `not(p)` is treated as the negation of `p` while all other predicates are independent.
+///
+/// # Errors
+///
+/// - If a `#[cfg(..)]` or `#[cfg_attr(..)]` field attribute is malformed.
+/// - If the fields use more than four distinct predicates.
+pub fn layout_variants(item: &syn::ItemStruct) -> Result<Option<Vec<LayoutVariant>>> {
+    let mut predicates = Predicates::default();
+    for field in &item.fields {
+        predicates.collect(&field.attrs)?;
+    }
+    if predicates.predicates.is_empty() {
+        return Ok(None)
+    }
+    if predicates.predicates.len() > MAX_PREDICATES {
+        return Err(format_err!(
+            item.ident,
+            "encountered {} distinct cfg predicates on the fields of a #[bitfield] struct, \
+             at most {} are supported",
+            predicates.predicates.len(),
+            MAX_PREDICATES,
+        ))
+    }
+    let len = predicates.predicates.len();
+    (0..1usize << len)
+        .map(|combination| {
+            let values = (0..len)
+                .map(|index| combination & (1 << index) != 0)
+                .collect::<Vec<_>>();
+            let mut variant = item.clone();
+            if let Some(fields) = fields_mut(&mut variant) {
+                let mut resolved = Punctuated::new();
+                for mut field in core::mem::take(fields) {
+                    if let Some(attrs) = predicates.resolve(&field.attrs, &values)? {
+                        field.attrs = attrs;
+                        resolved.push(field);
+                    }
+                }
+                *fields = resolved;
+            }
+            Ok(LayoutVariant {
+                predicate: predicates.variant_predicate(&values),
+                item: variant,
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Returns the attributes of the given item.
+fn item_attrs_mut(item: &mut syn::Item) -> Option<&mut Vec<syn::Attribute>> {
+    match item {
+        syn::Item::Const(item) => Some(&mut item.attrs),
+        syn::Item::Enum(item) => Some(&mut item.attrs),
+        syn::Item::ExternCrate(item) => Some(&mut item.attrs),
+        syn::Item::Fn(item) => Some(&mut item.attrs),
+        syn::Item::ForeignMod(item) => Some(&mut item.attrs),
+        syn::Item::Impl(item) => Some(&mut item.attrs),
+        syn::Item::Macro(item) => Some(&mut item.attrs),
+        syn::Item::Macro2(item) => Some(&mut item.attrs),
+        syn::Item::Mod(item) => Some(&mut item.attrs),
+        syn::Item::Static(item) => Some(&mut item.attrs),
+        syn::Item::Struct(item) => Some(&mut item.attrs),
+        syn::Item::Trait(item) => Some(&mut item.attrs),
+        syn::Item::TraitAlias(item) => Some(&mut item.attrs),
+        syn::Item::Type(item) => Some(&mut item.attrs),
+        syn::Item::Union(item) => Some(&mut item.attrs),
+        syn::Item::Use(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
+
+/// Annotates every item of the expanded layout variant with its `#[cfg(..)]` predicate.
+///
+/// # Errors
+///
+/// If the expansion cannot be parsed as a sequence of items.
+pub fn gate_expansion(variant: &LayoutVariant, expanded: TokenStream2) -> Result<TokenStream2> {
+    let mut file = syn::parse2::<syn::File>(expanded)?;
+    let predicate = &variant.predicate;
+    for item in &mut file.items {
+        let span = item.span();
+        match item_attrs_mut(item) {
+            Some(attrs) => attrs.insert(0, syn::parse_quote_spanned!(span=> #[cfg(#predicate)])),
+            None => {
+                return Err(format_err!(
+                    span,
+                    "encountered unexpected item in the expansion of a layout variant"
+                ))
+            }
+        }
+    }
+    Ok(file.into_token_stream())
+}
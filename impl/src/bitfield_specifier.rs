@@ -1,6 +1,9 @@
+use crate::errors::CombineError;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote_spanned;
-use syn::spanned::Spanned as _;
+use syn::{
+    ext::IdentExt as _,
+    spanned::Spanned as _,
+};
 
 pub fn generate(input: TokenStream2) -> TokenStream2 {
     match generate_or_error(input) {
@@ -37,52 +40,185 @@ fn generate_or_error(input: TokenStream2) -> syn::Result<TokenStream2> {
         }
     }
 }
+
 struct Attributes {
-    bits: Option<usize>,
+    bits: Option<(usize, proc_macro2::Span)>,
+    repr: Option<(usize, proc_macro2::Span)>,
+    from_str: Option<proc_macro2::Span>,
+    case_insensitive: Option<proc_macro2::Span>,
+    conversions: Option<proc_macro2::Span>,
+}
+
+/// Returns the bit width of the unsigned integer `#[repr(uN)]` within the given `#[repr(..)]`.
+///
+/// Other representations such as `C` or signed integers are ignored.
+fn parse_repr_attr(attr: &syn::Attribute) -> syn::Result<Option<(usize, proc_macro2::Span)>> {
+    let meta = match attr.parse_meta()? {
+        syn::Meta::List(meta) => meta,
+        _ => return Ok(None),
+    };
+    for nested_meta in &meta.nested {
+        if let syn::NestedMeta::Meta(syn::Meta::Path(path)) = nested_meta {
+            let bits = match path.get_ident().map(ToString::to_string).as_deref() {
+                Some("u8") => 8,
+                Some("u16") => 16,
+                Some("u32") => 32,
+                Some("u64") => 64,
+                Some("u128") => 128,
+                _ => continue,
+            };
+            return Ok(Some((bits, nested_meta.span())))
+        }
+    }
+    Ok(None)
 }
 
 fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
-    let attributes = attrs
-        .iter()
-        .filter(|attr| attr.path.is_ident("bits"))
-        .fold(
-            Ok(Attributes { bits: None }),
-            |acc: syn::Result<Attributes>, attr| {
-                let mut acc = acc?;
-                if acc.bits.is_some() {
+    let mut attributes = Attributes {
+        bits: None,
+        repr: None,
+        from_str: None,
+        case_insensitive: None,
+        conversions: None,
+    };
+    for attr in attrs {
+        if attr.path.is_ident("bits") {
+            if attributes.bits.is_some() {
+                return Err(format_err_spanned!(
+                    attr,
+                    "More than one 'bits' attributes is not permitted",
+                ))
+            }
+            let meta = attr.parse_meta()?;
+            attributes.bits = match meta {
+                syn::Meta::NameValue(syn::MetaNameValue {
+                    lit: syn::Lit::Int(lit),
+                    ..
+                }) => Some((lit.base10_parse::<usize>()?, attr.span())),
+                _ => {
                     return Err(format_err_spanned!(
                         attr,
-                        "More than one 'bits' attributes is not permitted",
+                        "could not parse 'bits' attribute",
                     ))
                 }
-                let meta = attr.parse_meta()?;
-                acc.bits = match meta {
-                    syn::Meta::NameValue(syn::MetaNameValue {
-                        lit: syn::Lit::Int(lit),
-                        ..
-                    }) => Some(lit.base10_parse::<usize>()?),
-                    _ => {
-                        return Err(format_err_spanned!(
-                            attr,
-                            "could not parse 'bits' attribute",
-                        ))
-                    }
-                };
-                Ok(acc)
-            },
-        )?;
+            };
+        } else if attr.path.is_ident("repr") {
+            if let Some(repr) = parse_repr_attr(attr)? {
+                attributes.repr = Some(repr);
+            }
+        } else if attr.path.is_ident("from_str") {
+            attributes.from_str =
+                Some(parse_flag_attr(attr, "from_str", attributes.from_str)?);
+        } else if attr.path.is_ident("case_insensitive") {
+            attributes.case_insensitive = Some(parse_flag_attr(
+                attr,
+                "case_insensitive",
+                attributes.case_insensitive,
+            )?);
+        } else if attr.path.is_ident("bitfield_specifier") {
+            parse_bitfield_specifier_attr(attr, &mut attributes)?;
+        }
+    }
+    if let (Some(case_insensitive), None) =
+        (attributes.case_insensitive, attributes.from_str)
+    {
+        return Err(format_err!(
+            case_insensitive,
+            "'case_insensitive' attribute requires the 'from_str' attribute",
+        ))
+    }
     Ok(attributes)
 }
 
+/// Parses the parameters of a `#[bitfield_specifier(..)]` attribute such as `conversions`.
+///
+/// Returns an error for unsupported or duplicate parameters.
+fn parse_bitfield_specifier_attr(
+    attr: &syn::Attribute,
+    attributes: &mut Attributes,
+) -> syn::Result<()> {
+    let meta = match attr.parse_meta()? {
+        syn::Meta::List(meta) => meta,
+        _ => {
+            return Err(format_err_spanned!(
+                attr,
+                "'bitfield_specifier' attribute expects a list of parameters, e.g. #[bitfield_specifier(conversions)]",
+            ))
+        }
+    };
+    for nested_meta in &meta.nested {
+        match nested_meta {
+            syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("conversions") => {
+                if attributes.conversions.is_some() {
+                    return Err(format_err_spanned!(
+                        path,
+                        "More than one 'conversions' parameters is not permitted",
+                    ))
+                }
+                attributes.conversions = Some(path.span());
+            }
+            unsupported => {
+                return Err(format_err_spanned!(
+                    unsupported,
+                    "encountered unsupported 'bitfield_specifier' parameter",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses an attribute without arguments such as `#[from_str]` and returns its span.
+///
+/// Returns an error if the attribute takes arguments or has already been found before.
+fn parse_flag_attr(
+    attr: &syn::Attribute,
+    name: &str,
+    previous: Option<proc_macro2::Span>,
+) -> syn::Result<proc_macro2::Span> {
+    if previous.is_some() {
+        return Err(format_err_spanned!(
+            attr,
+            "More than one '{}' attributes is not permitted",
+            name,
+        ))
+    }
+    if !matches!(attr.parse_meta()?, syn::Meta::Path(_)) {
+        return Err(format_err_spanned!(
+            attr,
+            "'{}' attribute does not take any arguments",
+            name,
+        ))
+    }
+    Ok(attr.path.span())
+}
+
 fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
     let span = input.span();
     let attributes = parse_attrs(&input.attrs)?;
     let enum_ident = &input.ident;
 
-    let bits = match attributes.bits {
-        Some(bits) => bits,
+    if let (Some((bits, bits_span)), Some((repr_bits, repr_span))) =
+        (attributes.bits, attributes.repr)
+    {
+        if bits > repr_bits {
+            return Err(format_err!(
+                bits_span,
+                "encountered #[bits = {}] that exceeds the {} bits of #[repr(u{})]",
+                bits,
+                repr_bits,
+                repr_bits,
+            )
+            .into_combine(format_err!(repr_span, "#[repr(u{})] here", repr_bits)))
+        }
+    }
+    let count_variants = input.variants.iter().count();
+    // Enums with a power-of-two number of variants keep the bit width derived from their
+    // number of variants even with `#[repr(uN)]` so that their layout does not change.
+    let repr = attributes.repr.filter(|_| !count_variants.is_power_of_two());
+    let bits = match attributes.bits.or(repr) {
+        Some((bits, _)) => bits,
         None => {
-            let count_variants = input.variants.iter().count();
             if !count_variants.is_power_of_two() {
                 return Err(format_err!(
                     span,
@@ -114,14 +250,23 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
         })
         .collect::<Vec<_>>();
 
+    // Discriminants are checked as `u128` and bit widths are used as `u32` shift amounts
+    // in the generated checks so that they do not depend on the width of `usize` of the
+    // compilation target.
+    let bits_u32 = proc_macro2::Literal::u32_suffixed(bits as u32);
     let check_discriminants = variants.iter().map(|ident| {
         let span = ident.span();
+        // The shift is split in two so that neither shift overflows for up to 128 bits.
         quote_spanned!(span =>
-            impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<[(); Self::#ident as usize]> for #enum_ident {
-                type CheckType = [(); ((Self::#ident as usize) < (0x01_usize << #bits)) as usize ];
+            impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<{ #enum_ident::#ident as ::core::primitive::u128 }> for #enum_ident {
+                type CheckType = [(); ((Self::#ident as ::core::primitive::u128) >> (#bits_u32 / 2) >> (#bits_u32 - #bits_u32 / 2) == 0) as ::core::primitive::usize];
             }
         )
     });
+    let discriminants = variants.iter().map(|ident| {
+        let span = ident.span();
+        quote_spanned!(span=> Self::#ident as ::core::primitive::u128)
+    });
     let from_bytes_arms = variants.iter().map(|ident| {
         let span = ident.span();
         quote_spanned!(span=>
@@ -131,11 +276,84 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
         )
     });
 
+    // The conversions from and to the discriminant use the primitive of the `Bytes` type
+    // of the specifier since trait impls cannot be written in terms of its projection.
+    let bytes_prim = match bits {
+        0..=8 => quote_spanned!(span=> ::core::primitive::u8),
+        9..=16 => quote_spanned!(span=> ::core::primitive::u16),
+        17..=32 => quote_spanned!(span=> ::core::primitive::u32),
+        33..=64 => quote_spanned!(span=> ::core::primitive::u64),
+        _ => quote_spanned!(span=> ::core::primitive::u128),
+    };
+    let from_str_arms = variants.iter().map(|ident| {
+        let span = ident.span();
+        let name = ident.unraw().to_string();
+        let matches_name = match attributes.case_insensitive.is_some() {
+            true => quote_spanned!(span=> __bf_str.eq_ignore_ascii_case(#name)),
+            false => quote_spanned!(span=> __bf_str == #name),
+        };
+        quote_spanned!(span=>
+            if #matches_name {
+                return ::core::result::Result::Ok(Self::#ident)
+            }
+        )
+    });
+
+    let from_str = attributes.from_str.map(|from_str| {
+        quote_spanned!(from_str=>
+            impl<'a> ::core::convert::TryFrom<&'a ::core::primitive::str> for #enum_ident {
+                type Error = ::modular_bitfield::error::UnknownVariant;
+
+                #[inline]
+                fn try_from(
+                    __bf_str: &'a ::core::primitive::str,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    #( #from_str_arms )*
+                    ::core::result::Result::Err(::modular_bitfield::error::UnknownVariant)
+                }
+            }
+
+            impl ::core::str::FromStr for #enum_ident {
+                type Err = ::modular_bitfield::error::UnknownVariant;
+
+                #[inline]
+                fn from_str(
+                    __bf_str: &::core::primitive::str,
+                ) -> ::core::result::Result<Self, Self::Err> {
+                    <Self as ::core::convert::TryFrom<&::core::primitive::str>>::try_from(__bf_str)
+                }
+            }
+        )
+    });
+
+    let conversions = attributes.conversions.map(|conversions| {
+        quote_spanned!(conversions=>
+            impl ::core::convert::TryFrom<#bytes_prim> for #enum_ident {
+                type Error = ::modular_bitfield::error::InvalidBitPattern<#bytes_prim>;
+
+                #[inline]
+                fn try_from(__bf_bytes: #bytes_prim) -> ::core::result::Result<Self, Self::Error> {
+                    <Self as ::modular_bitfield::Specifier>::from_bytes(__bf_bytes)
+                }
+            }
+
+            impl ::core::convert::From<#enum_ident> for #bytes_prim {
+                #[inline]
+                fn from(__bf_input: #enum_ident) -> Self {
+                    __bf_input as Self
+                }
+            }
+        )
+    });
+
     Ok(quote_spanned!(span=>
         #( #check_discriminants )*
 
         impl ::modular_bitfield::Specifier for #enum_ident {
             const BITS: usize = #bits;
+            const REQUIRED_BITS: usize = ::modular_bitfield::private::required_bits(&[
+                #( #discriminants ),*
+            ]);
             type Bytes = <[(); #bits] as ::modular_bitfield::private::SpecifierBytes>::Bytes;
             type InOut = Self;
 
@@ -156,5 +374,8 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
                 }
             }
         }
+
+        #from_str
+        #conversions
     ))
 }
@@ -6,8 +6,12 @@ use quote::{
 
 pub fn generate(_input: TokenStream2) -> TokenStream2 {
     let specifiers = (1usize..=128).map(generate_specifier_for);
+    let signed_specifiers = (1usize..=128).map(generate_signed_specifier_for);
+    let non_zero_specifiers = (1usize..=64).map(generate_non_zero_specifier_for);
     quote! {
         #( #specifiers )*
+        #( #signed_specifiers )*
+        #( #non_zero_specifiers )*
     }
 }
 
@@ -67,3 +71,102 @@ fn generate_specifier_for(bits: usize) -> TokenStream2 {
         impl crate::private::checks::private::Sealed for [(); #bits] {}
     }
 }
+
+fn generate_signed_specifier_for(bits: usize) -> TokenStream2 {
+    let (bytes, in_out, width) = match bits {
+        1..=8 => (quote! { ::core::primitive::u8 }, quote! { ::core::primitive::i8 }, 8),
+        9..=16 => (quote! { ::core::primitive::u16 }, quote! { ::core::primitive::i16 }, 16),
+        17..=32 => (quote! { ::core::primitive::u32 }, quote! { ::core::primitive::i32 }, 32),
+        33..=64 => (quote! { ::core::primitive::u64 }, quote! { ::core::primitive::i64 }, 64),
+        65..=128 => (quote! { ::core::primitive::u128 }, quote! { ::core::primitive::i128 }, 128),
+        _ => unreachable!(),
+    };
+    let ident = format_ident!("S{}", bits);
+    let doc_comment = if bits == 1 {
+        "Specifier for a single bit signed integer in two's complement.".to_string()
+    } else {
+        format!("Specifier for a {} bits signed integer in two's complement.", bits)
+    };
+    // The number of unused high bits of the underlying primitive types.
+    let shift = width - bits;
+    quote! {
+        #[doc = #doc_comment]
+        #[derive(Copy, Clone)]
+        pub enum #ident {}
+
+        impl crate::Specifier for #ident {
+            const BITS: usize = #bits;
+            type Bytes = #bytes;
+            type InOut = #in_out;
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+                // The value is in bounds if it survives truncation and sign extension.
+                if (input << #shift) >> #shift != input {
+                    return Err(crate::OutOfBounds)
+                }
+                Ok(((input as #bytes) << #shift) >> #shift)
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+                if (bytes << #shift) >> #shift != bytes {
+                    return Err(crate::InvalidBitPattern { invalid_bytes: bytes })
+                }
+                Ok(((bytes << #shift) as #in_out) >> #shift)
+            }
+        }
+    }
+}
+
+fn generate_non_zero_specifier_for(bits: usize) -> TokenStream2 {
+    let (bytes, in_out) = match bits {
+        1..=8 => (quote! { ::core::primitive::u8 }, quote! { ::core::num::NonZeroU8 }),
+        9..=16 => (quote! { ::core::primitive::u16 }, quote! { ::core::num::NonZeroU16 }),
+        17..=32 => (quote! { ::core::primitive::u32 }, quote! { ::core::num::NonZeroU32 }),
+        33..=64 => (quote! { ::core::primitive::u64 }, quote! { ::core::num::NonZeroU64 }),
+        _ => unreachable!(),
+    };
+    let ident = format_ident!("NonZeroB{}", bits);
+    let doc_comment = if bits == 1 {
+        "Specifier for a single bit that must not be zero.".to_string()
+    } else {
+        format!("Specifier for {} bits that must not all be zero.", bits)
+    };
+    let max_value = if bits.is_power_of_two() && bits >= 8 {
+        quote! {{ <#bytes>::MAX }}
+    } else {
+        quote! {{ ((0x01 as #bytes) << #bits) - 1 }}
+    };
+    quote! {
+        #[doc = #doc_comment]
+        ///
+        /// `Option<Self>` uses the all-zero bit pattern for `None` and therefore
+        /// occupies the same number of bits.
+        #[derive(Copy, Clone)]
+        pub enum #ident {}
+
+        impl crate::Specifier for #ident {
+            const BITS: usize = #bits;
+            const HAS_ZERO_NICHE: bool = true;
+            type Bytes = #bytes;
+            type InOut = #in_out;
+
+            #[inline]
+            fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+                if input.get() > #max_value {
+                    return Err(crate::OutOfBounds)
+                }
+                Ok(input.get())
+            }
+
+            #[inline]
+            fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+                if bytes > #max_value {
+                    return Err(crate::InvalidBitPattern { invalid_bytes: bytes })
+                }
+                <#in_out>::new(bytes).ok_or(crate::InvalidBitPattern { invalid_bytes: bytes })
+            }
+        }
+    }
+}
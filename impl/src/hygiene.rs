@@ -0,0 +1,22 @@
+/// Like [`quote::quote_spanned`] but resolves the generated tokens at the call site.
+///
+/// The given span only determines the location of the generated tokens that is used
+/// for diagnostics. Their hygiene is always the one of the `#[bitfield]` invocation.
+///
+/// # Note
+///
+/// The spans of the fields and parameters of a `#[bitfield]` struct carry the hygiene of
+/// the code they have been written in. If the struct is generated by a `macro_rules!`
+/// template this differs from the hygiene of the `#[bitfield]` invocation itself. Generated
+/// local variables, including `self`, are then not found if they are declared and used in
+/// code fragments that are spanned by different input tokens.
+/// Tokens interpolated into the generated code, such as the types and names of the fields,
+/// keep their original spans.
+macro_rules! quote_spanned {
+    ( $span:expr=> $($tt:tt)* ) => {{
+        let __span: ::proc_macro2::Span = $span;
+        ::quote::quote_spanned!(
+            __span.resolved_at(::proc_macro2::Span::call_site())=> $($tt)*
+        )
+    }};
+}
@@ -5,6 +5,8 @@ extern crate proc_macro;
 
 #[macro_use]
 mod errors;
+#[macro_use]
+mod hygiene;
 mod bitfield;
 mod bitfield_specifier;
 mod define_specifiers;
@@ -13,190 +15,2120 @@ use proc_macro::TokenStream;
 
 /// Generates the `B1`, `B2`, ..., `B128` bitfield specifiers.
 ///
-/// Only of use witihn the `modular_bitfield` crate itself.
-#[proc_macro]
-pub fn define_specifiers(input: TokenStream) -> TokenStream {
-    define_specifiers::generate(input.into()).into()
-}
-
-/// Applicable to structs to turn their fields into compact bitfields.
+/// Only of use witihn the `modular_bitfield` crate itself.
+#[proc_macro]
+pub fn define_specifiers(input: TokenStream) -> TokenStream {
+    define_specifiers::generate(input.into()).into()
+}
+
+/// Applicable to structs to turn their fields into compact bitfields.
+///
+/// # Generated API
+///
+/// By default this generates the following API:
+///
+/// - **Constructors:**
+///
+///     1. `new()`: Initializes all bits to 0 even if 0 bits may be invalid.
+///        Note that invalid bit patterns are supported in that getters and setters will
+///        be protecting accesses.
+///
+/// - **Getters:**
+///
+///     For every field `f` we generate the following getters:
+///
+///     1. `f()`: Returns the value of `f` and might panic
+///        if the value contains an invalid bit pattern.
+///     2. `f_or_err()`: Returns the value of `f` or an error
+///        if the value contains an invalid bit pattern.
+///
+/// - **Setters:**
+///
+///     For every field `f` we generate the following setters:
+///
+///     1. `set_f(new_value)`: Sets the value of `f` to `new_value` and might panic
+///        if `new_value` is out of bounds for the bit width of `f`.
+///     2. `set_f_checked(new_value)`: Sets the value of `f` to `new` or returns an error
+///        if `new_value` if out of bounds for the bit width of `f`.
+///     3. `with_f(new_value)`: Similar to `set_f` but consumes and returns `Self`.
+///        Primarily useful for method chaining.
+///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
+///        Primarily useful for method chaining.
+///     5. `set_f_chain(new_value)`: Similar to `set_f` but returns `&mut Self`.
+///        Primarily useful for method chaining on bitfields that cannot be moved.
+///
+/// - **Flags:**
+///
+///     For every field `f` of type `bool` we additionally generate:
+///
+///     1. `toggle_f()`: Flips the value of `f` with a single bit operation.
+///     2. `set_f_if(condition)`: Sets `f` to `true` if `condition` is `true`
+///        and leaves it unchanged otherwise.
+///     3. `f_then(func)`: Returns `Some(func())` if `f` is `true` and `None` otherwise.
+///
+/// - **Integers:**
+///
+///     For every field `f` of an unsigned integer type such as `B5` or `u16` we additionally generate:
+///
+///     1. `set_f_truncated(new_value)`: Sets `f` to `new_value` with all bits discarded
+///        that do not fit into the bit width of `f`.
+///     2. `set_f_saturating(new_value)`: Sets `f` to `new_value` or to the maximum value
+///        of `f` if `new_value` is out of bounds for the bit width of `f`.
+///     3. `or_f(bits)`, `and_f(bits)` and `xor_f(bits)`: Apply the bitwise operation with
+///        `bits` to the value of `f`. The bits that do not fit into the bit width of `f` are
+///        discarded so that the other fields are never affected.
+///
+/// - **Raw Bits:**
+///
+///     For every field `f` we additionally generate:
+///
+///     1. `f_raw()`: Returns the raw bits of `f` without decoding them, even if they
+///        do not represent a valid value of `f`.
+///     2. `set_f_raw(raw)`: Sets the raw bits of `f` without encoding them and panics
+///        if `raw` is out of bounds for the bit width of `f`.
+///
+/// - **Conversions:**
+///
+///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
+///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
+///     - `as_bitslice()`, `as_mut_bitslice()` and `from_bitslice(bits)`: Requires the `bitvec`
+///       crate feature. Allow to view and construct the bitfield as `bitvec` bit slice.
+///
+/// - **Queries:**
+///
+///     - `is_zeroed()`: Returns `true` if all defined bits of the bitfield are zero without
+///       decoding any of its fields.
+///
+/// - **Size:**
+///
+///     - `BYTES`: A `usize` constant with the number of bytes of the bitfield, i.e. the
+///       length of the array returned by `into_bytes()`. The `assert_total_bytes!` macro
+///       uses it to check that a group of bitfields fits into a fixed memory region.
+///
+/// - **Layout Version:**
+///
+///     - `LAYOUT_VERSION`: A `u64` constant hashing the bit order as well as the name, bit
+///       offset and bit width of every field at compile time. Two builds can compare it,
+///       e.g. during a handshake, to cheaply verify that they agree on the wire layout.
+///       The hash does not depend on the name of the struct or on the types of the fields.
+///
+/// - **Random Generation:**
+///
+///     - `Distribution<Self>` for `rand::distributions::Standard`: Requires the `rand` crate
+///       feature. Assigns every field random bits forming a valid bit pattern of its specifier
+///       while `#[skip]` fields are zero. Nested bitfields accept all bit patterns since their
+///       specifier does not validate their fields.
+///
+/// # Generated Hidden Items
+///
+/// Besides the documented API the expansion contains hidden items that are not part of the
+/// API but follow a stable naming scheme so that the expansions of two versions of a struct,
+/// e.g. as shown by `cargo expand`, only differ where the layout differs:
+///
+/// - Hidden functions, fields, constants and locals are prefixed with `__bf_` or `__BF_` and
+///   hidden types with `__Bf`. Only hidden items at module level, such as the unit test of
+///   `layout_guard`, embed the name of the struct in snake case, e.g. `__bf_layout_guard_header`.
+/// - Hidden items belonging to a field are named after the field instead of its position,
+///   e.g. `__bf_access_counter_f()` for the access counter of the field `f`. Only the fields
+///   of tuple structs are named after their index.
+/// - Compile time checks are emitted as anonymous `const _: () = ..;` items in the order of
+///   the fields.
+///
+/// # Parameters
+///
+/// The following parameters for the `#[bitfield]` macro are supported:
+///
+/// ## Parameter: `bytes = N`
+///
+/// This ensures at compilation time that the resulting `#[bitfield]` struct consists of
+/// exactly `N` bytes. Yield a compilation error if this does not hold true.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bytes = 2)]
+/// pub struct SingedInt {
+///     sign: bool, //  1 bit
+///     value: B15, // 15 bits
+/// }
+/// ```
+///
+/// ## Parameter: `filled: bool`
+///
+/// If `filled` is `true` ensures that the `#[bitfield]` struct defines all bits and
+/// therefore has a bitwidth that is divisible by 8. If `filled` is `false` ensures the
+/// exact opposite.
+///
+/// The default value is: `true`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(filled = false)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B2,        // 2 bits
+/// }
+/// ```
+///
+/// ## Parameter: `uninit: bool`
+///
+/// If `uninit` is `true` additionally generates constructors that do not zero initialize
+/// the underlying bytes of the `#[bitfield]` struct. This is useful for very large bitfields
+/// that are entirely overwritten right after their creation.
+///
+/// - `new_uninit()`: Returns a `MaybeUninit<Self>` with uninitialized data.
+/// - `unsafe from_uninit_with(f)`: Lets `f` initialize all the underlying bytes in place.
+///
+/// Note that the generated `from_uninit_with` is an `unsafe fn` and therefore in conflict with
+/// `#![forbid(unsafe_code)]`.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(uninit = true)]
+/// pub struct Table {
+///     entries: u128,
+///     checksum: u32,
+/// }
+///
+/// let table = unsafe {
+///     Table::from_uninit_with(|bytes| {
+///         bytes.as_mut_ptr().write([0xFF; 20]);
+///     })
+/// };
+/// assert_eq!(table.checksum(), u32::MAX);
+/// ```
+///
+/// ## Parameter: `new = "name"`
+///
+/// Renames the generated zero initializing constructor `new()` to `name()`, e.g. if `new`
+/// is expected to take arguments in the surrounding code base.
+///
+/// ## Parameter: `preset(name = "value", ..)`
+///
+/// Generates the `const` constructor `name()` for every preset that initializes the bitfield
+/// from the given packed value. This keeps the documented register values of a device, such
+/// as its reset value, next to the layout. Values are given as integer literals or strings
+/// containing them with up to 128 bits.
+///
+/// The least significant bit of the value is the first bit of the bitfield, or the last bit
+/// for `bit_order = be`. Values that do not fit into the bits of the bitfield fail to compile.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(new = "zeroed", preset(reset = "0x00FF_1234", safe_mode = 0x8000_0000))]
+/// pub struct Control {
+///     low: u16,
+///     high: B15,
+///     safe: bool,
+/// }
+///
+/// assert_eq!(Control::zeroed().low(), 0);
+/// assert_eq!(Control::reset().low(), 0x1234);
+/// assert_eq!(Control::reset().high(), 0x00FF);
+/// assert!(Control::safe_mode().safe());
+/// ```
+///
+/// ## Parameters: `words(u16, u32, u64)` and `word_endian = "little" | "big"`
+///
+/// Generates `into_words_uN()` and `from_words_uN(words)` for every given word type in
+/// addition to `into_bytes` and `from_bytes`. Every word consists of consecutive bytes of
+/// the bitfield whose order is set by `word_endian`. It defaults to the byte order matching
+/// the bit order of the bitfield. `from_words_uN` returns the same as `from_bytes`.
+///
+/// The number of bytes of the bitfield must be a multiple of the size of every word type,
+/// otherwise compilation fails.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(words(u16), word_endian = "big")]
+/// pub struct Request {
+///     address: u8,
+///     function: u8,
+///     value: u16,
+/// }
+///
+/// let request = Request::new().with_address(0x11).with_function(0x03).with_value(0xABCD);
+/// assert_eq!(request.into_words_u16(), [0x1103, 0xCDAB]);
+/// assert_eq!(Request::from_words_u16([0x1103, 0xCDAB]).value(), 0xABCD);
+/// ```
+///
+/// ## Parameter: `const_accessors`
+///
+/// Generates the getters `f()`, the setters `set_f(new_value)` and the `with_f(new_value)`
+/// setters of all `bool` and unsigned integer fields, e.g. `B5` or `u16`, as `const fn`.
+/// This allows to build bitfields in `const` and `static` items field by field. Fields of
+/// other types, e.g. enums, or with `#[offset_by = K]`, `#[validate(..)]` or
+/// `#[payload_of(..)]` keep their non-`const` accessors. The `const` setters still panic
+/// for values that are out of bounds which fails the compilation in `const` contexts.
+///
+/// For every `const` getter `f()` an associated `const fn f_from_bytes(bytes)` is generated
+/// as well which reads the field directly from the given bytes. This decodes constant data,
+/// e.g. option bytes embedded into a firmware image, at compile time even for bitfields whose
+/// `from_bytes` is not `const` because they are not filled.
+///
+/// Calling `set_f` in `const` contexts requires Rust 1.83 or later.
+/// The parameter cannot be combined with `access_stats` and `accessors_mod`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(const_accessors)]
+/// pub struct Control {
+///     enabled: bool,
+///     mode: B3,
+///     divider: B12,
+/// }
+///
+/// const DEFAULT: Control = Control::new().with_enabled(true).with_divider(1000);
+/// const DIVIDER: u16 = DEFAULT.divider();
+/// const MODE: u8 = Control::mode_from_bytes([0b0000_1011, 0x00]);
+///
+/// assert!(DEFAULT.enabled());
+/// assert_eq!(DEFAULT.mode(), 0);
+/// assert_eq!(DIVIDER, 1000);
+/// assert_eq!(MODE, 0b101);
+/// ```
+///
+/// ## Parameter: `emit_layout = "path"`
+///
+/// Writes a machine-readable JSON description of the computed layout to the given path
+/// during compilation. Relative paths are resolved against the directory of the manifest
+/// of the crate that is being compiled. This is useful for external tooling that generates
+/// matching accessors for other languages.
+///
+/// The bit widths of all fields must be known to the `#[bitfield]` macro. This is the case
+/// for the predefined `B1`, .., `B128`, `bool`, `ActiveLow<bool>`, `AsciiChar<N>`, `Flags<N>`, `u8`, .., `u128` specifiers
+/// and the signed encodings of these such as `SignMagnitude<B12>`. Fields of other
+/// types must be annotated with `#[bits = N]`. It is checked at compile time that the bit
+/// widths written to the layout file match the actual bit widths of the fields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(emit_layout = "target/layouts/package.json")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// The above writes the following JSON to `target/layouts/package.json`:
+///
+/// ```json
+/// {
+///   "name": "Package",
+///   "bits": 8,
+///   "bytes": 1,
+///   "filled": true,
+///   "bit_order": "le",
+///   "fields": [
+///     { "name": "is_received", "type": "bool", "offset": 0, "bits": 1, "getters": true, "setters": true },
+///     { "name": "is_alive", "type": "bool", "offset": 1, "bits": 1, "getters": true, "setters": true },
+///     { "name": "status", "type": "B6", "offset": 2, "bits": 6, "getters": true, "setters": true }
+///   ]
+/// }
+/// ```
+///
+/// Field arrays `[T; N]` and fields with `#[repeat = N]` are described by the bit width of
+/// a single element together with their `"len": N` or `"repeat": N` element count
+/// respectively. Their generated indexed accessors such as `f(index)` and `f_or_err(index)`
+/// or `f_at(index)` are listed under `"indexed_getters"` and `"indexed_setters"`.
+///
+/// ## Parameter: `register_layout`
+///
+/// Requires the `registry` crate feature.
+///
+/// Submits the layout of the bitfield to a global registry that can be iterated at runtime
+/// via `modular_bitfield::registry::iter()`. Every registered `BitfieldLayout` provides the
+/// name and module path of the bitfield struct, its bit width and bit order as well as the
+/// name, bit offset and bit width of every field. With `BitfieldLayout::read` the raw bits of
+/// a field can be read from the bytes of the bitfield. This allows tools such as diagnostic
+/// shells to list and decode all known bitfield types without maintaining a central table.
+///
+/// The registry is populated before `main` is entered on the platforms supported by the
+/// [`inventory`](https://docs.rs/inventory) crate.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[bitfield(register_layout)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let bytes = [0b0000_1101];
+/// for layout in modular_bitfield::registry::iter() {
+///     println!("{}::{}", layout.module_path(), layout.name());
+///     for field in layout.fields() {
+///         println!("  {} = {:?}", field.name(), layout.read(field, &bytes));
+///     }
+/// }
+/// ```
+///
+/// ## Parameter: `layout_guard = "path"`
+///
+/// Generates a `#[test]` that compares the layout of the bitfield against the snapshot file
+/// at the given path and fails with a line diff if they differ. Relative paths are resolved
+/// against the directory of the manifest of the crate that is being tested. Checking in the
+/// snapshot file makes every change of the layout visible during review.
+///
+/// The snapshot lists the total bit width of the bitfield followed by the bit offset and
+/// bit width of every field, one field per line. If the snapshot file does not exist the
+/// test fails and prints the expected content of the snapshot file.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(layout_guard = "tests/snapshots/package.snapshot")]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+/// ```
+///
+/// The above test expects the following content of `tests/snapshots/package.snapshot`:
+///
+/// ```text
+/// struct Package bits 8
+/// field is_received offset 0 width 1
+/// field is_alive offset 1 width 1
+/// field status offset 2 width 6
+/// ```
+///
+/// ## Parameter: `doc_examples`
+///
+/// Appends an `# Examples` section with a doctest to the docs of every getter and setter.
+/// The doctest constructs the bitfield via `new()` and calls the documented accessor, so
+/// running `cargo test --doc` additionally checks that every accessor signature compiles.
+/// The doctests are compiled but not run since the values they use are not known to be valid.
+///
+/// The doctests import the bitfield struct from the crate root. If the struct is defined in a
+/// module the path of that module can be given as in `doc_examples = "regs::status"`.
+/// Examples are only generated for public structs with public fields since doctests cannot
+/// access anything else. Setters of fields with skipped getters have no examples since the
+/// doctests take the values they set from the getters.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(doc_examples)]
+/// pub struct Header {
+///     pub is_compressed: bool,
+///     pub length: B15,
+/// }
+/// ```
+///
+/// The docs of `Header::set_length` then contain the following example:
+///
+/// ```ignore
+/// # use my_crate::Header;
+/// let value = Header::new().length();
+/// let mut bitfield = Header::new();
+/// bitfield.set_length(value);
+/// ```
+///
+/// ## Parameter: `split_at = "field"`
+///
+/// Generates a `split_mut(&mut self)` method that splits the bitfield into two disjoint
+/// mutable halves at the given field, similar to `slice::split_at_mut`. The first half
+/// provides the getters and setters of all fields before the given field, the second half
+/// provides the getters and setters of the given field and all fields after it.
+/// This allows to mutate both halves at the same time, e.g. from two different functions.
+///
+/// The halves are named `{Struct}HalfA` and `{Struct}HalfB` and share the visibility of
+/// the bitfield struct. The given field must not be the first field and must start at a
+/// byte boundary, otherwise compilation fails. The parameter cannot be combined with
+/// `dirty_tracking`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(split_at = "tx_level")]
+/// pub struct Radio {
+///     rx_enabled: bool,
+///     rx_gain: B7,
+///     tx_level: B4,
+///     tx_enabled: bool,
+///     tx_gain: B3,
+/// }
+///
+/// let mut radio = Radio::new();
+/// let (mut rx, mut tx) = radio.split_mut();
+/// rx.set_rx_gain(100);
+/// tx.set_tx_level(7);
+/// assert_eq!(rx.rx_gain(), 100);
+/// assert_eq!(radio.tx_level(), 7);
+/// ```
+///
+/// ## Parameter: `accessors_mod = "name"`
+///
+/// Generates the field accessors on wrapper types instead of directly on the bitfield
+/// struct so that they cannot collide with methods of user defined `impl` blocks.
+/// This allows to keep field names from a specification, e.g. a field named `len`
+/// next to a user defined `fn len(&self)`.
+///
+/// The `name(&self)` method returns a `{Struct}{Name}Ref` wrapper providing the getters of
+/// all fields and the `name_mut(&mut self)` method returns a `{Struct}{Name}Mut` wrapper
+/// providing all field accessors. Both wrappers share the visibility of the bitfield struct.
+/// The parameter cannot be combined with `dirty_tracking` or `doc_examples`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(accessors_mod = "fields")]
+/// pub struct Packet {
+///     len: B12,
+///     kind: B4,
+/// }
+///
+/// impl Packet {
+///     pub fn len(&self) -> usize {
+///         usize::from(self.fields().len()) + 2
+///     }
+/// }
+///
+/// let mut packet = Packet::new();
+/// packet.fields_mut().set_len(40);
+/// assert_eq!(packet.fields().len(), 40);
+/// assert_eq!(packet.len(), 42);
+/// ```
+///
+/// ## Parameter: `unchecked_setters`
+///
+/// Additionally generates an `unsafe fn set_f_unchecked(new_value)` setter for every field `f`
+/// that skips the bounds checks of the other setters entirely. This is useful for hot paths
+/// where the caller already guarantees that the given values are within bounds, e.g. because
+/// they have been masked upstream.
+///
+/// Passing an out of bounds value to an unchecked setter is undefined behavior.
+/// If debug assertions are enabled the unchecked setters panic on out of bounds values instead.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(unchecked_setters)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let input = 0xFF_u8;
+/// let mut package = Package::new();
+/// // Safety: The value has been masked to fit into 6 bits.
+/// unsafe { package.set_status_unchecked(input & 0b0011_1111) };
+/// assert_eq!(package.status(), 0b0011_1111);
+/// ```
+///
+/// ## Parameter: `copy: bool`
+///
+/// Large bitfields should usually not be `Copy` since copying them implicitly is expensive.
+/// Therefore a `#[bitfield]` struct that derives `Copy` and requires more than 64 bytes
+/// is reported as a deprecation warning, which can be turned into an error via
+/// `#![deny(deprecated)]`.
+///
+/// - `copy = true`: Explicitly allows a larger bitfield to derive `Copy`.
+/// - `copy = false`: Strips `Copy` from the derives of the bitfield.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(copy = false)]
+/// #[derive(Clone, Copy)] // `Copy` is stripped, `Clone` is still derived.
+/// pub struct Table {
+///     a: u128,
+///     b: u128,
+///     c: u128,
+///     d: u128,
+///     checksum: u32,
+/// }
+///
+/// let table = Table::new().with_checksum(42);
+/// let clone = table.clone();
+/// assert_eq!(clone.checksum(), 42);
+/// ```
+///
+/// ## Parameter: `bit_queries`
+///
+/// Additionally generates the following bit queries for every field `f`:
+///
+/// - `f_is_zero()`: Returns `true` if all bits of `f` are zero.
+/// - `f_significant_bits()`: Returns the position of the highest set bit of `f` plus one
+///   or zero if no bit is set.
+///
+/// Both queries operate directly on the underlying bytes and do not materialize the value of
+/// the field which is useful for wide fields, e.g. for variable-length encodings.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bit_queries)]
+/// pub struct Varint {
+///     length: B4,
+///     payload: B100,
+///     #[skip] __: B24,
+/// }
+///
+/// let varint = Varint::new().with_payload(0b1011 << 64);
+/// assert!(varint.length_is_zero());
+/// assert!(!varint.payload_is_zero());
+/// assert_eq!(varint.payload_significant_bits(), 68);
+/// ```
+///
+/// ## Parameter: `bit_order = le | be`
+///
+/// Controls how the fields of the `#[bitfield]` struct are packed into its bytes.
+///
+/// - `bit_order = le`: The first field starts at the least significant bit of the first byte
+///   and multi-byte fields are stored in little-endian byte order.
+/// - `bit_order = be`: The first field starts at the most significant bit of the first byte
+///   and multi-byte fields are stored in big-endian byte order. This is the layout that is
+///   commonly used in the diagrams of network protocol specifications.
+///
+/// With `bit_order = be` the `From` conversions of `#[repr(uN)]` bitfields use big-endian
+/// byte order so that the first field occupies the most significant bits of the primitive.
+/// An explicit `#[repr_endian = ".."]` attribute takes precedence over this default.
+///
+/// The default value is: `le`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bit_order = be)]
+/// pub struct Header {
+///     version: B4,
+///     ihl: B4,
+///     total_length: u16,
+///     #[skip] __: B8,
+/// }
+///
+/// let header = Header::new().with_version(4).with_ihl(5).with_total_length(0x1234);
+/// assert_eq!(header.into_bytes(), [0x45, 0x12, 0x34, 0x00]);
+/// ```
+///
+/// ## Parameter: `order = lsb | msb`
+///
+/// An alias of the `bit_order` parameter for code that names the bit order after the bit
+/// the first field starts at.
+///
+/// - `order = lsb`: The same as `bit_order = le`.
+/// - `order = msb`: The same as `bit_order = be`.
+///
+/// The default value is: `lsb`
+///
+/// It is an error to set both `order` and `bit_order`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(order = msb)]
+/// pub struct Header {
+///     version: B4,
+///     ihl: B4,
+/// }
+///
+/// let header = Header::new().with_version(4).with_ihl(5);
+/// assert_eq!(header.into_bytes(), [0x45]);
+/// ```
+///
+/// ## Parameter: `compat = "packed_struct"`
+///
+/// Replicates the bit layout of another bitfield crate so that identically declared structs
+/// produce the same bytes. This allows to migrate a code base struct by struct without a
+/// verification step that decodes every value with both crates.
+///
+/// - `compat = "packed_struct"`: Matches `#[packed_struct(bit_numbering = "msb0", endian = "msb")]`
+///   of the `packed_struct` crate. Fields are packed starting at the most significant bit of
+///   the first byte and multi-byte fields are stored in big-endian byte order. The bit ranges
+///   of `#[bits(start..end)]` field attributes are numbered the same way as the bit ranges of
+///   `#[packed_field(bits = "start..=end")]`.
+///
+/// This implies `bit_order = be` and therefore conflicts with `bit_order = le`.
+///
+/// ### Example
+///
+/// The following bitfield produces the same bytes as the equivalent `packed_struct` definition
+/// with `bits = "0..=2"`, `bits = "3"`, `bits = "4..=7"` and `bits = "8..=23"` field ranges.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(compat = "packed_struct")]
+/// pub struct Frame {
+///     #[bits(0..=2)]
+///     priority: B3,
+///     #[bits(3..=3)]
+///     urgent: bool,
+///     #[bits(4..=7)]
+///     kind: B4,
+///     #[bits(8..=23)]
+///     length: u16,
+/// }
+///
+/// let frame = Frame::new()
+///     .with_priority(0b101)
+///     .with_urgent(true)
+///     .with_kind(0x3)
+///     .with_length(0x1234);
+/// assert_eq!(frame.into_bytes(), [0b1011_0011, 0x12, 0x34]);
+/// ```
+///
+/// ## Parameter: `doc_bit_numbering = lsb0 | msb0`
+///
+/// Appends a `# Layout` table listing the bit range of every field to the documentation
+/// of the `#[bitfield]` struct. This helps to map the fields to the register diagrams of
+/// vendor datasheets which often number the bits starting from the most significant bit.
+///
+/// - `doc_bit_numbering = lsb0`: Bit 0 is the least significant bit of the bitfield and
+///   ranges are written as `high:low`, e.g. `31:28`.
+/// - `doc_bit_numbering = msb0`: Bit 0 is the most significant bit of the bitfield and
+///   ranges are written as `low:high`, e.g. `0:3`.
+///
+/// The first field occupies the least significant bits of the bitfield for the default
+/// `bit_order = le` and its most significant bits for `bit_order = be`.
+///
+/// Only the generated documentation is affected. The bits are stored the same way in both
+/// cases. If `emit_layout` is set as well the layout file contains the documented bit range
+/// of every field as `doc_bits`.
+///
+/// Every named field with getters or setters additionally gets an associated constant holding
+/// its bit offset that is named after its documented bits, e.g. `FLAGS_BIT_31_28` for a field
+/// `flags` documented at bits `31:28` or `ENABLE_BIT_0` for a single bit field `enable`.
+///
+/// As with `emit_layout` the bit widths of all fields must be known to the `#[bitfield]` macro.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// /// The documentation of `Control` lists `enable` at bit `0` and `prescaler` at bits `1:3`.
+/// #[bitfield(doc_bit_numbering = msb0)]
+/// pub struct Control {
+///     #[skip] __: B4,
+///     prescaler: B3,
+///     enable: bool,
+/// }
+///
+/// let control = Control::new().with_enable(true);
+/// assert_eq!(control.into_bytes(), [0b1000_0000]);
+/// assert_eq!(Control::PRESCALER_BIT_1_3, 4);
+/// assert_eq!(Control::ENABLE_BIT_0, 7);
+/// ```
+///
+/// ## Parameter: `bounds`
+///
+/// Generates the associated `const` functions `f_min()` and `f_max()` for every field `f`
+/// that has a setter. They return the smallest and largest raw value that the field's
+/// `set_f_checked` setter accepts, in the field's `Bytes` type. For tuple structs the
+/// functions are named after the getters, e.g. `get_0_min()` and `get_0_max()`.
+///
+/// This allows validating user input, e.g. in configuration tools, without hard-coding
+/// the field widths a second time.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bounds)]
+/// pub struct Register {
+///     enabled: bool,
+///     level: B12,
+///     #[skip] __: B3,
+/// }
+///
+/// assert_eq!(Register::level_min(), 0);
+/// assert_eq!(Register::level_max(), 0x0FFF);
+/// let mut register = Register::new();
+/// assert!(register.set_level_checked(Register::level_max()).is_ok());
+/// assert!(register.set_level_checked(Register::level_max() + 1).is_err());
+/// ```
+///
+/// ## Parameter: `masks`
+///
+/// Generates the associated `const` function `f_mask_for(value)` for every field `f` with
+/// getters or setters. It returns the bytes of the bitfield in which only the bits of `f`
+/// are set to the given raw value of `f` and all other bits are zero. This is useful to
+/// build the words written to the set and clear companion registers or to write-1-to-clear
+/// registers of microcontrollers without repeating the layout by hand.
+///
+/// The value is given in the field's `Bytes` type, e.g. `u8` for `bool` and `B5` fields or
+/// the discriminant of specifier enums. The function panics, or fails to compile when used
+/// in a constant, if the value is out of bounds for the bit width of `f`. For tuple structs
+/// the functions are named after the getters, e.g. `get_0_mask_for(value)`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(masks)]
+/// pub struct Status {
+///     rx_done: bool,
+///     tx_done: bool,
+///     error: B6,
+/// }
+///
+/// const CLEAR_TX_DONE: [u8; 1] = Status::tx_done_mask_for(1);
+/// assert_eq!(CLEAR_TX_DONE, [0b0000_0010]);
+/// assert_eq!(Status::error_mask_for(0b11_1111), [0b1111_1100]);
+/// ```
+///
+/// ## Parameter: `lints`
+///
+/// Reports suspicious layouts as compile warnings pointing at the offending field:
+///
+/// - A field that spans more bytes than a byte aligned field of the same bit width,
+///   e.g. a `B7` field starting at bit 3.
+/// - An enum field whose bit width exceeds the bit width required by its variants,
+///   e.g. due to a `#[bits = N]` attribute on the enum.
+/// - Padding, i.e. a `#[skip]` or `#[reserved]` field, that directly precedes the last field.
+///
+/// Since proc. macros cannot emit warnings on stable Rust the lints are reported as
+/// deprecation warnings. They can be turned into errors via `#![deny(deprecated)]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(lints)]
+/// pub struct Status {
+///     rx_done: bool,
+///     tx_done: bool,
+///     error: B6,
+///     #[skip]
+///     __: B8,
+/// }
+/// ```
+///
+/// ## Parameter: `no_straddle`
+///
+/// Fails compilation for every field that straddles a byte boundary, which some DMA
+/// engines do not support. A field is accepted if it lies within a single byte or both
+/// starts and ends at a byte boundary, e.g. a `u16` field starting at bit 8. Every element
+/// of a `#[repeat = N]` field is checked on its own.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(no_straddle)]
+/// pub struct Descriptor {
+///     enabled: bool,
+///     channel: B4,
+///     #[skip]
+///     __: B3,
+///     length: u16,
+/// }
+/// ```
+///
+/// Whereas the following fails to compile since `channel` occupies bits 6 to 9:
+///
+/// ```compile_fail
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(no_straddle)]
+/// pub struct Descriptor {
+///     enabled: bool,
+///     mode: B5,
+///     channel: B4,
+///     #[skip]
+///     __: B6,
+/// }
+/// ```
+///
+/// ## Parameter: `fault_injection`
+///
+/// Generates helpers for fault injection tests of code parsing or validating the bitfield,
+/// e.g. to check how a protocol parser copes with corrupted frames. The helpers are only
+/// compiled with `cfg(test)` so that they never end up in production builds.
+///
+/// - `flip_bit(self, index)` returns the bitfield with the bit at the given bit index flipped.
+///   Bit indices follow the `bit_order` of the bitfield and panic if out of bounds.
+/// - `corrupt_f(self, rng)` returns the bitfield with a random non-empty subset of the bits
+///   of the field `f` flipped. The result might be an invalid bit pattern for `f`.
+///   These are generated for every field with getters or setters and require the `rand`
+///   crate feature.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(fault_injection)]
+/// #[derive(Clone, Copy)]
+/// pub struct Frame {
+///     valid: bool,
+///     kind: B7,
+///     len: u8,
+/// }
+///
+/// /// Returns all frames that differ from `frame` in exactly one bit.
+/// #[cfg(test)]
+/// fn corrupted_frames(frame: Frame) -> impl Iterator<Item = Frame> {
+///     (0..16).map(move |index| frame.flip_bit(index))
+/// }
+/// ```
+///
+/// ## Parameter: `neighbor_check_tests`
+///
+/// Generates a `#[cfg(test)]` unit test named `__bf_neighbor_check_{struct}` that checks for
+/// every field with setters that setting it leaves the raw bits of all other fields unchanged.
+/// The test starts from random bytes and sets random valid values drawn from a deterministic
+/// generator seeded by the `LAYOUT_VERSION` of the bitfield, so failures are reproducible
+/// and no property testing crate is required.
+///
+/// This covers every layout automatically, including unaligned and big-endian fields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// // `cargo test` runs `__bf_neighbor_check_header` for this bitfield.
+/// #[bitfield(neighbor_check_tests, bit_order = be)]
+/// pub struct Header {
+///     version: B3,
+///     length: B11,
+///     flags: B2,
+/// }
+/// ```
+///
+/// ## Parameter: `dual_order`
+///
+/// Generates additional diagnostic getters that interpret the same bytes with the opposite
+/// bit order. For the default `bit_order = le` these are `f_msb0()` and `f_msb0_or_err()` for
+/// every field `f` and for `bit_order = be` these are `f_lsb0()` and `f_lsb0_or_err()`.
+///
+/// This is meant as temporary tooling while migrating a layout from a specification with
+/// the other bit numbering, e.g. to find discrepancies in tests against captured traffic.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(dual_order)]
+/// pub struct Header {
+///     version: B4,
+///     ihl: B4,
+/// }
+///
+/// let header = Header::from_bytes([0x45]);
+/// assert_eq!((header.version(), header.ihl()), (5, 4));
+/// assert_eq!((header.version_msb0(), header.ihl_msb0()), (4, 5));
+/// ```
+///
+/// ## Parameter: `dirty_tracking`
+///
+/// Tracks which fields have been modified by the setters of the bitfield, e.g. for register
+/// caches that only write back modified fields. For a `#[bitfield]` struct `S` this generates
+/// the `SFieldSet` type and adds an instance of it next to the bytes of `S`.
+///
+/// - `take_dirty()`: Returns the set of fields modified since the last call and clears it.
+/// - `is_dirty()`: Returns `true` if any field has been modified since the last call to `take_dirty`.
+///
+/// The `SFieldSet` provides a method named after the getter of every field with setters to query
+/// whether the field is contained in the set. Constructors such as `new` or `from_bytes` start
+/// with an empty set. Note that the set is compared by derived `PartialEq` impls and that this
+/// parameter conflicts with the `bytes = N` parameter since the size of the struct increases.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(dirty_tracking)]
+/// pub struct Control {
+///     enable: bool,
+///     mode: B3,
+///     divider: B4,
+/// }
+///
+/// let mut control = Control::new();
+/// assert!(!control.is_dirty());
+/// control.set_mode(5);
+/// control.toggle_enable();
+/// let dirty = control.take_dirty();
+/// assert!(dirty.enable() && dirty.mode() && !dirty.divider());
+/// assert!(!control.is_dirty());
+/// ```
+///
+/// ## Parameter: `c_embed`
+///
+/// Prepares the `#[bitfield]` struct for being embedded within larger `#[repr(C)]` structs,
+/// e.g. when mirroring the structs of a vendor SDK for FFI.
+///
+/// The struct is annotated with `#[repr(C)]` and it is asserted at compile time that it
+/// consists of its bytes only. Additionally the following items are generated:
+///
+/// - `assert_embedded_at(outer_size, offset)`: A `const fn` that panics if the bitfield does
+///   not fit into an outer struct of `outer_size` bytes at the byte `offset`. Used in `const`
+///   items together with `core::mem::offset_of!` or `memoffset::offset_of!` it checks the
+///   layout of the outer struct at compile time.
+/// - `from_outer(&bytes, offset)`: Reads the bitfield from the bytes of an outer struct.
+/// - `write_outer(&mut bytes, offset)`: Writes the bitfield to the bytes of an outer struct.
+///
+/// Both `from_outer` and `write_outer` return `OutOfBounds` if the bitfield does not fit into
+/// the bytes at the given offset. For bitfields with `filled = false` `from_outer` also returns
+/// `OutOfBounds` if undefined bits are set. This parameter conflicts with `dirty_tracking`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use core::mem::{offset_of, size_of};
+///
+/// #[bitfield(c_embed)]
+/// pub struct Status {
+///     ready: bool,
+///     code: B7,
+/// }
+///
+/// #[repr(C)]
+/// pub struct Device {
+///     id: u16,
+///     status: Status,
+///     flags: u8,
+/// }
+///
+/// const _: () = Status::assert_embedded_at(size_of::<Device>(), offset_of!(Device, status));
+///
+/// let bytes = [0x01, 0x00, 0b0000_0101, 0x00];
+/// let status = Status::from_outer(&bytes, offset_of!(Device, status)).unwrap();
+/// assert!(status.ready());
+/// assert_eq!(status.code(), 0b10);
+/// ```
+///
+/// ## Parameter: `transparent`
+///
+/// Annotates the `#[bitfield]` struct with `#[repr(transparent)]` over its underlying byte
+/// array `[u8; N]` where `N` is the number of bytes of the bitfield. It is guaranteed that
+/// the struct has the same size, alignment and ABI as the byte array which is additionally
+/// asserted at compile time. This makes it sound to cast pointers to existing byte buffers
+/// into pointers to the bitfield, e.g. in FFI contexts.
+///
+/// This parameter conflicts with `dirty_tracking` since the set of modified fields would be
+/// part of the struct as well as with `c_embed` which uses `#[repr(C)]` instead.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(transparent)]
+/// pub struct Header {
+///     kind: B4,
+///     len: B12,
+/// }
+///
+/// let buffer = [0x21_u8, 0x03];
+/// // SAFETY: `Header` is `#[repr(transparent)]` over `[u8; 2]`.
+/// let header = unsafe { &*(&buffer as *const [u8; 2] as *const Header) };
+/// assert_eq!(header.kind(), 1);
+/// assert_eq!(header.len(), 0x032);
+/// ```
+///
+/// ## Parameter: `mmio`
+///
+/// Generates a `RegisterRef` type named after the bitfield with a `RegisterRef` suffix that
+/// overlays the bitfield on a memory-mapped hardware register. The register is created from
+/// a pointer by the `unsafe fn new(ptr)` constructor and provides the following methods:
+///
+/// - `read_volatile()`: Reads the register with a single volatile read.
+/// - `write_volatile(value)`: Writes the register with a single volatile write.
+/// - `modify(|r| ..)`: Reads the register, updates the value in place and writes it back.
+///
+/// The parameter requires a `#[repr(uN)]` attribute since the register is always accessed
+/// as a whole through a pointer to `uN`, using the byte order of the `From` conversions.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(mmio)]
+/// #[repr(u32)]
+/// pub struct Control {
+///     enabled: bool,
+///     mode: B3,
+///     divider: B28,
+/// }
+///
+/// # let mut memory = 0_u32;
+/// # let address = &mut memory as *mut u32 as usize;
+/// // SAFETY: `address` is the address of the memory-mapped control register.
+/// let control = unsafe { ControlRegisterRef::new(address as *mut u32) };
+/// control.write_volatile(Control::new().with_divider(1000));
+/// control.modify(|control| control.set_enabled(true));
+/// assert!(control.read_volatile().enabled());
+/// ```
+///
+/// ## Parameter: `ffi_export(prefix = "..")`
+///
+/// Generates `#[no_mangle] extern "C"` functions that allow C code to access the fields of
+/// the bitfield through a pointer to its bytes using the same layout logic as the Rust code.
+/// For every field `f` with the given prefix `p_` we generate:
+///
+/// - `p_get_f(ptr: *const u8)`: Returns the raw bits of `f` without decoding them.
+/// - `p_set_f(ptr: *mut u8, raw)`: Sets the raw bits of `f` without encoding them.
+///   Returns `false` and leaves `f` unchanged if `raw` does not fit into the bit width of `f`.
+///
+/// The raw bits are of the unsigned integer type `<T as Specifier>::Bytes` of the field type `T`,
+/// e.g. `u8` for `bool` fields. The functions are `unsafe` since the caller must guarantee that
+/// the pointer points to the bytes of a valid instance of the bitfield. Functions are not
+/// generated for fields whose getters or setters are skipped.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(ffi_export(prefix = "header_"))]
+/// pub struct Header {
+///     kind: B4,
+///     len: B12,
+/// }
+///
+/// let mut bytes = Header::new().with_kind(1).into_bytes();
+/// // SAFETY: `bytes` are the bytes of a valid `Header`.
+/// unsafe {
+///     assert_eq!(header_get_kind(bytes.as_ptr()), 1);
+///     assert!(header_set_len(bytes.as_mut_ptr(), 0x032));
+///     assert!(!header_set_len(bytes.as_mut_ptr(), 0x1000));
+/// }
+/// assert_eq!(Header::from_bytes(bytes).len(), 0x032);
+/// ```
+///
+/// ## Parameter: `dump`
+///
+/// Requires the `alloc` crate feature.
+///
+/// Generates `dump_to(&mut writer)` that writes an aligned table of all fields to the given
+/// `core::fmt::Write` and `dump()` that returns the table as a `String`. Every field is written
+/// on its own line with its name, its bit range, its raw value in hexadecimal and its decoded
+/// value. This is useful for tooling such as register inspectors.
+///
+/// Fields with skipped getters are not part of the table. Reserved fields are shown with
+/// their raw bits. The decoded values are displayed via their `Debug` implementations
+/// which are therefore required for the types of all fields.
+///
+/// ### Example
+///
+/// For the following bitfield with `enabled = true`, `mode = Mode::Auto` and `divider = 0x123`:
+///
+/// ```ignore
+/// #[bitfield(dump)]
+/// pub struct Control {
+///     enabled: bool,
+///     mode: Mode,
+///     #[reserved]
+///     reserved: B5,
+///     divider: B12,
+///     status: B12,
+/// }
+/// ```
+///
+/// `control.dump()` returns the following table:
+///
+/// ```text
+/// enabled  [0]     0x1   true
+/// mode     [2:1]   0x2   Auto
+/// reserved [7:3]   0x00  0
+/// divider  [19:8]  0x123 291
+/// status   [31:20] 0x000 0
+/// ```
+///
+/// ## Parameter: `bitstring`
+///
+/// Generates `to_bitstring()` that renders the bytes of the bitfield as binary literals in
+/// the order in which they are stored, e.g. `0b1001_1101 0b0000_1110`. With the alternate flag
+/// `{:#}` the boundaries between the fields are marked with `|` instead of separating the
+/// nibbles of every byte with `_`. This helps to compare the bits of a bitfield with logic
+/// analyzer captures or the bit diagrams of a datasheet.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bitstring)]
+/// pub struct Packet {
+///     kind: B3,
+///     len: B5,
+///     flags: B4,
+///     channel: B4,
+/// }
+///
+/// let packet = Packet::new().with_kind(0b101).with_len(0b10011).with_flags(0b1110);
+/// assert_eq!(packet.to_bitstring().to_string(), "0b1001_1101 0b0000_1110");
+/// assert_eq!(format!("{:#}", packet.to_bitstring()), "0b10011|101 | 0b0000|1110");
+/// ```
+///
+/// ## Parameter: `serde = "bytes" | "fields"`
+///
+/// Requires the `serde` crate feature.
+///
+/// Implements `serde::Serialize` and `serde::Deserialize` for the bitfield. The bare `serde`
+/// parameter is the same as `serde = "bytes"` which represents the bitfield by its bytes in
+/// the order of `into_bytes`. Deserialized bytes are checked the same as by `from_bytes`, or by
+/// `from_bytes_checked` if the bitfield has validated fields.
+///
+/// With `serde = "fields"` the bitfield is represented by a struct with the values of all
+/// fields that have both getters and setters, so the types of these fields must implement
+/// `Serialize` and `Deserialize` themselves. Values that do not fit into their field are
+/// rejected when deserializing and all other bits are zero.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[bitfield(serde = "fields")]
+/// pub struct Header {
+///     enabled: bool,
+///     mode: B3,
+///     counter: B12,
+/// }
+///
+/// let header = Header::new().with_enabled(true).with_mode(5).with_counter(291);
+/// assert_eq!(
+///     serde_json::to_string(&header)?,
+///     r#"{"enabled":true,"mode":5,"counter":291}"#,
+/// );
+/// ```
+///
+/// ## Parameter: `iter_fields`
+///
+/// Generates `iter_fields(&self)` returning an iterator over a `modular_bitfield::FieldValue`
+/// for every field as well as an `IntoIterator` impl for references to the bitfield yielding
+/// the same items. Every item holds the name, the bit range and the raw bits of the field
+/// as well as its decoded value as `u128` if it is a `bool` or a non-negative integer.
+/// This is useful for generic telemetry encoders or diff tools.
+///
+/// Fields with skipped getters are not yielded. Reserved fields are yielded with their raw
+/// bits as their decoded value.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(iter_fields)]
+/// pub struct Control {
+///     enabled: bool,
+///     #[reserved]
+///     reserved: B3,
+///     divider: B12,
+/// }
+///
+/// let control = Control::new().with_enabled(true).with_divider(0x123);
+/// for field in &control {
+///     println!("{} {:?} = {:?}", field.name, field.bits, field.value);
+/// }
+/// let values = control.iter_fields().map(|field| field.value).collect::<Vec<_>>();
+/// assert_eq!(values, [Some(1), Some(0), Some(0x123)]);
+/// ```
+///
+/// ## Parameter: `fields_struct`
+///
+/// Generates a plain struct named after the bitfield with a `Fields` suffix that has a
+/// public field for every field of the bitfield with getters, holding its decoded value.
+/// The `fields()` method decodes all of these fields at once which is useful when all
+/// fields are read anyway, e.g. when decoding telemetry.
+///
+/// Like the getters `fields()` panics if any field contains an invalid bit pattern.
+/// Fields with skipped getters and reserved fields are not part of the struct. Field arrays
+/// `[T; N]` and fields with `#[repeat = N]` are held as arrays of the values of their
+/// elements. The parameter requires a struct with named fields.
+///
+/// The fields struct has no derives by default. They are given as `fields_struct(derive(..))`,
+/// e.g. `fields_struct(derive(Debug, Clone, PartialEq))`, which requires the value types of
+/// the fields to implement them.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(fields_struct(derive(Debug, Clone, PartialEq)))]
+/// pub struct Sample {
+///     pub channel: B4,
+///     pub valid: bool,
+///     pub value: B11,
+///     pub gains: [B4; 2],
+/// }
+///
+/// let mut sample = Sample::new().with_channel(3).with_valid(true).with_value(1000);
+/// sample.set_gains(1, 7);
+/// let fields = sample.fields();
+/// assert_eq!(fields.clone(), SampleFields { channel: 3, valid: true, value: 1000, gains: [0, 7] });
+/// let SampleFields { channel, valid, value, gains } = fields;
+/// assert_eq!(channel, 3);
+/// assert!(valid);
+/// assert_eq!(value, 1000);
+/// assert_eq!(gains, [0, 7]);
+/// ```
+///
+/// ## Parameter: `access_stats`
+///
+/// Counts the reads and writes of every field in debug builds to find the fields that are
+/// accessed most often, e.g. when deciding which registers of a driver to cache.
+///
+/// Generates `access_stats()` returning the name, the number of reads and the number of
+/// writes of every field as well as `reset_access_stats()` that sets all of them to zero.
+/// The accesses of all instances of the bitfield are counted together by atomic counters.
+/// Both functions as well as the counting are only generated with `debug_assertions` enabled
+/// so that release builds are not affected. Uses of the functions must therefore be guarded
+/// by `#[cfg(debug_assertions)]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(access_stats)]
+/// pub struct Status {
+///     ready: bool,
+///     code: B7,
+/// }
+///
+/// let mut status = Status::new();
+/// status.set_code(42);
+/// let _ = status.code();
+/// let _ = status.code();
+/// #[cfg(debug_assertions)]
+/// assert_eq!(Status::access_stats(), [("ready", 0, 0), ("code", 2, 1)]);
+/// ```
+///
+/// ## Parameter: `minimal`
+///
+/// Generates only the plain getter `f()` and the plain setter `set_f(new_value)` for every
+/// field `f`. The checked, `with_`, chaining and raw bit variants as well as the extra
+/// methods of `bool` and integer fields are not generated which keeps the generated code
+/// and compile times small for bitfields with many fields.
+///
+/// Methods that are explicitly opted into by other parameters, e.g. `unchecked_setters`
+/// or `bit_queries`, are still generated.
+///
+/// ### Example
+///
+/// ```compile_fail
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(minimal)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// let mut package = Package::new();
+/// package.set_status(0b01_0101);
+/// assert_eq!(package.status(), 0b01_0101);
+/// let package = package.with_is_alive(true); // Error: `with_is_alive` is not generated.
+/// ```
+///
+/// ## Parameter: `keep_phantom_fields`
+///
+/// Keeps a zero-sized `PhantomData<T>` field for every named field of type `T` next to the
+/// underlying bytes of the generated struct. This allows attributes and tools that operate
+/// on the fields of a struct, e.g. derive macros with field attributes, to keep working.
+///
+/// All attributes of a field other than its documentation are moved onto its phantom field
+/// instead of its getters and setters. Fields named `__` do not get a phantom field so that
+/// the name can still be used for multiple `#[skip]` fields. Tuple structs are not supported.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use core::marker::PhantomData;
+/// #[bitfield(keep_phantom_fields)]
+/// pub struct Status {
+///     pub ready: bool,
+///     pub code: B7,
+/// }
+///
+/// let status = Status::new().with_code(42);
+/// let Status { ready, code, .. } = status;
+/// let _: (PhantomData<bool>, PhantomData<B7>) = (ready, code);
+/// assert_eq!(core::mem::size_of::<Status>(), 1);
+/// ```
+///
+/// ## Parameter: `hash`
+///
+/// Generates a `Hash` implementation that only hashes the bits of the defined fields.
+/// The bits of `#[skip]` fields and of fields with skipped getters as well as undefined
+/// trailing bits do not affect the hash. This guarantees that logically equal bitfields
+/// hash equally even if they have been constructed from dirty buffers, e.g. to use them as
+/// keys of hash maps.
+///
+/// The `hash` parameter conflicts with `#[derive(Hash)]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use std::hash::{Hash, Hasher};
+/// # fn hash_of<T: Hash>(value: &T) -> u64 {
+/// #     let mut hasher = std::collections::hash_map::DefaultHasher::new();
+/// #     value.hash(&mut hasher);
+/// #     hasher.finish()
+/// # }
+/// #[bitfield(hash)]
+/// pub struct Flow {
+///     protocol: u8,
+///     #[skip] __: B4,
+///     port: B12,
+/// }
+///
+/// let clean = Flow::new().with_protocol(6).with_port(443);
+/// let dirty = Flow::from_bytes([6, 0b1011_1111, 0b0001_1011]);
+/// assert_eq!(dirty.port(), 443);
+/// assert_eq!(hash_of(&clean), hash_of(&dirty));
+/// ```
+///
+/// ## Parameters: `intercept(..)` and `passthrough(..)`
+///
+/// By default `#[derive(Debug)]`, `#[derive(PartialOrd)]` and `#[derive(Ord)]` are
+/// intercepted by `#[bitfield]` and implemented in terms of the fields of the bitfield
+/// whereas `#[derive(Default)]` and `#[derive(Hash)]` are passed through to the generated
+/// struct and thus operate on its underlying bytes. `#[derive(Default)]` is intercepted
+/// by default as well if any field has a `#[default = EXPR]` attribute.
+///
+/// The `intercept(..)` and `passthrough(..)` parameters override this policy for the
+/// given derives which must be one of `Debug`, `Default`, `Hash`, `PartialOrd` or `Ord`.
+///
+/// - An intercepted `Default` returns the zero initialized bitfield of `new` or the
+///   bitfield of `new_with_defaults` if any field has a `#[default = EXPR]`.
+/// - An intercepted `Hash` behaves like the `hash` parameter.
+/// - A passed through `Debug` shows the underlying bytes.
+///
+/// It is an error to give the same derive to both parameters. Since their orderings must
+/// agree, `PartialOrd` and `Ord` must either both be intercepted or both be passed through.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(intercept(Default, Hash), passthrough(Debug))]
+/// #[derive(Debug, Default, Hash, PartialEq, Eq)]
+/// pub struct Header {
+///     version: B4,
+///     kind: B4,
+/// }
+///
+/// let header = Header::default().with_kind(1);
+/// assert_eq!(header, Header::new().with_kind(1));
+/// assert_eq!(format!("{:?}", header), "Header { bytes: [16] }");
+/// ```
+///
+/// ## Parameter: `cast_to(A, B, ..)`
+///
+/// Generates a `cast::<T>()` method that reinterprets the bytes of the bitfield as one of
+/// the given bitfields without decoding any of its fields. This is useful to view a generic
+/// word as one of multiple mode specific bitfields.
+///
+/// The given bitfields must have the same number of bytes which is checked at compile time.
+/// They must also be filled so that every bit pattern is valid for them. The generated
+/// `SameLayout` implementations also allow to use the conversions in generic code.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(cast_to(Timer))]
+/// pub struct Register {
+///     mode: B2,
+///     data: B14,
+/// }
+///
+/// #[bitfield]
+/// pub struct Timer {
+///     mode: B2,
+///     enabled: bool,
+///     ticks: B13,
+/// }
+///
+/// let register = Register::from_bytes([0b0000_0101, 0b0000_0001]);
+/// let timer: Timer = register.cast();
+/// assert_eq!(timer.mode(), 1);
+/// assert!(timer.enabled());
+/// assert_eq!(timer.ticks(), 32);
+/// ```
+///
+/// ## Parameters: `rename_collisions` and `external_methods = "..."`
+///
+/// Attributes that are not consumed by `#[bitfield]`, e.g. other derive macros, are retained
+/// on the generated struct. If such a retained attribute generates methods with the same names
+/// as the methods generated by `#[bitfield]` a descriptive compile time error is raised.
+///
+/// Collisions are detected for the methods generated by the derive macros of the
+/// `getset`, `derive_more` and `derive-new` crates as well as for all methods listed
+/// in the comma separated `external_methods` parameter.
+///
+/// With `rename_collisions` the colliding methods generated by `#[bitfield]` are renamed
+/// by appending a `_bitfield` suffix instead of raising an error.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(external_methods = "new", rename_collisions)]
+/// pub struct Package {
+///     is_received: bool,
+///     is_alive: bool,
+///     status: B6,
+/// }
+///
+/// impl Package {
+///     pub fn new(status: u8) -> Self {
+///         Self::new_bitfield().with_status(status)
+///     }
+/// }
+///
+/// assert_eq!(Package::new(42).status(), 42);
+/// ```
+///
+/// ## Parameter: `bits = N`
+///
+/// With the `bits: int` parameter it is possible to control the targeted bit width of
+/// a `#[bitfield]` annoated struct. Using `bits = N` guarantees that the resulting bitfield
+/// struct will have a bit width of exactly `N`.
+///
+/// ### Example 1
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 16)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B14,       // 14 bits
+/// }
+/// ```
+///
+/// ### Example 2
+///
+/// The `bits: int` parameter is especially useful when using this in conjunction with
+/// `#[derive(BitfieldSpecifier)] and `filled = false` as shown in the below example.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 5)]
+/// #[derive(BitfieldSpecifier)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B3,        // 3 bits
+/// }
+/// ```
+///
+/// ## Parameter: `auto_pad`
+///
+/// Together with `bits = N` the `auto_pad` parameter lets the macro append the trailing
+/// padding of a bitfield instead of spelling it out. The fields are padded up to `N` bits
+/// with anonymous `#[skip]` fields that neither have getters nor setters. If the fields
+/// already span `N` bits or more nothing is appended and the usual bit width check applies.
+///
+/// Since the padding is computed before type checking, the bit width of every field must be
+/// known up front: `bool`, the primitive integers, `B1` to `B128` and fields annotated with
+/// `#[bits = N]` are supported.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 32, auto_pad)]
+/// pub struct Status {
+///     ready: bool, // 1 bit
+///     code: B7,    // 7 bits
+///     // 24 bits of padding
+/// }
+///
+/// let status = Status::new().with_ready(true).with_code(0x21);
+/// assert_eq!(status.into_bytes(), [0x43, 0x00, 0x00, 0x00]);
+/// ```
+///
+/// ## Parameter: `contiguous`
+///
+/// Ensures that the fields cover the bitfield from bit 0 without gaps. By default the bits
+/// in between `#[bits(start..end)]` fields are padded automatically and `bits = N` together
+/// with `filled = false` leaves the bits after the last field uncovered. This makes it easy
+/// to miss a field that was forgotten while transcribing a datasheet. With `contiguous` any
+/// such gap is a compile error naming the first uncovered bit range while `auto_pad` still
+/// appends the trailing padding up to `bits = N`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 16, auto_pad, contiguous)]
+/// pub struct Status {
+///     #[bits(4..8)]
+///     code: B4,
+///     #[bits(0..4)]
+///     flags: B4,
+///     // 8 bits of trailing padding
+/// }
+///
+/// let status = Status::new().with_code(0xA).with_flags(0x5);
+/// assert_eq!(status.into_bytes(), [0xA5, 0x00]);
+/// ```
+///
+/// ## Field Parameter: `#[bits = N]`
+///
+/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
+/// `N` a user may add `#[bits = N]` to the field in question.
+///
+/// On a mismatch the compile error names the field, the path of its specifier type as well
+/// as the expected and actual number of bits. This is also the case for specifier types
+/// defined in other crates.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # #[bitfield(filled = false)]
+/// # #[derive(BitfieldSpecifier)]
+/// # pub struct Header {
+/// #     is_received: bool, // 1 bit
+/// #     is_alive: bool,    // 1 bit
+/// #     status: B2,        // 2 bits
+/// # }
+/// #[bitfield]
+/// pub struct Base {
+///     #[bits = 4]
+///     header: Header, //  4 bits
+///     content: B28,   // 28 bits
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[bits(start..end)]`
+///
+/// Places a field at the given bit range. The ranges `start..end` and `start..=end` are
+/// supported. This allows to declare the fields in the order of a datasheet, e.g. sorted by
+/// name, while the `#[bitfield]` macro sorts them by their bit ranges internally.
+///
+/// If one field has a bit range all fields must have one. The bit ranges must not overlap.
+/// Bits that are not covered by any bit range, e.g. reserved bits of a datasheet, are padded
+/// with `#[skip]` fields automatically. As with `#[bits = N]` the width of the bit range is
+/// checked against the bit width of the field at compile time.
+/// The generated code such as `Debug` implementations uses the sorted order of the fields.
+/// Bit ranges are not supported for tuple structs.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Control {
+///     #[bits(1..8)]
+///     divider: B7,
+///     #[bits(0..=0)]
+///     enable: bool,
+/// }
+///
+/// let control = Control::new().with_enable(true).with_divider(3);
+/// assert_eq!(control.into_bytes(), [0b0000_0111]);
+///
+/// #[bitfield]
+/// pub struct Status {
+///     #[bits(24..=27)]
+///     flags: B4,
+///     #[bits(4..8)]
+///     code: B4,
+///     // Bits 0..4 and 8..24 are padded.
+///     #[bits(28..32)]
+///     #[skip]
+///     __: B4,
+/// }
+///
+/// let status = Status::new().with_code(0xA).with_flags(0x5);
+/// assert_eq!(status.into_bytes(), [0xA0, 0x00, 0x00, 0x05]);
+/// ```
+///
+/// ## Field Parameter: `#[skip(..)]`
+///
+/// It is possible to skip the entire code generation for getters or setters with the `#[skip]`
+/// field attribute.
+/// This is useful if a field just needs to be read or written exclusively. Skipping both
+/// setters and getters is useful if you want to have undefined blocks within your bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Sparse {
+///     #[skip(getters)]
+///     no_getters: B4,
+///     #[skip(setters)]
+///     no_setters: B4,
+///     #[skip]
+///     skipped_entirely: B4,
+///     #[skip(getters, setters)]
+///     skipped_entirely_2: B2,
+///     #[skip(getters)] #[skip(setters)]
+///     skipped_entirely_2: B2,
+/// }
+/// ```
+///
+/// ### Trick: Wildcards
+///
+/// If you are completely uninterested in a field of a bitfield, for example when specifying
+/// some undefined bits in your bitfield you can use double wildcards as their names:
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Sparse {
+///     #[skip] __: B10,
+///     a: bool,
+///     #[skip] __: B10,
+///     b: bool,
+///     #[skip] __: B10,
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[reserved]`
+///
+/// Reserves a named field for future use. No getters or setters are generated for it,
+/// so it cannot be accessed by accident. Unlike `#[skip]` fields the name of a reserved
+/// field stays visible: the generated `Debug` implementation shows its raw bits.
+/// This documents planned growth of the layout.
+///
+/// A field cannot be both `#[reserved]` and `#[skip]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(Debug)]
+/// pub struct Header {
+///     version: B4,
+///     #[reserved]
+///     reserved_flags: B4,
+/// }
+///
+/// let header = Header::from_bytes([0b1010_0001]);
+/// assert_eq!(
+///     format!("{:?}", header),
+///     "Header { version: 1, reserved_flags: 10 }",
+/// );
+/// ```
+///
+/// ## Field Parameter: `#[validate(..)]`
+///
+/// Declares the value that the bits of a field must have, usually for `#[skip]` or
+/// `#[reserved]` fields whose bits are documented to be constant. If any field is annotated
+/// with `#[validate(..)]` an additional constructor is generated:
+///
+/// - `from_bytes_checked(bytes)`: Similar to `from_bytes` but returns an `InvalidBytes` error
+///   naming the first field whose bits violate its rule. For bitfields with `filled = false`
+///   it also returns an error if undefined bits are set.
+///
+/// The supported rules are `#[validate(zero)]` and `#[validate(ones)]` requiring all bits of
+/// the field to be zero or one respectively as well as `#[validate(any)]` which explicitly
+/// allows any bits. `from_bytes` itself does not validate any field.
+/// A field cannot be both `#[validate(..)]` and `#[repeat = N]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::{error::InvalidBytes, prelude::*};
+/// #[bitfield]
+/// pub struct Status {
+///     ready: bool,
+///     #[skip]
+///     #[validate(zero)]
+///     __: B7,
+/// }
+///
+/// assert!(Status::from_bytes_checked([0b0000_0001]).is_ok());
+/// assert_eq!(
+///     Status::from_bytes_checked([0b0000_0011]).err(),
+///     Some(InvalidBytes::InvalidField("__")),
+/// );
+/// ```
+///
+/// ## Field Parameter: `#[repeat = N]`
+///
+/// Repeats the field `N` times in a row, e.g. for tables of records that share the same layout.
+/// The field occupies `N` times the bits of its type and its plain getters and setters are
+/// replaced by accessors taking the index of the repetition. For a field `f` we generate:
+///
+/// - `f_at(index)`: Returns the value of `f` at the given index.
+/// - `set_f_at(index, new_value)`: Sets the value of `f` at the given index.
+///
+/// Both accessors return `OutOfBounds` if the index is not less than `N`.
+/// They are built on the functions of the `modular_bitfield::access` module that read
+/// and write specifiers at bit offsets computed at runtime.
+///
+/// The generated `Debug`, `PartialOrd`, `Ord` and `Hash` impls as well as `serde = "fields"`
+/// treat the field as the list of its `N` values. `dump` and `iter_fields` show each of
+/// them as a field of its own named `f[index]`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(filled = false)]
+/// #[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+/// pub struct Entry {
+///     valid: bool,
+///     value: B5,
+/// }
+///
+/// #[bitfield]
+/// pub struct Table {
+///     len: B8,
+///     #[repeat = 4]
+///     entries: Entry,
+/// }
+///
+/// let mut table = Table::new();
+/// let entry = Entry::new().with_valid(true).with_value(7);
+/// table.set_entries_at(2, entry).unwrap();
+/// assert_eq!(table.entries_at(2), Ok(entry));
+/// assert!(table.entries_at(4).is_err());
+/// ```
 ///
-/// # Generated API
+/// ## Support: Field Arrays `[T; N]`
 ///
-/// By default this generates the following API:
+/// A field of a `#[bitfield]` struct may be declared as an array of a specifier type with an
+/// integer literal length, e.g. `channels: [B12; 8]`. It is laid out the same as a field of
+/// type `T` annotated with `#[repeat = N]` but its indexed accessors are named like plain
+/// getters and setters. For a field array `f` we generate:
 ///
-/// - **Constructors:**
+/// - `f(index)`: Returns the value of `f` at the given index.
+/// - `f_or_err(index)`: Same as `f(index)` but returns `OutOfBounds` for an invalid index.
+/// - `set_f(index, new_value)`: Sets the value of `f` at the given index.
+/// - `set_f_checked(index, new_value)`: Same as `set_f(index, new_value)` but returns
+///   `OutOfBounds` for an invalid index or an out of bounds value.
 ///
-///     1. `new()`: Initializes all bits to 0 even if 0 bits may be invalid.
-///        Note that invalid bit patterns are supported in that getters and setters will
-///        be protecting accesses.
+/// The `f(index)` and `set_f(index, new_value)` accessors panic in these cases.
+/// Like fields with `#[repeat = N]` field arrays take part in the generated `Debug`,
+/// `PartialOrd`, `Ord` and `Hash` impls, `serde`, `dump` and `iter_fields` with the
+/// values of their elements.
 ///
-/// - **Getters:**
+/// ### Example
 ///
-///     For every field `f` we generate the following getters:
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct DmxFrame {
+///     start_code: B4,
+///     channels: [B12; 8],
+///     #[skip]
+///     __: B4,
+/// }
 ///
-///     1. `f()`: Returns the value of `f` and might panic
-///        if the value contains an invalid bit pattern.
-///     2. `f_or_err()`: Returns the value of `f` or an error
-///        if the value contains an invalid bit pattern.
+/// let mut frame = DmxFrame::new();
+/// frame.set_channels(3, 0xABC);
+/// assert_eq!(frame.channels(3), 0xABC);
+/// assert!(frame.channels_or_err(8).is_err());
+/// assert!(frame.set_channels_checked(0, 0x1000).is_err());
+/// ```
 ///
-/// - **Setters:**
+/// ## Field Parameter: `#[payload_of(tag = "field", map(Variant => Type, ..))]`
 ///
-///     For every field `f` we generate the following setters:
+/// Declares the field to be the payload of the enum field `tag` whose variants select the
+/// type of the payload, e.g. in messages with a type tag followed by type specific data.
+/// For a payload field `f` and every mapped variant `Variant` we generate:
 ///
-///     1. `set_f(new_value)`: Sets the value of `f` to `new_value` and might panic
-///        if `new_value` is out of bounds for the bit width of `f`.
-///     2. `set_f_checked(new_value)`: Sets the value of `f` to `new` or returns an error
-///        if `new_value` if out of bounds for the bit width of `f`.
-///     3. `with_f(new_value)`: Similar to `set_f` but consumes and returns `Self`.
-///        Primarily useful for method chaining.
-///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
-///        Primarily useful for method chaining.
+/// - `f_as_variant()`: Returns the value of `f` decoded as the mapped type or a `WrongVariant`
+///   error if `tag` does not hold `Variant`.
+/// - `set_f_as_variant(new_value)`: Sets `f` to the given value of the mapped type and `tag`
+///   to `Variant`. The bits of `f` that are not covered by the mapped type are cleared.
 ///
-/// - **Conversions:**
+/// The mapped types must not have more bits than the payload field.
+/// A field cannot be both `#[payload_of(..)]` and `#[repeat = N]`.
 ///
-///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
-///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
+/// ### Example
 ///
-/// # Parameters
+/// ```
+/// # use modular_bitfield::{error::WrongVariant, prelude::*};
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// pub enum Kind {
+///     Short = 0,
+///     Long = 1,
+/// }
 ///
-/// The following parameters for the `#[bitfield]` macro are supported:
+/// #[bitfield]
+/// pub struct Message {
+///     kind: Kind,
+///     #[payload_of(tag = "kind", map(Short => B3, Long => B7))]
+///     payload: B7,
+/// }
 ///
-/// ## Parameter: `bytes = N`
+/// let mut message = Message::new();
+/// message.set_payload_as_long(100);
+/// assert_eq!(message.kind(), Kind::Long);
+/// assert_eq!(message.payload_as_long(), Ok(100));
+/// assert_eq!(message.payload_as_short(), Err(WrongVariant));
+/// ```
 ///
-/// This ensures at compilation time that the resulting `#[bitfield]` struct consists of
-/// exactly `N` bytes. Yield a compilation error if this does not hold true.
+/// ## Field Parameter: `#[const_values(NAME = value, ..)]`
+///
+/// Names well-known values of an unsigned integer field such as `B3` or `u8`.
+/// For a field `f` and every named value `NAME` we generate:
+///
+/// - `F_NAME`: An associated constant holding the value.
+/// - `set_f_to_name()`: Sets `f` to the value of `F_NAME`.
+///
+/// The values must fit into the bit width of the field. The constants are of the type that
+/// the accessors of the field operate on and hold the values as returned by the getters,
+/// e.g. including the offset of `#[offset_by = K]` fields. The setters are not generated if
+/// the setters of the field are skipped. A field cannot be both `#[const_values(..)]` and
+/// `#[repeat = N]` or `#[quantity(..)]`.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bytes = 2)]
-/// pub struct SingedInt {
-///     sign: bool, //  1 bit
-///     value: B15, // 15 bits
+/// #[bitfield]
+/// pub struct Clock {
+///     enabled: bool,
+///     #[const_values(OFF = 0, SLOW = 1, FAST = 3)]
+///     div: B2,
+///     #[skip] __: B5,
 /// }
+///
+/// let mut clock = Clock::new();
+/// clock.set_div_to_fast();
+/// assert_eq!(clock.div(), Clock::DIV_FAST);
+/// clock.set_div(Clock::DIV_SLOW);
+/// assert_eq!(clock.div(), 1);
 /// ```
 ///
-/// ## Parameter: `filled: bool`
+/// ## Field Parameter: `#[offset_by = K]`
 ///
-/// If `filled` is `true` ensures that the `#[bitfield]` struct defines all bits and
-/// therefore has a bitwidth that is divisible by 8. If `filled` is `false` ensures the
-/// exact opposite.
+/// Offsets the values of an unsigned integer field such as `B4` by `K`: the setters store
+/// the given value minus `K` and the getters add `K` back. This is useful for fields that
+/// are documented as "value minus one", e.g. lengths that cannot be zero.
 ///
-/// The default value is: `true`
+/// The setters reject values below `K` as well as values that do not fit into the bit width
+/// of the field after subtracting `K`. The largest value of the field plus `K` must fit into
+/// its `InOut` type, e.g. `u8` for `B4`. The raw bit accessors operate on the stored values
+/// and `set_f_truncated`, `set_f_saturating` as well as `or_f`, `and_f` and `xor_f` are not
+/// generated for such fields.
+/// A field cannot be both `#[offset_by = K]` and `#[repeat = N]` or `#[payload_of(..)]`.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(filled = false)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B2,        // 2 bits
+/// #[bitfield]
+/// pub struct Transfer {
+///     /// The number of bytes to transfer minus one.
+///     #[offset_by = 1]
+///     len: B4,
+///     channel: B4,
 /// }
+///
+/// let mut transfer = Transfer::new();
+/// assert_eq!(transfer.len(), 1);
+/// transfer.set_len(16);
+/// assert_eq!(transfer.len(), 16);
+/// assert_eq!(transfer.len_raw(), 15);
+/// assert!(transfer.set_len_checked(0).is_err());
+/// assert!(transfer.set_len_checked(17).is_err());
 /// ```
 ///
-/// ## Parameter: `bits = N`
+/// ## Field Parameter: `#[group = "name"]`
 ///
-/// With the `bits: int` parameter it is possible to control the targeted bit width of
-/// a `#[bitfield]` annoated struct. Using `bits = N` guarantees that the resulting bitfield
-/// struct will have a bit width of exactly `N`.
+/// Groups adjacent `bool` fields such as the flags of interrupt enable or status registers
+/// so that they can be read and written all at once in addition to their own accessors.
 ///
-/// ### Example 1
+/// For a group named `irq` a plain struct `IrqFlags` with a public `bool` field for every
+/// field of the group is generated together with the `irq()` getter returning the values
+/// of the group and the `set_irq(flags)` setter updating them. The flags struct implements
+/// `Debug`, `Default`, `Clone`, `Copy`, `PartialEq`, `Eq` and `Hash`.
+///
+/// The fields of a group must be named `bool` fields with getters and setters that are
+/// declared next to each other. A bitfield may have multiple groups.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bits = 16)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B14,       // 14 bits
+/// #[bitfield]
+/// pub struct InterruptEnable {
+///     #[group = "irq"]
+///     rx: bool,
+///     #[group = "irq"]
+///     tx: bool,
+///     #[group = "irq"]
+///     error: bool,
+///     priority: B5,
 /// }
+///
+/// let mut enable = InterruptEnable::new().with_tx(true);
+/// assert_eq!(enable.irq(), IrqFlags { rx: false, tx: true, error: false });
+/// enable.set_irq(IrqFlags { rx: true, ..IrqFlags::default() });
+/// assert!(enable.rx());
+/// assert!(!enable.tx());
 /// ```
 ///
-/// ### Example 2
+/// ## Field Parameter: `#[quantity(Kind, unit)]`
 ///
-/// The `bits: int` parameter is especially useful when using this in conjunction with
-/// `#[derive(BitfieldSpecifier)] and `filled = false` as shown in the below example.
+/// Requires the `uom` crate feature.
+///
+/// The getters of the field return a [`uom`](https://docs.rs/uom) quantity of the given kind
+/// and its setters accept one. The raw value of the field is the value of the quantity in the
+/// given unit. Quantities are rounded to the nearest raw value when set.
+///
+/// The quantities are stored as `f32` by default. Use `#[quantity(Kind, unit, f64)]`
+/// to use `f64` instead. Quantity kinds and units refer to the `uom::si` module that is
+/// re-exported as `modular_bitfield::uom`. An example can be found in the documentation
+/// of this re-export.
+///
+/// ## Field Parameter: `#[try_from = Type]`
+///
+/// Maps an unsigned integer field such as `B4` or `u8` through the existing conversions of a
+/// domain type instead of requiring a custom `Specifier` impl. For a field `f` of specifier `T`:
+///
+/// - The checked getter `f_or_err()` converts the raw value via `TryFrom<T::InOut>` and
+///   returns its `Result<Type, <Type as TryFrom<T::InOut>>::Error>`.
+/// - The getter `f()` panics if the conversion fails.
+/// - The setters accept `Type` and convert it via `Into<T::InOut>`.
+///
+/// The raw bit accessors still operate on the raw value. A field cannot be both
+/// `#[try_from = Type]` and `#[quantity(..)]`, `#[offset_by = K]`, `#[payload_of(..)]` or
+/// `#[const_values(..)]`.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield(bits = 5)]
-/// #[derive(BitfieldSpecifier)]
-/// pub struct Package {
-///     is_received: bool, // 1 bit
-///     is_alive: bool,    // 1 bit
-///     status: B3,        // 3 bits
+/// use core::num::NonZeroU8;
+///
+/// #[bitfield]
+/// pub struct Transfer {
+///     #[try_from = NonZeroU8]
+///     burst: u8,
 /// }
+///
+/// let mut transfer = Transfer::new();
+/// assert!(transfer.burst_or_err().is_err());
+/// transfer.set_burst(NonZeroU8::new(4).unwrap());
+/// assert_eq!(transfer.burst().get(), 4);
 /// ```
 ///
-/// ## Field Parameter: `#[bits = N]`
+/// ## Field Parameter: `#[default = EXPR]`
 ///
-/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
-/// `N` a user may add `#[bits = N]` to the field in question.
+/// Declares the default value of a field, e.g. the reset value of a hardware register.
+/// If any field has a default value `new_with_defaults()` is generated which returns an
+/// instance with every such field set to its default and all other bits zeroed. The value
+/// is written through the setter of the field and therefore has the type taken by the
+/// setter. It panics if the value is out of bounds.
+///
+/// `#[derive(Default)]` is intercepted for such bitfields and returns `new_with_defaults()`
+/// unless it is given to `passthrough(..)`. Fields without setters cannot have a default.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// # #[bitfield(filled = false)]
-/// # #[derive(BitfieldSpecifier)]
-/// # pub struct Header {
-/// #     is_received: bool, // 1 bit
-/// #     is_alive: bool,    // 1 bit
-/// #     status: B2,        // 2 bits
-/// # }
 /// #[bitfield]
-/// pub struct Base {
-///     #[bits = 4]
-///     header: Header, //  4 bits
-///     content: B28,   // 28 bits
+/// #[derive(Default)]
+/// pub struct Control {
+///     #[default = true]
+///     enabled: bool,
+///     #[skip]
+///     __: B3,
+///     #[default = 0x100]
+///     divider: B12,
 /// }
+///
+/// let control = Control::default();
+/// assert!(control.enabled());
+/// assert_eq!(control.divider(), 0x100);
+/// assert_eq!(Control::new().divider(), 0);
 /// ```
 ///
-/// ## Field Parameter: `#[skip(..)]`
+/// ## Field Type: `Rest`
 ///
-/// It is possible to skip the entire code generation for getters or setters with the `#[skip]`
-/// field attribute.
-/// This is useful if a field just needs to be read or written exclusively. Skipping both
-/// setters and getters is useful if you want to have undefined blocks within your bitfields.
+/// The last field of a `#[bitfield]` struct with named fields may be of the `Rest` pseudo-type
+/// to mark the start of a payload of arbitrary length that follows the bitfield, e.g. in
+/// header-plus-payload formats. The `Rest` field does not occupy any bits and is not part of
+/// the generated struct. Instead, for a `Rest` field `f` we generate:
+///
+/// - `f_offset_bits()`: Returns the offset in bits at which `f` starts, i.e. the sum of the
+///   bit widths of all other fields.
+/// - `f_of(buf)`: Returns the bytes of the record `buf` starting at the byte that contains
+///   the first bit of `f`.
 ///
 /// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
-/// #[bitfield]
-/// pub struct Sparse {
-///     #[skip(getters)]
-///     no_getters: B4,
-///     #[skip(setters)]
-///     no_setters: B4,
-///     #[skip]
-///     skipped_entirely: B4,
-///     #[skip(getters, setters)]
-///     skipped_entirely_2: B2,
-///     #[skip(getters)] #[skip(setters)]
-///     skipped_entirely_2: B2,
+/// #[bitfield(filled = false)]
+/// pub struct Record {
+///     kind: B4,
+///     len: B8,
+///     payload: Rest,
 /// }
+///
+/// let buf = [0x21, 0x03, 0xAA, 0xBB, 0xCC];
+/// let record = Record::from_bytes([buf[0], buf[1]]).unwrap();
+/// assert_eq!(record.len(), 0x32);
+/// assert_eq!(Record::payload_offset_bits(), 12);
+/// assert_eq!(record.payload_of(&buf), &[0x03, 0xAA, 0xBB, 0xCC]);
 /// ```
 ///
-/// ### Trick: Wildcards
+/// ## Field Attributes: `#[cfg(..)]` and `#[cfg_attr(..)]`
 ///
-/// If you are completely uninterested in a field of a bitfield, for example when specifying
-/// some undefined bits in your bitfield you can use double wildcards as their names:
+/// Fields may be annotated with `#[cfg(..)]` and their field parameters may be wrapped in
+/// `#[cfg_attr(..)]` in order to share one struct definition between several layout variants,
+/// e.g. for silicon revisions in which a field was widened.
+///
+/// The struct is expanded once for every combination of the distinct predicates of these
+/// attributes and every expansion is only compiled if its combination holds. At most four
+/// distinct predicates are supported. A predicate `not(p)` is treated as the negation of `p`
+/// while all other predicates are treated as independent of each other.
+///
+/// Every layout variant is validated by the `#[bitfield]` macro even if it is not compiled.
+/// If the bit widths of all fields of a layout variant are known its total bit width is
+/// checked against the `filled`, `bits = N` and `#[repr(uN)]` requirements. Every layout
+/// variant provides its own `BYTES` and `LAYOUT_VERSION` constants as well as a
+/// `LAYOUT_VARIANT` constant holding the predicate of its combination.
+///
+/// The `emit_layout` and `layout_guard` parameters are not supported for layout variants.
+///
+/// ### Example
 ///
 /// ```
 /// # use modular_bitfield::prelude::*;
 /// #[bitfield]
-/// pub struct Sparse {
-///     #[skip] __: B10,
-///     a: bool,
-///     #[skip] __: B10,
-///     b: bool,
-///     #[skip] __: B10,
+/// pub struct Control {
+///     enable: bool,
+///     #[cfg(not(feature = "rev_b"))]
+///     mode: B4,
+///     #[cfg(feature = "rev_b")]
+///     mode: B6,
+///     #[cfg(not(feature = "rev_b"))]
+///     reserved: B3,
+///     #[cfg(feature = "rev_b")]
+///     reserved: B1,
+///     #[cfg_attr(feature = "rev_b", skip(setters))]
+///     status: B8,
 /// }
+///
+/// # #[cfg(not(feature = "rev_b"))]
+/// assert_eq!(Control::LAYOUT_VARIANT, "all(not(feature = \"rev_b\"))");
 /// ```
 ///
 /// # Features
@@ -271,6 +2203,60 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// );
 /// ```
 ///
+/// ## Support: `#[derive(defmt::Format)]`
+///
+/// Requires the `defmt` crate feature.
+///
+/// Like `#[derive(Debug)]` a `#[derive(defmt::Format)]` is intercepted and implemented
+/// field by field instead of logging the underlying bytes. Invalid bit patterns are
+/// logged as such and reserved fields with their raw bits. The types of all fields must
+/// implement `defmt::Format`. Without the crate feature the derive is passed through.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[bitfield]
+/// #[derive(defmt::Format)]
+/// pub struct Package {
+///     is_received: bool,
+///     is_alive: bool,
+///     status: B6,
+/// }
+///
+/// // Logs `Package { is_received: false, is_alive: true, status: 3 }`.
+/// defmt::info!("{}", package);
+/// ```
+///
+/// ## Support: `#[derive(PartialOrd)]` and `#[derive(Ord)]`
+///
+/// If a `#[derive(PartialOrd)]` or `#[derive(Ord)]` is found by the `#[bitfield]` an
+/// implementation is going to be generated that compares bitfields lexicographically by
+/// the decoded values of their fields in the order of their declaration.
+/// Comparing the underlying little-endian byte array instead would yield unexpected
+/// orderings for fields spanning multiple bytes.
+///
+/// Invalid bit patterns are ordered after all valid values of a field. Bitfields whose
+/// fields compare equal, e.g. since they only differ in the bits of skipped fields, are
+/// ordered by their raw bytes so that the ordering agrees with the derived `PartialEq`
+/// and `Eq` impls.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// pub struct Version {
+///     major: u16,
+///     minor: u8,
+///     patch: u8,
+/// }
+///
+/// let v1 = Version::new().with_major(2).with_minor(1);
+/// let v2 = Version::new().with_major(256).with_minor(0);
+/// assert!(v1 < v2);
+/// ```
+///
 /// ## Support: `#[repr(uN)]`
 ///
 /// It is possible to additionally annotate a `#[bitfield]` annotated struct with `#[repr(uN)]`
@@ -297,11 +2283,115 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// assert_eq!(sint.abs_value(), 0b0011_1000);
 /// assert_eq!(u16::from(sint), 0b0111_0001_u16);
 /// ```
+///
+/// ### Byte Order: `#[repr_endian = ".."]`
+///
+/// By default the `From` conversions use little-endian byte order, or big-endian byte order
+/// for `bit_order = be`. An additional `#[repr_endian = "big"]` or `#[repr_endian = "little"]`
+/// attribute overrides this, e.g. to match the register value of a big-endian wire word.
+///
+/// Regardless of the chosen byte order the `from_le` and `from_be` constructors convert the
+/// primitive with an explicit byte order.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[repr(u16)]
+/// #[repr_endian = "big"]
+/// #[derive(Clone, Copy)]
+/// pub struct Word {
+///     low: B8,
+///     high: B8,
+/// }
+///
+/// let word = Word::from(0x1234_u16);
+/// assert_eq!(word.into_bytes(), [0x12, 0x34]);
+/// assert_eq!(u16::from(word), 0x1234);
+/// assert_eq!(Word::from_le(0x1234).into_bytes(), [0x34, 0x12]);
+/// assert_eq!(Word::from_be(0x1234).into_bytes(), [0x12, 0x34]);
+/// ```
+///
+/// ## Support: `#[computed(..)]`
+///
+/// A `#[computed(name = "f", from = "a, b", expr = "..")]` attribute on the struct generates
+/// the read-only getter `f()` whose value is computed by `expr` from the values of the fields
+/// `a` and `b`. This keeps small derived values of a format, e.g. the total length of a
+/// packet, next to its layout instead of computing them at every use site.
+///
+/// The return type is given by `ty = ".."`. It may be omitted if the leftmost operand of
+/// `expr` is an `as T` cast, e.g. for `a as u32 + b as u32`. All fields in `from` must be
+/// named and have getters.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[computed(name = "total_len", from = "hdr_len, payload_len", expr = "hdr_len as u32 + payload_len as u32")]
+/// #[computed(name = "has_payload", from = "payload_len", ty = "bool", expr = "payload_len != 0")]
+/// pub struct Packet {
+///     hdr_len: u8,
+///     payload_len: u16,
+///     #[skip] __: u8,
+/// }
+///
+/// let packet = Packet::new().with_hdr_len(20).with_payload_len(1000);
+/// assert_eq!(packet.total_len(), 1020);
+/// assert!(packet.has_payload());
+/// ```
 #[proc_macro_attribute]
 pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
     bitfield::analyse_and_expand(args.into(), input.into()).into()
 }
 
+/// Defines multiple `#[bitfield]` structs that share common parameters and attributes.
+///
+/// Register files often consist of dozens of bitfield structs with identical parameters.
+/// Instead of repeating them for every struct they can be given once as inner attributes:
+///
+/// - The parameters of an inner `#![bitfield(..)]` attribute apply to all structs.
+///   A struct may have its own `#[bitfield(..)]` attribute with additional parameters.
+///   Its parameters replace the shared parameters of the same name.
+/// - All other inner attributes, e.g. `#![derive(Debug)]` or `#![repr(u32)]`, are added to
+///   the attributes of every struct.
+///
+/// Every struct is expanded exactly as if it were annotated with `#[bitfield(..)]` and the
+/// combined parameters. The structs do not need to be annotated with `#[bitfield]` themselves.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// bitfields! {
+///     #![bitfield(bit_order = be, bounds)]
+///     #![derive(Debug, Clone, Copy)]
+///
+///     /// The control register.
+///     pub struct Control {
+///         enable: bool,
+///         prescaler: B7,
+///     }
+///
+///     /// The status register.
+///     #[bitfield(bit_order = le)]
+///     pub struct Status {
+///         ready: bool,
+///         errors: B7,
+///     }
+/// }
+///
+/// let control = Control::new().with_enable(true);
+/// assert_eq!(control.into_bytes(), [0b1000_0000]);
+/// assert_eq!(Control::prescaler_max(), 127);
+/// let status = Status::new().with_ready(true);
+/// assert_eq!(status.into_bytes(), [0b0000_0001]);
+/// ```
+#[proc_macro]
+pub fn bitfields(input: TokenStream) -> TokenStream {
+    bitfield::analyse_and_expand_all(input.into()).into()
+}
+
 /// Derive macro for Rust `enums` to implement `Specifier` trait.
 ///
 /// This allows such an enum to be used as a field of a `#[bitfield]` struct.
@@ -364,6 +2454,78 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Example: `#[repr(uN)]`
+///
+/// Enums annotated with `#[repr(uN)]` where `uN` is one of `u8`, `u16`, `u32`, `u64` or
+/// `u128` and whose number of variants is not a power of 2 use the bit width of the
+/// primitive unless overridden with `#[bits = N]`. Enums with a power of 2 variants keep
+/// the bit width derived from their number of variants and require `#[bits = N]` to use
+/// a wider bit width.
+/// It is an error to override it with a bit width that exceeds the width of the primitive.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier)]
+/// #[repr(u8)]
+/// pub enum Opcode {
+///     Nop = 0x00,
+///     Load = 0x10,
+///     Store = 0x20,
+/// }
+///
+/// assert_eq!(<Opcode as Specifier>::BITS, 8);
+/// ```
+///
+/// ## Example: Parsing from Strings
+///
+/// Adding `#[from_str]` to the enum makes the derive macro also implement `FromStr` and
+/// `TryFrom<&str>` that parse the names of its variants. Adding `#[case_insensitive]`
+/// next to it makes the parsing ignore ASCII case.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use modular_bitfield::error::UnknownVariant;
+/// # use core::convert::TryFrom;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 3]
+/// #[from_str]
+/// #[case_insensitive]
+/// pub enum Weekday {
+///     Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday
+/// }
+///
+/// assert_eq!("Monday".parse::<Weekday>(), Ok(Weekday::Monday));
+/// assert_eq!(Weekday::try_from("sUNDAY"), Ok(Weekday::Sunday));
+/// assert_eq!("Caturday".parse::<Weekday>(), Err(UnknownVariant));
+/// ```
+///
+/// ## Example: Converting from and to Discriminants
+///
+/// Adding `#[bitfield_specifier(conversions)]` to the enum makes the derive macro also
+/// implement `TryFrom<uN>` for the enum and `From<Enum>` for `uN` where `uN` is the primitive
+/// `Bytes` type of the specifier, e.g. `u8` for up to 8 bits. This allows to reuse the enum
+/// for values that have been parsed elsewhere. Without it these conversions are left to the
+/// user.
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use modular_bitfield::error::InvalidBitPattern;
+/// # use core::convert::TryFrom;
+/// #
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 3]
+/// #[bitfield_specifier(conversions)]
+/// pub enum Weekday {
+///     Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday
+/// }
+///
+/// assert_eq!(Weekday::try_from(6_u8), Ok(Weekday::Sunday));
+/// assert_eq!(Weekday::try_from(7_u8), Err(InvalidBitPattern::new(7)));
+/// assert_eq!(u8::from(Weekday::Tuesday), 1);
+/// ```
+///
 /// ## Example: Use in `#[bitfield]`
 ///
 /// Given the above `Weekday` enum that starts at `Sunday` and uses 3 bits in total
@@ -432,7 +2594,10 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(slot.to(), 15);
 /// assert!(!slot.expired());
 /// ```
-#[proc_macro_derive(BitfieldSpecifier, attributes(bits))]
+#[proc_macro_derive(
+    BitfieldSpecifier,
+    attributes(bits, from_str, case_insensitive, bitfield_specifier)
+)]
 pub fn bitfield_specifier(input: TokenStream) -> TokenStream {
     bitfield_specifier::generate(input.into()).into()
 }
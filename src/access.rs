@@ -0,0 +1,151 @@
+//! Reading and writing specifiers at bit offsets that are only known at runtime.
+//!
+//! The getters and setters generated by `#[bitfield]` operate on bit offsets that are
+//! known at compile time. The functions of this module instead check at runtime that the
+//! accessed bits are within the given bytes and return [`OutOfBounds`] otherwise. This
+//! allows to implement table-driven layouts, e.g. records that are repeated many times
+//! within a larger byte buffer, without manually indexing into the raw bytes.
+//!
+//! The `_be` variants operate on the bit order of `#[bitfield(bit_order = be)]`.
+//!
+//! # Example
+//!
+//! ```
+//! use modular_bitfield::{access, prelude::*};
+//!
+//! let mut bytes = [0x00_u8; 4];
+//! for index in 0..5 {
+//!     access::write_specifier::<B6>(&mut bytes, index * 6, index as u8).unwrap();
+//! }
+//! assert_eq!(access::read_specifier::<B6>(&bytes, 3 * 6), Ok(3));
+//! assert!(access::read_specifier::<B6>(&bytes, 27).is_err());
+//! assert!(access::write_specifier::<B6>(&mut bytes, 0, 0b100_0000).is_err());
+//! ```
+
+use crate::{
+    bitstream::{
+        PopBits,
+        PopBuffer,
+        PushBits,
+        PushBuffer,
+    },
+    error::OutOfBounds,
+    private,
+    Specifier,
+};
+
+/// Returns `Ok` if the bits of `T` at the bit `offset` are within the given bytes.
+#[inline]
+fn check_range<T>(bytes: &[u8], offset: usize) -> Result<(), OutOfBounds>
+where
+    T: Specifier,
+{
+    let end = offset.checked_add(<T as Specifier>::BITS).ok_or(OutOfBounds)?;
+    if <T as Specifier>::BITS == 0 || end > bytes.len().saturating_mul(8) {
+        return Err(OutOfBounds)
+    }
+    Ok(())
+}
+
+/// Returns `Ok` if the given value does not have any bits set beyond the bits of `T`.
+#[inline]
+fn check_value<T>(new_val: <T as Specifier>::Bytes) -> Result<(), OutOfBounds>
+where
+    T: Specifier,
+    <T as Specifier>::Bytes: Default + PartialEq,
+    PopBuffer<T::Bytes>: PopBits,
+{
+    let mut buffer = <PopBuffer<T::Bytes>>::from_bytes(new_val);
+    let mut remaining = <T as Specifier>::BITS;
+    while remaining > 0 {
+        let amount = core::cmp::min(remaining, 8);
+        buffer.pop_bits(amount as u32);
+        remaining -= amount;
+    }
+    if buffer.into_bytes() != <T::Bytes as Default>::default() {
+        return Err(OutOfBounds)
+    }
+    Ok(())
+}
+
+/// Reads the raw bits of `T` at the bit `offset` of the given bytes.
+///
+/// # Errors
+///
+/// If the bits of `T` at the given `offset` are not within the given bytes.
+#[inline]
+pub fn read_specifier<T>(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<<T as Specifier>::Bytes, OutOfBounds>
+where
+    T: Specifier,
+    PushBuffer<T::Bytes>: Default + PushBits,
+{
+    check_range::<T>(bytes, offset)?;
+    Ok(private::read_specifier::<T>(bytes, offset))
+}
+
+/// Reads the raw bits of `T` at the bit `offset` of the given bytes with `be` bit order.
+///
+/// # Errors
+///
+/// If the bits of `T` at the given `offset` are not within the given bytes.
+#[inline]
+pub fn read_specifier_be<T>(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<<T as Specifier>::Bytes, OutOfBounds>
+where
+    T: Specifier,
+    PushBuffer<T::Bytes>: Default + PushBits,
+{
+    check_range::<T>(bytes, offset)?;
+    Ok(private::read_specifier_be::<T>(bytes, offset))
+}
+
+/// Writes the raw bits of `T` at the bit `offset` of the given bytes.
+///
+/// # Errors
+///
+/// - If the bits of `T` at the given `offset` are not within the given bytes.
+/// - If the given value has bits set beyond the bits of `T`.
+#[inline]
+pub fn write_specifier<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    new_val: <T as Specifier>::Bytes,
+) -> Result<(), OutOfBounds>
+where
+    T: Specifier,
+    <T as Specifier>::Bytes: Copy + Default + PartialEq,
+    PopBuffer<T::Bytes>: PopBits,
+{
+    check_range::<T>(bytes, offset)?;
+    check_value::<T>(new_val)?;
+    private::write_specifier::<T>(bytes, offset, new_val);
+    Ok(())
+}
+
+/// Writes the raw bits of `T` at the bit `offset` of the given bytes with `be` bit order.
+///
+/// # Errors
+///
+/// - If the bits of `T` at the given `offset` are not within the given bytes.
+/// - If the given value has bits set beyond the bits of `T`.
+#[inline]
+pub fn write_specifier_be<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    new_val: <T as Specifier>::Bytes,
+) -> Result<(), OutOfBounds>
+where
+    T: Specifier,
+    <T as Specifier>::Bytes: Copy + Default + PartialEq,
+    PopBuffer<T::Bytes>: PopBits,
+{
+    check_range::<T>(bytes, offset)?;
+    check_value::<T>(new_val)?;
+    private::write_specifier_be::<T>(bytes, offset, new_val);
+    Ok(())
+}
@@ -0,0 +1,166 @@
+//! Incremental bit packing on top of unsigned integer primitives.
+//!
+//! These are the buffers that the generated getters and setters use to assemble and
+//! split field values byte by byte. They can also be used to pack variable-length
+//! records that are adjacent to fixed `#[bitfield]` headers.
+//!
+//! Both buffers operate on one of the primitives `u8`, `u16`, `u32`, `u64` or `u128`
+//! and process at most 8 bits per call:
+//!
+//! - [`PushBuffer`] shifts its contents towards the most significant bit before
+//!   inserting the pushed bits at the least significant end. The bits pushed first
+//!   therefore end up as the most significant bits.
+//! - [`PopBuffer`] removes bits from the least significant end. The bits popped first
+//!   are therefore the least significant bits.
+//!
+//! # Example
+//!
+//! ```
+//! use modular_bitfield::bitstream::{PopBits, PopBuffer, PushBits, PushBuffer};
+//!
+//! let mut buffer = <PushBuffer<u16>>::new();
+//! buffer.push_bits(4, 0b1010);
+//! buffer.push_bits(8, 0b1100_0011);
+//! assert_eq!(buffer.into_bytes(), 0b1010_1100_0011);
+//!
+//! let mut buffer = PopBuffer::from_bytes(0b1010_1100_0011_u16);
+//! assert_eq!(buffer.pop_bits(8), 0b1100_0011);
+//! assert_eq!(buffer.pop_bits(4), 0b1010);
+//! assert_eq!(buffer.into_bytes(), 0);
+//! ```
+
+use crate::private::checks::private::Sealed;
+
+/// Types that allow to push up to 8 bits at a time onto them.
+///
+/// # Note
+///
+/// Must not and cannot be implemented by dependencies.
+pub trait PushBits: Sealed {
+    /// Shifts the buffer by `amount` bits towards its most significant bit and inserts
+    /// the `amount` least significant bits of `bits` at its least significant end.
+    ///
+    /// Bits that are shifted beyond the most significant bit of the buffer are lost.
+    /// The `amount` must be within `1..=8` which is checked in debug builds.
+    fn push_bits(&mut self, amount: u32, bits: u8);
+}
+
+/// Types that allow to pop up to 8 bits at a time from them.
+///
+/// # Note
+///
+/// Must not and cannot be implemented by dependencies.
+pub trait PopBits: Sealed {
+    /// Removes and returns the `amount` least significant bits of the buffer.
+    ///
+    /// The remaining bits are shifted towards the least significant bit and the buffer
+    /// is filled up with zeros. The `amount` must be within `1..=8` which is checked in
+    /// debug builds.
+    fn pop_bits(&mut self, amount: u32) -> u8;
+}
+
+/// A bit buffer that allows to pop bits from it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PopBuffer<T> {
+    bytes: T,
+}
+
+impl<T> PopBuffer<T> {
+    /// Creates a new pop buffer from the given bytes.
+    #[inline]
+    pub fn from_bytes(bytes: T) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the bits of the pop buffer that have not yet been popped.
+    #[inline]
+    pub fn into_bytes(self) -> T {
+        self.bytes
+    }
+}
+
+impl Sealed for PopBuffer<u8> {}
+
+impl PopBits for PopBuffer<u8> {
+    #[inline]
+    fn pop_bits(&mut self, amount: u32) -> u8 {
+        let Self { bytes } = self;
+        let orig_ones = bytes.count_ones();
+        debug_assert!((1..=8).contains(&amount));
+        let res = *bytes & ((0x01_u16.wrapping_shl(amount)).wrapping_sub(1) as u8);
+        *bytes = bytes.checked_shr(amount).unwrap_or(0);
+        debug_assert_eq!(res.count_ones() + bytes.count_ones(), orig_ones);
+        res
+    }
+}
+
+macro_rules! impl_pop_bits {
+    ( $($type:ty),+ ) => {
+        $(
+            impl Sealed for PopBuffer<$type> {}
+
+            impl PopBits for PopBuffer<$type> {
+                #[inline]
+                fn pop_bits(&mut self, amount: u32) -> u8 {
+                    let Self { bytes } = self;
+                    let orig_ones = bytes.count_ones();
+                    debug_assert!((1..=8).contains(&amount));
+                    let bitmask = 0xFF >> (8 - amount);
+                    let res = (*bytes & bitmask) as u8;
+                    *bytes = bytes.checked_shr(amount).unwrap_or(0);
+                    debug_assert_eq!(res.count_ones() + bytes.count_ones(), orig_ones);
+                    res
+                }
+            }
+        )+
+    };
+}
+impl_pop_bits!(u16, u32, u64, u128);
+
+/// A bit buffer that allows to push bits onto it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PushBuffer<T> {
+    bytes: T,
+}
+
+impl<T> PushBuffer<T> {
+    /// Returns the underlying bytes of the push buffer.
+    #[inline]
+    pub fn into_bytes(self) -> T {
+        self.bytes
+    }
+}
+
+macro_rules! impl_push_bits {
+    ( $($type:ty),+ ) => {
+        $(
+            impl PushBuffer<$type> {
+                /// Creates a new push buffer with all bits initialized to 0.
+                #[inline]
+                pub const fn new() -> Self {
+                    Self { bytes: 0 }
+                }
+            }
+
+            impl Sealed for PushBuffer<$type> {}
+
+            impl Default for PushBuffer<$type> {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl PushBits for PushBuffer<$type> {
+                #[inline]
+                fn push_bits(&mut self, amount: u32, bits: u8) {
+                    let Self { bytes } = self;
+                    debug_assert!((1..=8).contains(&amount));
+                    let bitmask = 0xFF >> (8 - amount as u8);
+                    *bytes = bytes.checked_shl(amount).unwrap_or(0) | ((bits & bitmask) as $type);
+                }
+            }
+        )+
+    }
+}
+impl_push_bits!(u8, u16, u32, u64, u128);
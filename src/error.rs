@@ -12,8 +12,84 @@ impl core::fmt::Display for OutOfBounds {
     }
 }
 
-/// The bitfield contained an invalid bit pattern.
+/// The bytes given to `from_bytes` of a bitfield with `filled = false` had undefined bits set.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PaddingBitsSet {
+    /// The index of the byte containing the undefined bits that are set.
+    pub byte_index: usize,
+    /// The undefined bits of the byte that are set.
+    pub mask: u8,
+}
+
+impl core::fmt::Display for PaddingBitsSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "encountered set padding bits {:#010b} in byte {}",
+            self.mask, self.byte_index
+        )
+    }
+}
+
+impl From<PaddingBitsSet> for OutOfBounds {
+    #[inline]
+    fn from(_: PaddingBitsSet) -> Self {
+        OutOfBounds
+    }
+}
+
+/// The bytes given to `from_bytes_checked` of a bitfield are invalid.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InvalidBytes {
+    /// The bytes have bits set at positions that are undefined for a bitfield with `filled = false`.
+    PaddingBitsSet(PaddingBitsSet),
+    /// The bits of the field with the given name violate its `#[validate(..)]` rule.
+    InvalidField(&'static str),
+}
+
+impl core::fmt::Display for InvalidBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::PaddingBitsSet(error) => core::fmt::Display::fmt(error, f),
+            Self::InvalidField(field) => {
+                write!(f, "encountered invalid bits for validated field {}", field)
+            }
+        }
+    }
+}
+
+impl From<PaddingBitsSet> for InvalidBytes {
+    #[inline]
+    fn from(error: PaddingBitsSet) -> Self {
+        Self::PaddingBitsSet(error)
+    }
+}
+
+/// The tag field of a bitfield does not select the requested type of its payload field.
+///
+/// Returned by the accessors generated for fields annotated with `#[payload_of(..)]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct WrongVariant;
+
+impl core::fmt::Display for WrongVariant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "encountered a tag that does not select the requested payload type")
+    }
+}
+
+/// The given name did not match any variant of the specifier.
 #[derive(Debug, PartialEq, Eq)]
+pub struct UnknownVariant;
+
+impl core::fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "encountered an unknown variant name")
+    }
+}
+
+/// The bitfield contained an invalid bit pattern.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct InvalidBitPattern<Bytes> {
     pub invalid_bytes: Bytes,
 }
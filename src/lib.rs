@@ -61,6 +61,20 @@
 //! Any type that implements the `Specifier` trait can be used as a bitfield field.
 //! Besides the already mentioned `B1`, .. `B128` also the `bool`, `u8`, `u16`, `u32`,
 //! `u64` or `u128` primitive types can be used from prelude.
+//! Active-low signals can be declared as `ActiveLow<bool>` fields whose accessors
+//! operate on the logical value while the inverted value is stored and blocks of
+//! `N` independent flag bits can be declared as `Flags<N>` fields.
+//! Signed integers in two's complement can be declared as `S1`, .. `S128` fields whose
+//! getters sign extend to `i8`, `i16`, `i32`, `i64` or `i128` and whose checked setters
+//! reject values that are out of range for the bit width.
+//! Other signed encodings can be declared as `TwosComplement<B12>`, `SignMagnitude<B12>`
+//! or `ExcessK<B8, 127>` fields whose accessors operate on signed integers.
+//! Optional values can be declared as `Option<T>` fields for specifiers `T` of up to 64 bits.
+//! They span one more bit than `T` which is set for `Some` values and their accessors
+//! operate on `Option<T::InOut>`.
+//! Values that must not be zero can be declared as `NonZeroB1`, .. `NonZeroB64` fields whose
+//! accessors operate on `NonZeroU8`, `NonZeroU16`, `NonZeroU32` or `NonZeroU64`.
+//! As `Option<NonZeroB7>` they occupy no additional bit since `None` is stored as zero.
 //!
 //! We can use this knowledge to encode our `is_alive` as `bool` type instead of `B1`:
 //!
@@ -211,7 +225,9 @@
 //!
 //! In the above example `SomeBitsUndefined` only defines the first 5 bits and leaves the rest
 //! 3 bits of its entire 8 bits undefined. The consequences are that its generated `from_bytes`
-//! method is fallible since it must guard against those undefined bits.
+//! method is fallible since it must guard against those undefined bits. Its error is a
+//! [`PaddingBitsSet`](crate::error::PaddingBitsSet) naming the byte and the undefined bits
+//! of it that are set.
 //!
 //! #### Example: Recursive Bitfields
 //!
@@ -355,6 +371,7 @@
 //! | `fn new() -> Self` | Creates a new instance of the bitfield with all bits initialized to 0. |
 //! | `fn from_bytes([u8; 1]) -> Self` | Creates a new instance of the bitfield from the given raw bytes. |
 //! | `fn into_bytes(self) -> [u8; 1]` | Returns the underlying bytes of the bitfield. |
+//! | `fn is_zeroed(&self) -> bool` | Returns `true` if all defined bits of the bitfield are zero. |
 //!
 //! And below the generated signatures for field `a`:
 //!
@@ -366,6 +383,10 @@
 //! | `fn set_a_checked(&mut self, new_value: bool) -> Result<(), OutOfBounds>` | Sets `a` to the new value of returns an out of bounds error. |
 //! | `fn with_a(self, new_value: bool) -> Self` | Similar to `set_a` but useful for method chaining. |
 //! | `fn with_a_checked(self, new_value: bool) -> Result<Self, OutOfBounds>` | Similar to `set_a_checked` but useful for method chaining. |
+//! | `fn set_a_chain(&mut self, new_value: bool) -> &mut Self` | Similar to `set_a` but useful for method chaining without moving. |
+//! | `fn toggle_a(&mut self)` | Flips the value of `a`. Only generated for `bool` fields. |
+//! | `fn set_a_if(&mut self, condition: bool)` | Sets `a` to `true` if `condition` is `true`. Only generated for `bool` fields. |
+//! | `fn a_then<R>(&self, f: impl FnOnce() -> R) -> Option<R>` | Returns `Some(f())` if `a` is `true`. Only generated for `bool` fields. |
 //!
 //! ## Generated Structure
 //!
@@ -415,11 +436,19 @@
 #![no_std]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 extern crate static_assertions;
 
+pub mod access;
+pub mod bitstream;
 pub mod error;
 #[doc(hidden)]
 pub mod private;
+#[cfg(feature = "registry")]
+pub mod registry;
+pub mod support;
 
 use self::error::{
     InvalidBitPattern,
@@ -427,15 +456,114 @@ use self::error::{
 };
 pub use modular_bitfield_impl::{
     bitfield,
+    bitfields,
     BitfieldSpecifier,
 };
 
+/// The `uom` crate used by `#[quantity(..)]` fields.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::uom::si::{
+///     electric_potential::{millivolt, volt},
+///     f32::ElectricPotential,
+/// };
+///
+/// #[bitfield]
+/// pub struct Sensor {
+///     #[quantity(ElectricPotential, millivolt)]
+///     voltage: B12,
+///     status: B4,
+/// }
+///
+/// let sensor = Sensor::new().with_voltage(ElectricPotential::new::<volt>(3.3));
+/// assert_eq!(sensor.voltage().get::<millivolt>(), 3300.0);
+/// assert_eq!(sensor.into_bytes(), [0xE4, 0x0C]);
+/// ```
+#[cfg(feature = "uom")]
+pub use uom;
+
+/// The `rand` crate used for generating random bitfields.
+///
+/// With the `rand` crate feature every `#[bitfield]` struct implements
+/// `Distribution<T>` for the `Standard` distribution.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::rand::{rngs::mock::StepRng, Rng};
+///
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 2]
+/// pub enum Mode {
+///     Off = 0,
+///     On = 1,
+///     Auto = 2,
+/// }
+///
+/// #[bitfield]
+/// pub struct Register {
+///     mode: Mode,
+///     level: B6,
+/// }
+///
+/// let mut rng = StepRng::new(0x0123_4567_89AB_CDEF, 0x9E37_79B9_7F4A_7C15);
+/// for _ in 0..100 {
+///     let register: Register = rng.gen();
+///     assert!(register.mode_or_err().is_ok());
+/// }
+/// ```
+#[cfg(feature = "rand")]
+pub use rand;
+
+/// The `bitvec` crate used for viewing bitfields as bit slices.
+///
+/// With the `bitvec` crate feature every `#[bitfield]` struct additionally provides:
+///
+/// - `as_bitslice()`: Returns a view of its bits as `&BitSlice<u8, O>`.
+/// - `as_mut_bitslice()`: Returns a mutable view of its bits as `&mut BitSlice<u8, O>`.
+/// - `from_bitslice(bits)`: Creates the bitfield from a bit slice of matching length.
+///
+/// The bit order `O` is `Lsb0` for bitfields with `bit_order = le` and `Msb0` for
+/// bitfields with `bit_order = be`. The bit slices do not include the undefined
+/// bits of bitfields with `filled = false`.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::bitvec::prelude::*;
+///
+/// #[bitfield]
+/// pub struct Register {
+///     enabled: bool,
+///     level: B7,
+/// }
+///
+/// let mut register = Register::new().with_level(0b101);
+/// assert_eq!(register.as_bitslice().count_ones(), 2);
+/// register.as_mut_bitslice().set(0, true);
+/// assert!(register.enabled());
+///
+/// let bits = bits![u8, Lsb0; 0, 1, 1, 0, 0, 0, 0, 0];
+/// let register = Register::from_bitslice(bits).unwrap();
+/// assert_eq!(register.level(), 0b11);
+/// assert!(Register::from_bitslice(&bits[..7]).is_err());
+/// ```
+#[cfg(feature = "bitvec")]
+pub use bitvec;
+
 /// The prelude: `use modular_bitfield::prelude::*;`
 pub mod prelude {
     pub use super::{
         bitfield,
+        bitfields,
         specifiers::*,
         BitfieldSpecifier,
+        SameLayout,
         Specifier,
     };
 }
@@ -448,12 +576,25 @@ pub mod prelude {
 /// # Note
 ///
 /// These can be all unsigned fixed-size primitives,
-/// represented by `B1, B2, ... B64` and enums that
+/// represented by `B1, B2, ... B64`, the signed integers
+/// represented by `S1, S2, ... S64` and enums that
 /// derive from `BitfieldSpecifier`.
 pub trait Specifier {
     /// The amount of bits used by the specifier.
     const BITS: usize;
 
+    /// The amount of bits required to represent all valid values of the specifier.
+    ///
+    /// This is used by the `lints` parameter of `#[bitfield]` to detect oversized enums.
+    #[doc(hidden)]
+    const REQUIRED_BITS: usize = Self::BITS;
+
+    /// Whether the all-zero bit pattern is not a valid value of the specifier.
+    ///
+    /// If so `Option<Self>` encodes `None` as all zeros instead of using a validity bit.
+    #[doc(hidden)]
+    const HAS_ZERO_NICHE: bool = false;
+
     /// The base type of the specifier.
     ///
     /// # Note
@@ -490,7 +631,612 @@ pub trait Specifier {
     ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>>;
 }
 
+/// Trait implemented by bitfields that share their byte layout with the bitfield `T`.
+///
+/// Should generally not be implemented directly by users but through the
+/// `#[bitfield(cast_to(Other))]` parameter on `T`. This generates the `cast` method
+/// on `T` that reinterprets its bytes as `Other` without any conversion.
+pub trait SameLayout<T>: Sized {
+    /// Reinterprets the bytes of the given bitfield as `Self`.
+    fn from_same_layout(from: T) -> Self;
+}
+
+/// The value of a single field as yielded by the `iter_fields` method generated by
+/// the `#[bitfield(iter_fields)]` parameter.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// use modular_bitfield::FieldValue;
+///
+/// #[bitfield(iter_fields)]
+/// pub struct Status {
+///     ready: bool,
+///     #[offset_by = 1]
+///     level: B7,
+/// }
+///
+/// let status = Status::new().with_ready(true).with_level(10);
+/// let mut fields = status.iter_fields();
+/// assert_eq!(
+///     fields.next(),
+///     Some(FieldValue { name: "ready", bits: 0..1, raw: 1, value: Some(1) }),
+/// );
+/// assert_eq!(
+///     fields.next(),
+///     Some(FieldValue { name: "level", bits: 1..8, raw: 9, value: Some(10) }),
+/// );
+/// assert_eq!(fields.next(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldValue {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The bit range of the field within the bitfield.
+    pub bits: core::ops::Range<usize>,
+    /// The raw bits of the field.
+    pub raw: u128,
+    /// The decoded value of the field as `u128`.
+    ///
+    /// This is `None` if the raw bits are an invalid bit pattern of the field or if the
+    /// decoded value is not a `bool` or a non-negative integer, e.g. of specifier enums.
+    pub value: Option<u128>,
+}
+
+/// Modifies the fields of a memory mapped `#[bitfield]` register with a single volatile
+/// read and a single volatile write.
+///
+/// `modify_fields!(ptr, Register { a: x, b: y })` reads the register behind the raw
+/// pointer `ptr: *mut Register`, sets its fields `a` and `b` to the given values as
+/// `with_a(x).with_b(y)` would and writes the register back. Fields that are not
+/// mentioned keep the values that have been read.
+///
+/// The field values are evaluated in order between the volatile read and write.
+///
+/// # Safety
+///
+/// The macro must be invoked within an `unsafe` block since it dereferences `ptr` which
+/// must be valid for volatile reads and writes of the register as described by
+/// [`core::ptr::read_volatile`] and [`core::ptr::write_volatile`].
+///
+/// # Panics
+///
+/// If any of the values is out of bounds for its field.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::{modify_fields, prelude::*};
+/// #[bitfield]
+/// pub struct CtrlReg {
+///     en: bool,
+///     div: B3,
+///     mode: B4,
+/// }
+///
+/// let mut mmio = CtrlReg::new().with_mode(0b1010);
+/// let reg = &mut mmio as *mut CtrlReg;
+/// unsafe {
+///     modify_fields!(reg, CtrlReg { en: true, div: 4 });
+/// }
+/// assert!(mmio.en());
+/// assert_eq!(mmio.div(), 4);
+/// assert_eq!(mmio.mode(), 0b1010);
+/// ```
+///
+/// The following fails to compile since the macro is not invoked within an `unsafe` block:
+///
+/// ```compile_fail
+/// # use modular_bitfield::{modify_fields, prelude::*};
+/// # #[bitfield]
+/// # pub struct CtrlReg {
+/// #     en: bool,
+/// #     div: B7,
+/// # }
+/// #
+/// let mut reg = CtrlReg::new();
+/// modify_fields!(&mut reg, CtrlReg { en: true });
+/// ```
+#[macro_export]
+macro_rules! modify_fields {
+    ( $reg:expr, $bitfield:path { $( $field:ident : $value:expr ),* $(,)? } ) => {{
+        let __bf_reg: *mut $bitfield = $reg;
+        let __bf_fields = <$bitfield as $crate::private::ModifyFields>::into_fields(
+            ::core::ptr::read_volatile(__bf_reg),
+        );
+        $(
+            let __bf_fields = __bf_fields.$field($value);
+        )*
+        ::core::ptr::write_volatile(
+            __bf_reg,
+            <$bitfield as $crate::private::ModifyFields>::from_fields(__bf_fields),
+        );
+    }};
+}
+
+/// Asserts at compile time that the total size of the given `#[bitfield]` structs does not
+/// exceed the given number of bytes.
+///
+/// `assert_total_bytes!(budget, A, B, ..)` sums up the `BYTES` constants of the bitfields
+/// `A`, `B`, .. and fails to compile if the sum is greater than `budget`. This keeps the
+/// bookkeeping of fixed memory regions, e.g. an EEPROM page, next to the definitions of
+/// the bitfields that are stored in them.
+///
+/// # Example
+///
+/// ```
+/// # use modular_bitfield::{assert_total_bytes, prelude::*};
+/// #[bitfield]
+/// pub struct Settings {
+///     volume: B4,
+///     brightness: B4,
+/// }
+///
+/// #[bitfield]
+/// pub struct Calibration {
+///     offset: u16,
+///     gain: u16,
+/// }
+///
+/// assert_total_bytes!(8, Settings, Calibration);
+/// ```
+///
+/// The following fails to compile since the bitfields need 5 bytes:
+///
+/// ```compile_fail
+/// # use modular_bitfield::{assert_total_bytes, prelude::*};
+/// # #[bitfield]
+/// # pub struct Settings {
+/// #     volume: B4,
+/// #     brightness: B4,
+/// # }
+/// #
+/// # #[bitfield]
+/// # pub struct Calibration {
+/// #     offset: u16,
+/// #     gain: u16,
+/// # }
+/// assert_total_bytes!(4, Settings, Calibration);
+/// ```
+#[macro_export]
+macro_rules! assert_total_bytes {
+    ( $budget:expr, $( $bitfield:ty ),+ $(,)? ) => {
+        const _: () = ::core::assert!(
+            0usize $( + <$bitfield>::BYTES )+ <= $budget,
+            ::core::concat!(
+                "the total size of ",
+                ::core::stringify!($( $bitfield ),+),
+                " exceeds the budget of ",
+                ::core::stringify!($budget),
+                " bytes",
+            ),
+        );
+    };
+}
+
 /// The default set of predefined specifiers.
 pub mod specifiers {
     ::modular_bitfield_impl::define_specifiers!();
+
+    /// Specifier for an active-low signal that is stored inverted.
+    ///
+    /// The getters of an `ActiveLow<bool>` field return the logical value of the signal,
+    /// i.e. `true` if the stored bit is `0`, and the setters invert the logical value on write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Control {
+    ///     /// The `ENABLE#` pin: the device is enabled if the stored bit is `0`.
+    ///     enable: ActiveLow<bool>,
+    ///     #[skip] __: B7,
+    /// }
+    ///
+    /// let control = Control::new().with_enable(true);
+    /// assert_eq!(control.into_bytes(), [0b0000_0000]);
+    /// let control = Control::from_bytes([0b0000_0001]);
+    /// assert!(!control.enable());
+    /// ```
+    pub struct ActiveLow<T>(::core::marker::PhantomData<T>);
+
+    impl crate::Specifier for ActiveLow<bool> {
+        const BITS: usize = 1;
+        type Bytes = u8;
+        type InOut = bool;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            Ok(!input as u8)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            match bytes {
+                0 => Ok(true),
+                1 => Ok(false),
+                invalid_bytes => Err(crate::InvalidBitPattern { invalid_bytes }),
+            }
+        }
+    }
+
+    /// Specifier for an ASCII character that is stored in `N` bits with `N` being `7` or `8`.
+    ///
+    /// The getters and setters of an `AsciiChar<N>` field operate on `u8` values that are
+    /// restricted to the ASCII range `0x00..=0x7F`. The setters reject non-ASCII values as
+    /// out of bounds and the getters of an `AsciiChar<8>` field report stored bytes with the
+    /// most significant bit set as invalid bit patterns. `N` defaults to `8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Ident {
+    ///     tag: AsciiChar,
+    ///     unit: AsciiChar<7>,
+    ///     valid: bool,
+    /// }
+    ///
+    /// let ident = Ident::new().with_tag(b'T').with_unit(b'm');
+    /// assert_eq!(ident.tag(), b'T');
+    /// assert_eq!(ident.unit(), b'm');
+    /// assert!(Ident::new().with_tag_checked(0xC4).is_err());
+    /// assert!(Ident::from_bytes([0xC4, 0x00]).tag_or_err().is_err());
+    /// ```
+    pub struct AsciiChar<const N: usize = 8>;
+
+    impl crate::Specifier for AsciiChar<7> {
+        const BITS: usize = 7;
+        type Bytes = u8;
+        type InOut = u8;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            if !input.is_ascii() {
+                return Err(crate::OutOfBounds)
+            }
+            Ok(input)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            if !bytes.is_ascii() {
+                return Err(crate::InvalidBitPattern { invalid_bytes: bytes })
+            }
+            Ok(bytes)
+        }
+    }
+
+    impl crate::Specifier for AsciiChar<8> {
+        const BITS: usize = 8;
+        type Bytes = u8;
+        type InOut = u8;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            <AsciiChar<7> as crate::Specifier>::into_bytes(input)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            <AsciiChar<7> as crate::Specifier>::from_bytes(bytes)
+        }
+    }
+
+    /// Specifier for a block of `N` independent flag bits with `1 <= N <= 128`.
+    ///
+    /// The getters and setters of a `Flags<N>` field operate on the `Flags<N>` value
+    /// which provides access to the individual flags by their index where the flag at
+    /// index `0` is stored in the least significant bit of the block.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct InterruptMask {
+    ///     mask: Flags<24>,
+    ///     vector: B8,
+    /// }
+    ///
+    /// let mut register = InterruptMask::new();
+    /// register.set_mask(Flags::new().with(0, true).with(23, true));
+    /// let mut mask = register.mask();
+    /// assert!(mask.get(23));
+    /// mask.set(23, false);
+    /// mask.set(5, true);
+    /// assert_eq!(mask.count_ones(), 2);
+    /// assert_eq!(
+    ///     mask.iter().enumerate().filter(|(_, flag)| *flag).map(|(index, _)| index).collect::<Vec<_>>(),
+    ///     [0, 5],
+    /// );
+    /// register.set_mask(mask);
+    /// assert_eq!(register.into_bytes(), [0b0010_0001, 0x00, 0x00, 0x00]);
+    /// ```
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct Flags<const N: usize> {
+        bits: u128,
+    }
+
+    impl<const N: usize> Flags<N> {
+        /// Returns a block of `N` flags that are all unset.
+        #[inline]
+        pub const fn new() -> Self {
+            Self { bits: 0 }
+        }
+
+        /// Returns `true` if the flag at `index` is set.
+        ///
+        /// # Panics
+        ///
+        /// If `index` is not less than `N`.
+        #[inline]
+        pub fn get(&self, index: usize) -> bool {
+            assert!(index < N, "flag index {} is out of bounds for Flags<{}>", index, N);
+            self.bits & (0x01 << index) != 0
+        }
+
+        /// Sets the flag at `index` to `value`.
+        ///
+        /// # Panics
+        ///
+        /// If `index` is not less than `N`.
+        #[inline]
+        pub fn set(&mut self, index: usize, value: bool) {
+            assert!(index < N, "flag index {} is out of bounds for Flags<{}>", index, N);
+            self.bits = (self.bits & !(0x01 << index)) | ((value as u128) << index);
+        }
+
+        /// Similar to [`Flags::set`] but consumes and returns `Self`.
+        ///
+        /// # Panics
+        ///
+        /// If `index` is not less than `N`.
+        #[inline]
+        #[must_use]
+        pub fn with(mut self, index: usize, value: bool) -> Self {
+            self.set(index, value);
+            self
+        }
+
+        /// Returns an iterator over all `N` flags starting at index `0`.
+        #[inline]
+        pub fn iter(&self) -> impl Iterator<Item = bool> {
+            let bits = self.bits;
+            (0..N).map(move |index| bits & (0x01 << index) != 0)
+        }
+
+        /// Returns the number of flags that are set.
+        #[inline]
+        pub fn count_ones(&self) -> u32 {
+            self.bits.count_ones()
+        }
+    }
+
+    impl<const N: usize> crate::Specifier for Flags<N>
+    where
+        [(); N]: crate::private::SpecifierBytes,
+        <[(); N] as crate::private::SpecifierBytes>::Bytes:
+            Into<u128> + ::core::convert::TryFrom<u128>,
+    {
+        const BITS: usize = N;
+        type Bytes = <[(); N] as crate::private::SpecifierBytes>::Bytes;
+        type InOut = Self;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            <Self::Bytes as ::core::convert::TryFrom<u128>>::try_from(input.bits)
+                .map_err(|_| crate::OutOfBounds)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            Ok(Self { bits: bytes.into() })
+        }
+    }
+
+    /// Specifier for a signed integer that is stored in two's complement in the bits of `T`.
+    ///
+    /// `T` is an unsigned specifier of at most 64 bits such as `B12`. The getters and setters
+    /// operate on the signed type that is twice as wide as the base type of `T`, e.g. `i32` for
+    /// `B12`, so that all signed encodings of `T` share the same signed type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Sample {
+    ///     value: TwosComplement<B12>,
+    ///     channel: B4,
+    /// }
+    ///
+    /// let sample = Sample::new().with_value(-2);
+    /// assert_eq!(sample.value(), -2_i32);
+    /// assert_eq!(sample.into_bytes(), [0xFE, 0x0F]);
+    /// assert!(Sample::new().with_value_checked(2048).is_err());
+    /// ```
+    pub struct TwosComplement<T>(::core::marker::PhantomData<T>);
+
+    impl<T> crate::Specifier for TwosComplement<T>
+    where
+        T: crate::Specifier,
+        T::Bytes: crate::private::SignedBytes
+            + Copy
+            + Into<u128>
+            + ::core::convert::TryFrom<u128>,
+        <T::Bytes as crate::private::SignedBytes>::Signed:
+            Into<i128> + ::core::convert::TryFrom<i128>,
+    {
+        const BITS: usize = <T as crate::Specifier>::BITS;
+        type Bytes = T::Bytes;
+        type InOut = <T::Bytes as crate::private::SignedBytes>::Signed;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            crate::private::signed::encode_twos_complement(input.into(), Self::BITS)
+                .and_then(|raw| <Self::Bytes as ::core::convert::TryFrom<u128>>::try_from(raw).ok())
+                .ok_or(crate::OutOfBounds)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            crate::private::signed::decode_twos_complement(bytes.into(), Self::BITS)
+                .and_then(|value| <Self::InOut as ::core::convert::TryFrom<i128>>::try_from(value).ok())
+                .ok_or(crate::InvalidBitPattern { invalid_bytes: bytes })
+        }
+    }
+
+    /// Specifier for a signed integer that is stored as sign bit and magnitude in the bits of `T`.
+    ///
+    /// The most significant bit of `T` is the sign bit and the remaining bits store the
+    /// magnitude of the value. A stored negative zero is read as `0` and `0` is always
+    /// written with a cleared sign bit.
+    ///
+    /// `T` is an unsigned specifier of at most 64 bits such as `B12`. The getters and setters
+    /// operate on the same signed type as for [`TwosComplement<T>`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Sample {
+    ///     value: SignMagnitude<B12>,
+    ///     channel: B4,
+    /// }
+    ///
+    /// let sample = Sample::new().with_value(-2);
+    /// assert_eq!(sample.value(), -2_i32);
+    /// assert_eq!(sample.into_bytes(), [0x02, 0x08]);
+    /// assert_eq!(Sample::from_bytes([0x00, 0x08]).value(), 0);
+    /// assert!(Sample::new().with_value_checked(-2048).is_err());
+    /// ```
+    pub struct SignMagnitude<T>(::core::marker::PhantomData<T>);
+
+    impl<T> crate::Specifier for SignMagnitude<T>
+    where
+        T: crate::Specifier,
+        T::Bytes: crate::private::SignedBytes
+            + Copy
+            + Into<u128>
+            + ::core::convert::TryFrom<u128>,
+        <T::Bytes as crate::private::SignedBytes>::Signed:
+            Into<i128> + ::core::convert::TryFrom<i128>,
+    {
+        const BITS: usize = <T as crate::Specifier>::BITS;
+        type Bytes = T::Bytes;
+        type InOut = <T::Bytes as crate::private::SignedBytes>::Signed;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            crate::private::signed::encode_sign_magnitude(input.into(), Self::BITS)
+                .and_then(|raw| <Self::Bytes as ::core::convert::TryFrom<u128>>::try_from(raw).ok())
+                .ok_or(crate::OutOfBounds)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            crate::private::signed::decode_sign_magnitude(bytes.into(), Self::BITS)
+                .and_then(|value| <Self::InOut as ::core::convert::TryFrom<i128>>::try_from(value).ok())
+                .ok_or(crate::InvalidBitPattern { invalid_bytes: bytes })
+        }
+    }
+
+    /// Specifier for a signed integer that is stored in offset binary with the excess `K`
+    /// in the bits of `T`.
+    ///
+    /// A value `v` is stored as the unsigned value `v + K` so that `ExcessK<B8, 127>` stores
+    /// the values from `-127` to `128`. Values that cannot be stored in the bits of `T`
+    /// are out of bounds.
+    ///
+    /// `T` is an unsigned specifier of at most 64 bits such as `B12`. The getters and setters
+    /// operate on the same signed type as for [`TwosComplement<T>`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Exponent {
+    ///     value: ExcessK<B8, 127>,
+    /// }
+    ///
+    /// let exponent = Exponent::new().with_value(-2);
+    /// assert_eq!(exponent.value(), -2_i16);
+    /// assert_eq!(exponent.into_bytes(), [125]);
+    /// assert_eq!(Exponent::from_bytes([255]).value(), 128);
+    /// assert!(Exponent::new().with_value_checked(-128).is_err());
+    /// ```
+    pub struct ExcessK<T, const K: i128>(::core::marker::PhantomData<T>);
+
+    impl<T, const K: i128> crate::Specifier for ExcessK<T, K>
+    where
+        T: crate::Specifier,
+        T::Bytes: crate::private::SignedBytes
+            + Copy
+            + Into<u128>
+            + ::core::convert::TryFrom<u128>,
+        <T::Bytes as crate::private::SignedBytes>::Signed:
+            Into<i128> + ::core::convert::TryFrom<i128>,
+    {
+        const BITS: usize = <T as crate::Specifier>::BITS;
+        type Bytes = T::Bytes;
+        type InOut = <T::Bytes as crate::private::SignedBytes>::Signed;
+
+        #[inline]
+        fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, crate::OutOfBounds> {
+            crate::private::signed::encode_excess_k(input.into(), Self::BITS, K)
+                .and_then(|raw| <Self::Bytes as ::core::convert::TryFrom<u128>>::try_from(raw).ok())
+                .ok_or(crate::OutOfBounds)
+        }
+
+        #[inline]
+        fn from_bytes(
+            bytes: Self::Bytes,
+        ) -> Result<Self::InOut, crate::InvalidBitPattern<Self::Bytes>> {
+            crate::private::signed::decode_excess_k(bytes.into(), Self::BITS, K)
+                .and_then(|value| <Self::InOut as ::core::convert::TryFrom<i128>>::try_from(value).ok())
+                .ok_or(crate::InvalidBitPattern { invalid_bytes: bytes })
+        }
+    }
+
+    /// Pseudo-type for a trailing field marking the start of a payload after a bitfield.
+    ///
+    /// A `Rest` field does not occupy any bits and is removed from the `#[bitfield]` struct.
+    /// See the documentation of the `#[bitfield]` macro for the generated methods.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use modular_bitfield::prelude::*;
+    /// #[bitfield]
+    /// pub struct Header {
+    ///     kind: B4,
+    ///     len: B12,
+    ///     payload: Rest,
+    /// }
+    ///
+    /// let buf = [0x21, 0x03, 0xAA, 0xBB];
+    /// let header = Header::from_bytes([buf[0], buf[1]]);
+    /// assert_eq!(Header::payload_offset_bits(), 16);
+    /// assert_eq!(header.payload_of(&buf), &[0xAA, 0xBB]);
+    /// ```
+    #[derive(Debug)]
+    pub enum Rest {}
 }
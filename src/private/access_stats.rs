@@ -0,0 +1,51 @@
+use core::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+
+/// Counts the reads and writes of a single field for the `access_stats` parameter.
+///
+/// The counters wrap around on overflow.
+#[derive(Debug)]
+pub struct AccessCounter {
+    reads: AtomicUsize,
+    writes: AtomicUsize,
+}
+
+impl AccessCounter {
+    /// Creates a new counter without any recorded accesses.
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            reads: AtomicUsize::new(0),
+            writes: AtomicUsize::new(0),
+        }
+    }
+
+    /// Records a read of the field.
+    #[inline]
+    pub fn record_read(&self) {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records a write of the field.
+    #[inline]
+    pub fn record_write(&self) {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Returns the given field name together with the recorded reads and writes.
+    pub fn stats(&self, name: &'static str) -> (&'static str, u64, u64) {
+        (
+            name,
+            self.reads.load(Ordering::Relaxed) as u64,
+            self.writes.load(Ordering::Relaxed) as u64,
+        )
+    }
+
+    /// Resets the recorded reads and writes to zero.
+    pub fn reset(&self) {
+        self.reads.store(0, Ordering::Relaxed);
+        self.writes.store(0, Ordering::Relaxed);
+    }
+}
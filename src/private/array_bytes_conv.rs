@@ -1,10 +1,16 @@
 use crate::private::SpecifierBytes;
 
+/// Trait implemented by `[(); N]` for every bit width `N` that is a multiple of 8
+/// to convert between the base type of `N` bit specifiers and their bytes.
 pub trait ArrayBytesConversion {
+    /// The little-endian byte array of `N / 8` bytes.
     type Array;
+    /// The base type of the specifiers with `N` bits.
     type Bytes;
 
+    /// Converts the base type value into its little-endian byte array.
     fn bytes_into_array(bytes: Self::Bytes) -> Self::Array;
+    /// Converts the little-endian byte array into its base type value.
     fn array_into_bytes(bytes: Self::Array) -> Self::Bytes;
 }
 
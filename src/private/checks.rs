@@ -127,7 +127,11 @@ impl DispatchTrueFalse for [(); 1] {
 /// Public facing trait that is implemented by bitfield specifiers to
 /// let the compiler check if all its variant discriminants are within
 /// valid bounds.
-pub trait CheckDiscriminantInRange<A>
+///
+/// The trait is implemented once per variant with its discriminant as `DISCRIMINANT`.
+/// Using `u128` ensures that distinct discriminants never collapse into the same
+/// implementation independent of the width of `usize` of the compilation target.
+pub trait CheckDiscriminantInRange<const DISCRIMINANT: u128>
 where
     <Self::CheckType as DispatchTrueFalse>::Out: DiscriminantInRange,
 {
@@ -149,6 +153,87 @@ pub struct BitsCheck<A> {
     pub arr: A,
 }
 
+/// Panics at compile time if the `actual` bits of a field specifier do not match
+/// the `expected` bits of its `#[bits = N]` attribute.
+///
+/// The panic message is the given `message` followed by the actual number of bits.
+/// This also works for specifier types of other crates for which the compiler
+/// might not show the evaluated number of bits in its own error messages.
+pub const fn check_bits_attribute(actual: usize, expected: usize, message: &str) {
+    if actual == expected {
+        return
+    }
+    let buffer = [0_u8; 512];
+    if message.len() + 3 + 5 > buffer.len() {
+        panic!("{}", "field specifier does not match its #[bits = N] attribute")
+    }
+    let (buffer, len) = push_bytes(buffer, 0, message.as_bytes());
+    let (buffer, len) = push_decimal(buffer, len, actual);
+    let (buffer, len) = push_bytes(buffer, len, b" bits");
+    match ::core::str::from_utf8(buffer.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("{}", "field specifier does not match its #[bits = N] attribute"),
+    }
+}
+
+/// Panics at compile time if the `actual` bits of the fields of a `contiguous` bitfield
+/// do not cover all of its `expected` bits given by `bits = N`.
+///
+/// The panic message names the uncovered bit range followed by the given `message`.
+pub const fn check_contiguous(actual: usize, expected: usize, message: &str) {
+    if actual >= expected {
+        return
+    }
+    const PREFIX: &[u8] = b"encountered uncovered bits ";
+    let buffer = [0_u8; 512];
+    if PREFIX.len() + 2 * 20 + 2 + message.len() > buffer.len() {
+        panic!("{}", "fields of `contiguous` bitfield leave bits uncovered")
+    }
+    let (buffer, len) = push_bytes(buffer, 0, PREFIX);
+    let (buffer, len) = push_decimal(buffer, len, actual);
+    let (buffer, len) = push_bytes(buffer, len, b"..");
+    let (buffer, len) = push_decimal(buffer, len, expected);
+    let (buffer, len) = push_bytes(buffer, len, message.as_bytes());
+    match ::core::str::from_utf8(buffer.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("{}", "fields of `contiguous` bitfield leave bits uncovered"),
+    }
+}
+
+/// Writes the given bytes into the buffer at `len` and returns it with its new length.
+const fn push_bytes(
+    mut buffer: [u8; 512],
+    mut len: usize,
+    bytes: &[u8],
+) -> ([u8; 512], usize) {
+    let mut index = 0;
+    while index < bytes.len() {
+        buffer[len] = bytes[index];
+        len += 1;
+        index += 1;
+    }
+    (buffer, len)
+}
+
+/// Writes the decimal digits of `value` into the buffer at `len` and returns it with its
+/// new length.
+const fn push_decimal(
+    mut buffer: [u8; 512],
+    mut len: usize,
+    value: usize,
+) -> ([u8; 512], usize) {
+    let mut divisor = 1;
+    while value / divisor >= 10 {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        buffer[len] = b'0' + ((value / divisor) % 10) as u8;
+        len += 1;
+        divisor /= 10;
+    }
+    (buffer, len)
+}
+
 pub trait CheckFillsUnalignedBits
 where
     <Self::CheckType as DispatchTrueFalse>::Out: FillsUnalignedBits,
@@ -0,0 +1,177 @@
+use core::fmt::{
+    self,
+    Debug,
+    Write,
+};
+
+/// The column widths of a register dump table generated by the `dump` parameter.
+#[derive(Debug, Copy, Clone)]
+pub struct DumpWidths {
+    name: usize,
+    range: usize,
+    hex: usize,
+}
+
+impl DumpWidths {
+    /// Computes the column widths for the given `(name, offset, bits)` of all dumped fields.
+    pub fn new(fields: &[(&str, usize, usize)]) -> Self {
+        let mut widths = Self {
+            name: 0,
+            range: 0,
+            hex: 0,
+        };
+        for &(name, offset, bits) in fields {
+            widths.name = widths.name.max(name.len());
+            widths.range = widths.range.max(BitRange { offset, bits }.len());
+            widths.hex = widths.hex.max(hex_digits(bits));
+        }
+        widths
+    }
+}
+
+/// Returns the number of hexadecimal digits required to display `bits` bits.
+fn hex_digits(bits: usize) -> usize {
+    core::cmp::max(bits.div_ceil(4), 1)
+}
+
+/// Returns the number of decimal digits of `value`.
+fn decimal_digits(mut value: usize) -> usize {
+    let mut digits = 1;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// The bit range `[msb:lsb]` of a field, displayed as `[lsb]` for single bit fields.
+struct BitRange {
+    offset: usize,
+    bits: usize,
+}
+
+impl BitRange {
+    /// Returns the index of the most significant bit of the range.
+    fn msb(&self) -> usize {
+        self.offset + self.bits.saturating_sub(1)
+    }
+
+    /// Returns the number of characters of the displayed bit range.
+    fn len(&self) -> usize {
+        if self.bits <= 1 {
+            return decimal_digits(self.offset) + 2
+        }
+        decimal_digits(self.msb()) + decimal_digits(self.offset) + 3
+    }
+}
+
+impl fmt::Display for BitRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.bits <= 1 {
+            return write!(f, "[{}]", self.offset)
+        }
+        write!(f, "[{}:{}]", self.msb(), self.offset)
+    }
+}
+
+/// Writes `amount` spaces.
+fn write_padding<W>(w: &mut W, amount: usize) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    for _ in 0..amount {
+        w.write_char(' ')?;
+    }
+    Ok(())
+}
+
+/// Writes a single line of a register dump table.
+///
+/// The line consists of the field name, its bit range, its raw value in hexadecimal
+/// and its decoded value, each column padded to the given widths.
+pub fn dump_row<W>(
+    w: &mut W,
+    widths: &DumpWidths,
+    name: &str,
+    offset: usize,
+    bits: usize,
+    raw: u128,
+    decoded: &dyn Debug,
+) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    let range = BitRange { offset, bits };
+    let digits = hex_digits(bits);
+    write!(w, "{:<width$} ", name, width = widths.name)?;
+    write!(w, "{}", range)?;
+    write_padding(w, widths.range - range.len() + 1)?;
+    write!(w, "0x{:0digits$X}", raw, digits = digits)?;
+    write_padding(w, widths.hex - digits + 1)?;
+    writeln!(w, "{:?}", decoded)
+}
+
+/// The bits of a bitfield rendered as binary byte literals by `to_bitstring()`.
+///
+/// Every byte is written as `0b0101_0011` with the bytes separated by spaces.
+/// The alternate flag `{:#}` marks the boundaries between the fields with `|`
+/// instead of separating the nibbles of every byte with `_`.
+#[derive(Debug, Copy, Clone)]
+pub struct Bitstring<'a, const N: usize> {
+    bytes: &'a [u8],
+    offsets: [usize; N],
+    msb0: bool,
+}
+
+impl<'a, const N: usize> Bitstring<'a, N> {
+    /// Creates a bitstring of the given bytes with fields starting at the given bit offsets.
+    ///
+    /// With `msb0` the first bit of every byte is its most significant bit.
+    pub fn new(bytes: &'a [u8], offsets: [usize; N], msb0: bool) -> Self {
+        Self {
+            bytes,
+            offsets,
+            msb0,
+        }
+    }
+
+    /// Returns `true` if a field other than the first field starts at the given bit offset.
+    fn is_boundary(&self, offset: usize) -> bool {
+        offset != 0 && self.offsets.contains(&offset)
+    }
+}
+
+impl<const N: usize> fmt::Display for Bitstring<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mark_fields = f.alternate();
+        for (index, byte) in self.bytes.iter().enumerate() {
+            let first = index * 8;
+            if index != 0 {
+                match mark_fields && self.is_boundary(first) {
+                    true => f.write_str(" | ")?,
+                    false => f.write_char(' ')?,
+                }
+            }
+            f.write_str("0b")?;
+            for position in (0..8).rev() {
+                if position != 7 {
+                    // The bit offset of the field that would start right before this bit.
+                    let boundary = match self.msb0 {
+                        true => first + 7 - position,
+                        false => first + position + 1,
+                    };
+                    if mark_fields && self.is_boundary(boundary) {
+                        f.write_char('|')?;
+                    } else if !mark_fields && position == 3 {
+                        f.write_char('_')?;
+                    }
+                }
+                match (byte >> position) & 0x01 {
+                    0 => f.write_char('0')?,
+                    _ => f.write_char('1')?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use super::WideBytes;
 use crate::{
     error::{
         InvalidBitPattern,
@@ -5,6 +6,7 @@ use crate::{
     },
     Specifier,
 };
+use core::convert::TryFrom;
 
 impl Specifier for bool {
     const BITS: usize = 1;
@@ -56,3 +58,47 @@ impl_specifier_for_primitive!(
     (u64: 64),
     (u128: 128),
 );
+
+/// Specifier for an optional value of `T` with an additional validity bit.
+///
+/// The value of `T` occupies the least significant `T::BITS` bits followed by the
+/// validity bit which is set for `Some` values. `None` is written as all zeros and
+/// the bits of `T` are ignored when reading a cleared validity bit.
+///
+/// Specifiers whose all-zero bit pattern is invalid, e.g. `NonZeroB7`, do not need
+/// the validity bit and instead store `None` as all zeros in their own bits.
+impl<T> Specifier for Option<T>
+where
+    T: Specifier,
+    T::Bytes: WideBytes + Into<u128> + TryFrom<u128>,
+    <T::Bytes as WideBytes>::Wide: Copy + Into<u128> + TryFrom<u128>,
+{
+    const BITS: usize = T::BITS + !T::HAS_ZERO_NICHE as usize;
+    type Bytes = <T::Bytes as WideBytes>::Wide;
+    type InOut = Option<T::InOut>;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let valid = if T::HAS_ZERO_NICHE { 0 } else { 0x01 << T::BITS };
+        let raw = match input {
+            Some(value) => T::into_bytes(value)?.into() | valid,
+            None => 0,
+        };
+        <Self::Bytes as TryFrom<u128>>::try_from(raw).map_err(|_| OutOfBounds)
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let raw: u128 = bytes.into();
+        if raw == 0 || (!T::HAS_ZERO_NICHE && raw >> T::BITS == 0) {
+            return Ok(None)
+        }
+        <T::Bytes as TryFrom<u128>>::try_from(raw & !(!0 << T::BITS))
+            .ok()
+            .and_then(|value| T::from_bytes(value).ok())
+            .map(Some)
+            .ok_or(InvalidBitPattern { invalid_bytes: bytes })
+    }
+}
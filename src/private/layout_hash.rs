@@ -0,0 +1,55 @@
+/// The FNV-1a 64-bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// The FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes the `LAYOUT_VERSION` of a bitfield at compile time.
+///
+/// This is a 64-bit FNV-1a hash over the bit order and the name, bit offset and bit
+/// width of every field. Strings are prefixed by their length so that adjacent names
+/// cannot be shifted into each other.
+#[derive(Debug, Copy, Clone)]
+pub struct LayoutHasher {
+    state: u64,
+}
+
+impl LayoutHasher {
+    /// Creates a new hasher for a bitfield with the given bit order name.
+    pub const fn new(bit_order: &str) -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+        .write_str(bit_order)
+    }
+
+    /// Hashes the given bytes.
+    const fn write_bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.state ^= bytes[i] as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+            i += 1;
+        }
+        self
+    }
+
+    /// Hashes the given string prefixed by its length.
+    pub const fn write_str(self, value: &str) -> Self {
+        self.write_usize(value.len()).write_bytes(value.as_bytes())
+    }
+
+    /// Hashes the given value as little-endian `u64`.
+    pub const fn write_usize(self, value: usize) -> Self {
+        self.write_bytes(&(value as u64).to_le_bytes())
+    }
+
+    /// Hashes the name, bit offset and bit width of a field.
+    pub const fn write_field(self, name: &str, offset: usize, bits: usize) -> Self {
+        self.write_str(name).write_usize(offset).write_usize(bits)
+    }
+
+    /// Returns the computed hash.
+    pub const fn finish(self) -> u64 {
+        self.state
+    }
+}
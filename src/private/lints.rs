@@ -0,0 +1,85 @@
+/// Reports the warnings of the `lints` parameter of `#[bitfield]` and of large `Copy` bitfields.
+///
+/// Stable Rust provides no way for proc. macros to emit warnings. Therefore lints are
+/// reported as deprecation warnings by calling one of the functions of `Lint<true>`.
+/// The generated code selects `Lint<true>` or `Lint<false>` depending on whether the
+/// condition of the lint holds which can only be evaluated after expansion.
+#[derive(Debug, Copy, Clone)]
+pub struct Lint<const WARN: bool>;
+
+impl Lint<true> {
+    #[deprecated(
+        note = "bitfield lint: this field crosses more byte boundaries than its bit width requires"
+    )]
+    pub const fn field_crosses_byte_boundary() {}
+
+    #[deprecated(
+        note = "bitfield lint: this enum field is wider than required by its variants"
+    )]
+    pub const fn oversized_enum_field() {}
+
+    #[deprecated(
+        note = "bitfield lint: this padding precedes the last field, consider moving it to the end"
+    )]
+    pub const fn padding_before_last_field() {}
+
+    #[deprecated(
+        note = "bitfield: this `Copy` bitfield is larger than 64 bytes, use `#[bitfield(copy = false)]` to strip `Copy` or `#[bitfield(copy = true)]` to allow it"
+    )]
+    pub const fn large_copy_bitfield() {}
+}
+
+impl Lint<false> {
+    pub const fn field_crosses_byte_boundary() {}
+
+    pub const fn oversized_enum_field() {}
+
+    pub const fn padding_before_last_field() {}
+
+    pub const fn large_copy_bitfield() {}
+}
+
+/// Returns `true` if a field of the given bit width at the given bit offset spans more
+/// bytes than a byte aligned field of the same bit width.
+pub const fn crosses_byte_boundary(offset: usize, bits: usize) -> bool {
+    if bits == 0 {
+        return false
+    }
+    let spanned_bytes = (offset + bits - 1) / 8 - offset / 8 + 1;
+    spanned_bytes > bits.div_ceil(8)
+}
+
+/// Returns `true` if any of `repetitions` consecutive fields of the given bit width starting
+/// at the given bit offset straddles a byte boundary.
+///
+/// A field straddles a byte boundary unless it lies within a single byte or both starts and
+/// ends at a byte boundary.
+// `usize::is_multiple_of` requires Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+pub const fn straddles_byte_boundary(offset: usize, bits: usize, repetitions: usize) -> bool {
+    let mut i = 0;
+    while i < repetitions {
+        let start = offset + i * bits;
+        let end = start + bits;
+        let single_byte = bits == 0 || start / 8 == (end - 1) / 8;
+        let byte_aligned = start % 8 == 0 && end % 8 == 0;
+        if !single_byte && !byte_aligned {
+            return true
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns the bit width required to represent all of the given enum discriminants.
+pub const fn required_bits(discriminants: &[u128]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < discriminants.len() {
+        if discriminants[i] > max {
+            max = discriminants[i];
+        }
+        i += 1;
+    }
+    (128 - max.leading_zeros()) as usize
+}
@@ -1,31 +1,97 @@
+mod access_stats;
 mod array_bytes_conv;
 pub mod checks;
+mod dump;
 mod impls;
+mod layout_hash;
+mod lints;
+mod numeric;
 mod proc;
-mod push_pop;
+#[cfg(feature = "serde")]
+mod serde_bytes;
+pub mod signed;
+mod snapshot;
+mod test_rng;
 mod traits;
 
 pub mod static_assertions {
     pub use static_assertions::*;
 }
+#[cfg(feature = "rand")]
+pub use self::proc::random_specifier;
+#[cfg(feature = "alloc")]
+pub use alloc::string::String;
+#[cfg(feature = "serde")]
+pub use self::serde_bytes::{
+    deserialize_bytes,
+    serialize_bytes,
+    SerdeArray,
+};
+#[cfg(feature = "serde")]
+pub use ::serde;
+#[cfg(feature = "registry")]
+pub use ::inventory;
 pub use self::{
+    access_stats::AccessCounter,
     array_bytes_conv::ArrayBytesConversion,
+    dump::{
+        dump_row,
+        Bitstring,
+        DumpWidths,
+    },
+    layout_hash::LayoutHasher,
+    lints::{
+        crosses_byte_boundary,
+        required_bits,
+        straddles_byte_boundary,
+        Lint,
+    },
+    numeric::{
+        IntoNumericValue,
+        NoNumericValue,
+        NumericValue,
+    },
     proc::{
+        is_byte_aligned,
+        is_zero_specifier,
+        is_zero_specifier_be,
+        mask_bytes,
+        mask_bytes_be,
+        read_bits,
+        read_bits_be,
         read_specifier,
+        read_specifier_be,
+        set_bit_if,
+        set_bit_if_be,
+        significant_bits_specifier,
+        significant_bits_specifier_be,
+        toggle_bit,
+        toggle_bit_be,
+        write_aligned_specifier,
+        write_aligned_specifier_be,
+        write_bits,
+        write_bits_be,
         write_specifier,
+        write_specifier_be,
     },
-    push_pop::{
-        PopBuffer,
-        PushBuffer,
-    },
+    snapshot::check_layout_snapshot,
+    test_rng::TestRng,
     traits::{
         IsU128Compatible,
         IsU16Compatible,
         IsU32Compatible,
         IsU64Compatible,
         IsU8Compatible,
-        PopBits,
-        PushBits,
+        ModifyFields,
+        SignedBytes,
         SpecifierBytes,
+        WideBytes,
+        WriteBytes,
     },
 };
+pub use crate::bitstream::{
+    PopBits,
+    PopBuffer,
+    PushBits,
+    PushBuffer,
+};
@@ -0,0 +1,36 @@
+//! Autoref based dispatch returning the numeric value of decoded field values for `iter_fields`.
+//!
+//! `(&NumericValue(value)).numeric_value()` resolves to [`IntoNumericValue`] if the value
+//! converts into `u128` and falls back to [`NoNumericValue`] otherwise.
+
+use core::convert::TryInto;
+
+/// Wrapper of a decoded field value.
+pub struct NumericValue<T>(pub T);
+
+/// Returns the value as `u128` if it is non-negative and fits into `u128`.
+pub trait IntoNumericValue {
+    fn numeric_value(&self) -> Option<u128>;
+}
+
+impl<T> IntoNumericValue for NumericValue<T>
+where
+    T: Copy + TryInto<u128>,
+{
+    #[inline]
+    fn numeric_value(&self) -> Option<u128> {
+        self.0.try_into().ok()
+    }
+}
+
+/// Fallback for values that do not convert into `u128`, e.g. of specifier enums.
+pub trait NoNumericValue {
+    fn numeric_value(&self) -> Option<u128>;
+}
+
+impl<T> NoNumericValue for &NumericValue<T> {
+    #[inline]
+    fn numeric_value(&self) -> Option<u128> {
+        None
+    }
+}
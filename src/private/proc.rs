@@ -4,6 +4,7 @@ use crate::{
         PopBuffer,
         PushBits,
         PushBuffer,
+        WriteBytes,
     },
     Specifier,
 };
@@ -113,3 +114,381 @@ pub fn write_specifier<T>(
         }
     }
 }
+
+/// Returns the range of bits within the byte at `index` that belong to the bit range `offset..end`.
+///
+/// The returned range is relative to the bit order of the bitfield, e.g. `0` refers to the least
+/// significant bit for little-endian bit order and to the most significant bit for big-endian bit order.
+#[inline]
+fn bits_within_byte(index: usize, offset: usize, end: usize) -> (u32, u32) {
+    let start = if index == offset / 8 { offset % 8 } else { 0 };
+    let stop = if index == (end - 1) / 8 {
+        ((end - 1) % 8) + 1
+    } else {
+        8
+    };
+    (start as u32, stop as u32)
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn read_specifier_be<T>(bytes: &[u8], offset: usize) -> <T as Specifier>::Bytes
+where
+    T: Specifier,
+    PushBuffer<T::Bytes>: Default + PushBits,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+
+    let mut buffer = push_buffer::<T>();
+
+    // With big-endian bit order the bits of the first byte are the most significant.
+    for (index, byte) in bytes.iter().enumerate().take(ms_byte + 1).skip(ls_byte) {
+        let (start, stop) = bits_within_byte(index, offset, end);
+        buffer.push_bits(stop - start, byte >> (8 - stop));
+    }
+    buffer.into_bytes()
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn write_specifier_be<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    new_val: <T as Specifier>::Bytes,
+) where
+    T: Specifier,
+    PopBuffer<T::Bytes>: PopBits,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+
+    let mut buffer = <PopBuffer<T::Bytes>>::from_bytes(new_val);
+
+    // With big-endian bit order the bits of the last byte are the least significant.
+    for index in (ls_byte..=ms_byte).rev() {
+        let (start, stop) = bits_within_byte(index, offset, end);
+        let mask = (((0x01_u16 << (stop - start)) - 1) << (8 - stop)) as u8;
+        let overwrite = buffer.pop_bits(stop - start) << (8 - stop);
+        bytes[index] = (bytes[index] & !mask) | (overwrite & mask);
+    }
+}
+
+/// Returns `true` if a field of type `T` at the given offset covers whole bytes.
+///
+/// Setters of such fields use [`write_aligned_specifier`] instead of [`write_specifier`].
+#[doc(hidden)]
+#[inline]
+// `usize::is_multiple_of` requires Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+pub const fn is_byte_aligned<T>(offset: usize) -> bool
+where
+    T: Specifier,
+{
+    offset % 8 == 0 && <T as Specifier>::BITS % 8 == 0
+}
+
+/// Writes a field that covers whole bytes by copying the bytes of its value directly.
+#[doc(hidden)]
+#[inline]
+pub fn write_aligned_specifier<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    new_val: <T as Specifier>::Bytes,
+) where
+    T: Specifier,
+    T::Bytes: WriteBytes,
+{
+    debug_assert!(is_byte_aligned::<T>(offset));
+    let ls_byte = offset / 8;
+    let len = <T as Specifier>::BITS / 8;
+    new_val.write_le_bytes(&mut bytes[ls_byte..(ls_byte + len)]);
+}
+
+/// Writes a field that covers whole bytes with big-endian bit order by copying the bytes
+/// of its value directly.
+#[doc(hidden)]
+#[inline]
+pub fn write_aligned_specifier_be<T>(
+    bytes: &mut [u8],
+    offset: usize,
+    new_val: <T as Specifier>::Bytes,
+) where
+    T: Specifier,
+    T::Bytes: WriteBytes,
+{
+    debug_assert!(is_byte_aligned::<T>(offset));
+    let ls_byte = offset / 8;
+    let len = <T as Specifier>::BITS / 8;
+    new_val.write_be_bytes(&mut bytes[ls_byte..(ls_byte + len)]);
+}
+
+/// Returns the bits of the byte at `index` that belong to the bit range `offset..end`.
+#[inline]
+fn masked_byte(bytes: &[u8], index: usize, offset: usize, end: usize) -> u8 {
+    let (lsb_offset, msb_offset) = bits_within_byte(index, offset, end);
+    let mask = ((0x01_u16 << msb_offset) - 1) & !((0x01_u16 << lsb_offset) - 1);
+    bytes[index] & (mask as u8)
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn is_zero_specifier<T>(bytes: &[u8], offset: usize) -> bool
+where
+    T: Specifier,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+    (ls_byte..=ms_byte).all(|index| masked_byte(bytes, index, offset, end) == 0)
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn significant_bits_specifier<T>(bytes: &[u8], offset: usize) -> u32
+where
+    T: Specifier,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+    for index in (ls_byte..=ms_byte).rev() {
+        let byte = masked_byte(bytes, index, offset, end);
+        if byte != 0 {
+            let highest_bit = index * 8 + (8 - byte.leading_zeros() as usize);
+            return (highest_bit - offset) as u32
+        }
+    }
+    0
+}
+
+/// Returns the bits of the byte at `index` that belong to the big-endian bit range `offset..end`.
+#[inline]
+fn masked_byte_be(bytes: &[u8], index: usize, offset: usize, end: usize) -> u8 {
+    let (start, stop) = bits_within_byte(index, offset, end);
+    let mask = (((0x01_u16 << (stop - start)) - 1) << (8 - stop)) as u8;
+    bytes[index] & mask
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn is_zero_specifier_be<T>(bytes: &[u8], offset: usize) -> bool
+where
+    T: Specifier,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+    (ls_byte..=ms_byte).all(|index| masked_byte_be(bytes, index, offset, end) == 0)
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn significant_bits_specifier_be<T>(bytes: &[u8], offset: usize) -> u32
+where
+    T: Specifier,
+{
+    let end = offset + <T as Specifier>::BITS;
+    let ls_byte = offset / 8; // compile-time
+    let ms_byte = (end - 1) / 8; // compile-time
+    for index in ls_byte..=ms_byte {
+        let byte = masked_byte_be(bytes, index, offset, end);
+        if byte != 0 {
+            let highest_bit = index * 8 + byte.leading_zeros() as usize;
+            return (end - highest_bit) as u32
+        }
+    }
+    0
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn toggle_bit(bytes: &mut [u8], offset: usize) {
+    bytes[offset / 8] ^= 0x01 << (offset % 8);
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn set_bit_if(bytes: &mut [u8], offset: usize, condition: bool) {
+    bytes[offset / 8] |= (condition as u8) << (offset % 8);
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn toggle_bit_be(bytes: &mut [u8], offset: usize) {
+    bytes[offset / 8] ^= 0x80 >> (offset % 8);
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn set_bit_if_be(bytes: &mut [u8], offset: usize, condition: bool) {
+    bytes[offset / 8] |= ((condition as u8) << 7) >> (offset % 8);
+}
+
+/// Returns the bytes of a bitfield with `N` bytes in which only the bits of the field
+/// at the given bit offset and with the given bit width are set to `value`.
+///
+/// Returns `None` if `value` does not fit into the bit width of the field.
+#[doc(hidden)]
+#[inline]
+pub const fn mask_bytes<const N: usize>(
+    value: u128,
+    offset: usize,
+    bits: usize,
+) -> Option<[u8; N]> {
+    if bits < 128 && value >> bits != 0 {
+        return None
+    }
+    let mut bytes = [0x00; N];
+    let mut index = 0;
+    while index < bits {
+        if (value >> index) & 0x01 == 0x01 {
+            let position = offset + index;
+            bytes[position / 8] |= 0x01 << (position % 8);
+        }
+        index += 1;
+    }
+    Some(bytes)
+}
+
+/// Returns the bytes of a big-endian bitfield with `N` bytes in which only the bits of the
+/// field at the given bit offset and with the given bit width are set to `value`.
+///
+/// Returns `None` if `value` does not fit into the bit width of the field.
+#[doc(hidden)]
+#[inline]
+pub const fn mask_bytes_be<const N: usize>(
+    value: u128,
+    offset: usize,
+    bits: usize,
+) -> Option<[u8; N]> {
+    if bits < 128 && value >> bits != 0 {
+        return None
+    }
+    let mut bytes = [0x00; N];
+    let mut index = 0;
+    while index < bits {
+        if (value >> index) & 0x01 == 0x01 {
+            let position = offset + bits - 1 - index;
+            bytes[position / 8] |= 0x80 >> (position % 8);
+        }
+        index += 1;
+    }
+    Some(bytes)
+}
+
+/// Returns the bits of the field at the given bit offset and with the given bit width
+/// in a `const` context.
+#[doc(hidden)]
+#[inline]
+pub const fn read_bits<const N: usize>(bytes: &[u8; N], offset: usize, bits: usize) -> u128 {
+    let mut value = 0;
+    let mut index = 0;
+    while index < bits {
+        let position = offset + index;
+        let shift = position % 8;
+        let len = min(8 - shift, bits - index);
+        let chunk = (bytes[position / 8] >> shift) as u128 & ((0x01 << len) - 1);
+        value |= chunk << index;
+        index += len;
+    }
+    value
+}
+
+/// Returns the bytes with the field at the given bit offset and with the given bit width
+/// set to the given value in a `const` context.
+#[doc(hidden)]
+#[inline]
+pub const fn write_bits<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut index = 0;
+    while index < bits {
+        let position = offset + index;
+        let shift = position % 8;
+        let len = min(8 - shift, bits - index);
+        let mask = (((0x01_u16 << len) - 1) << shift) as u8;
+        let chunk = ((value >> index) as u8) << shift;
+        bytes[position / 8] = (bytes[position / 8] & !mask) | (chunk & mask);
+        index += len;
+    }
+    bytes
+}
+
+/// Returns the bits of the field at the given bit offset and with the given bit width
+/// of a big-endian bitfield in a `const` context.
+#[doc(hidden)]
+#[inline]
+pub const fn read_bits_be<const N: usize>(bytes: &[u8; N], offset: usize, bits: usize) -> u128 {
+    let mut value = 0;
+    let mut index = 0;
+    while index < bits {
+        // The position of the least significant remaining bit of the field.
+        let position = offset + bits - 1 - index;
+        let shift = 7 - position % 8;
+        let len = min(8 - shift, bits - index);
+        let chunk = (bytes[position / 8] >> shift) as u128 & ((0x01 << len) - 1);
+        value |= chunk << index;
+        index += len;
+    }
+    value
+}
+
+/// Returns the bytes of a big-endian bitfield with the field at the given bit offset and with
+/// the given bit width set to the given value in a `const` context.
+#[doc(hidden)]
+#[inline]
+pub const fn write_bits_be<const N: usize>(
+    mut bytes: [u8; N],
+    offset: usize,
+    bits: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut index = 0;
+    while index < bits {
+        // The position of the least significant remaining bit of the field.
+        let position = offset + bits - 1 - index;
+        let shift = 7 - position % 8;
+        let len = min(8 - shift, bits - index);
+        let mask = (((0x01_u16 << len) - 1) << shift) as u8;
+        let chunk = ((value >> index) as u8) << shift;
+        bytes[position / 8] = (bytes[position / 8] & !mask) | (chunk & mask);
+        index += len;
+    }
+    bytes
+}
+
+/// Returns the minimum of the given values in a `const` context.
+const fn min(lhs: usize, rhs: usize) -> usize {
+    if lhs < rhs {
+        lhs
+    } else {
+        rhs
+    }
+}
+
+/// Returns uniformly distributed random bits for the specifier `T` that form a valid bit pattern.
+///
+/// Invalid bit patterns, e.g. of enums with fewer variants than bit patterns, are redrawn.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[inline]
+pub fn random_specifier<T, R>(rng: &mut R) -> <T as Specifier>::Bytes
+where
+    T: Specifier,
+    PushBuffer<T::Bytes>: Default + PushBits,
+    R: rand::Rng + ?Sized,
+{
+    let mut buffer = [0x00_u8; 16];
+    loop {
+        rng.fill_bytes(&mut buffer[..<T as Specifier>::BITS.div_ceil(8)]);
+        if <T as Specifier>::from_bytes(read_specifier::<T>(&buffer[..], 0)).is_ok() {
+            return read_specifier::<T>(&buffer[..], 0)
+        }
+    }
+}
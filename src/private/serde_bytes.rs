@@ -0,0 +1,135 @@
+use core::{
+    fmt,
+    marker::PhantomData,
+};
+use serde::{
+    de::{
+        Error as _,
+        SeqAccess,
+        Visitor,
+    },
+    ser::SerializeTuple as _,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/// Serializes the bytes of a bitfield as a tuple of `N` bytes.
+///
+/// Unlike the `Serialize` impls of arrays this is not limited to 32 bytes.
+pub fn serialize_bytes<S, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Deserializes the bytes of a bitfield from a tuple of `N` bytes.
+pub fn deserialize_bytes<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_tuple(N, BytesVisitor::<N>(PhantomData))
+}
+
+/// Visits a sequence of exactly `N` bytes.
+struct BytesVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {} bytes", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0x00; N];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self))
+        }
+        Ok(bytes)
+    }
+}
+
+/// The values of a field with `#[repeat = N]` or of a field array as a tuple of `N` values.
+///
+/// Unlike the `Serialize` and `Deserialize` impls of arrays this is not limited to
+/// 32 values and does not require the values to implement `Default`.
+pub struct SerdeArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Serialize for SerdeArray<T, N>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for value in &self.0 {
+            tuple.serialize_element(value)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for SerdeArray<T, N>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(N, ArrayVisitor::<T, N>(PhantomData))
+    }
+}
+
+/// Visits a sequence of exactly `N` values of type `T`.
+struct ArrayVisitor<T, const N: usize>(PhantomData<[T; N]>);
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = SerdeArray<T, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an array of {} values", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values: [Option<T>; N] = core::array::from_fn(|_| None);
+        for (index, value) in values.iter_mut().enumerate() {
+            *value = Some(
+                seq.next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(index, &self))?,
+            );
+        }
+        if seq.next_element::<T>()?.is_some() {
+            return Err(A::Error::invalid_length(N + 1, &self))
+        }
+        Ok(SerdeArray(core::array::from_fn(|index| {
+            values[index].take().expect("all values have been visited")
+        })))
+    }
+}
@@ -0,0 +1,87 @@
+//! Conversions between raw bits and the signed values of the signed encodings.
+//!
+//! All functions operate on `bits`-wide raw values with `1 <= bits <= 64` and return
+//! `None` if the raw value does not fit into `bits` bits or the signed value is not
+//! representable by the encoding.
+
+/// Returns the number of distinct raw values of a `bits`-wide specifier.
+#[inline]
+fn raw_count(bits: usize) -> u128 {
+    1_u128 << bits
+}
+
+/// Returns `Some(raw)` if `raw` fits into `bits` bits.
+#[inline]
+fn checked_raw(raw: u128, bits: usize) -> Option<u128> {
+    if raw >= raw_count(bits) {
+        return None
+    }
+    Some(raw)
+}
+
+/// Encodes `value` in two's complement.
+#[inline]
+pub fn encode_twos_complement(value: i128, bits: usize) -> Option<u128> {
+    let half = (raw_count(bits) / 2) as i128;
+    if value < -half || value >= half {
+        return None
+    }
+    Some((value as u128) & (raw_count(bits) - 1))
+}
+
+/// Decodes the two's complement `raw` value.
+#[inline]
+pub fn decode_twos_complement(raw: u128, bits: usize) -> Option<i128> {
+    let raw = checked_raw(raw, bits)?;
+    if raw >= raw_count(bits) / 2 {
+        return Some(raw as i128 - raw_count(bits) as i128)
+    }
+    Some(raw as i128)
+}
+
+/// Encodes `value` as sign bit followed by `bits - 1` bits of magnitude.
+///
+/// Zero is always encoded with a cleared sign bit.
+#[inline]
+pub fn encode_sign_magnitude(value: i128, bits: usize) -> Option<u128> {
+    let sign_bit = raw_count(bits) / 2;
+    let magnitude = value.unsigned_abs();
+    if magnitude >= sign_bit {
+        return None
+    }
+    if value < 0 {
+        return Some(sign_bit | magnitude)
+    }
+    Some(magnitude)
+}
+
+/// Decodes the sign-magnitude `raw` value.
+///
+/// Negative zero is decoded as zero.
+#[inline]
+pub fn decode_sign_magnitude(raw: u128, bits: usize) -> Option<i128> {
+    let raw = checked_raw(raw, bits)?;
+    let sign_bit = raw_count(bits) / 2;
+    let magnitude = (raw & !sign_bit) as i128;
+    if raw & sign_bit != 0 {
+        return Some(-magnitude)
+    }
+    Some(magnitude)
+}
+
+/// Encodes `value` in offset binary with the excess `k`, i.e. as `value + k`.
+#[inline]
+pub fn encode_excess_k(value: i128, bits: usize, k: i128) -> Option<u128> {
+    let raw = value.checked_add(k)?;
+    if raw < 0 {
+        return None
+    }
+    checked_raw(raw as u128, bits)
+}
+
+/// Decodes the offset binary `raw` value with the excess `k`, i.e. as `raw - k`.
+#[inline]
+pub fn decode_excess_k(raw: u128, bits: usize, k: i128) -> Option<i128> {
+    let raw = checked_raw(raw, bits)?;
+    (raw as i128).checked_sub(k)
+}
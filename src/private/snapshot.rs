@@ -0,0 +1,56 @@
+use core::fmt;
+
+/// Displays the lines that differ between an expected and an actual snapshot.
+///
+/// Lines only found in the expected snapshot are prefixed with `-` and lines
+/// only found in the actual snapshot are prefixed with `+`.
+struct SnapshotDiff<'a> {
+    expected: &'a str,
+    actual: &'a str,
+}
+
+impl fmt::Display for SnapshotDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut expected = self.expected.lines();
+        let mut actual = self.actual.lines();
+        loop {
+            match (expected.next(), actual.next()) {
+                (None, None) => return Ok(()),
+                (Some(lhs), Some(rhs)) if lhs == rhs => writeln!(f, "  {}", lhs)?,
+                (lhs, rhs) => {
+                    if let Some(lhs) = lhs {
+                        writeln!(f, "- {}", lhs)?;
+                    }
+                    if let Some(rhs) = rhs {
+                        writeln!(f, "+ {}", rhs)?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Asserts that the actual layout snapshot of a bitfield matches the snapshot at `path`.
+///
+/// # Panics
+///
+/// If the snapshot file does not exist or if the layouts differ.
+#[track_caller]
+pub fn check_layout_snapshot(path: &str, expected: Option<&str>, actual: &str) {
+    match expected {
+        None => {
+            panic!(
+                "missing layout snapshot {}, create it with the following content:\n{}",
+                path, actual,
+            )
+        }
+        Some(expected) if expected.trim_end() != actual.trim_end() => {
+            panic!(
+                "layout does not match the snapshot {}:\n{}",
+                path,
+                SnapshotDiff { expected, actual },
+            )
+        }
+        Some(_) => {}
+    }
+}
@@ -0,0 +1,34 @@
+/// Small deterministic pseudo random number generator for the tests generated by `#[bitfield]`.
+///
+/// Implements the `xorshift64*` algorithm which is good enough to draw random bit patterns
+/// without depending on the `rand` crate.
+#[derive(Debug)]
+pub struct TestRng {
+    state: u64,
+}
+
+impl TestRng {
+    /// Creates a new generator from the given seed.
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            // The state of `xorshift64*` must never be zero.
+            state: seed | 0x01,
+        }
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Fills the given bytes with random bits.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let random = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&random[..chunk.len()]);
+        }
+    }
+}
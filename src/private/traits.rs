@@ -1,27 +1,9 @@
 use super::checks;
 
-/// Helper trait for underlying primitives handling of bitfields.
+/// Trait implemented by `[(); N]` for every bit width `1 <= N <= 128` that maps
+/// the bit width to the base type of the specifiers with `N` bits.
 ///
-/// # Note
-///
-/// Must not and cannot be implemented by dependencies.
-#[doc(hidden)]
-pub trait PushBits: checks::private::Sealed {
-    fn push_bits(&mut self, amount: u32, bits: u8);
-}
-
-/// Helper trait for underlying primitives handling of bitfields.
-///
-/// # Note
-///
-/// Must not and cannot be implemented by dependencies.
-#[doc(hidden)]
-pub trait PopBits: checks::private::Sealed {
-    fn pop_bits(&mut self, amount: u32) -> u8;
-}
-
-/// Trait implemented by primitives that drive bitfield manipulations generically.
-#[doc(hidden)]
+/// This is e.g. `u8` for `N = 7` and `u32` for `N = 24`.
 pub trait SpecifierBytes: checks::private::Sealed {
     /// The base type that the specifier is operating on.
     type Bytes;
@@ -38,3 +20,89 @@ impl IsU16Compatible for [(); 16] {}
 impl IsU32Compatible for [(); 32] {}
 impl IsU64Compatible for [(); 64] {}
 impl IsU128Compatible for [(); 128] {}
+
+/// Trait implemented by the primitive base types of the specifiers supported by the
+/// signed encodings `TwosComplement`, `SignMagnitude` and `ExcessK`.
+#[doc(hidden)]
+pub trait SignedBytes: checks::private::Sealed {
+    /// The signed type of the values of the signed encodings.
+    ///
+    /// This is twice as wide as the base type so that all encodings of the same
+    /// specifier share the same signed type, including `ExcessK` with large offsets.
+    type Signed;
+}
+
+impl SignedBytes for u8 {
+    type Signed = i16;
+}
+impl SignedBytes for u16 {
+    type Signed = i32;
+}
+impl SignedBytes for u32 {
+    type Signed = i64;
+}
+impl SignedBytes for u64 {
+    type Signed = i128;
+}
+
+/// Trait implemented by the primitive base types of the specifiers supported by `Option<T>`.
+#[doc(hidden)]
+pub trait WideBytes: checks::private::Sealed {
+    /// The base type of `Option<T>` that is wide enough for the validity bit.
+    type Wide;
+}
+
+impl WideBytes for u8 {
+    type Wide = u16;
+}
+impl WideBytes for u16 {
+    type Wide = u32;
+}
+impl WideBytes for u32 {
+    type Wide = u64;
+}
+impl WideBytes for u64 {
+    type Wide = u128;
+}
+
+/// Trait implemented by the primitive base types to write whole bytes of a value at once.
+#[doc(hidden)]
+pub trait WriteBytes: checks::private::Sealed {
+    /// Writes the `bytes.len()` least significant bytes of `self` in little-endian byte order.
+    fn write_le_bytes(self, bytes: &mut [u8]);
+    /// Writes the `bytes.len()` least significant bytes of `self` in big-endian byte order.
+    fn write_be_bytes(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_write_bytes_for_prim {
+    ( $($prim:ty),* ) => {
+        $(
+            impl WriteBytes for $prim {
+                #[inline]
+                fn write_le_bytes(self, bytes: &mut [u8]) {
+                    let len = bytes.len();
+                    bytes.copy_from_slice(&self.to_le_bytes()[..len]);
+                }
+
+                #[inline]
+                fn write_be_bytes(self, bytes: &mut [u8]) {
+                    let array = self.to_be_bytes();
+                    bytes.copy_from_slice(&array[(array.len() - bytes.len())..]);
+                }
+            }
+        )*
+    };
+}
+impl_write_bytes_for_prim!(u8, u16, u32, u64, u128);
+
+/// Trait implemented by all `#[bitfield]` structs for the `modify_fields!` macro.
+#[doc(hidden)]
+pub trait ModifyFields: Sized {
+    /// Wrapper of the bitfield providing a chaining setter named after every field.
+    type Fields;
+
+    /// Wraps the bitfield in order to modify its fields.
+    fn into_fields(self) -> Self::Fields;
+    /// Unwraps the modified bitfield.
+    fn from_fields(fields: Self::Fields) -> Self;
+}
@@ -0,0 +1,135 @@
+//! Global registry of the layouts of `#[bitfield]` structs.
+//!
+//! Bitfields with the `register_layout` parameter submit their layout to this registry
+//! so that tools such as diagnostic shells can list and decode all known bitfield types
+//! at runtime without maintaining a central table.
+//!
+//! Requires the `registry` crate feature.
+
+/// The layout of a `#[bitfield]` struct with the `register_layout` parameter.
+#[derive(Debug)]
+pub struct BitfieldLayout {
+    name: &'static str,
+    module_path: &'static str,
+    bits: usize,
+    big_endian: bool,
+    fields: &'static [FieldLayout],
+}
+
+/// The layout of a single field of a registered `#[bitfield]` struct.
+#[derive(Debug)]
+pub struct FieldLayout {
+    name: &'static str,
+    offset: usize,
+    bits: usize,
+}
+
+::inventory::collect!(BitfieldLayout);
+
+/// Returns an iterator over the layouts of all registered `#[bitfield]` structs.
+///
+/// The order of the layouts is unspecified.
+pub fn iter() -> impl Iterator<Item = &'static BitfieldLayout> {
+    ::inventory::iter::<BitfieldLayout>.into_iter()
+}
+
+impl BitfieldLayout {
+    #[doc(hidden)]
+    pub const fn new(
+        name: &'static str,
+        module_path: &'static str,
+        bits: usize,
+        big_endian: bool,
+        fields: &'static [FieldLayout],
+    ) -> Self {
+        Self {
+            name,
+            module_path,
+            bits,
+            big_endian,
+            fields,
+        }
+    }
+
+    /// Returns the name of the bitfield struct.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the path of the module in which the bitfield struct is defined.
+    pub fn module_path(&self) -> &'static str {
+        self.module_path
+    }
+
+    /// Returns the bit width of the bitfield.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the number of bytes of the bitfield as returned by `into_bytes`.
+    pub fn bytes(&self) -> usize {
+        (self.bits.saturating_sub(1) / 8) + 1
+    }
+
+    /// Returns `true` if the fields start at the most significant bit of the first byte.
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    /// Returns the layouts of all fields in the order of their bit offsets.
+    pub fn fields(&self) -> &'static [FieldLayout] {
+        self.fields
+    }
+
+    /// Returns the layout of the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&'static FieldLayout> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the raw bits of the field read from the given bytes of the bitfield.
+    ///
+    /// Returns `None` if the bytes are fewer than [`Self::bytes`] or if the field is wider
+    /// than 128 bits which can only be the case for fields with `#[repeat = N]`.
+    pub fn read(&self, field: &FieldLayout, bytes: &[u8]) -> Option<u128> {
+        if bytes.len() < self.bytes() || field.bits > 128 {
+            return None
+        }
+        let mut value = 0;
+        for index in 0..field.bits {
+            let bit = match self.big_endian {
+                true => {
+                    let position = field.offset + field.bits - 1 - index;
+                    (bytes[position / 8] >> (7 - position % 8)) & 0x01
+                }
+                false => {
+                    let position = field.offset + index;
+                    (bytes[position / 8] >> (position % 8)) & 0x01
+                }
+            };
+            value |= u128::from(bit) << index;
+        }
+        Some(value)
+    }
+}
+
+impl FieldLayout {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str, offset: usize, bits: usize) -> Self {
+        Self { name, offset, bits }
+    }
+
+    /// Returns the name of the field.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the bit offset of the field within the bitfield.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the bit width of the field.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+}
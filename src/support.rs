@@ -0,0 +1,90 @@
+//! Building blocks for implementing [`Specifier`] manually.
+//!
+//! Most specifiers are best defined via `#[bitfield]` or `#[derive(BitfieldSpecifier)]`.
+//! Specifiers with custom encodings, e.g. generic over their bit width, instead have to
+//! implement [`Specifier`] by hand. This module provides the pieces required for this.
+//! Unlike the rest of the crate internals the items of this module follow semantic
+//! versioning and are only changed in breaking releases.
+//!
+//! - [`SpecifierBytes`] maps a bit width `N` to the base type of all `N` bit specifiers.
+//! - [`ArrayBytesConversion`] converts between the base type of a specifier whose bit width
+//!   is a multiple of 8 and its little-endian byte array.
+//! - [`checks`] provides the traits used to check the bit widths of specifiers at compile time.
+//!
+//! # Example
+//!
+//! An `N` bit specifier that stores the bitwise complement of its value.
+//!
+//! ```
+//! use core::convert::TryFrom;
+//! use modular_bitfield::{
+//!     error::{InvalidBitPattern, OutOfBounds},
+//!     prelude::*,
+//!     support::SpecifierBytes,
+//! };
+//!
+//! pub struct Inverted<const N: usize>;
+//!
+//! impl<const N: usize> Specifier for Inverted<N>
+//! where
+//!     [(); N]: SpecifierBytes,
+//!     <[(); N] as SpecifierBytes>::Bytes: Copy + Into<u128> + TryFrom<u128>,
+//! {
+//!     const BITS: usize = N;
+//!     type Bytes = <[(); N] as SpecifierBytes>::Bytes;
+//!     type InOut = Self::Bytes;
+//!
+//!     fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+//!         let mask = u128::MAX >> (128 - N);
+//!         let value: u128 = input.into();
+//!         if value > mask {
+//!             return Err(OutOfBounds)
+//!         }
+//!         Self::Bytes::try_from(!value & mask).map_err(|_| OutOfBounds)
+//!     }
+//!
+//!     fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+//!         let mask = u128::MAX >> (128 - N);
+//!         let value: u128 = bytes.into();
+//!         Self::Bytes::try_from(!value & mask).map_err(|_| InvalidBitPattern::new(bytes))
+//!     }
+//! }
+//!
+//! #[bitfield]
+//! pub struct Pins {
+//!     levels: Inverted<12>,
+//!     mode: B4,
+//! }
+//!
+//! let pins = Pins::new().with_levels(0x00F);
+//! assert_eq!(pins.levels(), 0x00F);
+//! assert_eq!(pins.into_bytes(), [0xF0, 0x0F]);
+//! ```
+//!
+//! [`Specifier`]: crate::Specifier
+
+pub use crate::private::{
+    ArrayBytesConversion,
+    SpecifierBytes,
+};
+
+/// Traits used to check at compile time whether a bit width forms complete bytes.
+///
+/// `TotalSize<[(); N % 8]>` implements [`RenameSizeType`] with a `CheckType` implementing
+/// [`TotalSizeIsMultipleOfEightBits`] if `N` is a multiple of 8 and
+/// [`TotalSizeIsNotMultipleOfEightBits`] otherwise. The traits are sealed and can only
+/// be used as bounds.
+///
+/// [`RenameSizeType`]: checks::RenameSizeType
+/// [`TotalSizeIsMultipleOfEightBits`]: checks::TotalSizeIsMultipleOfEightBits
+/// [`TotalSizeIsNotMultipleOfEightBits`]: checks::TotalSizeIsNotMultipleOfEightBits
+pub mod checks {
+    pub use crate::private::checks::{
+        CheckTotalSizeIsNotMultipleOf8,
+        CheckTotalSizeMultipleOf8,
+        RenameSizeType,
+        TotalSize,
+        TotalSizeIsMultipleOfEightBits,
+        TotalSizeIsNotMultipleOfEightBits,
+    };
+}
@@ -0,0 +1,36 @@
+// Generate `set_*_chain` setters that return `&mut Self` so that bitfields
+// living inside larger structs can be configured in place.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct MyFourBytes {
+    a: bool,
+    b: B3,
+    c: B4,
+    d: B24,
+}
+
+pub struct Device {
+    id: u32,
+    register: MyFourBytes,
+}
+
+fn main() {
+    let mut device = Device {
+        id: 42,
+        register: MyFourBytes::new(),
+    };
+    device
+        .register
+        .set_a_chain(true)
+        .set_b_chain(2)
+        .set_c_chain(14)
+        .set_d_chain(1_000_000);
+
+    assert_eq!(device.id, 42);
+    assert_eq!(device.register.a(), true);
+    assert_eq!(device.register.b(), 2);
+    assert_eq!(device.register.c(), 14);
+    assert_eq!(device.register.d(), 1_000_000);
+}
@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Flags {
+    a: bool,
+    b: B7,
+    c: u16,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    a: B3,
+    b: B10,
+}
+
+#[bitfield(filled = false, bit_order = be)]
+pub struct UnfilledBe {
+    a: B3,
+    b: B2,
+}
+
+const ZEROED: bool = Flags::new().is_zeroed();
+
+fn main() {
+    assert!(ZEROED);
+    assert!(!Flags::new().with_a(true).is_zeroed());
+    assert!(!Flags::new().with_c(1 << 15).is_zeroed());
+    let mut flags = Flags::new().with_b(3);
+    assert!(!flags.is_zeroed());
+    flags.set_b(0);
+    assert!(flags.is_zeroed());
+
+    assert!(Unfilled::new().is_zeroed());
+    assert!(!Unfilled::new().with_b(1 << 9).is_zeroed());
+    assert!(!Unfilled::from_bytes([0x00, 0b0001_0000]).unwrap().is_zeroed());
+
+    assert!(UnfilledBe::new().is_zeroed());
+    assert!(!UnfilledBe::new().with_b(1).is_zeroed());
+    assert!(!UnfilledBe::new().with_a(0b100).is_zeroed());
+}
@@ -0,0 +1,31 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Control {
+    enable: ActiveLow<bool>,
+    reset: ActiveLow<bool>,
+    ready: bool,
+    #[skip] __: B5,
+}
+
+fn main() {
+    assert_eq!(<ActiveLow<bool> as Specifier>::BITS, 1);
+    let control = Control::new();
+    assert!(control.enable());
+    assert!(control.reset());
+    assert!(!control.ready());
+
+    let control = Control::new().with_enable(true).with_reset(false);
+    assert_eq!(control.into_bytes(), [0b0000_0010]);
+    let mut control = Control::new().with_reset(false);
+    control.set_enable(false);
+    assert!(!control.enable());
+    assert_eq!(control.into_bytes(), [0b0000_0011]);
+
+    let control = Control::from_bytes([0b0000_0101]);
+    assert!(!control.enable());
+    assert!(control.reset());
+    assert!(control.ready());
+    assert_eq!(control.enable_or_err(), Ok(false));
+}
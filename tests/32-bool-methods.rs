@@ -0,0 +1,51 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Flags {
+    ready: bool,
+    error: ::core::primitive::bool,
+    mode: B5,
+    #[skip(setters)]
+    locked: bool,
+}
+
+#[bitfield(bit_order = be)]
+pub struct FlagsBe {
+    ready: bool,
+    mode: B6,
+    error: bool,
+}
+
+#[bitfield]
+pub struct TupleFlags(bool, B7);
+
+fn main() {
+    let mut flags = Flags::new();
+    flags.toggle_ready();
+    assert!(flags.ready());
+    flags.toggle_ready();
+    assert!(!flags.ready());
+
+    flags.set_error_if(false);
+    assert!(!flags.error());
+    flags.set_error_if(true);
+    assert!(flags.error());
+    flags.set_error_if(false);
+    assert!(flags.error());
+
+    assert_eq!(flags.ready_then(|| 1), None);
+    assert_eq!(flags.error_then(|| 2), Some(2));
+    assert_eq!(flags.locked_then(|| 3), None);
+    assert_eq!(flags.mode(), 0);
+
+    let mut flags = FlagsBe::new();
+    flags.toggle_ready();
+    flags.set_error_if(true);
+    assert_eq!(flags.into_bytes(), [0b1000_0001]);
+
+    let mut flags = TupleFlags::new();
+    flags.toggle_0();
+    flags.set_0_if(true);
+    assert_eq!(flags.get_0_then(|| "set"), Some("set"));
+    assert_eq!(flags.into_bytes(), [0b0000_0001]);
+}
@@ -0,0 +1,24 @@
+// Tests that a mismatching #[bits = N] attribute for a nested bitfield specifier
+// of another module reports both numbers of bits and the path of the specifier.
+
+use modular_bitfield::prelude::*;
+
+mod other {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(filled = false)]
+    #[derive(BitfieldSpecifier)]
+    pub struct Header {
+        pub kind: B3,
+        pub len: B2,
+    }
+}
+
+#[bitfield]
+pub struct Packet {
+    #[bits = 3]
+    header: other::Header,
+    body: B3,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct InterruptMask {
+    mask: Flags<24>,
+    vector: B8,
+}
+
+#[bitfield(bounds)]
+pub struct Wide {
+    single: Flags<1>,
+    many: Flags<127>,
+}
+
+fn main() {
+    assert_eq!(<Flags<24> as Specifier>::BITS, 24);
+    assert_eq!(<Flags<128> as Specifier>::BITS, 128);
+
+    let mut register = InterruptMask::new().with_vector(0xAB);
+    let mut mask = register.mask();
+    assert_eq!(mask, Flags::new());
+    assert_eq!(mask.count_ones(), 0);
+    for index in (0..24).step_by(3) {
+        mask.set(index, true);
+    }
+    mask.set(3, false);
+    assert_eq!(mask.count_ones(), 7);
+    assert_eq!(mask.iter().count(), 24);
+    assert!(mask.iter().enumerate().all(|(index, flag)| flag == (index % 3 == 0 && index != 3)));
+    register.set_mask(mask);
+    assert_eq!(register.into_bytes(), [0b0100_0001, 0b1001_0010, 0b0010_0100, 0xAB]);
+
+    let register = InterruptMask::from_bytes([0xFF, 0x00, 0x80, 0x01]);
+    assert!(register.mask().get(0));
+    assert!(register.mask().get(23));
+    assert!(!register.mask().get(8));
+    assert_eq!(register.mask().count_ones(), 9);
+    assert_eq!(register.vector(), 1);
+
+    let wide = Wide::new().with_many(Flags::new().with(126, true).with(0, true));
+    assert!(!wide.single().get(0));
+    assert!(wide.many().get(126));
+    assert_eq!(wide.into_bytes()[15], 0x80);
+    assert_eq!(Wide::many_max(), u128::MAX >> 1);
+
+    let result = std::panic::catch_unwind(|| Flags::<24>::new().get(24));
+    assert!(result.is_err());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct TooMany {
+    mask: Flags<129>,
+    rest: B7,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sample {
+    gain: B5,
+    level: B11,
+    raw: u16,
+    #[skip(setters)]
+    status: B8,
+    enabled: bool,
+    #[skip]
+    __: B7,
+}
+
+#[bitfield]
+pub struct Tuple(B4, B4);
+
+fn main() {
+    let mut sample = Sample::new();
+    sample.set_gain_truncated(0b1110_0110);
+    assert_eq!(sample.gain(), 0b0_0110);
+    sample.set_gain_saturating(0b1110_0110);
+    assert_eq!(sample.gain(), 0b1_1111);
+    sample.set_gain_saturating(7);
+    assert_eq!(sample.gain(), 7);
+
+    sample.set_level_truncated(u16::MAX);
+    assert_eq!(sample.level(), 0x07FF);
+    sample.set_level_truncated(0x0800);
+    assert_eq!(sample.level(), 0);
+    sample.set_level_saturating(0x0800);
+    assert_eq!(sample.level(), 0x07FF);
+
+    sample.set_raw_truncated(u16::MAX);
+    assert_eq!(sample.raw(), u16::MAX);
+    sample.set_raw_saturating(1234);
+    assert_eq!(sample.raw(), 1234);
+
+    let mut tuple = Tuple::new();
+    tuple.set_0_truncated(0x1F);
+    tuple.set_1_saturating(0x1F);
+    assert_eq!(tuple.into_bytes(), [0xFF]);
+}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sample {
+    enabled: bool,
+    #[skip(setters)]
+    gain: B7,
+}
+
+fn main() {
+    let mut sample = Sample::new();
+    sample.set_enabled_truncated(true);
+    sample.set_gain_saturating(1);
+}
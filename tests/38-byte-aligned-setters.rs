@@ -0,0 +1,72 @@
+// Tests that setters of fields covering whole bytes at byte-aligned offsets
+// only overwrite the bytes of the field itself.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Descriptor {
+    flags: u8,
+    len: B24,
+    addr: B32,
+    #[skip]
+    __: B4,
+    unaligned: B32,
+    tail: B4,
+    wide: u128,
+}
+
+#[bitfield(bit_order = be, unchecked_setters)]
+pub struct BigEndianDescriptor {
+    kind: B8,
+    len: u16,
+    head: B4,
+    unaligned: B16,
+    tail: B4,
+    addr: B32,
+}
+
+fn main() {
+    let mut descriptor = Descriptor::new();
+    descriptor.set_tail(0xF);
+    descriptor.set_flags(0xA5);
+    descriptor.set_len(0x12_3456);
+    descriptor.set_addr(0xDEAD_BEEF);
+    descriptor.set_unaligned(0x0BAD_F00D);
+    descriptor.set_wide(u128::MAX - 1);
+    assert_eq!(descriptor.flags(), 0xA5);
+    assert_eq!(descriptor.len(), 0x12_3456);
+    assert_eq!(descriptor.addr(), 0xDEAD_BEEF);
+    assert_eq!(descriptor.unaligned(), 0x0BAD_F00D);
+    assert_eq!(descriptor.tail(), 0xF);
+    assert_eq!(descriptor.wide(), u128::MAX - 1);
+    let bytes = descriptor.into_bytes();
+    assert_eq!(
+        bytes[..13],
+        [0xA5, 0x56, 0x34, 0x12, 0xEF, 0xBE, 0xAD, 0xDE, 0xD0, 0x00, 0xDF, 0xBA, 0xF0]
+    );
+    assert_eq!(bytes[13], 0xFE);
+    assert!(bytes[14..].iter().all(|&byte| byte == 0xFF));
+
+    let mut descriptor = Descriptor::from_bytes(bytes);
+    assert_eq!(descriptor.set_len_checked(0x0100_0000), Err(modular_bitfield::error::OutOfBounds));
+    descriptor.set_len(0);
+    assert_eq!(descriptor.flags(), 0xA5);
+    assert_eq!(descriptor.len(), 0);
+    assert_eq!(descriptor.addr(), 0xDEAD_BEEF);
+
+    let mut descriptor = BigEndianDescriptor::new();
+    descriptor.set_head(0xF);
+    descriptor.set_tail(0xF);
+    descriptor.set_kind(0x42);
+    descriptor.set_len(0x1234);
+    descriptor.set_unaligned(0xABCD);
+    unsafe { descriptor.set_addr_unchecked(0xDEAD_BEEF) };
+    assert_eq!(descriptor.kind(), 0x42);
+    assert_eq!(descriptor.len(), 0x1234);
+    assert_eq!(descriptor.unaligned(), 0xABCD);
+    assert_eq!(descriptor.addr(), 0xDEAD_BEEF);
+    assert_eq!(
+        descriptor.into_bytes(),
+        [0x42, 0x12, 0x34, 0xFA, 0xBC, 0xDF, 0xDE, 0xAD, 0xBE, 0xEF]
+    );
+}
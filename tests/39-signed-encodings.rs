@@ -0,0 +1,84 @@
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Adc {
+    twos: TwosComplement<B12>,
+    sign_magnitude: SignMagnitude<B12>,
+    excess: ExcessK<B8, 127>,
+}
+
+#[bitfield]
+pub struct Wide {
+    twos: TwosComplement<B64>,
+    sign_magnitude: SignMagnitude<u32>,
+    excess: ExcessK<B31, 1_073_741_824>,
+    flag: bool,
+}
+
+fn main() {
+    assert_eq!(<TwosComplement<B12> as Specifier>::BITS, 12);
+    assert_eq!(<ExcessK<B8, 127> as Specifier>::BITS, 8);
+    assert_eq!(core::mem::size_of::<Adc>(), 4);
+
+    // All encodings of a specifier share the same signed type.
+    let mut adc = Adc::new();
+    let value: i32 = -1000;
+    adc.set_twos(value);
+    adc.set_sign_magnitude(value);
+    adc.set_excess(-100_i16);
+    assert_eq!(adc.twos(), -1000);
+    assert_eq!(adc.sign_magnitude(), -1000);
+    assert_eq!(adc.excess(), -100);
+
+    // Two's complement bounds.
+    assert_eq!(adc.set_twos_checked(-2048), Ok(()));
+    assert_eq!(adc.twos(), -2048);
+    assert_eq!(adc.set_twos_checked(2047), Ok(()));
+    assert_eq!(adc.twos(), 2047);
+    assert_eq!(adc.set_twos_checked(2048), Err(OutOfBounds));
+    assert_eq!(adc.set_twos_checked(-2049), Err(OutOfBounds));
+
+    // Sign-magnitude bounds and negative zero.
+    assert_eq!(adc.set_sign_magnitude_checked(-2047), Ok(()));
+    assert_eq!(adc.sign_magnitude(), -2047);
+    assert_eq!(adc.set_sign_magnitude_checked(2047), Ok(()));
+    assert_eq!(adc.set_sign_magnitude_checked(-2048), Err(OutOfBounds));
+    assert_eq!(adc.set_sign_magnitude_checked(2048), Err(OutOfBounds));
+
+    // Excess-K bounds.
+    assert_eq!(adc.set_excess_checked(-127), Ok(()));
+    assert_eq!(adc.set_excess_checked(128), Ok(()));
+    assert_eq!(adc.excess(), 128);
+    assert_eq!(adc.set_excess_checked(-128), Err(OutOfBounds));
+    assert_eq!(adc.set_excess_checked(129), Err(OutOfBounds));
+
+    // Raw encodings.
+    let adc = Adc::new()
+        .with_twos(-1)
+        .with_sign_magnitude(-1)
+        .with_excess(0);
+    assert_eq!(adc.into_bytes(), [0xFF, 0x1F, 0x80, 0x7F]);
+    let adc = Adc::from_bytes([0x00, 0x00, 0x80, 0x00]);
+    assert_eq!(adc.sign_magnitude(), 0);
+    assert_eq!(adc.excess(), -127);
+
+    // Wide specifiers.
+    let mut wide = Wide::new();
+    wide.set_twos(i64::MIN as i128);
+    assert_eq!(wide.twos(), i64::MIN as i128);
+    wide.set_twos(i64::MAX as i128);
+    assert_eq!(wide.twos(), i64::MAX as i128);
+    assert_eq!(wide.set_twos_checked(i64::MAX as i128 + 1), Err(OutOfBounds));
+    wide.set_sign_magnitude(-(i32::MAX as i64));
+    assert_eq!(wide.sign_magnitude(), -(i32::MAX as i64));
+    wide.set_excess(-1_073_741_824);
+    assert_eq!(wide.excess(), -1_073_741_824);
+    wide.set_excess(1_073_741_823);
+    assert_eq!(wide.excess(), 1_073_741_823);
+    assert_eq!(wide.set_excess_checked(1_073_741_824), Err(OutOfBounds));
+    wide.set_flag(true);
+    assert!(wide.flag());
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct TooWide {
+    value: SignMagnitude<B128>,
+}
+
+fn main() {}
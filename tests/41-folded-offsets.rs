@@ -0,0 +1,193 @@
+// Tests that the offsets of fields with statically known bit widths are folded
+// correctly when mixed with fields whose bit widths are only known to the compiler.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 3]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 5,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug, Copy, Clone)]
+pub struct Inner {
+    x: B3,
+    y: bool,
+}
+
+#[bitfield]
+pub struct Mixed {
+    a: B5,
+    mode: Mode,
+    b: bool,
+    inner: Inner,
+    c: u8,
+    #[repeat = 2]
+    d: B4,
+    e: ActiveLow<bool>,
+    f: B10,
+}
+
+#[bitfield]
+pub struct Many {
+    f0: B3,
+    f1: B3,
+    f2: B3,
+    f3: B3,
+    f4: B3,
+    f5: B3,
+    f6: B3,
+    f7: B3,
+    f8: B3,
+    f9: B3,
+    f10: B3,
+    f11: B3,
+    f12: B3,
+    f13: B3,
+    f14: B3,
+    f15: B3,
+    f16: B3,
+    f17: B3,
+    f18: B3,
+    f19: B3,
+    f20: B3,
+    f21: B3,
+    f22: B3,
+    f23: B3,
+    f24: B3,
+    f25: B3,
+    f26: B3,
+    f27: B3,
+    f28: B3,
+    f29: B3,
+    f30: B3,
+    f31: B3,
+    f32: B3,
+    f33: B3,
+    f34: B3,
+    f35: B3,
+    f36: B3,
+    f37: B3,
+    f38: B3,
+    f39: B3,
+    f40: B3,
+    f41: B3,
+    f42: B3,
+    f43: B3,
+    f44: B3,
+    f45: B3,
+    f46: B3,
+    f47: B3,
+    f48: B3,
+    f49: B3,
+    f50: B3,
+    f51: B3,
+    f52: B3,
+    f53: B3,
+    f54: B3,
+    f55: B3,
+    f56: B3,
+    f57: B3,
+    f58: B3,
+    f59: B3,
+    f60: B3,
+    f61: B3,
+    f62: B3,
+    f63: B3,
+    f64: B3,
+    f65: B3,
+    f66: B3,
+    f67: B3,
+    f68: B3,
+    f69: B3,
+    f70: B3,
+    f71: B3,
+    f72: B3,
+    f73: B3,
+    f74: B3,
+    f75: B3,
+    f76: B3,
+    f77: B3,
+    f78: B3,
+    f79: B3,
+    f80: B3,
+    f81: B3,
+    f82: B3,
+    f83: B3,
+    f84: B3,
+    f85: B3,
+    f86: B3,
+    f87: B3,
+    f88: B3,
+    f89: B3,
+    f90: B3,
+    f91: B3,
+    f92: B3,
+    f93: B3,
+    f94: B3,
+    f95: B3,
+    f96: B3,
+    f97: B3,
+    f98: B3,
+    f99: B3,
+    f100: B3,
+    f101: B3,
+    f102: B3,
+    f103: B3,
+    f104: B3,
+    f105: B3,
+    f106: B3,
+    f107: B3,
+    f108: B3,
+    f109: B3,
+    last: B4,
+    mode: Mode,
+    tail: B7,
+}
+
+fn main() {
+    let mut mixed = Mixed::new()
+        .with_a(0b1_0101)
+        .with_mode(Mode::C)
+        .with_b(true)
+        .with_inner(Inner::new().with_x(0b110).with_y(true))
+        .with_c(0xA5)
+        .with_e(true)
+        .with_f(0b10_0101_0101);
+    mixed.set_d_at(1, 0b1001).unwrap();
+    assert_eq!(mixed.a(), 0b1_0101);
+    assert_eq!(mixed.mode(), Mode::C);
+    assert!(mixed.b());
+    assert_eq!(mixed.inner().x(), 0b110);
+    assert!(mixed.inner().y());
+    assert_eq!(mixed.c(), 0xA5);
+    assert_eq!(mixed.d_at(0), Ok(0));
+    assert_eq!(mixed.d_at(1), Ok(0b1001));
+    assert!(mixed.e());
+    assert_eq!(mixed.f(), 0b10_0101_0101);
+    assert_eq!(
+        mixed.into_bytes(),
+        [0b1011_0101, 0b1011_1101, 0b0001_0100, 0b0101_0010, 0b1001_0101],
+    );
+
+    let mut many = Many::new();
+    many.set_f0(0b111);
+    many.set_f55(0b101);
+    many.set_f109(0b011);
+    many.set_last(0b1010);
+    many.set_mode(Mode::B);
+    many.set_tail(0b111);
+    assert_eq!(many.f0(), 0b111);
+    assert_eq!(many.f1(), 0);
+    assert_eq!(many.f55(), 0b101);
+    assert_eq!(many.f109(), 0b011);
+    assert_eq!(many.last(), 0b1010);
+    assert_eq!(many.mode(), Mode::B);
+    assert_eq!(many.tail(), 0b111);
+    let bytes = many.into_bytes();
+    assert_eq!(bytes.len(), 43);
+    assert_eq!(bytes[42], 0b0000_1110);
+}
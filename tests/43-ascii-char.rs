@@ -0,0 +1,35 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Station {
+    id: AsciiChar,
+    class: AsciiChar<7>,
+    active: bool,
+}
+
+fn main() {
+    assert_eq!(<AsciiChar as Specifier>::BITS, 8);
+    assert_eq!(<AsciiChar<7> as Specifier>::BITS, 7);
+
+    let station = Station::new().with_id(b'K').with_class(b'z').with_active(true);
+    assert_eq!(station.id(), b'K');
+    assert_eq!(station.class(), b'z');
+    assert!(station.active());
+    assert_eq!(station.into_bytes(), [b'K', b'z' | 0x80]);
+
+    let mut station = Station::new();
+    assert!(station.set_id_checked(0x80).is_err());
+    assert!(station.set_class_checked(0xFF).is_err());
+    assert_eq!(station.id(), 0);
+    assert!(station.set_id_checked(0x7F).is_ok());
+    assert_eq!(station.id(), 0x7F);
+
+    let station = Station::from_bytes([0xE9, 0xFF]);
+    assert_eq!(
+        station.id_or_err(),
+        Err(modular_bitfield::error::InvalidBitPattern::new(0xE9)),
+    );
+    assert_eq!(station.class(), 0x7F);
+    assert!(station.active());
+}
@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    low: B4,
+    flags: B6,
+    raw: u8,
+    #[skip(getters)]
+    write_only: B6,
+}
+
+#[bitfield]
+pub struct Tuple(B4, B4);
+
+fn main() {
+    let mut status = Status::new().with_low(0b1111).with_flags(0b00_1100);
+    status.or_flags(0b11_0001);
+    assert_eq!(status.flags(), 0b11_1101);
+    status.and_flags(0b10_1010);
+    assert_eq!(status.flags(), 0b10_1000);
+    status.xor_flags(0b11_1111);
+    assert_eq!(status.flags(), 0b01_0111);
+
+    // Bits that do not fit into the field are discarded and other fields are unaffected.
+    status.or_flags(0b1100_0000);
+    assert_eq!(status.flags(), 0b01_0111);
+    status.xor_flags(0xFF);
+    assert_eq!(status.flags(), 0b10_1000);
+    status.and_flags(0b0100_0000);
+    assert_eq!(status.flags(), 0);
+    assert_eq!(status.low(), 0b1111);
+    assert_eq!(status.raw(), 0);
+
+    status.or_raw(0xF0);
+    status.xor_raw(0xFF);
+    assert_eq!(status.raw(), 0x0F);
+    assert_eq!(status.low(), 0b1111);
+
+    let mut tuple = Tuple::new();
+    tuple.or_0(0x1F);
+    tuple.xor_1(0x13);
+    assert_eq!(tuple.into_bytes(), [0x3F]);
+}
@@ -0,0 +1,63 @@
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+#[derive(Copy, Clone)]
+pub struct Register {
+    offset: S12,
+    flag: S1,
+    byte: S8,
+    rest: S3,
+}
+
+#[bitfield]
+pub struct Wide {
+    small: S63,
+    sign: bool,
+    large: S128,
+}
+
+fn main() {
+    assert_eq!(<S12 as Specifier>::BITS, 12);
+    assert_eq!(<S128 as Specifier>::BITS, 128);
+    assert_eq!(core::mem::size_of::<Register>(), 3);
+
+    // Getters sign extend to the signed primitive of the same width.
+    let mut reg = Register::new();
+    reg.set_offset(-1000_i16);
+    reg.set_flag(-1_i8);
+    reg.set_byte(i8::MIN);
+    reg.set_rest(3_i8);
+    assert_eq!(reg.offset(), -1000);
+    assert_eq!(reg.flag(), -1);
+    assert_eq!(reg.byte(), i8::MIN);
+    assert_eq!(reg.rest(), 3);
+    assert_eq!(reg.into_bytes(), [0x18, 0x1C, 0x70]);
+
+    // Checked setters reject values out of range for the bit width.
+    assert_eq!(reg.set_offset_checked(-2048), Ok(()));
+    assert_eq!(reg.offset(), -2048);
+    assert_eq!(reg.set_offset_checked(2047), Ok(()));
+    assert_eq!(reg.offset(), 2047);
+    assert_eq!(reg.set_offset_checked(2048), Err(OutOfBounds));
+    assert_eq!(reg.set_offset_checked(-2049), Err(OutOfBounds));
+    assert_eq!(reg.set_flag_checked(0), Ok(()));
+    assert_eq!(reg.set_flag_checked(1), Err(OutOfBounds));
+    assert_eq!(reg.set_rest_checked(-4), Ok(()));
+    assert_eq!(reg.rest(), -4);
+    assert_eq!(reg.set_rest_checked(4), Err(OutOfBounds));
+    assert_eq!(reg.set_rest_checked(-5), Err(OutOfBounds));
+    assert_eq!(reg.offset(), 2047);
+
+    // Full width and wide fields.
+    let wide = Wide::new()
+        .with_small(i64::MIN / 2)
+        .with_sign(true)
+        .with_large(i128::MIN);
+    assert_eq!(wide.small(), i64::MIN / 2);
+    assert!(wide.sign());
+    assert_eq!(wide.large(), i128::MIN);
+    assert!(wide.with_small_checked(i64::MAX).is_err());
+}
@@ -0,0 +1,62 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 2]
+pub enum Mode {
+    A,
+    B,
+    C,
+}
+
+#[bitfield]
+#[derive(Copy, Clone)]
+pub struct Entry {
+    address: Option<B6>,
+    mode: Option<Mode>,
+    flag: Option<bool>,
+    nested: Option<Option<B2>>,
+    byte: Option<B7>,
+}
+
+fn main() {
+    assert_eq!(<Option<B6> as Specifier>::BITS, 7);
+    assert_eq!(<Option<Option<B2>> as Specifier>::BITS, 4);
+    assert_eq!(core::mem::size_of::<Entry>(), 3);
+
+    // All fields are `None` by default.
+    let entry = Entry::new();
+    assert_eq!(entry.address(), None);
+    assert_eq!(entry.mode(), None);
+    assert_eq!(entry.flag(), None);
+    assert_eq!(entry.nested(), None);
+    assert_eq!(entry.byte(), None);
+
+    let entry = entry
+        .with_address(Some(0x2A))
+        .with_mode(Some(Mode::C))
+        .with_flag(Some(false))
+        .with_nested(Some(None))
+        .with_byte(Some(0x7F));
+    assert_eq!(entry.address(), Some(0x2A));
+    assert_eq!(entry.mode(), Some(Mode::C));
+    assert_eq!(entry.flag(), Some(false));
+    assert_eq!(entry.nested(), Some(None));
+    assert_eq!(entry.byte(), Some(0x7F));
+    let entry = entry.with_nested(Some(Some(3))).with_address(None);
+    assert_eq!(entry.nested(), Some(Some(3)));
+    assert_eq!(entry.address(), None);
+
+    // The validity bit follows the bits of the value.
+    let entry = Entry::new().with_address(Some(0b00_0101));
+    assert_eq!(entry.into_bytes(), [0b0100_0101, 0x00, 0x00]);
+
+    // Values are checked against the bit width of the inner specifier.
+    let mut entry = Entry::new();
+    assert!(entry.set_address_checked(Some(0x40)).is_err());
+    assert!(entry.set_address_checked(Some(0x3F)).is_ok());
+    assert_eq!(entry.address(), Some(0x3F));
+
+    // Invalid bit patterns of the inner specifier are reported for `Some` values.
+    let entry = Entry::from_bytes([0b1000_0000, 0b0000_0011, 0x00]);
+    assert!(entry.mode_or_err().is_err());
+}
@@ -0,0 +1,51 @@
+use core::num::{
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU8,
+};
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Copy, Clone)]
+pub struct Descriptor {
+    len: Option<NonZeroB7>,
+    flag: bool,
+    id: NonZeroB12,
+    extra: Option<Option<NonZeroB3>>,
+    wide: Option<NonZeroB24>,
+}
+
+fn main() {
+    assert_eq!(<NonZeroB7 as Specifier>::BITS, 7);
+    assert_eq!(<Option<NonZeroB7> as Specifier>::BITS, 7);
+    assert_eq!(<Option<Option<NonZeroB3>> as Specifier>::BITS, 4);
+    assert_eq!(core::mem::size_of::<Descriptor>(), 6);
+
+    // A zeroed `NonZeroBN` field is an invalid bit pattern while `Option<NonZeroBN>` is `None`.
+    let descriptor = Descriptor::new();
+    assert_eq!(descriptor.len(), None);
+    assert_eq!(descriptor.extra(), None);
+    assert_eq!(descriptor.wide(), None);
+    assert!(descriptor.id_or_err().is_err());
+
+    let descriptor = descriptor
+        .with_len(NonZeroU8::new(0x7F))
+        .with_id(NonZeroU16::new(0xABC).unwrap())
+        .with_extra(Some(None))
+        .with_wide(NonZeroU32::new(0xF_FFFF));
+    assert_eq!(descriptor.len(), NonZeroU8::new(0x7F));
+    assert!(!descriptor.flag());
+    assert_eq!(descriptor.id().get(), 0xABC);
+    assert_eq!(descriptor.extra(), Some(None));
+    assert_eq!(descriptor.wide(), NonZeroU32::new(0xF_FFFF));
+    assert_eq!(descriptor.into_bytes(), [0x7F, 0xBC, 0x8A, 0xFF, 0xFF, 0x0F]);
+
+    let descriptor = descriptor.with_extra(Some(NonZeroU8::new(5))).with_len(None);
+    assert_eq!(descriptor.extra(), Some(NonZeroU8::new(5)));
+    assert_eq!(descriptor.len(), None);
+
+    // Values exceeding the bit width are rejected.
+    let mut descriptor = descriptor;
+    assert!(descriptor.set_len_checked(NonZeroU8::new(0x80)).is_err());
+    assert!(descriptor.set_id_checked(NonZeroU16::new(0x1000).unwrap()).is_err());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(access_stats, access_stats)]
+pub struct Control {
+    enabled: bool,
+    mode: B7,
+}
+
+fn main() {}
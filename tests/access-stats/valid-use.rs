@@ -0,0 +1,46 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(access_stats)]
+pub struct Control {
+    enabled: bool,
+    #[skip(getters)]
+    mode: B3,
+    #[reserved]
+    reserved: B4,
+    divider: u16,
+}
+
+#[bitfield(access_stats, split_at = "divider", minimal)]
+pub struct Split {
+    prescaler: u8,
+    divider: u8,
+}
+
+fn main() {
+    let mut control = Control::new();
+    control.set_enabled(true);
+    control.set_mode(5);
+    control.set_divider_checked(300).unwrap();
+    let control = control.with_divider(400);
+    assert!(control.enabled());
+    assert_eq!(control.divider_or_err(), Ok(400));
+    assert_eq!(
+        Control::access_stats(),
+        [("enabled", 1, 1), ("mode", 0, 1), ("reserved", 0, 0), ("divider", 1, 2)]
+    );
+    Control::reset_access_stats();
+    assert_eq!(
+        Control::access_stats(),
+        [("enabled", 0, 0), ("mode", 0, 0), ("reserved", 0, 0), ("divider", 0, 0)]
+    );
+
+    let mut split = Split::new();
+    {
+        let (mut first, mut second) = split.split_mut();
+        first.set_prescaler(1);
+        second.set_divider(2);
+        assert_eq!(second.divider(), 2);
+    }
+    assert_eq!(split.prescaler(), 1);
+    assert_eq!(Split::access_stats(), [("prescaler", 1, 1), ("divider", 1, 1)]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(accessors_mod = "not a name")]
+pub struct Frame {
+    len: B12,
+    kind: B4,
+}
+
+fn main() {}
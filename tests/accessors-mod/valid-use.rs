@@ -0,0 +1,65 @@
+use modular_bitfield::{modify_fields, prelude::*};
+
+#[bitfield(accessors_mod = "fields")]
+#[derive(Debug, Hash)]
+pub struct Frame {
+    pub len: B12,
+    #[group = "tcp"]
+    pub ack: bool,
+    #[group = "tcp"]
+    pub fin: bool,
+    pub kind: B2,
+    #[skip(setters)]
+    pub checksum: u8,
+}
+
+impl Frame {
+    pub fn len(&self) -> usize {
+        usize::from(self.fields().len()) + 4
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields().len() == 0
+    }
+}
+
+#[bitfield(accessors_mod = "regs", bit_order = be, minimal)]
+pub struct Compact {
+    first: B3,
+    second: B5,
+}
+
+fn main() {
+    let mut frame = Frame::new();
+    assert!(frame.is_empty());
+    frame.fields_mut().set_len(100);
+    frame.fields_mut().with_kind(2).set_ack(true);
+    assert_eq!(frame.fields().len(), 100);
+    assert_eq!(frame.fields_mut().len(), 100);
+    assert_eq!(frame.fields().kind(), 2);
+    assert_eq!(frame.len(), 104);
+    assert!(frame.tcp().ack);
+    assert!(!frame.tcp().fin);
+    frame.set_tcp(TcpFlags { ack: false, fin: true });
+    assert!(frame.fields().fin());
+    assert!(!frame.fields().ack());
+    assert_eq!(frame.fields().checksum(), 0);
+    assert_eq!(frame.fields().len_or_err(), Ok(100));
+
+    let reg = &mut frame as *mut Frame;
+    unsafe {
+        modify_fields!(reg, Frame { len: 7, kind: 1 });
+    }
+    assert_eq!(frame.len(), 11);
+    assert_eq!(frame.fields().kind(), 1);
+    assert_eq!(
+        format!("{:?}", frame),
+        "Frame { len: 7, ack: false, fin: true, kind: 1, checksum: 0 }",
+    );
+
+    let mut compact = Compact::new();
+    compact.regs_mut().set_first(5);
+    compact.regs_mut().set_second(17);
+    assert_eq!(compact.regs().first(), 5);
+    assert_eq!(compact.regs().second(), 17);
+}
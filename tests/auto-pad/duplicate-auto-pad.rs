@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, auto_pad, auto_pad)]
+pub struct Status {
+    ready: bool,
+    code: B3,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(auto_pad)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {}
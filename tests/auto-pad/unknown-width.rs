@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield(bits = 16, auto_pad)]
+pub struct Status {
+    ready: bool,
+    mode: Mode,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+#[bitfield(bits = 32, auto_pad)]
+#[derive(Debug)]
+pub struct Status {
+    ready: bool,
+    #[bits = 2]
+    mode: Mode,
+    code: B5,
+}
+
+#[bitfield(bits = 200, auto_pad)]
+pub struct Wide {
+    header: u8,
+}
+
+#[bitfield(bits = 16, auto_pad)]
+pub struct Exact {
+    low: u8,
+    high: u8,
+}
+
+#[bitfield(bits = 24, auto_pad)]
+pub struct Tuple(B4, bool);
+
+fn main() {
+    let status = Status::new()
+        .with_ready(true)
+        .with_mode(Mode::Auto)
+        .with_code(0b10101);
+    assert_eq!(status.mode(), Mode::Auto);
+    assert_eq!(status.into_bytes(), [0b1010_1101, 0x00, 0x00, 0x00]);
+    assert_eq!(core::mem::size_of::<Status>(), 4);
+    assert_eq!(
+        format!("{:?}", Status::new().with_code(3)),
+        "Status { ready: false, mode: Off, code: 3 }",
+    );
+
+    assert_eq!(core::mem::size_of::<Wide>(), 25);
+    assert_eq!(Wide::new().with_header(0xAB).into_bytes()[0], 0xAB);
+
+    let exact = Exact::new().with_low(1).with_high(2);
+    assert_eq!(exact.into_bytes(), [1, 2]);
+
+    let tuple = Tuple::new().with_0(0xF).with_1(true);
+    assert_eq!(tuple.into_bytes(), [0x1F, 0x00, 0x00]);
+}
@@ -0,0 +1,35 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_order = be)]
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub struct ColorEntry {
+    r: B5,
+    g: B5,
+    b: B5,
+    unused: B1,
+}
+
+fn main() {
+    for i in 0..=std::u16::MAX {
+        let entry = ColorEntry::from_bytes(i.to_be_bytes());
+        assert_eq!(entry.r(), ((i >> 11) & 0b1_1111) as u8);
+        assert_eq!(entry.g(), ((i >> 6) & 0b1_1111) as u8);
+        assert_eq!(entry.b(), ((i >> 1) & 0b1_1111) as u8);
+        assert_eq!(entry.unused(), (i & 0b1) as u8);
+
+        let mut new = ColorEntry::new();
+        new.set_r(entry.r());
+        assert_eq!(new.r(), entry.r());
+        new.set_g(entry.g());
+        assert_eq!(new.g(), entry.g());
+        new.set_b(entry.b());
+        assert_eq!(new.b(), entry.b());
+        new.set_unused(entry.unused());
+
+        assert_eq!(new.r(), entry.r());
+        assert_eq!(new.g(), entry.g());
+        assert_eq!(new.b(), entry.b());
+        assert_eq!(new.unused(), entry.unused());
+        assert_eq!(new.into_bytes(), i.to_be_bytes());
+    }
+}
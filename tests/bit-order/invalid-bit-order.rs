@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_order = msb)]
+pub struct InvalidBitOrder {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(bit_order = be, bit_order = le)]
+pub struct DuplicateBitOrder {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(order = be)]
+pub struct InvalidOrder {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(order = msb, order = lsb)]
+pub struct DuplicateOrder {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(bit_order = be, order = msb)]
+pub struct OrderAndBitOrder {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(order = msb)]
+pub struct Header {
+    version: B4,
+    ihl: B4,
+    total_length: u16,
+    flags: B3,
+    fragment_offset: B13,
+}
+
+#[bitfield(order = lsb)]
+pub struct Explicit {
+    a: B4,
+    b: B4,
+}
+
+bitfields! {
+    #![bitfield(bit_order = be)]
+
+    // The `order` alias overrides the shared `bit_order` parameter.
+    #[bitfield(order = lsb)]
+    pub struct Overridden {
+        a: B4,
+        b: B4,
+    }
+}
+
+fn main() {
+    let header = Header::new()
+        .with_version(4)
+        .with_ihl(5)
+        .with_total_length(0x1234)
+        .with_flags(0b010)
+        .with_fragment_offset(0x0ABC);
+    assert_eq!(header.into_bytes(), [0x45, 0x12, 0x34, 0x4A, 0xBC]);
+
+    let explicit = Explicit::new().with_a(0x1).with_b(0x2);
+    assert_eq!(explicit.into_bytes(), [0x21]);
+
+    let overridden = Overridden::new().with_a(0x1).with_b(0x2);
+    assert_eq!(overridden.into_bytes(), [0x21]);
+}
@@ -0,0 +1,81 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_order = be)]
+pub struct Header {
+    version: B4,
+    ihl: B4,
+    total_length: u16,
+    flags: B3,
+    fragment_offset: B13,
+}
+
+#[bitfield(bit_order = be, filled = false)]
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Nibbles {
+    hi: B2,
+    lo: B3,
+}
+
+#[bitfield(bit_order = be, bit_queries)]
+#[repr(u32)]
+pub struct Word {
+    nibbles: Nibbles,
+    #[bits = 5]
+    nested: Nibbles,
+    value: B22,
+}
+
+#[bitfield(bit_order = le)]
+pub struct Explicit {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let header = Header::new()
+        .with_version(4)
+        .with_ihl(5)
+        .with_total_length(0x1234)
+        .with_flags(0b010)
+        .with_fragment_offset(0x0ABC);
+    assert_eq!(header.into_bytes(), [0x45, 0x12, 0x34, 0x4A, 0xBC]);
+    let header = Header::from_bytes([0x45, 0x12, 0x34, 0x4A, 0xBC]);
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.ihl(), 5);
+    assert_eq!(header.total_length(), 0x1234);
+    assert_eq!(header.flags(), 0b010);
+    assert_eq!(header.fragment_offset(), 0x0ABC);
+
+    // Undefined bits are the least significant bits of the last byte.
+    let nibbles = Nibbles::new().with_hi(0b10).with_lo(0b011);
+    assert_eq!(nibbles.into_bytes(), [0b1001_1000]);
+    assert_eq!(Nibbles::from_bytes([0b1001_1000]), Ok(nibbles));
+    assert_eq!(
+        Nibbles::from_bytes([0b1001_1101]),
+        Err(modular_bitfield::error::PaddingBitsSet { byte_index: 0, mask: 0b0000_0101 })
+    );
+
+    let word = Word::new()
+        .with_nibbles(nibbles)
+        .with_nested(Nibbles::new().with_lo(0b111))
+        .with_value(0x3F_FFFF);
+    assert_eq!(word.nibbles(), nibbles);
+    assert_eq!(word.nested(), Nibbles::new().with_lo(0b111));
+    assert_eq!(word.value(), 0x3F_FFFF);
+    assert_eq!(
+        u32::from(word),
+        0b10011_00111_1111111111111111111111_u32
+    );
+    let word = Word::from(0b00001_00000_0000000000000000000001_u32);
+    assert_eq!(word.nibbles(), Nibbles::new().with_lo(0b001));
+    assert!(word.nested_is_zero());
+    assert_eq!(word.nibbles_significant_bits(), 1);
+    assert_eq!(word.value_significant_bits(), 1);
+    let word = Word::from(0b00000_00000_1000000000000000000000_u32);
+    assert!(word.nibbles_is_zero());
+    assert!(!word.value_is_zero());
+    assert_eq!(word.value_significant_bits(), 22);
+
+    let explicit = Explicit::new().with_a(0x1).with_b(0x2);
+    assert_eq!(explicit.into_bytes(), [0x21]);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_queries)]
+pub struct Varint {
+    length: B4,
+    #[skip(getters)]
+    payload: B4,
+}
+
+fn main() {
+    let varint = Varint::new();
+    let _ = varint.payload_is_zero();
+}
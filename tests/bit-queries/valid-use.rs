@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_queries)]
+pub struct Varint {
+    flag: bool,
+    small: B3,
+    payload: B100,
+    tail: B8,
+    #[skip(getters)]
+    hidden: B16,
+}
+
+#[bitfield(bit_queries)]
+pub struct Tuple(B5, B3);
+
+fn main() {
+    let tuple = Tuple::new().with_0(0b00110);
+    assert!(!tuple.get_0_is_zero());
+    assert!(tuple.get_1_is_zero());
+    assert_eq!(tuple.get_0_significant_bits(), 3);
+
+    let mut varint = Varint::new();
+    assert!(varint.flag_is_zero());
+    assert!(varint.small_is_zero());
+    assert!(varint.payload_is_zero());
+    assert!(varint.tail_is_zero());
+    assert_eq!(varint.payload_significant_bits(), 0);
+
+    // Bits of neighboring fields do not leak into the queries.
+    varint.set_flag(true);
+    varint.set_tail(0xFF);
+    varint.set_hidden(0xFFFF);
+    assert!(!varint.flag_is_zero());
+    assert_eq!(varint.flag_significant_bits(), 1);
+    assert!(varint.small_is_zero());
+    assert!(varint.payload_is_zero());
+    assert_eq!(varint.payload_significant_bits(), 0);
+    assert_eq!(varint.tail_significant_bits(), 8);
+
+    for shift in 0..100 {
+        varint.set_payload(1 << shift);
+        assert!(!varint.payload_is_zero());
+        assert_eq!(varint.payload_significant_bits(), shift + 1);
+    }
+    varint.set_payload((1 << 100) - 1);
+    assert_eq!(varint.payload_significant_bits(), 100);
+    varint.set_small(0b010);
+    assert_eq!(varint.small_significant_bits(), 2);
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[bits(0..4)]
+    #[bits(0..4)]
+    a: B4,
+    #[bits(4..8)]
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[bits(4..4)]
+    b: B8,
+}
+
+fn main() {}
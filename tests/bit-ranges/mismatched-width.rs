@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[bits(0..3)]
+    a: B4,
+    #[bits(3..7)]
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[bits(4..8)]
+    b: B4,
+    a: B4,
+}
+
+fn main() {}
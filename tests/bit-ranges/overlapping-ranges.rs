@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[bits(4..8)]
+    b: B4,
+    #[bits(0..5)]
+    a: B5,
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use modular_bitfield::prelude::*;
+
+/// Only the documented fields of the datasheet are declared.
+#[bitfield]
+#[derive(Debug)]
+pub struct Status {
+    #[bits(24..=27)]
+    flags: B4,
+    #[bits(4..8)]
+    code: B4,
+    #[bits(30..32)]
+    state: B2,
+}
+
+/// Gaps wider than 128 bits are padded as well.
+#[bitfield]
+pub struct Wide {
+    #[bits(0..8)]
+    first: u8,
+    #[bits(200..208)]
+    last: u8,
+}
+
+fn main() {
+    let status = Status::new()
+        .with_code(0xA)
+        .with_flags(0x5)
+        .with_state(0b11);
+    assert_eq!(status.into_bytes(), [0xA0, 0x00, 0x00, 0xC5]);
+    let status = Status::from_bytes([0xFF, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(status.code(), 0xF);
+    assert_eq!(status.flags(), 0xF);
+    assert_eq!(status.state(), 0b11);
+    assert_eq!(
+        format!("{:?}", Status::new().with_flags(1)),
+        "Status { code: 0, flags: 1, state: 0 }",
+    );
+
+    assert_eq!(core::mem::size_of::<Wide>(), 26);
+    let wide = Wide::new().with_first(0x12).with_last(0x34);
+    let bytes = wide.into_bytes();
+    assert_eq!(bytes[0], 0x12);
+    assert_eq!(bytes[25], 0x34);
+    assert!(bytes[1..25].iter().all(|byte| *byte == 0));
+}
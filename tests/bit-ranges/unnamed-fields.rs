@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control(#[bits(4..8)] B4, #[bits(0..4)] B4);
+
+fn main() {}
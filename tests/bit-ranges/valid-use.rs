@@ -0,0 +1,54 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Off, Low, High, Auto,
+}
+
+/// Fields are declared in alphabetical order as listed by the datasheet.
+#[bitfield]
+#[derive(Debug)]
+pub struct Control {
+    #[bits(0..=0)]
+    enable: bool,
+    #[bits(8..16)]
+    gain: B8,
+    #[bits(3..5)]
+    mode: Mode,
+    #[bits(16..32)]
+    period: u16,
+    #[bits(1..3)]
+    prescaler: B2,
+    /// Reserved for future use.
+    #[bits(5..8)]
+    #[skip]
+    __: B3,
+}
+
+/// Declaration order matching the storage order is fine as well.
+#[bitfield]
+pub struct InOrder {
+    #[bits(0..4)]
+    lo: B4,
+    #[bits(4..8)]
+    hi: B4,
+}
+
+fn main() {
+    let control = Control::new()
+        .with_enable(true)
+        .with_prescaler(0b10)
+        .with_mode(Mode::High)
+        .with_gain(0xAB)
+        .with_period(0x1234);
+    assert_eq!(control.into_bytes(), [0b0001_0101, 0xAB, 0x34, 0x12]);
+    let control = Control::from_bytes([0b0001_0101, 0xAB, 0x34, 0x12]);
+    assert!(control.enable());
+    assert_eq!(control.prescaler(), 0b10);
+    assert_eq!(control.mode(), Mode::High);
+    assert_eq!(control.gain(), 0xAB);
+    assert_eq!(control.period(), 0x1234);
+
+    let in_order = InOrder::new().with_lo(0x1).with_hi(0x2);
+    assert_eq!(in_order.into_bytes(), [0x21]);
+}
@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+bitfields! {
+    pub struct Control {
+        enable: bool,
+        prescaler: B7,
+    }
+
+    pub enum Mode {
+        Off,
+        On,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use modular_bitfield::prelude::*;
+
+bitfields! {
+    #![bitfield(bounds)]
+
+    pub struct Control {
+        enable: bool,
+        prescaler: B7,
+    }
+
+    #[bitfield(bounds, bounds)]
+    pub struct Status {
+        ready: bool,
+        errors: B7,
+    }
+}
+
+fn main() {
+    let _ = Control::new();
+}
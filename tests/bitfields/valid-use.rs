@@ -0,0 +1,56 @@
+use modular_bitfield::prelude::*;
+
+bitfields! {
+    #![bitfield(bit_order = be, bounds, filled = false)]
+    #![derive(Debug, Clone, Copy, PartialEq)]
+
+    /// The control register.
+    pub struct Control {
+        enable: bool,
+        prescaler: B6,
+    }
+
+    #[bitfield(bit_order = le, masks)]
+    pub struct Status {
+        ready: bool,
+        errors: B5,
+    }
+
+    #[bitfield]
+    #[derive(BitfieldSpecifier)]
+    pub struct Nested {
+        low: B2,
+        high: B2,
+    }
+
+    pub(crate) struct Tuple(B3, B3);
+}
+
+bitfields! {
+    pub struct Plain {
+        first: B4,
+        second: B4,
+    }
+}
+
+bitfields! {}
+
+fn main() {
+    let control = Control::new().with_enable(true).with_prescaler(1);
+    assert_eq!(control.into_bytes(), [0b1000_0010]);
+    assert_eq!(Control::prescaler_max(), 63);
+    assert_eq!(control, control.clone());
+    assert!(format!("{:?}", control).starts_with("Control"));
+
+    let status = Status::new().with_ready(true).with_errors(2);
+    assert_eq!(status.into_bytes(), [0b0000_0101]);
+    assert_eq!(Status::errors_mask_for(1), [0b0000_0010]);
+    assert_eq!(Status::errors_max(), 31);
+
+    assert_eq!(<Nested as Specifier>::BITS, 4);
+    let tuple = Tuple::new().with_0(1).with_1(2);
+    assert_eq!(tuple.into_bytes(), [0b0010_1000]);
+
+    let plain = Plain::new().with_first(1).with_second(2);
+    assert_eq!(plain.into_bytes(), [0x21]);
+}
@@ -0,0 +1,103 @@
+//! Randomized tests comparing the bitstream buffers against a reference model.
+
+use modular_bitfield::bitstream::{
+    PopBits,
+    PopBuffer,
+    PushBits,
+    PushBuffer,
+};
+
+/// A xorshift generator to keep the tests deterministic.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a random amount of bits within `1..=8`.
+    fn amount(&mut self) -> u32 {
+        (self.next() % 8) as u32 + 1
+    }
+}
+
+/// Returns the `amount` least significant bits of `bits`.
+fn mask(amount: u32, bits: u8) -> u8 {
+    (bits as u16 & ((1 << amount) - 1)) as u8
+}
+
+macro_rules! fuzz_tests {
+    ( $( ($push:ident, $pop:ident, $roundtrip:ident, $type:ty) ),* $(,)? ) => {
+        $(
+            #[test]
+            fn $push() {
+                const WIDTH: u32 = <$type>::BITS;
+                let mut rng = XorShift(0x2545_F491_4F6C_DD1D ^ WIDTH as u64);
+                for _ in 0..1000 {
+                    let mut buffer = <PushBuffer<$type>>::new();
+                    let mut expected = 0_u128;
+                    for _ in 0..rng.next() % 40 {
+                        let amount = rng.amount();
+                        let bits = rng.next() as u8;
+                        buffer.push_bits(amount, bits);
+                        expected = (expected << amount) | mask(amount, bits) as u128;
+                    }
+                    let expected = expected & (u128::MAX >> (128 - WIDTH));
+                    assert_eq!(buffer.into_bytes(), expected as $type);
+                }
+            }
+
+            #[test]
+            fn $pop() {
+                let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+                for _ in 0..1000 {
+                    let value = ((rng.next() as u128) << 64 | rng.next() as u128) as $type;
+                    let mut buffer = PopBuffer::from_bytes(value);
+                    let mut expected = value as u128;
+                    for _ in 0..rng.next() % 40 {
+                        let amount = rng.amount();
+                        assert_eq!(buffer.pop_bits(amount), mask(amount, expected as u8));
+                        expected = expected.checked_shr(amount).unwrap_or(0);
+                    }
+                    assert_eq!(buffer.into_bytes(), expected as $type);
+                }
+            }
+
+            #[test]
+            fn $roundtrip() {
+                const WIDTH: u32 = <$type>::BITS;
+                let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+                for _ in 0..1000 {
+                    let mut chunks = Vec::new();
+                    let mut total = 0;
+                    let mut buffer = <PushBuffer<$type>>::new();
+                    loop {
+                        let amount = rng.amount();
+                        if total + amount > WIDTH {
+                            break
+                        }
+                        let bits = mask(amount, rng.next() as u8);
+                        buffer.push_bits(amount, bits);
+                        chunks.push((amount, bits));
+                        total += amount;
+                    }
+                    let mut buffer = PopBuffer::from_bytes(buffer.into_bytes());
+                    for (amount, bits) in chunks.into_iter().rev() {
+                        assert_eq!(buffer.pop_bits(amount), bits);
+                    }
+                    assert_eq!(buffer.into_bytes(), 0);
+                }
+            }
+        )*
+    };
+}
+fuzz_tests!(
+    (push_u8, pop_u8, roundtrip_u8, u8),
+    (push_u16, pop_u16, roundtrip_u16, u16),
+    (push_u32, pop_u32, roundtrip_u32, u32),
+    (push_u64, pop_u64, roundtrip_u64, u64),
+    (push_u128, pop_u128, roundtrip_u128, u128),
+);
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bitstring, bitstring)]
+pub struct Packet {
+    a: B3,
+    b: B5,
+}
+
+fn main() {}
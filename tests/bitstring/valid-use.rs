@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bitstring)]
+pub struct Packet {
+    a: B3,
+    b: B5,
+    c: B4,
+    d: B4,
+}
+
+#[bitfield(bitstring, bit_order = be)]
+pub struct Header {
+    a: B3,
+    b: B5,
+    c: B4,
+    d: B4,
+}
+
+#[bitfield(bitstring)]
+pub struct Aligned {
+    low: u8,
+    #[skip]
+    __: B4,
+    flag: bool,
+    rest: B3,
+}
+
+fn main() {
+    let packet = Packet::new().with_a(0b101).with_b(0b10011).with_c(0b1110);
+    assert_eq!(packet.to_bitstring().to_string(), "0b1001_1101 0b0000_1110");
+    assert_eq!(format!("{:#}", packet.to_bitstring()), "0b10011|101 | 0b0000|1110");
+
+    let header = Header::new().with_a(0b101).with_b(0b10011).with_c(0b1110);
+    assert_eq!(header.to_bitstring().to_string(), "0b1011_0011 0b1110_0000");
+    assert_eq!(format!("{:#}", header.to_bitstring()), "0b101|10011 | 0b1110|0000");
+
+    let aligned = Aligned::new().with_low(0xFF).with_flag(true);
+    assert_eq!(aligned.to_bitstring().to_string(), "0b1111_1111 0b0001_0000");
+    assert_eq!(format!("{:#}", aligned.to_bitstring()), "0b11111111 | 0b000|1|0000");
+}
@@ -0,0 +1,50 @@
+use modular_bitfield::{
+    bitvec::prelude::*,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Le {
+    a: B3,
+    b: B9,
+    c: B4,
+}
+
+#[bitfield(bit_order = be)]
+pub struct Be {
+    a: B3,
+    b: B5,
+}
+
+#[bitfield(filled = false, dirty_tracking)]
+pub struct Unfilled {
+    a: bool,
+    b: B4,
+}
+
+fn main() {
+    let mut le = Le::new().with_a(0b101).with_b(0x1FF);
+    assert_eq!(le.as_bitslice().len(), 16);
+    assert_eq!(le.as_bitslice()[..3], bits![u8, Lsb0; 1, 0, 1]);
+    assert_eq!(le.as_bitslice()[3..12].count_ones(), 9);
+    le.as_mut_bitslice()[12..].fill(true);
+    assert_eq!(le.c(), 0b1111);
+    let copy = Le::from_bitslice(le.as_bitslice()).unwrap();
+    assert_eq!(copy.into_bytes(), le.into_bytes());
+
+    let be = Be::new().with_a(0b100).with_b(1);
+    assert_eq!(be.as_bitslice(), bits![u8, Msb0; 1, 0, 0, 0, 0, 0, 0, 1]);
+    let be = Be::from_bitslice(bits![u16, Lsb0; 0, 1, 1, 1, 1, 1, 1, 1]).unwrap();
+    assert_eq!(be.a(), 0b011);
+    assert_eq!(be.b(), 0b11111);
+
+    let mut unfilled = Unfilled::new();
+    assert_eq!(unfilled.as_bitslice().len(), 5);
+    assert!(Unfilled::from_bitslice(bits![u8, Lsb0; 0; 8]).is_err());
+    let unfilled2 = Unfilled::from_bitslice(bits![u8, Lsb0; 1, 0, 0, 0, 1]).unwrap();
+    assert!(unfilled2.a());
+    assert_eq!(unfilled2.b(), 0b1000);
+    assert!(!unfilled2.is_dirty());
+    unfilled.as_mut_bitslice().set(0, true);
+    assert!(unfilled.a());
+}
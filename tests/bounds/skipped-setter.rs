@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bounds)]
+pub struct Register {
+    level: B4,
+    #[skip(setters)]
+    status: B4,
+}
+
+fn main() {
+    let _ = Register::new().status_max();
+}
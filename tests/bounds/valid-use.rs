@@ -0,0 +1,45 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 3]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield(bounds)]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    level: B12,
+    #[skip(setters)]
+    status: B16,
+    wide: u32,
+}
+
+#[bitfield(bounds)]
+pub struct Tuple(B5, B3);
+
+const LEVEL_MAX: u16 = Register::level_max();
+
+/// Validates a raw user input against the bounds of a field.
+fn validate<T: PartialOrd>(value: T, min: T, max: T) -> bool {
+    min <= value && value <= max
+}
+
+fn main() {
+    assert_eq!(Register::enabled_min(), 0);
+    assert_eq!(Register::enabled_max(), 1);
+    assert_eq!(Register::mode_max(), 0b111);
+    assert_eq!(Register::level_min(), 0);
+    assert_eq!(LEVEL_MAX, 0x0FFF);
+    assert_eq!(Register::wide_max(), u32::MAX);
+    assert_eq!(Tuple::get_0_max(), 31);
+    assert_eq!(Tuple::get_1_max(), 7);
+
+    assert!(validate(4095, Register::level_min(), Register::level_max()));
+    assert!(!validate(4096, Register::level_min(), Register::level_max()));
+    let mut register = Register::new();
+    assert!(register.set_level_checked(Register::level_max()).is_ok());
+    assert!(register.set_level_checked(Register::level_max() + 1).is_err());
+}
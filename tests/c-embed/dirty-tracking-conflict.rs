@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(c_embed, dirty_tracking)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use core::mem::{offset_of, size_of};
+use modular_bitfield::prelude::*;
+
+#[bitfield(c_embed)]
+pub struct Status {
+    ready: bool,
+    code: B15,
+}
+
+#[repr(C)]
+pub struct Device {
+    id: u8,
+    status: [u8; 1],
+}
+
+const _: () = Status::assert_embedded_at(size_of::<Device>(), offset_of!(Device, status));
+
+fn main() {}
@@ -0,0 +1,54 @@
+use core::mem::{offset_of, size_of};
+use modular_bitfield::{error::OutOfBounds, prelude::*};
+
+#[bitfield(c_embed)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    code: B6,
+    count: u16,
+}
+
+#[bitfield(c_embed, filled = false)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Mode {
+    level: B3,
+}
+
+/// Mirrors a struct of a vendor SDK.
+#[repr(C)]
+pub struct Device {
+    id: u32,
+    status: Status,
+    mode: Mode,
+    reserved: u8,
+}
+
+const _: () = Status::assert_embedded_at(size_of::<Device>(), offset_of!(Device, status));
+const _: () = Mode::assert_embedded_at(size_of::<Device>(), offset_of!(Device, mode));
+
+fn main() {
+    assert_eq!(size_of::<Status>(), 3);
+    assert_eq!(size_of::<Device>(), 12);
+    assert_eq!(offset_of!(Device, status), 4);
+    assert_eq!(offset_of!(Device, mode), 7);
+
+    let status = Status::new().with_ready(true).with_code(0x15).with_count(0x1234);
+    let mut bytes = [0xFF_u8; 12];
+    status.write_outer(&mut bytes, 4).unwrap();
+    assert_eq!(bytes, [0xFF, 0xFF, 0xFF, 0xFF, 0x55, 0x34, 0x12, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    assert_eq!(Status::from_outer(&bytes, 4), Ok(status));
+    assert_eq!(Status::from_outer(&bytes, 9), Ok(Status::from_bytes([0xFF; 3])));
+    assert_eq!(Status::from_outer(&bytes, 10), Err(OutOfBounds));
+    assert_eq!(Status::from_outer(&bytes, usize::MAX), Err(OutOfBounds));
+    assert_eq!(status.write_outer(&mut bytes, 10), Err(OutOfBounds));
+
+    bytes[7] = 0b0000_0101;
+    assert_eq!(Mode::from_outer(&bytes, 7), Ok(Mode::new().with_level(0b101)));
+    bytes[7] = 0b0000_1101;
+    assert_eq!(Mode::from_outer(&bytes, 7), Err(OutOfBounds));
+
+    let result = std::panic::catch_unwind(|| Status::assert_embedded_at(12, 10));
+    assert!(result.is_err());
+}
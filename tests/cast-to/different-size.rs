@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cast_to(Wide))]
+pub struct Narrow {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield]
+pub struct Wide {
+    a: B4,
+    b: B12,
+}
+
+fn main() {}
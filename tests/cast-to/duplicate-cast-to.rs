@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cast_to(Other), cast_to(Other))]
+pub struct Base {
+    a: B8,
+}
+
+#[bitfield]
+pub struct Other {
+    a: B8,
+}
+
+fn main() {}
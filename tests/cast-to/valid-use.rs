@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(cast_to(ModeA, modes::ModeB))]
+#[derive(Debug, Clone, Copy)]
+pub struct Word {
+    kind: B2,
+    payload: B14,
+}
+
+#[bitfield]
+pub struct ModeA {
+    kind: B2,
+    enabled: bool,
+    level: B13,
+}
+
+mod modes {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(cast_to(super::Word))]
+    pub struct ModeB {
+        pub kind: B2,
+        pub low: u8,
+        pub high: B6,
+    }
+}
+
+fn main() {
+    let word = Word::new().with_kind(1).with_payload(0b11_0000_0000_0101);
+    let mode_a: ModeA = word.cast();
+    assert_eq!(mode_a.kind(), 1);
+    assert!(mode_a.enabled());
+    assert_eq!(mode_a.level(), 0b11_0000_0000_010);
+
+    let mode_b = word.cast::<modes::ModeB>();
+    assert_eq!(mode_b.kind(), 1);
+    assert_eq!(mode_b.low(), 0b0000_0101);
+    assert_eq!(mode_b.high(), 0b11_0000);
+
+    let word: Word = mode_b.cast();
+    assert_eq!(word.payload(), 0b11_0000_0000_0101);
+    assert_eq!(<ModeA as SameLayout<Word>>::from_same_layout(word).into_bytes(), word.into_bytes());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(compat = "packed_struct", bit_order = le)]
+pub struct Flags {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
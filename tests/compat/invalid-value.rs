@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(compat = "bitfield")]
+pub struct Flags {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use modular_bitfield::prelude::*;
+
+// Equivalent to:
+//
+// #[derive(PackedStruct)]
+// #[packed_struct(bit_numbering = "msb0", endian = "msb")]
+// pub struct Status {
+//     #[packed_field(bits = "0")]
+//     ready: bool,
+//     #[packed_field(bits = "1..=3")]
+//     channel: Integer<u8, packed_bits::Bits<3>>,
+//     #[packed_field(bits = "4..=15")]
+//     count: Integer<u16, packed_bits::Bits<12>>,
+//     #[packed_field(bits = "16..=47")]
+//     timestamp: u32,
+// }
+#[bitfield(compat = "packed_struct")]
+pub struct Status {
+    ready: bool,
+    channel: B3,
+    count: B12,
+    timestamp: u32,
+}
+
+#[bitfield(compat = packed_struct, bit_order = be)]
+pub struct Flags {
+    #[bits(4..8)]
+    low: B4,
+    #[bits(0..4)]
+    high: B4,
+}
+
+fn main() {
+    let status = Status::new()
+        .with_ready(true)
+        .with_channel(0b010)
+        .with_count(0xABC)
+        .with_timestamp(0x0102_0304);
+    assert_eq!(
+        status.into_bytes(),
+        [0b1010_1010, 0xBC, 0x01, 0x02, 0x03, 0x04]
+    );
+    let status = Status::from_bytes([0x80, 0x00, 0x00, 0x00, 0x00, 0x2A]);
+    assert!(status.ready());
+    assert_eq!(status.channel(), 0);
+    assert_eq!(status.count(), 0);
+    assert_eq!(status.timestamp(), 42);
+
+    let flags = Flags::new().with_high(0xA).with_low(0x5);
+    assert_eq!(flags.into_bytes(), [0xA5]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "payload_len", from = "hdr_len", expr = "hdr_len as u16 * 4")]
+pub struct Header {
+    hdr_len: u8,
+    payload_len: u8,
+}
+
+fn main() {}
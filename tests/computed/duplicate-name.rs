@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len, payload_len", expr = "hdr_len as u16 + payload_len as u16")]
+#[computed(name = "total_len", from = "payload_len", expr = "payload_len as u16")]
+pub struct Header {
+    hdr_len: u8,
+    payload_len: u8,
+}
+
+fn main() {}
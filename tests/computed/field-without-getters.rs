@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len, payload_len", expr = "hdr_len as u32 + payload_len as u32")]
+pub struct Header {
+    hdr_len: B4,
+    flags: B4,
+    #[skip(getters)]
+    payload_len: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len", expr = "hdr_len as u16", unit = "bytes")]
+pub struct Header {
+    hdr_len: u8,
+    payload_len: u8,
+}
+
+fn main() {}
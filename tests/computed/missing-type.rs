@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len, payload_len", expr = "hdr_len + payload_len")]
+pub struct Header {
+    hdr_len: u8,
+    payload_len: u8,
+}
+
+fn main() {}
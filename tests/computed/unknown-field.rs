@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len, body_len", expr = "hdr_len as u32 + body_len as u32")]
+pub struct Header {
+    hdr_len: B4,
+    flags: B4,
+    payload_len: u16,
+}
+
+fn main() {}
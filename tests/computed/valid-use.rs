@@ -0,0 +1,37 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[computed(name = "total_len", from = "hdr_len, payload_len", expr = "hdr_len as u32 + payload_len as u32")]
+#[computed(name = "hdr_bytes", from = "hdr_len", expr = "(hdr_len as usize) * 4")]
+#[computed(name = "is_empty", from = "payload_len", ty = "bool", expr = "payload_len == 0")]
+#[computed(name = "version", ty = "u8", expr = "4")]
+#[derive(Debug)]
+pub struct Header {
+    hdr_len: B4,
+    flags: B4,
+    payload_len: u16,
+    #[skip(setters)]
+    checksum: u8,
+}
+
+#[bitfield(accessors_mod = "fields")]
+#[computed(name = "sum", from = "low, high", expr = "low as u16 + high as u16")]
+pub struct Wrapped {
+    low: u8,
+    high: u8,
+}
+
+fn main() {
+    let header = Header::new().with_hdr_len(5).with_payload_len(0xFFFF);
+    assert_eq!(header.total_len(), 0x1_0004);
+    assert_eq!(header.hdr_bytes(), 20);
+    assert!(!header.is_empty());
+    assert!(Header::new().is_empty());
+    assert_eq!(header.version(), 4);
+    assert_eq!(header.checksum(), 0);
+
+    let mut wrapped = Wrapped::new();
+    wrapped.fields_mut().set_low(200);
+    wrapped.fields_mut().set_high(100);
+    assert_eq!(wrapped.sum(), 300);
+}
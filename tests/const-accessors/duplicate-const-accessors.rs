@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(const_accessors, const_accessors)]
+pub struct Control {
+    enabled: bool,
+    level: B7,
+}
+
+fn main() {}
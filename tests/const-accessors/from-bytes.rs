@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(const_accessors)]
+pub struct OptionBytes {
+    read_protection: u8,
+    watchdog_sw: bool,
+    reset_on_stop: bool,
+    brown_out_level: B6,
+    user_data: u16,
+}
+
+#[bitfield(filled = false, const_accessors, dirty_tracking)]
+pub struct Partial {
+    low: B4,
+    high: B8,
+}
+
+const OPTION_BYTES: [u8; OptionBytes::BYTES] = [0xAA, 0b0010_1101, 0x34, 0x12];
+const READ_PROTECTION: u8 = OptionBytes::read_protection_from_bytes(OPTION_BYTES);
+const WATCHDOG_SW: bool = OptionBytes::watchdog_sw_from_bytes(OPTION_BYTES);
+const RESET_ON_STOP: bool = OptionBytes::reset_on_stop_from_bytes(OPTION_BYTES);
+const BROWN_OUT_LEVEL: u8 = OptionBytes::brown_out_level_from_bytes(OPTION_BYTES);
+const USER_DATA: u16 = OptionBytes::user_data_from_bytes(OPTION_BYTES);
+const BUFFER_LEN: usize = USER_DATA as usize / 0x100;
+
+const HIGH: u8 = Partial::high_from_bytes([0x5A, 0xFC]);
+
+fn main() {
+    assert_eq!(READ_PROTECTION, 0xAA);
+    assert!(WATCHDOG_SW);
+    assert!(!RESET_ON_STOP);
+    assert_eq!(BROWN_OUT_LEVEL, 0b00_1011);
+    assert_eq!(USER_DATA, 0x1234);
+    let buffer = [0u8; BUFFER_LEN];
+    assert_eq!(buffer.len(), 0x12);
+
+    let bitfield = OptionBytes::from_bytes(OPTION_BYTES);
+    assert_eq!(bitfield.brown_out_level(), BROWN_OUT_LEVEL);
+    assert_eq!(HIGH, 0xC5);
+    assert_eq!(Partial::low_from_bytes([0x5A, 0xFC]), 0xA);
+}
@@ -0,0 +1,78 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield(const_accessors)]
+#[derive(Debug, PartialEq)]
+pub struct Control {
+    enabled: bool,
+    level: B3,
+    mode: Mode,
+    divider: B10,
+    wide: u64,
+}
+
+#[bitfield(bit_order = be, const_accessors)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+    count: u16,
+}
+
+#[bitfield(const_accessors, minimal)]
+pub struct Minimal {
+    low: B4,
+    high: B4,
+}
+
+const CONTROL: Control = Control::new()
+    .with_enabled(true)
+    .with_level(5)
+    .with_divider(0x2BC)
+    .with_wide(u64::MAX);
+const LEVEL: u8 = CONTROL.level();
+const DIVIDER: u16 = CONTROL.divider();
+
+const STATUS: Status = {
+    let mut status = Status::new();
+    status.set_ready(true);
+    status.set_code(0x2A);
+    status.set_count(0x1234);
+    status
+};
+
+const MINIMAL: Minimal = {
+    let mut minimal = Minimal::new();
+    minimal.set_high(0xA);
+    minimal
+};
+
+fn main() {
+    assert!(CONTROL.enabled());
+    assert_eq!(LEVEL, 5);
+    assert_eq!(DIVIDER, 0x2BC);
+    assert_eq!(CONTROL.wide(), u64::MAX);
+    assert_eq!(CONTROL.mode(), Mode::Off);
+
+    let runtime = Control::new()
+        .with_enabled(true)
+        .with_level(5)
+        .with_divider(0x2BC)
+        .with_wide(u64::MAX);
+    assert_eq!(CONTROL, runtime);
+
+    assert_eq!(STATUS.into_bytes(), [0xAA, 0x12, 0x34]);
+    assert!(STATUS.ready());
+    assert_eq!(STATUS.code(), 0x2A);
+    assert_eq!(STATUS.count(), 0x1234);
+
+    assert_eq!(MINIMAL.low(), 0);
+    assert_eq!(MINIMAL.high(), 0xA);
+    assert_eq!(MINIMAL.into_bytes(), [0xA0]);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(const_accessors)]
+pub struct Control {
+    enabled: bool,
+    level: B7,
+}
+
+const CONTROL: Control = Control::new().with_level(0x80);
+
+fn main() {
+    let _ = CONTROL;
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Clock {
+    #[repeat = 2]
+    #[const_values(SLOW = 1)]
+    div: B4,
+}
+
+fn main() {}
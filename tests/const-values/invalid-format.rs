@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Clock {
+    #[const_values(SLOW = "1")]
+    div: B8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Clock {
+    #[const_values(ON = 1)]
+    enabled: bool,
+    #[skip]
+    __: B7,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Transfer {
+    #[offset_by = 1]
+    #[const_values(MIN = 1, MAX = 16)]
+    len: B4,
+    channel: B4,
+}
+
+// The constants are of the type that the accessors of the field operate on.
+const MAX_LEN: u8 = Transfer::LEN_MAX;
+
+fn main() {
+    let mut transfer = Transfer::new();
+    transfer.set_len_to_max();
+    assert_eq!(transfer.len(), MAX_LEN);
+    assert_eq!(transfer.len(), 16);
+    assert_eq!(transfer.len_raw(), 15);
+    transfer.set_len(Transfer::LEN_MIN);
+    assert_eq!(transfer.len(), 1);
+    assert_eq!(transfer.len_raw(), 0);
+}
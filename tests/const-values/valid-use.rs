@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Clock {
+    enabled: bool,
+    #[const_values(OFF = 0, SLOW = 1, FAST = 0b11)]
+    div: B2,
+    #[const_values(MAX = 0xFF_u8)]
+    r#type: u8,
+    #[skip(setters)]
+    #[const_values(IDLE = 5)]
+    state: B5,
+}
+
+#[bitfield(minimal)]
+pub struct Wide {
+    #[const_values(ALL = 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF)]
+    value: u128,
+}
+
+const DEFAULT_DIV: u8 = Clock::DIV_SLOW;
+
+fn main() {
+    let mut clock = Clock::new();
+    clock.set_div_to_fast();
+    assert_eq!(clock.div(), Clock::DIV_FAST);
+    assert_eq!(clock.div(), 3);
+    clock.set_div(DEFAULT_DIV);
+    assert_eq!(clock.div(), 1);
+    clock.set_div_to_off();
+    assert_eq!(clock.div(), Clock::DIV_OFF);
+
+    clock.set_type_to_max();
+    assert_eq!(clock.r#type(), Clock::TYPE_MAX);
+    assert_eq!(Clock::STATE_IDLE, 5);
+
+    let mut wide = Wide::new();
+    wide.set_value_to_all();
+    assert_eq!(wide.value(), u128::MAX);
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Clock {
+    enabled: bool,
+    #[const_values(OFF = 0, TURBO = 4)]
+    div: B2,
+    #[skip]
+    __: B5,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(contiguous)]
+pub struct Status {
+    #[bits(0..4)]
+    flags: B4,
+    // Bits 4..8 have been forgotten.
+    #[bits(8..16)]
+    code: u8,
+}
+
+fn main() {}
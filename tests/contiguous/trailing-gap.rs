@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16, filled = false, contiguous)]
+#[derive(BitfieldSpecifier)]
+pub struct Header {
+    ready: bool,
+    code: B7,
+    // Bits 12..16 have been forgotten.
+    length: B4,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 32, auto_pad, contiguous)]
+pub struct Status {
+    #[bits(8..16)]
+    code: u8,
+    #[bits(0..8)]
+    flags: u8,
+}
+
+#[bitfield(contiguous)]
+pub struct Plain {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {
+    let status = Status::new().with_code(0xA5).with_flags(0x0F);
+    assert_eq!(status.into_bytes(), [0x0F, 0xA5, 0x00, 0x00]);
+
+    let plain = Plain::new().with_ready(true).with_code(1);
+    assert_eq!(plain.into_bytes(), [0b0000_0011]);
+}
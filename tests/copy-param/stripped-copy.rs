@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(copy = false)]
+#[derive(Clone, Copy)]
+pub struct Table {
+    a: u128,
+    b: u128,
+}
+
+fn main() {
+    let table = Table::new();
+    let moved = table;
+    let _ = table.a();
+    let _ = moved.a();
+}
@@ -0,0 +1,25 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Large {
+    a: u128,
+    b: u128,
+    c: u128,
+    d: u128,
+    e: u8,
+}
+
+#[bitfield]
+#[derive(Clone, core::marker::Copy)]
+pub struct LargePath {
+    a: u128,
+    b: u128,
+    c: u128,
+    d: u128,
+    e: u8,
+}
+
+fn main() {}
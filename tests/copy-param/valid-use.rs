@@ -0,0 +1,60 @@
+use modular_bitfield::prelude::*;
+
+// Small bitfields may be `Copy` without further ado.
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Small {
+    a: u128,
+    b: u128,
+}
+
+// Large bitfields must explicitly allow being `Copy`.
+#[bitfield(copy = true)]
+#[derive(Clone, Copy)]
+pub struct Large {
+    a: u128,
+    b: u128,
+    c: u128,
+    d: u128,
+    e: u8,
+}
+
+// Large bitfields may strip their `Copy` derive.
+#[bitfield(copy = false)]
+#[derive(Clone, Copy)]
+pub struct Table {
+    a: u128,
+    b: u128,
+    c: u128,
+    d: u128,
+    e: u8,
+}
+
+// The `Copy` derive is also recognized by its full path.
+#[bitfield(copy = false)]
+#[derive(Clone, core::marker::Copy)]
+pub struct PathTable {
+    a: u128,
+    b: u128,
+    c: u128,
+    d: u128,
+    e: u8,
+}
+
+fn main() {
+    let small = Small::new().with_a(1);
+    let copy = small;
+    assert_eq!(small.a(), copy.a());
+
+    let large = Large::new().with_e(1);
+    let copy = large;
+    assert_eq!(large.e(), copy.e());
+
+    let table = Table::new().with_e(1);
+    let clone = table.clone();
+    assert_eq!(table.e(), clone.e());
+
+    let table = PathTable::new().with_e(1);
+    let clone = table.clone();
+    assert_eq!(table.e(), clone.e());
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(defmt::Format)]
+#[derive(defmt::Format)]
+pub struct Control {
+    enabled: bool,
+    divider: B7,
+}
+
+fn main() {}
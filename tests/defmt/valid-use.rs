@@ -0,0 +1,44 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, defmt::Format, Debug)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+#[bitfield]
+#[derive(defmt::Format)]
+pub struct Control {
+    enabled: bool,
+    mode: Mode,
+    #[reserved]
+    reserved: B5,
+    divider: B12,
+    #[skip(getters)]
+    status: B12,
+}
+
+#[bitfield(minimal)]
+#[derive(Debug, defmt::Format)]
+pub struct Packed {
+    kind: B3,
+    len: B5,
+}
+
+#[bitfield]
+#[derive(defmt::Format)]
+pub struct Modes {
+    count: B2,
+    #[repeat = 3]
+    modes: Mode,
+}
+
+fn assert_format<T: defmt::Format>() {}
+
+fn main() {
+    assert_format::<Control>();
+    assert_format::<Packed>();
+    assert_format::<Modes>();
+}
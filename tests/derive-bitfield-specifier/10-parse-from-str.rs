@@ -0,0 +1,46 @@
+use core::convert::TryFrom;
+use modular_bitfield::{
+    error::UnknownVariant,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+#[from_str]
+pub enum Mode {
+    Idle,
+    Active,
+    r#Sleep,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[from_str]
+#[case_insensitive]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[bitfield]
+pub struct Config {
+    mode: Mode,
+    level: Level,
+    #[skip] __: B5,
+}
+
+fn main() {
+    assert_eq!("Idle".parse::<Mode>(), Ok(Mode::Idle));
+    assert_eq!(Mode::try_from("Sleep"), Ok(Mode::Sleep));
+    assert_eq!("active".parse::<Mode>(), Err(UnknownVariant));
+    assert_eq!("".parse::<Mode>(), Err(UnknownVariant));
+
+    assert_eq!("high".parse::<Level>(), Ok(Level::High));
+    assert_eq!(Level::try_from("LOW"), Ok(Level::Low));
+    assert_eq!("medium".parse::<Level>(), Err(UnknownVariant));
+
+    let config = Config::new()
+        .with_mode("Active".parse().unwrap())
+        .with_level("HIGH".parse().unwrap());
+    assert_eq!(config.mode(), Mode::Active);
+    assert_eq!(config.level(), Level::High);
+}
@@ -0,0 +1,31 @@
+// Discriminant range checks must not overflow for wide specifiers.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 64]
+#[repr(u64)]
+pub enum Wide {
+    Low = 0,
+    High = 0xFFFF_FFFF_FFFF_FFFF,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 100]
+pub enum Sparse {
+    Low = 0,
+    High = 0x7FFF_FFFF_FFFF_FFFF,
+}
+
+#[bitfield]
+pub struct Register {
+    wide: Wide,
+    sparse: Sparse,
+    #[skip] __: B92,
+}
+
+fn main() {
+    let register = Register::new().with_wide(Wide::High).with_sparse(Sparse::High);
+    assert_eq!(register.wide(), Wide::High);
+    assert_eq!(register.sparse(), Sparse::High);
+}
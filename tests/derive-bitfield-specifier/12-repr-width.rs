@@ -0,0 +1,59 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Opcode {
+    Nop = 0x00,
+    Load = 0x10,
+    Store = 0x20,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum Wide {
+    A = 0x0100,
+    B = 0x0200,
+    C = 0xFFFF,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+#[repr(u8)]
+pub enum Overridden {
+    A,
+    B,
+    C,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[repr(i8)]
+pub enum Signed {
+    A,
+    B,
+}
+
+#[bitfield]
+pub struct Instruction {
+    opcode: Opcode,
+    mode: Overridden,
+    signed: Signed,
+    #[skip]
+    __: B5,
+    wide: Wide,
+}
+
+fn main() {
+    assert_eq!(<Opcode as Specifier>::BITS, 8);
+    assert_eq!(<Wide as Specifier>::BITS, 16);
+    assert_eq!(<Overridden as Specifier>::BITS, 2);
+    assert_eq!(<Signed as Specifier>::BITS, 1);
+
+    let instruction = Instruction::new()
+        .with_opcode(Opcode::Store)
+        .with_mode(Overridden::C)
+        .with_wide(Wide::C);
+    assert_eq!(instruction.opcode(), Opcode::Store);
+    assert_eq!(instruction.mode(), Overridden::C);
+    assert_eq!(instruction.wide(), Wide::C);
+    assert_eq!(instruction.into_bytes(), [0x20, 0b0000_0010, 0xFF, 0xFF]);
+}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 9]
+#[repr(u8)]
+pub enum Opcode {
+    Nop,
+    Load,
+    Store,
+}
+
+fn main() {}
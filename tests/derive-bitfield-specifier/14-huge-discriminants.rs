@@ -0,0 +1,66 @@
+// Discriminants are checked as `u128` so that discriminants that only differ
+// above the width of `usize` do not collide and enums may have many variants.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 128]
+#[repr(u128)]
+pub enum Huge {
+    One = 1,
+    AboveU32 = (1 << 32) + 1,
+    AboveU64 = (1 << 64) + 1,
+    AboveU100 = (1 << 100) + 1,
+    Max = u128::MAX,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 72]
+#[repr(u128)]
+pub enum Sparse {
+    Low = 0x01,
+    High = 0x80_0000_0000_0000_0001,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 7]
+pub enum Many {
+    V0, V1, V2, V3, V4, V5, V6, V7,
+    V8, V9, V10, V11, V12, V13, V14, V15,
+    V16, V17, V18, V19, V20, V21, V22, V23,
+    V24, V25, V26, V27, V28, V29, V30, V31,
+    V32, V33, V34, V35, V36, V37, V38, V39,
+    V40, V41, V42, V43, V44, V45, V46, V47,
+    V48, V49, V50, V51, V52, V53, V54, V55,
+    V56, V57, V58, V59, V60, V61, V62, V63,
+    V64, V65, V66, V67, V68, V69, V70, V71,
+}
+
+#[bitfield]
+pub struct Register {
+    huge: Huge,
+    sparse: Sparse,
+    many: Many,
+    #[skip] __: B1,
+}
+
+fn main() {
+    for huge in [Huge::One, Huge::AboveU32, Huge::AboveU64, Huge::AboveU100, Huge::Max] {
+        let register = Register::new().with_huge(huge);
+        assert_eq!(register.huge(), huge);
+    }
+    let register = Register::new()
+        .with_huge(Huge::AboveU64)
+        .with_sparse(Sparse::High)
+        .with_many(Many::V71);
+    assert_eq!(register.huge(), Huge::AboveU64);
+    assert_eq!(register.sparse(), Sparse::High);
+    assert_eq!(register.many(), Many::V71);
+    assert_eq!(<Many as Specifier>::from_bytes(64), Ok(Many::V64));
+    assert!(<Many as Specifier>::from_bytes(72).is_err());
+    assert_eq!(
+        <Huge as Specifier>::from_bytes(1),
+        Ok(Huge::One),
+    );
+    assert!(<Huge as Specifier>::from_bytes(1 << 64).is_err());
+}
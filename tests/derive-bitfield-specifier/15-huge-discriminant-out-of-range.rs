@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 65]
+#[repr(u128)]
+pub enum Huge {
+    Low = 1,
+    AboveU64 = (1 << 64) + 1,
+    OutOfRange = (1 << 65) + 1,
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+use core::convert::TryFrom;
+use modular_bitfield::{
+    error::InvalidBitPattern,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 3]
+#[from_str]
+#[bitfield_specifier(conversions)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 0,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+#[bits = 12]
+#[bitfield_specifier(conversions)]
+pub enum Opcode {
+    Nop = 0x000,
+    Load = 0x100,
+    Store = 0x200,
+    Halt = 0xFFF,
+}
+
+fn parse_day(value: u8) -> Option<Weekday> {
+    Weekday::try_from(value).ok()
+}
+
+fn main() {
+    assert_eq!(Weekday::try_from(1_u8), Ok(Weekday::Monday));
+    assert_eq!(Weekday::try_from(0_u8), Ok(Weekday::Sunday));
+    assert_eq!(
+        Weekday::try_from(7_u8),
+        Err(InvalidBitPattern { invalid_bytes: 7 }),
+    );
+    assert_eq!(parse_day(6), Some(Weekday::Saturday));
+    assert_eq!(u8::from(Weekday::Friday), 5);
+    let value: u8 = Weekday::Sunday.into();
+    assert_eq!(value, 0);
+
+    assert_eq!(Opcode::try_from(0x200_u16), Ok(Opcode::Store));
+    assert!(Opcode::try_from(0x201_u16).is_err());
+    assert_eq!(u16::from(Opcode::Halt), 0xFFF);
+
+    // The string conversions are still available.
+    assert_eq!(Weekday::try_from("Monday"), Ok(Weekday::Monday));
+}
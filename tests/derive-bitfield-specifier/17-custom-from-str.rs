@@ -0,0 +1,28 @@
+use core::str::FromStr;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Idle,
+    Active,
+    Sleep,
+}
+
+impl FromStr for Mode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "i" => Ok(Mode::Idle),
+            "a" => Ok(Mode::Active),
+            "s" => Ok(Mode::Sleep),
+            _ => Err(()),
+        }
+    }
+}
+
+fn main() {
+    assert_eq!("a".parse::<Mode>(), Ok(Mode::Active));
+    assert_eq!("Active".parse::<Mode>(), Err(()));
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[case_insensitive]
+pub enum Level {
+    Low,
+    High,
+}
+
+fn main() {}
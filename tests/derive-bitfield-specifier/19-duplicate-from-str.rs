@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[from_str]
+#[from_str]
+pub enum Level {
+    Low,
+    High,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use modular_bitfield::prelude::*;
+
+// A `#[repr(uN)]` enum with a power of 2 variants keeps the bit width derived from its
+// number of variants.
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Mode {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[bitfield]
+pub struct Register {
+    mode: Mode,
+    value: B6,
+}
+
+fn main() {
+    assert_eq!(<Mode as Specifier>::BITS, 2);
+
+    let register = Register::new().with_mode(Mode::D).with_value(0b10_1010);
+    assert_eq!(register.mode(), Mode::D);
+    assert_eq!(register.value(), 0b10_1010);
+    assert_eq!(register.into_bytes(), [0b1010_1011]);
+}
@@ -0,0 +1,38 @@
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+// Without `#[bitfield_specifier(conversions)]` the conversions from and to the discriminant
+// type are not generated and can be implemented by hand.
+#[derive(BitfieldSpecifier, Debug, PartialEq, Copy, Clone)]
+pub enum Mode {
+    Off,
+    Low,
+    Mid,
+    High,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mode::Off),
+            1 => Ok(Mode::Low),
+            2 => Ok(Mode::Mid),
+            3 => Ok(Mode::High),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(mode: Mode) -> Self {
+        mode as u8 * 10
+    }
+}
+
+fn main() {
+    assert_eq!(Mode::try_from(2_u8), Ok(Mode::Mid));
+    assert_eq!(Mode::try_from(4_u8), Err(()));
+    assert_eq!(u8::from(Mode::High), 30);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bitfield_specifier(conversions, from_bytes)]
+pub enum Mode {
+    Off,
+    On,
+}
+
+fn main() {}
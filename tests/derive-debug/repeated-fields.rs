@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bits = 2]
+pub enum Status {
+    Green = 0,
+    Yellow = 1,
+    Red = 2,
+}
+
+#[bitfield]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Lights {
+    id: B2,
+    #[repeat = 3]
+    status: Status,
+}
+
+fn main() {
+    let mut lights = Lights::new();
+    lights.set_id(1);
+    lights.set_status_at(0, Status::Red).unwrap();
+    lights.set_status_at(2, Status::Yellow).unwrap();
+    assert_eq!(
+        format!("{:?}", lights),
+        "Lights { id: 1, status: [Red, Green, Yellow] }",
+    );
+
+    let invalid = Lights::from_bytes([0b0000_1101]);
+    assert_eq!(
+        format!("{:?}", invalid),
+        "Lights { id: 1, status: [InvalidBitPattern { invalid_bytes: 3 }, Green, Green] }",
+    );
+
+    // Repeated fields are compared element by element in the order of their elements.
+    let mut other = Lights::new();
+    other.set_id(1);
+    other.set_status_at(0, Status::Red).unwrap();
+    assert!(other < lights);
+    other.set_status_at(1, Status::Yellow).unwrap();
+    assert!(other > lights);
+    assert_ne!(other, lights);
+}
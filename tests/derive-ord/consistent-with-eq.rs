@@ -0,0 +1,36 @@
+use core::cmp::Ordering;
+use modular_bitfield::prelude::*;
+use std::collections::BTreeSet;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Entry {
+    a: B4,
+    #[skip(getters)]
+    b: B4,
+    c: u8,
+}
+
+fn assert_consistent(lhs: Entry, rhs: Entry) {
+    assert_eq!(lhs == rhs, lhs.cmp(&rhs) == Ordering::Equal);
+    assert_eq!(lhs.partial_cmp(&rhs), Some(lhs.cmp(&rhs)));
+    assert_eq!(lhs.cmp(&rhs), rhs.cmp(&lhs).reverse());
+}
+
+fn main() {
+    let e1 = Entry::new().with_a(1).with_b(2).with_c(3);
+    let e2 = Entry::new().with_a(1).with_b(5).with_c(3);
+    let e3 = Entry::new().with_a(2).with_b(0).with_c(0);
+    assert_ne!(e1, e2);
+    assert_ne!(e1.cmp(&e2), Ordering::Equal);
+    for lhs in [e1, e2, e3] {
+        for rhs in [e1, e2, e3] {
+            assert_consistent(lhs, rhs);
+        }
+    }
+    // The decoded field values still take precedence over the skipped bits.
+    assert!(e1 < e3 && e2 < e3);
+
+    let set = [e1, e2, e3, e1].iter().copied().collect::<BTreeSet<_>>();
+    assert_eq!(set.len(), 3);
+}
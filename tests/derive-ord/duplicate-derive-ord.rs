@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Ord)]
+pub struct SignedInt {
+    sign: bool,
+    value: B31,
+}
+
+fn main() {}
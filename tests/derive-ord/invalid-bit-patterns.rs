@@ -0,0 +1,33 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, PartialOrd)]
+#[bits = 2]
+pub enum Priority {
+    Low = 0,
+    High = 1,
+    Critical = 2,
+}
+
+#[bitfield]
+#[derive(PartialEq, PartialOrd)]
+pub struct Task {
+    priority: Priority,
+    #[skip] __: B2,
+    id: B4,
+}
+
+fn main() {
+    let low = Task::new().with_priority(Priority::Low).with_id(15);
+    let high = Task::new().with_priority(Priority::High).with_id(0);
+    let invalid = Task::from_bytes([0b0000_0011]);
+    assert!(low < high);
+    // Invalid bit patterns are ordered after all valid values.
+    assert!(high < invalid);
+    // Skipped fields only break ties between otherwise equal bitfields.
+    assert_eq!(
+        Task::from_bytes([0b0000_0000]).partial_cmp(&Task::from_bytes([0b0000_1100])),
+        Some(core::cmp::Ordering::Less),
+    );
+    assert!(Task::from_bytes([0b0001_0000]) < Task::from_bytes([0b1111_1100]));
+    assert!(Task::from_bytes([0b0000_1100]) < Task::from_bytes([0b0001_0000]));
+}
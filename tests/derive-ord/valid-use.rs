@@ -0,0 +1,24 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    major: u16,
+    minor: B12,
+    patch: B4,
+}
+
+fn main() {
+    let v1 = Version::new().with_major(0x0002).with_minor(0x100).with_patch(0);
+    let v2 = Version::new().with_major(0x0100).with_minor(0x001).with_patch(0);
+    let v3 = Version::new().with_major(0x0100).with_minor(0x001).with_patch(1);
+    // Comparing the raw little-endian bytes would order `v2` before `v1`.
+    assert!(v1.into_bytes() > v2.into_bytes());
+    assert!(v1 < v2);
+    assert!(v2 < v3);
+    assert_eq!(v2.partial_cmp(&v2), Some(core::cmp::Ordering::Equal));
+    assert_eq!(v3.cmp(&v1), core::cmp::Ordering::Greater);
+    let mut versions = [v3, v1, v2];
+    versions.sort();
+    assert_eq!(versions, [v1, v2, v3]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(passthrough(Ord))]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+}
+
+fn main() {}
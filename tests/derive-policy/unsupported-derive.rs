@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(intercept(Clone))]
+#[derive(Clone)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+}
+
+fn main() {}
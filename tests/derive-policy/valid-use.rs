@@ -0,0 +1,53 @@
+use modular_bitfield::prelude::*;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+#[bitfield(intercept(Default, Hash), passthrough(Debug))]
+#[derive(Debug, Default, Hash)]
+pub struct Header {
+    version: B4,
+    #[skip]
+    __: B4,
+}
+
+#[bitfield(passthrough(PartialOrd, Ord))]
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+pub struct Raw {
+    low: B4,
+    high: B4,
+}
+
+#[bitfield(intercept(Debug), passthrough(Default, Hash))]
+#[derive(Debug, Default, Hash)]
+pub struct Defaults {
+    a: B4,
+    b: B4,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let header = Header::default().with_version(3);
+    assert_eq!(header.version(), 3);
+    assert_eq!(format!("{:?}", header), "Header { bytes: [3] }");
+
+    // The intercepted `Hash` ignores the bits of skipped fields.
+    let dirty = Header::from_bytes([0b1111_0011]);
+    assert_eq!(hash_of(&header), hash_of(&dirty));
+
+    // The passed through orderings compare the underlying bytes.
+    let a = Raw::new().with_low(0b1111);
+    let b = Raw::new().with_high(0b0001);
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Less);
+
+    let defaults = Defaults::default().with_b(2);
+    assert_eq!(format!("{:?}", defaults), "Defaults { a: 0, b: 2 }");
+    assert_eq!(hash_of(&defaults), hash_of(&Defaults::from_bytes([0x20])));
+}
@@ -1,5 +1,5 @@
 use modular_bitfield::prelude::*;
-use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::error::{OutOfBounds, PaddingBitsSet};
 
 #[bitfield(filled = false)]
 #[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
@@ -12,5 +12,20 @@ fn main() {
     assert_eq!(Unfilled::from_bytes([0b0000_0001]), Ok(Unfilled::new().with_a(1)));
     assert_eq!(Unfilled::from_bytes([0b0000_0010]), Ok(Unfilled::new().with_a(2)));
     assert_eq!(Unfilled::from_bytes([0b0000_0011]), Ok(Unfilled::new().with_a(3)));
-    assert_eq!(Unfilled::from_bytes([0b0000_0100]), Err(OutOfBounds));
+    assert_eq!(
+        Unfilled::from_bytes([0b0000_0100]),
+        Err(PaddingBitsSet { byte_index: 0, mask: 0b0000_0100 })
+    );
+    assert_eq!(
+        Unfilled::from_bytes([0b1010_0111]),
+        Err(PaddingBitsSet { byte_index: 0, mask: 0b1010_0100 })
+    );
+    assert_eq!(
+        Unfilled::from_bytes([0b1000_0000]).map_err(OutOfBounds::from),
+        Err(OutOfBounds)
+    );
+    assert_eq!(
+        PaddingBitsSet { byte_index: 3, mask: 0b1100_0000 }.to_string(),
+        "encountered set padding bits 0b11000000 in byte 3"
+    );
 }
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(dirty_tracking, bytes = 1)]
+pub struct Register {
+    enable: bool,
+    mode: B7,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(dirty_tracking, dirty_tracking)]
+pub struct Register {
+    enable: bool,
+    mode: B7,
+}
+
+fn main() {}
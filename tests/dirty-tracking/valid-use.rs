@@ -0,0 +1,70 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(dirty_tracking, unchecked_setters)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+pub struct Register {
+    pub enable: bool,
+    pub mode: B3,
+    pub divider: B4,
+    #[skip(setters)]
+    pub status: B4,
+    #[skip]
+    __: B4,
+}
+
+#[bitfield(dirty_tracking, keep_phantom_fields)]
+#[derive(BitfieldSpecifier)]
+pub struct Nested {
+    low: B4,
+    high: B4,
+}
+
+#[bitfield(dirty_tracking)]
+pub struct Tuple(B4, B4);
+
+fn main() {
+    let mut register = Register::new();
+    assert!(!register.is_dirty());
+    assert!(register.take_dirty().is_empty());
+
+    register.set_mode(5);
+    register.set_divider_saturating(0xFF);
+    let dirty = register.take_dirty();
+    assert_eq!(dirty.len(), 2);
+    assert!(!dirty.enable());
+    assert!(dirty.mode());
+    assert!(dirty.divider());
+    assert!(!register.is_dirty());
+    assert_eq!(register.mode(), 5);
+
+    register.set_enable_if(false);
+    assert!(!register.is_dirty());
+    register.set_enable_if(true);
+    assert!(register.take_dirty().enable());
+    register.toggle_enable();
+    assert!(register.take_dirty().enable());
+    unsafe { register.set_mode_unchecked(1) };
+    assert!(register.take_dirty().mode());
+    assert!(register.set_divider_checked(0x10).is_err());
+    assert!(!register.is_dirty());
+    let register = register.with_divider(3);
+    assert!(register.is_dirty());
+
+    let register = Register::from(0xFFFF_u16);
+    assert!(!register.is_dirty());
+    assert_eq!(u16::from(register), 0xFFFF);
+    assert_eq!(Register::from_bytes([0x12, 0x34]), Register::from_bytes([0x12, 0x34]));
+
+    let mut nested = Nested::new();
+    nested.set_high(3);
+    assert!(nested.take_dirty().high());
+    assert_eq!(<Nested as Specifier>::from_bytes(0x30).unwrap().high(), 3);
+
+    let mut tuple = Tuple::new();
+    tuple.set_1(2);
+    let dirty: TupleFieldSet = tuple.take_dirty();
+    assert!(!dirty.get_0() && dirty.get_1());
+    assert_eq!(TupleFieldSet::default(), TupleFieldSet::empty());
+    assert_ne!(dirty, TupleFieldSet::empty());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(doc_bit_numbering = "msb0", doc_bit_numbering = "lsb0")]
+pub struct Register {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield(doc_bit_numbering = "msb0")]
+pub struct Register {
+    mode: Mode,
+    rest: B6,
+}
+
+fn main() {}
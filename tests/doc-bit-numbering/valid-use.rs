@@ -0,0 +1,122 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+/// A control register.
+#[bitfield(doc_bit_numbering = "msb0", emit_layout = "layouts/doc-bit-numbering-msb0.json")]
+pub struct Msb0Register {
+    enabled: bool,
+    #[bits = 2]
+    mode: Mode,
+    #[skip] __: B5,
+    value: u16,
+    tail: B8,
+}
+
+#[bitfield(doc_bit_numbering = lsb0, emit_layout = "layouts/doc-bit-numbering-lsb0.json")]
+pub struct Lsb0Register {
+    enabled: bool,
+    #[bits = 2]
+    mode: Mode,
+    #[skip] __: B5,
+}
+
+#[bitfield(
+    bit_order = be,
+    doc_bit_numbering = msb0,
+    emit_layout = "layouts/doc-bit-numbering-be.json"
+)]
+pub struct BeRegister {
+    flags: B4,
+    #[skip] __: B4,
+    code: u8,
+}
+
+#[bitfield(bit_order = be, doc_bit_numbering = lsb0)]
+pub struct BeLsb0Register {
+    a: B4,
+    #[skip] __: B27,
+    b: bool,
+}
+
+fn read_layout(name: &str) -> String {
+    std::fs::read_to_string(format!("{}/layouts/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+fn main() {
+    // The bit numbering does not affect the storage of the bitfield.
+    let register = Msb0Register::new().with_enabled(true).with_tail(0xAB);
+    assert_eq!(register.into_bytes(), [0x01, 0x00, 0x00, 0xAB]);
+
+    assert_eq!(
+        read_layout("doc-bit-numbering-msb0.json"),
+        r#"{
+  "name": "Msb0Register",
+  "bits": 32,
+  "bytes": 4,
+  "filled": true,
+  "bit_order": "le",
+  "doc_bit_numbering": "msb0",
+  "fields": [
+    { "name": "enabled", "type": "bool", "offset": 0, "bits": 1, "doc_bits": "31", "getters": true, "setters": true },
+    { "name": "mode", "type": "Mode", "offset": 1, "bits": 2, "doc_bits": "29:30", "getters": true, "setters": true },
+    { "name": "__", "type": "B5", "offset": 3, "bits": 5, "doc_bits": "24:28", "getters": false, "setters": false },
+    { "name": "value", "type": "u16", "offset": 8, "bits": 16, "doc_bits": "8:23", "getters": true, "setters": true },
+    { "name": "tail", "type": "B8", "offset": 24, "bits": 8, "doc_bits": "0:7", "getters": true, "setters": true }
+  ]
+}
+"#
+    );
+    // The bit numbers follow the bit order: with `be` the first field starts at the
+    // most significant bit.
+    assert_eq!(
+        read_layout("doc-bit-numbering-be.json"),
+        r#"{
+  "name": "BeRegister",
+  "bits": 16,
+  "bytes": 2,
+  "filled": true,
+  "bit_order": "be",
+  "doc_bit_numbering": "msb0",
+  "fields": [
+    { "name": "flags", "type": "B4", "offset": 0, "bits": 4, "doc_bits": "0:3", "getters": true, "setters": true },
+    { "name": "__", "type": "B4", "offset": 4, "bits": 4, "doc_bits": "4:7", "getters": false, "setters": false },
+    { "name": "code", "type": "u8", "offset": 8, "bits": 8, "doc_bits": "8:15", "getters": true, "setters": true }
+  ]
+}
+"#
+    );
+
+    // The offset constants are named after the documented bits.
+    assert_eq!(Msb0Register::ENABLED_BIT_31, 0);
+    assert_eq!(Msb0Register::MODE_BIT_29_30, 1);
+    assert_eq!(Msb0Register::VALUE_BIT_8_23, 8);
+    assert_eq!(Msb0Register::TAIL_BIT_0_7, 24);
+    assert_eq!(Lsb0Register::ENABLED_BIT_0, 0);
+    assert_eq!(Lsb0Register::MODE_BIT_2_1, 1);
+    assert_eq!(BeRegister::FLAGS_BIT_0_3, 0);
+    assert_eq!(BeRegister::CODE_BIT_8_15, 8);
+    assert_eq!(BeLsb0Register::A_BIT_31_28, 0);
+    assert_eq!(BeLsb0Register::B_BIT_0, 31);
+
+    assert_eq!(
+        read_layout("doc-bit-numbering-lsb0.json"),
+        r#"{
+  "name": "Lsb0Register",
+  "bits": 8,
+  "bytes": 1,
+  "filled": true,
+  "bit_order": "le",
+  "doc_bit_numbering": "lsb0",
+  "fields": [
+    { "name": "enabled", "type": "bool", "offset": 0, "bits": 1, "doc_bits": "0", "getters": true, "setters": true },
+    { "name": "mode", "type": "Mode", "offset": 1, "bits": 2, "doc_bits": "2:1", "getters": true, "setters": true },
+    { "name": "__", "type": "B5", "offset": 3, "bits": 5, "doc_bits": "7:3", "getters": false, "setters": false }
+  ]
+}
+"#
+    );
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(doc_examples, doc_examples = "regs")]
+pub struct Header {
+    pub is_compressed: bool,
+    pub length: B7,
+}
+
+fn main() {}
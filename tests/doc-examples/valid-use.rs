@@ -0,0 +1,35 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(doc_examples)]
+pub struct Header {
+    pub is_compressed: bool,
+    #[skip(getters)]
+    pub version: B3,
+    pub length: B12,
+    checksum: B16,
+}
+
+mod regs {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(doc_examples = "regs", minimal)]
+    pub struct Status {
+        pub ready: bool,
+        pub code: B7,
+    }
+}
+
+fn main() {
+    let mut header = Header::new().with_is_compressed(true);
+    header.set_version(5);
+    header.set_length(1000);
+    header.set_checksum(0xBEEF);
+    assert!(header.is_compressed());
+    assert_eq!(header.length(), 1000);
+    assert_eq!(header.checksum(), 0xBEEF);
+
+    let mut status = regs::Status::new();
+    status.set_code(42);
+    assert_eq!(status.code(), 42);
+    assert!(!status.ready());
+}
@@ -0,0 +1,50 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(dual_order)]
+pub struct Header {
+    version: B4,
+    kind: B4,
+    length: u16,
+}
+
+#[bitfield(dual_order, bit_order = be)]
+pub struct HeaderBe {
+    version: B4,
+    kind: B4,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield(dual_order)]
+pub struct WithEnum {
+    mode: Mode,
+    #[skip(getters)]
+    rest: B6,
+}
+
+fn main() {
+    let header = Header::from_bytes([0x12, 0x34, 0x56]);
+    assert_eq!(header.version(), 0x2);
+    assert_eq!(header.kind(), 0x1);
+    assert_eq!(header.length(), 0x5634);
+    assert_eq!(header.version_msb0(), 0x1);
+    assert_eq!(header.kind_msb0(), 0x2);
+    assert_eq!(header.length_msb0_or_err(), Ok(0x3456));
+
+    let header = HeaderBe::from_bytes([0x12]);
+    assert_eq!(header.version(), 0x1);
+    assert_eq!(header.version_lsb0(), 0x2);
+    assert_eq!(header.kind_lsb0(), 0x1);
+
+    let value = WithEnum::from_bytes([0b1100_0001]);
+    assert_eq!(value.mode(), Mode::B);
+    assert!(value.mode_msb0_or_err().is_err());
+    let value = WithEnum::from_bytes([0b1000_0000]);
+    assert_eq!(value.mode_msb0(), Mode::C);
+}
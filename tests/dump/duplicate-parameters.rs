@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(dump, dump)]
+pub struct Control {
+    enabled: bool,
+    divider: B7,
+}
+
+fn main() {}
@@ -0,0 +1,61 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+#[bitfield(dump)]
+pub struct Control {
+    enabled: bool,
+    mode: Mode,
+    #[reserved]
+    reserved: B1,
+    #[skip]
+    __: B4,
+    divider: B12,
+    status: B12,
+}
+
+#[bitfield(dump)]
+pub struct Modes {
+    count: B2,
+    #[repeat = 3]
+    modes: Mode,
+}
+
+fn main() {
+    let mut control = Control::new();
+    control.set_enabled(true);
+    control.set_mode(Mode::Auto);
+    control.set_divider(0x123);
+    control.set_status(7);
+    assert_eq!(
+        control.dump(),
+        "enabled  [0]     0x1   true\n\
+         mode     [2:1]   0x2   Auto\n\
+         reserved [3]     0x0   0\n\
+         divider  [19:8]  0x123 291\n\
+         status   [31:20] 0x007 7\n",
+    );
+
+    let invalid = Control::from_bytes([0b0000_0110, 0x00, 0x00, 0x00]);
+    let mut dump = String::new();
+    invalid.dump_to(&mut dump).unwrap();
+    assert!(dump.contains("mode     [2:1]   0x3   InvalidBitPattern { invalid_bytes: 3 }\n"));
+
+    let mut modes = Modes::new();
+    modes.set_count(2);
+    modes.set_modes_at(0, Mode::On).unwrap();
+    modes.set_modes_at(2, Mode::Auto).unwrap();
+    assert_eq!(
+        modes.dump(),
+        "count    [1:0] 0x2 2\n\
+         modes[0] [3:2] 0x1 On\n\
+         modes[1] [5:4] 0x0 Off\n\
+         modes[2] [7:6] 0x2 Auto\n",
+    );
+}
@@ -0,0 +1,38 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(emit_layout = "layouts/emit-layout-field-arrays.json")]
+pub struct Channels {
+    enabled: bool,
+    #[skip] __: B1,
+    ch: [B6; 2],
+    #[repeat = 2]
+    #[skip(setters)]
+    gain: B5,
+    #[skip] __: B8,
+}
+
+fn main() {
+    let layout = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/layouts/emit-layout-field-arrays.json"
+    ))
+    .unwrap();
+    assert_eq!(
+        layout,
+        r#"{
+  "name": "Channels",
+  "bits": 32,
+  "bytes": 4,
+  "filled": true,
+  "bit_order": "le",
+  "fields": [
+    { "name": "enabled", "type": "bool", "offset": 0, "bits": 1, "getters": true, "setters": true },
+    { "name": "__", "type": "B1", "offset": 1, "bits": 1, "getters": false, "setters": false },
+    { "name": "ch", "type": "B6", "offset": 2, "bits": 6, "len": 2, "getters": true, "setters": true, "indexed_getters": ["ch", "ch_or_err"], "indexed_setters": ["set_ch", "set_ch_checked"] },
+    { "name": "gain", "type": "B5", "offset": 14, "bits": 5, "repeat": 2, "getters": true, "setters": false, "indexed_getters": ["gain_at"], "indexed_setters": [] },
+    { "name": "__", "type": "B8", "offset": 24, "bits": 8, "getters": false, "setters": false }
+  ]
+}
+"#
+    );
+}
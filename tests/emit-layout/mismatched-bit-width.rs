@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+// A user defined `B6` shadows the predefined specifier.
+type B6 = modular_bitfield::specifiers::B7;
+
+#[bitfield(emit_layout = "layouts/emit-layout-mismatched-bit-width.json")]
+pub struct Register {
+    a: bool,
+    b: B6,
+}
+
+fn main() {}
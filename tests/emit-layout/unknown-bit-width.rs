@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield(emit_layout = "layouts/emit-layout-unknown-bit-width.json")]
+pub struct Register {
+    mode: Mode,
+    rest: B6,
+}
+
+fn main() {}
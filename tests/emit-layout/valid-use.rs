@@ -0,0 +1,43 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield(emit_layout = "layouts/emit-layout-valid-use.json")]
+pub struct Register {
+    enabled: bool,
+    #[bits = 2]
+    mode: Mode,
+    #[skip] __: B5,
+    #[skip(setters)]
+    value: u16,
+    tail: modular_bitfield::specifiers::B8,
+}
+
+fn main() {
+    let layout = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/layouts/emit-layout-valid-use.json"
+    ))
+    .unwrap();
+    assert_eq!(
+        layout,
+        r#"{
+  "name": "Register",
+  "bits": 32,
+  "bytes": 4,
+  "filled": true,
+  "bit_order": "le",
+  "fields": [
+    { "name": "enabled", "type": "bool", "offset": 0, "bits": 1, "getters": true, "setters": true },
+    { "name": "mode", "type": "Mode", "offset": 1, "bits": 2, "getters": true, "setters": true },
+    { "name": "__", "type": "B5", "offset": 3, "bits": 5, "getters": false, "setters": false },
+    { "name": "value", "type": "u16", "offset": 8, "bits": 16, "getters": true, "setters": false },
+    { "name": "tail", "type": "modular_bitfield::specifiers::B8", "offset": 24, "bits": 8, "getters": true, "setters": true }
+  ]
+}
+"#
+    );
+}
@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Kind {
+    Data = 0b00,
+    Ack = 0b01,
+    Nack = 0b10,
+}
+
+#[bitfield(fault_injection)]
+#[derive(Clone, Copy)]
+pub struct Frame {
+    valid: bool,
+    kind: Kind,
+    #[skip]
+    __: B5,
+    len: u8,
+}
+
+#[bitfield(fault_injection, bit_order = be)]
+pub struct Compact {
+    first: B3,
+    second: B5,
+}
+
+#[test]
+fn flip_bit_toggles_single_bit() {
+    let frame = Frame::new().with_len(0b1000_0001);
+    assert!(frame.flip_bit(0).valid());
+    assert_eq!(frame.flip_bit(8).len(), 0b1000_0000);
+    assert_eq!(frame.flip_bit(15).flip_bit(15).len(), 0b1000_0001);
+    assert_eq!(Frame::new().flip_bit(2).kind(), Kind::Nack);
+    assert!(Frame::new().flip_bit(1).flip_bit(2).kind_or_err().is_err());
+    assert_eq!(Compact::new().flip_bit(0).first(), 0b100);
+    assert_eq!(Compact::new().flip_bit(7).second(), 0b00001);
+}
+
+#[test]
+#[should_panic(expected = "bit index out of bounds for bitfield Frame")]
+fn flip_bit_out_of_bounds() {
+    let _ = Frame::new().flip_bit(16);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn corrupt_changes_only_the_field() {
+    use modular_bitfield::rand::rngs::mock::StepRng;
+
+    let mut rng = StepRng::new(0x1234_5678, 0x9E37_79B9_7F4A_7C15);
+    let frame = Frame::new().with_valid(true).with_kind(Kind::Ack).with_len(42);
+    for _ in 0..32 {
+        let corrupted = frame.corrupt_len(&mut rng);
+        assert_ne!(corrupted.len(), 42);
+        assert!(corrupted.valid());
+        assert_eq!(corrupted.kind(), Kind::Ack);
+        let corrupted = frame.corrupt_kind(&mut rng);
+        assert_ne!(corrupted.kind_or_err().ok(), Some(Kind::Ack));
+        assert_eq!(corrupted.len(), 42);
+    }
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ffi_export(prefix = "1st-"))]
+pub struct Register {
+    value: B8,
+}
+
+fn main() {}
@@ -0,0 +1,58 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+#[bitfield(ffi_export(prefix = "reg_"))]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    #[skip(setters)]
+    status: B5,
+    value: B24,
+}
+
+#[bitfield(bit_order = be, ffi_export(prefix = "be_"))]
+pub struct BigEndian(B3, B5);
+
+fn main() {
+    let mut bytes = Register::new()
+        .with_enabled(true)
+        .with_mode(Mode::Auto)
+        .with_value(0xAB_CDEF)
+        .into_bytes();
+    unsafe {
+        assert_eq!(reg_get_enabled(bytes.as_ptr()), 1);
+        assert_eq!(reg_get_mode(bytes.as_ptr()), 2);
+        assert_eq!(reg_get_status(bytes.as_ptr()), 0);
+        assert_eq!(reg_get_value(bytes.as_ptr()), 0xAB_CDEF);
+
+        assert!(reg_set_mode(bytes.as_mut_ptr(), 1));
+        assert!(reg_set_value(bytes.as_mut_ptr(), 0x12_3456));
+        // Out of bounds bits are rejected and leave the field unchanged.
+        assert!(!reg_set_value(bytes.as_mut_ptr(), 0x100_0000));
+        // Bits that are invalid for the field type are written as they are.
+        assert!(reg_set_mode(bytes.as_mut_ptr(), 3));
+        assert_eq!(reg_get_mode(bytes.as_ptr()), 3);
+        assert!(reg_set_mode(bytes.as_mut_ptr(), 0));
+    }
+    let register = Register::from_bytes(bytes);
+    assert!(register.enabled());
+    assert_eq!(register.mode(), Mode::Off);
+    assert_eq!(register.status(), 0);
+    assert_eq!(register.value(), 0x12_3456);
+
+    let mut bytes = BigEndian::new().with_0(0b101).into_bytes();
+    unsafe {
+        assert_eq!(be_get_0(bytes.as_ptr()), 0b101);
+        assert!(be_set_1(bytes.as_mut_ptr(), 0b1_0001));
+    }
+    assert_eq!(bytes, [0b1011_0001]);
+    let big_endian = BigEndian::from_bytes(bytes);
+    assert_eq!((big_endian.get_0(), big_endian.get_1()), (0b101, 0b1_0001));
+}
@@ -0,0 +1,46 @@
+use modular_bitfield::{prelude::*, FieldValue};
+use std::{
+    cmp::Ordering,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+#[bitfield(hash, iter_fields)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Dmx {
+    start: B8,
+    channels: [B12; 2],
+}
+
+fn hash_of(dmx: &Dmx) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    dmx.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let mut dmx = Dmx::new();
+    dmx.set_channels(1, 0x123);
+    assert_eq!(format!("{:?}", dmx), "Dmx { start: 0, channels: [0, 291] }");
+
+    let other = Dmx::new();
+    assert_ne!(dmx, other);
+    assert_eq!(dmx.cmp(&other), Ordering::Greater);
+    assert_eq!(dmx.partial_cmp(&other), Some(Ordering::Greater));
+    assert_ne!(hash_of(&dmx), hash_of(&other));
+
+    // The elements are compared in order before the fields that follow them.
+    let mut first = Dmx::new();
+    first.set_channels(0, 1);
+    assert!(first > dmx);
+    assert_eq!(first.cmp(&first), Ordering::Equal);
+
+    assert_eq!(
+        dmx.iter_fields().collect::<Vec<_>>(),
+        [
+            FieldValue { name: "start", bits: 0..8, raw: 0, value: Some(0) },
+            FieldValue { name: "channels[0]", bits: 8..20, raw: 0, value: Some(0) },
+            FieldValue { name: "channels[1]", bits: 20..32, raw: 0x123, value: Some(0x123) },
+        ],
+    );
+}
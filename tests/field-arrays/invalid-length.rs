@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+const LEN: usize = 2;
+
+#[bitfield]
+pub struct Frame {
+    channels: [B4; LEN],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Frame {
+    #[repeat = 2]
+    channels: [B2; 2],
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(serde = "fields")]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Dmx {
+    start: B8,
+    channels: [B12; 2],
+}
+
+fn main() {
+    let mut dmx = Dmx::new().with_start(1);
+    dmx.set_channels(1, 0x123);
+    let json = serde_json::to_string(&dmx).unwrap();
+    assert_eq!(json, r#"{"start":1,"channels":[0,291]}"#);
+    assert_eq!(serde_json::from_str::<Dmx>(&json).unwrap(), dmx);
+    assert!(serde_json::from_str::<Dmx>(r#"{"start":1,"channels":[0,4096]}"#).is_err());
+    assert!(serde_json::from_str::<Dmx>(r#"{"start":1,"channels":[0,1,2]}"#).is_err());
+}
@@ -0,0 +1,53 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield]
+pub struct Frame {
+    start: B4,
+    channels: [B12; 8],
+    modes: [Mode; 2],
+    flags: [bool; 4],
+    #[skip]
+    __: B4,
+}
+
+#[bitfield(bit_order = be)]
+pub struct Pair(u8, [B4; 2]);
+
+fn main() {
+    let mut frame = Frame::new();
+    for index in 0..8 {
+        frame.set_channels(index, 0x100 + index as u16);
+    }
+    frame.set_modes(1, Mode::Fast);
+    frame.set_flags(3, true);
+    for index in 0..8 {
+        assert_eq!(frame.channels(index), 0x100 + index as u16);
+    }
+    assert_eq!(frame.modes(0), Mode::Off);
+    assert_eq!(frame.modes(1), Mode::Fast);
+    assert!(frame.flags(3));
+    assert!(!frame.flags(0));
+
+    assert_eq!(frame.channels_or_err(7), Ok(0x107));
+    assert!(frame.channels_or_err(8).is_err());
+    assert!(frame.set_channels_checked(8, 0).is_err());
+    assert!(frame.set_channels_checked(0, 0x1000).is_err());
+    assert_eq!(frame.set_channels_checked(0, 0xFFF), Ok(()));
+    assert_eq!(frame.channels(0), 0xFFF);
+    assert_eq!(frame.into_bytes().len(), 14);
+
+    let mut pair = Pair::new().with_0(0xAB);
+    pair.set_1(0, 0x1);
+    pair.set_1(1, 0x2);
+    assert_eq!(pair.get_1(1), 0x2);
+    assert_eq!(pair.get_1_or_err(2).is_err(), true);
+    assert_eq!(pair.into_bytes(), [0xAB, 0x12]);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    enabled: bool,
+    #[default = 1]
+    #[default = 2]
+    divider: B7,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    enabled: bool,
+    #[default(1)]
+    divider: B7,
+}
+
+fn main() {}
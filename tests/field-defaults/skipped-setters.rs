@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    enabled: bool,
+    #[skip(setters)]
+    #[default = 3]
+    divider: B7,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+const RESET_DIVIDER: u16 = 0x100;
+
+#[bitfield]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Control {
+    #[default = true]
+    enabled: bool,
+    #[default = Mode::Auto]
+    mode: Mode,
+    #[skip]
+    __: B5,
+    #[default = RESET_DIVIDER + 1]
+    divider: B12,
+    status: B4,
+}
+
+#[bitfield(passthrough(Default))]
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Passed {
+    #[default = 0b101]
+    level: B7,
+    enabled: bool,
+}
+
+fn main() {
+    let control = Control::new_with_defaults();
+    assert!(control.enabled());
+    assert_eq!(control.mode(), Mode::Auto);
+    assert_eq!(control.divider(), 0x101);
+    assert_eq!(control.status(), 0);
+    assert_eq!(Control::default(), control);
+    assert_ne!(Control::new(), control);
+
+    assert_eq!(Passed::new_with_defaults().level(), 0b101);
+    assert_eq!(Passed::default().level(), 0);
+}
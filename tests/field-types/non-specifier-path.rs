@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+mod widths {
+    pub struct B24;
+}
+
+#[bitfield]
+pub struct Packet {
+    a: B8,
+    b: widths::B24,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+
+mod widths {
+    pub use modular_bitfield::specifiers::B24;
+}
+
+const HEADER: usize = 3;
+
+pub struct Bits<const N: usize>;
+
+impl Specifier for Bits<6> {
+    const BITS: usize = 6;
+    type Bytes = u8;
+    type InOut = u8;
+
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, modular_bitfield::error::OutOfBounds> {
+        if input >= 1 << 6 {
+            return Err(modular_bitfield::error::OutOfBounds)
+        }
+        Ok(input)
+    }
+
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+        Ok(bytes)
+    }
+}
+
+#[bitfield]
+pub struct Packet {
+    a: bool,
+    b: widths::B24,
+    c: Bits<{ HEADER * 2 }>,
+    d: ::modular_bitfield::specifiers::B1,
+}
+
+fn main() {
+    let packet = Packet::new()
+        .with_a(true)
+        .with_b(0xAB_CDEF)
+        .with_c(0b11_1111)
+        .with_d(1);
+    assert!(packet.a());
+    assert_eq!(packet.b(), 0xAB_CDEF);
+    assert_eq!(packet.c(), 0b11_1111);
+    assert_eq!(packet.d(), 1);
+    assert_eq!(packet.into_bytes(), [0xDF, 0x9B, 0x57, 0xFF]);
+}
@@ -0,0 +1,47 @@
+use modular_bitfield::prelude::*;
+
+// The derives of the bitfield are not forwarded to the fields struct so the value types
+// of the fields do not need to implement them.
+#[derive(BitfieldSpecifier, Debug)]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+    Auto = 0b11,
+}
+
+#[bitfield(fields_struct)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Channels {
+    pub enabled: bool,
+    #[skip] __: B3,
+    pub ch: [B6; 2],
+    #[repeat = 2]
+    pub mode: Mode,
+    #[skip(getters)]
+    pub hidden: B8,
+    #[repeat = 2]
+    #[skip(getters)]
+    pub hidden_repeat: B2,
+}
+
+#[bitfield(fields_struct, minimal)]
+pub struct Compact {
+    first: [B4; 2],
+}
+
+fn main() {
+    let mut channels = Channels::new().with_enabled(true);
+    channels.set_ch(0, 42);
+    channels.set_ch(1, 17);
+    channels.set_mode_at(1, Mode::Auto).unwrap();
+    let ChannelsFields { enabled, ch, mode } = channels.fields();
+    assert!(enabled);
+    assert_eq!(ch, [42, 17]);
+    assert!(matches!(mode, [Mode::Off, Mode::Auto]));
+
+    let mut compact = Compact::new();
+    compact.set_first(1, 9);
+    let CompactFields { first } = compact.fields();
+    assert_eq!(first, [0, 9]);
+}
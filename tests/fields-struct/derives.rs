@@ -0,0 +1,45 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+    Auto = 0b11,
+}
+
+#[bitfield(fields_struct(derive(Debug, Clone, Copy, PartialEq, Eq, Hash)))]
+pub struct Channels {
+    pub enabled: bool,
+    #[skip] __: B3,
+    pub ch: [B6; 2],
+    #[repeat = 2]
+    pub mode: Mode,
+    pub gain: B4,
+}
+
+fn main() {
+    let mut channels = Channels::new().with_enabled(true).with_gain(5);
+    channels.set_ch(0, 42);
+    channels.set_ch(1, 17);
+    channels.set_mode_at(1, Mode::Auto).unwrap();
+    let fields = channels.fields();
+    let copy = fields;
+    assert_eq!(copy, fields.clone());
+    assert_eq!(
+        fields,
+        ChannelsFields {
+            enabled: true,
+            ch: [42, 17],
+            mode: [Mode::Off, Mode::Auto],
+            gain: 5,
+        }
+    );
+    assert_eq!(
+        format!("{:?}", fields),
+        "ChannelsFields { enabled: true, ch: [42, 17], mode: [Off, Auto], gain: 5 }",
+    );
+    let mut set = std::collections::HashSet::new();
+    set.insert(fields);
+    assert!(set.contains(&copy));
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(fields_struct, fields_struct)]
+pub struct Pair {
+    first: B4,
+    second: B4,
+}
+
+fn main() {}
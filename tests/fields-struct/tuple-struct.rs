@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(fields_struct)]
+pub struct Pair(B4, B4);
+
+fn main() {}
@@ -0,0 +1,53 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+    Auto = 0b11,
+}
+
+#[bitfield(fields_struct)]
+pub struct Telemetry {
+    pub enabled: bool,
+    pub mode: Mode,
+    #[reserved]
+    reserved: B5,
+    #[skip(getters)]
+    pub hidden: B4,
+    #[offset_by = 1]
+    pub count: B4,
+    pub reading: u16,
+}
+
+#[bitfield(fields_struct, bit_order = be, minimal)]
+pub struct Compact {
+    first: B3,
+    second: B5,
+}
+
+fn main() {
+    let telemetry = Telemetry::new()
+        .with_enabled(true)
+        .with_mode(Mode::Auto)
+        .with_count(16)
+        .with_reading(0xBEEF);
+    let fields = telemetry.fields();
+    assert!(fields.enabled);
+    assert_eq!(fields.mode, Mode::Auto);
+    assert_eq!(fields.count, 16);
+    assert_eq!(fields.reading, 0xBEEF);
+    let TelemetryFields { enabled, mode, count, reading } = Telemetry::new().fields();
+    assert!(!enabled);
+    assert_eq!(mode, Mode::Off);
+    assert_eq!(count, 1);
+    assert_eq!(reading, 0);
+
+    let mut compact = Compact::new();
+    compact.set_first(5);
+    compact.set_second(17);
+    let CompactFields { first, second } = compact.fields();
+    assert_eq!(first, 5);
+    assert_eq!(second, 17);
+}
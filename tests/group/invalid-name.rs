@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupts {
+    #[group = "irq enable"]
+    rx: bool,
+    priority: B7,
+}
+
+fn main() {}
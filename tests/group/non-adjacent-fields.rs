@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupts {
+    #[group = "irq"]
+    rx: bool,
+    priority: B6,
+    #[group = "irq"]
+    tx: bool,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupts {
+    #[group = "irq"]
+    rx: bool,
+    #[group = "irq"]
+    count: B7,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupts {
+    #[group = "irq"]
+    rx: bool,
+    #[group = "irq"]
+    #[skip(setters)]
+    tx: bool,
+    priority: B6,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Interrupts {
+    #[group = "irq_enable"]
+    rx_enable: bool,
+    #[group = "irq_enable"]
+    tx_enable: bool,
+    #[group = "irq_status"]
+    rx_pending: bool,
+    #[group = "irq_status"]
+    tx_pending: core::primitive::bool,
+    priority: B4,
+}
+
+#[bitfield(minimal, bit_order = be)]
+pub struct Single {
+    #[group = "flags"]
+    only: bool,
+    rest: B7,
+}
+
+fn main() {
+    let mut interrupts = Interrupts::new().with_tx_enable(true).with_priority(7);
+    assert_eq!(
+        interrupts.irq_enable(),
+        IrqEnableFlags {
+            rx_enable: false,
+            tx_enable: true,
+        }
+    );
+    interrupts.set_irq_status(IrqStatusFlags {
+        rx_pending: true,
+        tx_pending: true,
+    });
+    assert!(interrupts.rx_pending());
+    assert!(interrupts.tx_pending());
+    assert_eq!(interrupts.priority(), 7);
+    interrupts.set_irq_enable(IrqEnableFlags::default());
+    assert!(!interrupts.tx_enable());
+    assert_eq!(interrupts.into_bytes(), [0b0111_1100]);
+
+    let mut single = Single::new();
+    single.set_flags(FlagsFlags { only: true });
+    assert!(single.flags().only);
+    assert_eq!(single.into_bytes(), [0b1000_0000]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(hash)]
+#[derive(Hash)]
+pub struct FlowDescriptor {
+    protocol: u8,
+    port: u8,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use modular_bitfield::prelude::*;
+use std::{
+    collections::hash_map::DefaultHasher,
+    collections::HashMap,
+    hash::{Hash, Hasher},
+};
+
+#[bitfield(hash)]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowDescriptor {
+    protocol: u8,
+    #[skip]
+    __: B4,
+    port: B12,
+    #[skip(getters)]
+    scratch: B8,
+}
+
+#[bitfield(hash, bit_order = be)]
+#[derive(Clone, Copy)]
+pub struct BigEndian {
+    a: B3,
+    #[skip]
+    __: B2,
+    b: B3,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let clean = FlowDescriptor::new().with_protocol(6).with_port(443);
+    // The same descriptor read from a dirty buffer with garbage in the padding bits.
+    let mut bytes = clean.into_bytes();
+    bytes[1] |= 0b0000_1111;
+    bytes[3] = 0xFF;
+    let dirty = FlowDescriptor::from_bytes(bytes);
+    assert_eq!(dirty.protocol(), 6);
+    assert_eq!(dirty.port(), 443);
+    assert_eq!(hash_of(&clean), hash_of(&dirty));
+
+    let other = FlowDescriptor::new().with_protocol(17).with_port(443);
+    assert_ne!(hash_of(&clean), hash_of(&other));
+
+    let mut cache = HashMap::new();
+    cache.insert(hash_of(&clean), "https");
+    assert_eq!(cache.get(&hash_of(&dirty)), Some(&"https"));
+
+    let clean = BigEndian::new().with_a(0b101).with_b(0b011);
+    let dirty = BigEndian::from_bytes([clean.into_bytes()[0] | 0b0001_1000]);
+    assert_eq!(hash_of(&clean), hash_of(&dirty));
+}
@@ -0,0 +1,84 @@
+use modular_bitfield::{prelude::*, FieldValue};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+}
+
+#[bitfield(iter_fields)]
+pub struct Telemetry {
+    enabled: bool,
+    mode: Mode,
+    #[reserved]
+    reserved: B5,
+    #[skip(getters)]
+    hidden: B4,
+    level: TwosComplement<B4>,
+    reading: u16,
+}
+
+#[bitfield(iter_fields, bit_order = be, minimal)]
+pub struct Compact(B3, B5);
+
+#[bitfield(iter_fields)]
+pub struct Samples {
+    count: B2,
+    #[repeat = 3]
+    samples: B2,
+}
+
+fn main() {
+    let telemetry = Telemetry::new()
+        .with_enabled(true)
+        .with_mode(Mode::High)
+        .with_level(5)
+        .with_reading(0xBEEF);
+    let fields = telemetry.iter_fields().collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [
+            FieldValue { name: "enabled", bits: 0..1, raw: 1, value: Some(1) },
+            FieldValue { name: "mode", bits: 1..3, raw: 2, value: None },
+            FieldValue { name: "reserved", bits: 3..8, raw: 0, value: Some(0) },
+            FieldValue { name: "level", bits: 12..16, raw: 5, value: Some(5) },
+            FieldValue { name: "reading", bits: 16..32, raw: 0xBEEF, value: Some(0xBEEF) },
+        ],
+    );
+    let negative = Telemetry::new().with_level(-1);
+    let level = (&negative).into_iter().find(|field| field.name == "level").unwrap();
+    assert_eq!(level.raw, 0b1111);
+    assert_eq!(level.value, None);
+    let invalid = Telemetry::from_bytes([0b0000_0110, 0, 0, 0]);
+    let mode = invalid.iter_fields().nth(1).unwrap();
+    assert_eq!(mode.raw, 0b11);
+    assert_eq!(mode.value, None);
+
+    let mut compact = Compact::new();
+    compact.set_0(5);
+    compact.set_1(17);
+    let mut count = 0;
+    for field in &compact {
+        count += 1;
+        assert!(field.name == "0" || field.name == "1");
+        assert_eq!(field.value, Some(field.raw));
+    }
+    assert_eq!(count, 2);
+    assert_eq!(compact.iter_fields().last().unwrap().bits, 3..8);
+
+    let mut samples = Samples::new();
+    samples.set_count(2);
+    samples.set_samples_at(0, 1).unwrap();
+    samples.set_samples_at(2, 3).unwrap();
+    assert_eq!(
+        samples.iter_fields().collect::<Vec<_>>(),
+        [
+            FieldValue { name: "count", bits: 0..2, raw: 2, value: Some(2) },
+            FieldValue { name: "samples[0]", bits: 2..4, raw: 1, value: Some(1) },
+            FieldValue { name: "samples[1]", bits: 4..6, raw: 0, value: Some(0) },
+            FieldValue { name: "samples[2]", bits: 6..8, raw: 3, value: Some(3) },
+        ],
+    );
+}
@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(keep_phantom_fields)]
+pub struct Tuple(B4, B4);
+
+fn main() {}
@@ -0,0 +1,47 @@
+use core::marker::PhantomData;
+use modular_bitfield::prelude::*;
+
+#[bitfield(keep_phantom_fields, uninit = true)]
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub struct Control {
+    /// Whether the device is enabled.
+    pub enabled: bool,
+    #[skip] __: B3,
+    #[skip] __: B4,
+    mode: B4,
+    #[reserved]
+    reserved: B4,
+}
+
+#[bitfield(keep_phantom_fields, filled = false)]
+pub struct Unfilled {
+    a: B3,
+}
+
+#[bitfield]
+pub struct Outer {
+    control: Control,
+}
+
+fn main() {
+    let control = Control::new().with_enabled(true).with_mode(5);
+    let Control {
+        enabled,
+        mode,
+        reserved,
+        ..
+    } = control;
+    let _: PhantomData<bool> = enabled;
+    let _: PhantomData<B4> = mode;
+    let _: PhantomData<B4> = reserved;
+    assert_eq!(core::mem::size_of::<Control>(), 2);
+
+    assert_eq!(Control::from(u16::from(control)), control);
+    assert_eq!(Control::from_bytes(control.into_bytes()), control);
+    let uninit = unsafe { Control::from_uninit_with(|bytes| { bytes.write([0x01, 0x05]); }) };
+    assert_eq!(uninit, control);
+    assert_eq!(Outer::new().with_control(control).control(), control);
+
+    assert_eq!(Unfilled::from_bytes([0b101]).unwrap().a(), 0b101);
+}
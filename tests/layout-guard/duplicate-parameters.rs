@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(layout_guard = "a.snapshot", layout_guard = "b.snapshot")]
+pub struct Header {
+    is_compact: bool,
+    len: B7,
+}
+
+fn main() {}
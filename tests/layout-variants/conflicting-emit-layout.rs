@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(emit_layout = "target/layouts/register.json")]
+pub struct Register {
+    enable: bool,
+    #[cfg(not(any()))]
+    mode: B7,
+    #[cfg(any())]
+    mode: B7,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+// The `any()` layout variant is invalid although it is never compiled.
+#[bitfield]
+pub struct Register {
+    enable: bool,
+    #[cfg(not(any()))]
+    mode: B7,
+    #[cfg(any())]
+    mode: B6,
+}
+
+fn main() {}
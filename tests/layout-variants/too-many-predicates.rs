@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    #[cfg(feature = "a")]
+    a: B8,
+    #[cfg(feature = "b")]
+    b: B8,
+    #[cfg(feature = "c")]
+    c: B8,
+    #[cfg(feature = "d")]
+    d: B8,
+    #[cfg(feature = "e")]
+    e: B8,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+use modular_bitfield::prelude::*;
+
+// The `any()` predicate never holds so the `not(any())` fields are used.
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Register {
+    enable: bool,
+    #[cfg(not(any()))]
+    mode: B4,
+    #[cfg(any())]
+    #[bits = 6]
+    mode: B6,
+    #[cfg_attr(not(any()), skip)]
+    #[cfg(not(any()))]
+    reserved: B3,
+    #[cfg(any())]
+    reserved: B1,
+}
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Widened {
+    #[cfg_attr(not(any()), bits = 16)]
+    #[cfg_attr(any(), bits = 8)]
+    value: u16,
+}
+
+fn main() {
+    let register = Register::new().with_enable(true).with_mode(0b1010);
+    assert!(register.enable());
+    assert_eq!(register.mode(), 0b1010);
+    assert_eq!(register.into_bytes(), [0b0001_0101]);
+    assert_eq!(Register::LAYOUT_VARIANT, "all(not(any()))");
+
+    let widened = Widened::new().with_value(0xABCD);
+    assert_eq!(widened.value(), 0xABCD);
+    assert_eq!(Widened::LAYOUT_VARIANT, "all(not(any()))");
+}
@@ -0,0 +1,69 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield]
+pub struct Header {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+    #[repeat = 2]
+    lanes: B4,
+}
+
+// Same layout with different field types and struct name.
+#[bitfield]
+pub struct HeaderCopy {
+    enabled: bool,
+    mode: B2,
+    #[skip]
+    __: B5,
+    lanes: B8,
+}
+
+#[bitfield]
+pub struct RenamedField {
+    enabled: bool,
+    kind: Mode,
+    #[skip]
+    __: B5,
+    lanes: B8,
+}
+
+#[bitfield]
+pub struct ResizedField {
+    enabled: bool,
+    mode: B3,
+    #[skip]
+    __: B4,
+    lanes: B8,
+}
+
+#[bitfield(bit_order = be)]
+pub struct BigEndian {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+    lanes: B8,
+}
+
+#[bitfield]
+pub struct Single {
+    value: u8,
+}
+
+const VERSION: u64 = Header::LAYOUT_VERSION;
+
+fn main() {
+    assert_eq!(VERSION, HeaderCopy::LAYOUT_VERSION);
+    assert_ne!(Header::LAYOUT_VERSION, RenamedField::LAYOUT_VERSION);
+    assert_ne!(Header::LAYOUT_VERSION, ResizedField::LAYOUT_VERSION);
+    assert_ne!(Header::LAYOUT_VERSION, BigEndian::LAYOUT_VERSION);
+    // The layout version must be stable across releases.
+    assert_eq!(Single::LAYOUT_VERSION, 0xE3DE_4552_0E57_34CE);
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use modular_bitfield::{
+    prelude::*,
+    private::check_layout_snapshot,
+};
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A = 0b00,
+    B = 0b01,
+    C = 0b10,
+    D = 0b11,
+}
+
+// Generates a `#[test]` comparing the layout against the checked-in snapshot.
+#[bitfield(layout_guard = "tests/layout-guard/header.snapshot")]
+pub struct Header {
+    is_compact: bool,
+    mode: Mode,
+    #[skip]
+    __: B5,
+    len: B24,
+}
+
+#[test]
+fn matching_snapshot() {
+    check_layout_snapshot("header.snapshot", Some("struct H bits 8\n"), "struct H bits 8\n");
+}
+
+#[test]
+#[should_panic(expected = "layout does not match the snapshot header.snapshot:\n  \
+                           struct H bits 8\n- field a offset 0 width 8\n+ field a offset 0 width 4\n")]
+fn mismatching_snapshot() {
+    check_layout_snapshot(
+        "header.snapshot",
+        Some("struct H bits 8\nfield a offset 0 width 8\n"),
+        "struct H bits 8\nfield a offset 0 width 4\n",
+    );
+}
+
+#[test]
+#[should_panic(expected = "missing layout snapshot header.snapshot")]
+fn missing_snapshot() {
+    check_layout_snapshot("header.snapshot", None, "struct H bits 8\n");
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(lints, lints)]
+pub struct Register {
+    value: B8,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 4]
+pub enum Mode {
+    A,
+    B,
+    C,
+}
+
+#[bitfield(lints)]
+pub struct Register {
+    enabled: B3,
+    value: B7,
+    mode: Mode,
+    #[skip]
+    __: B2,
+    address: B8,
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 3]
+pub enum Sparse {
+    A = 0,
+    B = 5,
+}
+
+#[bitfield(lints)]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    sparse: Sparse,
+    #[skip]
+    __: B2,
+    value: B16,
+    #[skip]
+    __: B8,
+}
+
+fn main() {
+    let register = Register::new()
+        .with_enabled(true)
+        .with_mode(Mode::C)
+        .with_sparse(Sparse::B)
+        .with_value(0xBEEF);
+    assert!(register.enabled());
+    assert_eq!(register.mode(), Mode::C);
+    assert_eq!(register.sparse(), Sparse::B);
+    assert_eq!(register.value(), 0xBEEF);
+}
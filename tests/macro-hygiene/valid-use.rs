@@ -0,0 +1,112 @@
+use modular_bitfield::prelude::*;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+// Generates specifiers and bitfields from templates so that the spans of the
+// fields carry the hygiene of the template invocation instead of the one of
+// the `#[bitfield]` invocation.
+macro_rules! specifier {
+    ( $vis:vis enum $name:ident { $( $variant:ident = $value:expr ),* $(,)? } ) => {
+        #[derive(BitfieldSpecifier, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[bits = 2]
+        $vis enum $name {
+            $( $variant = $value ),*
+        }
+    };
+}
+
+macro_rules! register {
+    (
+        $( #[$attr:meta] )*
+        $vis:vis struct $name:ident {
+            $( $( #[$field_attr:meta] )* $field_vis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        #[bitfield]
+        $( #[$attr] )*
+        $vis struct $name {
+            $( $( #[$field_attr] )* $field_vis $field: $ty ),*
+        }
+    };
+}
+
+macro_rules! register_with_params {
+    ( $name:ident, $first:ident, $second:ident ) => {
+        #[bitfield(hash, dirty_tracking, bit_order = be)]
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        pub struct $name {
+            $first: bool,
+            #[skip]
+            __: B3,
+            #[repeat = 2]
+            $second: B2,
+        }
+    };
+}
+
+macro_rules! split_register {
+    ( $name:ident ) => {
+        #[bitfield(split_at = "high", minimal)]
+        pub struct $name {
+            low: u8,
+            high: u8,
+        }
+    };
+}
+
+specifier!(pub enum Mode { Idle = 0, Run = 1, Halt = 2 });
+
+register! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Ctrl {
+        pub en: bool,
+        #[bits = 2]
+        mode: Mode,
+        #[skip(setters)]
+        pub status: B5,
+        divider: u8,
+    }
+}
+
+register_with_params!(Status, ready, lanes);
+split_register!(Split);
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let mut ctrl = Ctrl::new().with_en(true).with_mode(Mode::Run);
+    ctrl.set_divider(42);
+    assert_eq!(ctrl.mode(), Mode::Run);
+    assert_eq!(ctrl.mode_or_err(), Ok(Mode::Run));
+    assert_eq!(ctrl.divider(), 42);
+    assert!(ctrl.set_divider_checked(7).is_ok());
+    assert_eq!(
+        format!("{:?}", ctrl),
+        "Ctrl { en: true, mode: Run, status: 0, divider: 7 }",
+    );
+    assert!(ctrl > Ctrl::new());
+
+    let mut status = Status::new();
+    status.set_ready(true);
+    status.set_lanes_at(1, 3).unwrap();
+    assert_eq!(status.lanes_at(1), Ok(3));
+    assert!(status.take_dirty().ready());
+    let mut other = Status::new();
+    other.set_ready(true);
+    other.set_lanes_at(1, 3).unwrap();
+    other.take_dirty();
+    assert_eq!(status, other);
+    assert_eq!(hash_of(&status), hash_of(&other));
+
+    let mut split = Split::new();
+    let (mut low, mut high) = split.split_mut();
+    low.set_low(1);
+    high.set_high(2);
+    assert_eq!(split.into_bytes(), [1, 2]);
+}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(masks)]
+pub struct Status {
+    rx_done: bool,
+    tx_done: bool,
+    error: B6,
+}
+
+const CLEAR_ERROR: [u8; 1] = Status::error_mask_for(0b100_0000);
+
+fn main() {
+    let _ = CLEAR_ERROR;
+}
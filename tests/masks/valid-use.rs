@@ -0,0 +1,46 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+    Auto = 0b11,
+}
+
+#[bitfield(masks)]
+pub struct Control {
+    enabled: bool,
+    mode: Mode,
+    #[skip(setters)]
+    status: B5,
+    divider: u16,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(masks, bit_order = be)]
+pub struct Header(B4, B4, u16);
+
+const SET_ENABLED: [u8; 4] = Control::enabled_mask_for(1);
+
+fn main() {
+    assert_eq!(SET_ENABLED, [0x01, 0x00, 0x00, 0x00]);
+    assert_eq!(Control::mode_mask_for(Mode::Auto as u8), [0b0000_0110, 0x00, 0x00, 0x00]);
+    assert_eq!(Control::status_mask_for(0b1_0001), [0b1000_1000, 0x00, 0x00, 0x00]);
+    assert_eq!(Control::divider_mask_for(0xABCD), [0x00, 0xCD, 0xAB, 0x00]);
+    let control = Control::new().with_enabled(true).with_divider(0xABCD);
+    let mut combined = Control::enabled_mask_for(1);
+    for (byte, mask) in combined.iter_mut().zip(Control::divider_mask_for(0xABCD)) {
+        *byte |= mask;
+    }
+    assert_eq!(control.into_bytes(), combined);
+
+    assert_eq!(Header::get_0_mask_for(0x4), [0x40, 0x00, 0x00]);
+    assert_eq!(Header::get_1_mask_for(0x5), [0x05, 0x00, 0x00]);
+    assert_eq!(Header::get_2_mask_for(0x1234), [0x00, 0x12, 0x34]);
+    assert_eq!(
+        Header::new().with_0(4).with_1(5).with_2(0x1234).into_bytes(),
+        [0x45, 0x12, 0x34]
+    );
+}
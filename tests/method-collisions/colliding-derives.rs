@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(getset::Getters, new)]
+pub struct Package {
+    bytes: u8,
+    is_alive: bool,
+    status: B7,
+}
+
+#[bitfield(external_methods = "set_status")]
+pub struct External {
+    is_alive: bool,
+    status: B7,
+}
+
+fn main() {}
@@ -0,0 +1,71 @@
+use modular_bitfield::prelude::*;
+
+// Emulates another macro generating a method for every listed name that returns its name.
+macro_rules! generate_methods {
+    ( $name:ident { $( $method:ident ),* $(,)? } ) => {
+        impl $name {
+            $(
+                pub fn $method(&self) -> &'static str {
+                    stringify!($method)
+                }
+            )*
+        }
+    };
+}
+
+// Every method generated by `#[bitfield]` is renamed if it collides so that this only
+// compiles if none of the listed methods is missed by the collision check.
+#[bitfield(
+    rename_collisions,
+    external_methods = "into_bytes, is_zeroed, enabled, enabled_or_err, enabled_raw, enabled_then, \
+                        set_enabled, set_enabled_checked, set_enabled_chain, set_enabled_raw, \
+                        set_enabled_if, toggle_enabled, with_enabled, with_enabled_checked, \
+                        level, level_raw, set_level_raw, set_level_chain, set_level_truncated, \
+                        set_level_saturating, or_level, and_level, xor_level"
+)]
+pub struct Register {
+    enabled: bool,
+    level: B7,
+}
+generate_methods!(Register {
+    into_bytes,
+    is_zeroed,
+    enabled,
+    enabled_or_err,
+    enabled_raw,
+    enabled_then,
+    set_enabled,
+    set_enabled_checked,
+    set_enabled_chain,
+    set_enabled_raw,
+    set_enabled_if,
+    toggle_enabled,
+    with_enabled,
+    with_enabled_checked,
+    level,
+    level_raw,
+    set_level_raw,
+    set_level_chain,
+    set_level_truncated,
+    set_level_saturating,
+    or_level,
+    and_level,
+    xor_level,
+});
+
+fn main() {
+    let mut register = Register::new();
+    assert_eq!(register.is_zeroed(), "is_zeroed");
+    assert!(register.is_zeroed_bitfield());
+    assert_eq!(register.toggle_enabled(), "toggle_enabled");
+    register.toggle_enabled_bitfield();
+    assert!(register.enabled_bitfield());
+    assert_eq!(register.set_level_saturating(), "set_level_saturating");
+    register.set_level_saturating_bitfield(0xFF);
+    assert_eq!(register.level_bitfield(), 0x7F);
+    assert_eq!(register.xor_level(), "xor_level");
+    register.xor_level_bitfield(0x0F);
+    assert_eq!(register.level_raw_bitfield(), 0x70);
+    assert_eq!(register.into_bytes(), "into_bytes");
+    assert_eq!(register.into_bytes_bitfield(), [0b1110_0001]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(external_methods = "new, not a method")]
+pub struct Package {
+    is_alive: bool,
+    status: B7,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use modular_bitfield::prelude::*;
+
+// Emulates another macro generating methods for the same struct.
+macro_rules! generate_methods {
+    ( $name:ident ) => {
+        impl $name {
+            pub fn new(status: u8) -> Self {
+                Self::new_bitfield().with_status(status)
+            }
+
+            pub fn status(&self) -> &'static str {
+                if self.is_alive() { "alive" } else { "dead" }
+            }
+        }
+    };
+}
+
+#[bitfield(external_methods = "new, status", rename_collisions)]
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct Package {
+    is_received: bool,
+    is_alive: bool,
+    status: B6,
+}
+generate_methods!(Package);
+
+fn main() {
+    let mut package = Package::new(42);
+    assert_eq!(package.status_bitfield(), 42);
+    assert_eq!(package.status(), "dead");
+    package.set_is_alive(true);
+    assert_eq!(package.status(), "alive");
+    package.set_status(7);
+    assert_eq!(package.status_or_err_unchanged(), 7);
+    assert!(package > Package::new(1));
+    assert_eq!(
+        format!("{:?}", Package::new(1)),
+        "Package { is_received: false, is_alive: false, status: 1 }",
+    );
+}
+
+trait Unchanged {
+    fn status_or_err_unchanged(&self) -> u8;
+}
+
+impl Unchanged for Package {
+    // Generated methods that do not collide keep their names.
+    fn status_or_err_unchanged(&self) -> u8 {
+        self.status_or_err().unwrap()
+    }
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(minimal, minimal)]
+pub struct Header {
+    is_compact: bool,
+    len: B15,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(minimal)]
+pub struct Header {
+    is_compact: bool,
+    len: B15,
+}
+
+fn main() {
+    let mut header = Header::new();
+    header.set_len(1);
+    let _ = header.len_or_err();
+    header.set_len_checked(1).unwrap();
+    let _ = header.with_is_compact(true);
+    header.toggle_is_compact();
+    header.set_len_saturating(1);
+}
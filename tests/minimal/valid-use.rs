@@ -0,0 +1,50 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bits = 2]
+pub enum Mode {
+    A = 0b00,
+    B = 0b01,
+    C = 0b10,
+}
+
+#[bitfield(minimal, unchecked_setters)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Header {
+    is_compact: bool,
+    mode: Mode,
+    len: B13,
+}
+
+fn main() {
+    let mut header = Header::new();
+    assert!(!header.is_compact());
+    assert_eq!(header.mode(), Mode::A);
+    assert_eq!(header.len(), 0);
+
+    header.set_is_compact(true);
+    header.set_mode(Mode::C);
+    header.set_len(0x1ABC);
+    assert!(header.is_compact());
+    assert_eq!(header.mode(), Mode::C);
+    assert_eq!(header.len(), 0x1ABC);
+
+    // Explicitly requested methods are still generated.
+    unsafe { header.set_len_unchecked(42) };
+    assert_eq!(header.len(), 42);
+
+    let mut other = Header::new();
+    other.set_is_compact(true);
+    other.set_mode(Mode::B);
+    assert!(other < header);
+
+    assert_eq!(
+        format!("{:?}", header),
+        "Header { is_compact: true, mode: C, len: 42 }",
+    );
+    let invalid = Header::from_bytes([0b0000_0110, 0x00]);
+    assert_eq!(
+        format!("{:?}", invalid),
+        "Header { is_compact: false, mode: InvalidBitPattern { invalid_bytes: 3 }, len: 0 }",
+    );
+}
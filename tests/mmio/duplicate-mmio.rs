@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(mmio, mmio)]
+#[repr(u8)]
+pub struct Control {
+    enabled: bool,
+    divider: B7,
+}
+
+fn main() {}
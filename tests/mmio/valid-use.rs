@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield(mmio)]
+#[repr(u32)]
+#[derive(Debug)]
+pub struct Control {
+    enabled: bool,
+    mode: Mode,
+    divider: B13,
+    status: u16,
+}
+
+#[bitfield(mmio)]
+#[repr(u16)]
+#[repr_endian = "big"]
+pub struct Word {
+    low: B8,
+    high: B8,
+}
+
+fn main() {
+    let mut register: u32 = 0xABCD_0000;
+    let control = unsafe { ControlRegisterRef::new(&mut register) };
+    let copy = control;
+    assert_eq!(control.read_volatile().status(), 0xABCD);
+    control.write_volatile(Control::new().with_enabled(true).with_divider(0x100));
+    copy.modify(|control| control.set_mode(Mode::Fast));
+    let value = control.read_volatile();
+    assert!(value.enabled());
+    assert_eq!(value.mode(), Mode::Fast);
+    assert_eq!(value.divider(), 0x100);
+    assert_eq!(value.status(), 0);
+    assert_eq!(control.as_ptr(), copy.as_ptr());
+    assert_eq!(register, 0x0000_0805);
+
+    let mut register: u16 = 0x1234;
+    let word = unsafe { WordRegisterRef::new(&mut register) };
+    assert_eq!(word.read_volatile().low(), 0x12);
+    word.modify(|word| word.set_high(0xFF));
+    assert_eq!(register, 0x12FF);
+}
@@ -0,0 +1,15 @@
+use modular_bitfield::{modify_fields, prelude::*};
+
+#[bitfield]
+pub struct CtrlReg {
+    en: bool,
+    #[skip(setters)]
+    status: B7,
+}
+
+fn main() {
+    let mut reg = CtrlReg::new();
+    unsafe {
+        modify_fields!(&mut reg, CtrlReg { en: true, status: 1 });
+    }
+}
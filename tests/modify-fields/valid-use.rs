@@ -0,0 +1,73 @@
+use modular_bitfield::{modify_fields, prelude::*};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Idle = 0,
+    Run = 1,
+    Halt = 2,
+}
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct CtrlReg {
+    en: bool,
+    div: B4,
+    mode: Mode,
+    #[skip]
+    __: B1,
+    prescaler: u8,
+}
+
+#[bitfield(minimal)]
+pub struct StatusReg {
+    r#type: B4,
+    level: B4,
+}
+
+mod registers {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield]
+    pub struct Private {
+        pub visible: B4,
+        hidden: B4,
+    }
+}
+
+fn main() {
+    // Emulates a memory mapped block of two control registers.
+    let mut mmio = [CtrlReg::new().with_prescaler(0xAB), CtrlReg::new()];
+    let base = mmio.as_mut_ptr();
+    unsafe {
+        modify_fields!(base, CtrlReg { en: true, div: 4 });
+        modify_fields!(base.add(1), CtrlReg { mode: Mode::Halt, div: 0b1111, });
+        modify_fields!(base, CtrlReg {});
+    }
+    assert!(mmio[0].en());
+    assert_eq!(mmio[0].div(), 4);
+    assert_eq!(mmio[0].mode(), Mode::Idle);
+    assert_eq!(mmio[0].prescaler(), 0xAB);
+    assert!(!mmio[1].en());
+    assert_eq!(mmio[1].div(), 0b1111);
+    assert_eq!(mmio[1].mode(), Mode::Halt);
+
+    // Fields are set in the order of the given initializers.
+    unsafe {
+        modify_fields!(base, CtrlReg { div: 1, div: 2 });
+    }
+    assert_eq!(mmio[0].div(), 2);
+
+    let mut status = StatusReg::new();
+    unsafe {
+        modify_fields!(&mut status, StatusReg { r#type: 3, level: 9 });
+    }
+    assert_eq!(status.r#type(), 3);
+    assert_eq!(status.level(), 9);
+
+    let mut private = registers::Private::new();
+    unsafe {
+        modify_fields!(&mut private, registers::Private { visible: 7 });
+    }
+    assert_eq!(private.visible(), 7);
+}
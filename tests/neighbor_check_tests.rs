@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0b00,
+    Low = 0b01,
+    High = 0b10,
+}
+
+// Each bitfield generates a `#[test]` checking that its setters leave the other fields unchanged.
+#[bitfield(neighbor_check_tests)]
+pub struct Mixed {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B3,
+    #[offset_by = 1]
+    count: B4,
+    level: TwosComplement<B12>,
+    inverted: ActiveLow<bool>,
+    #[skip(getters)]
+    hidden: B1,
+    wide: u32,
+}
+
+#[bitfield(neighbor_check_tests, bit_order = be, dirty_tracking)]
+pub struct BigEndian {
+    a: B3,
+    b: B9,
+    c: B17,
+    d: B3,
+}
+
+#[bitfield(neighbor_check_tests, filled = false, minimal)]
+pub struct Unfilled {
+    a: B5,
+    b: B9,
+}
+
+#[bitfield(neighbor_check_tests, accessors_mod = "regs")]
+pub struct Namespaced(B2, B30, u64);
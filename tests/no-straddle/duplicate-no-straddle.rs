@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(no_straddle, no_straddle)]
+pub struct Descriptor {
+    enabled: bool,
+    channel: B7,
+}
+
+fn main() {}
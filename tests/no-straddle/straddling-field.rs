@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(no_straddle)]
+pub struct Descriptor {
+    enabled: bool,
+    mode: B3,
+    channel: B5,
+    priority: B7,
+    length: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(no_straddle)]
+pub struct Descriptor {
+    enabled: bool,
+    #[repeat = 5]
+    flags: B3,
+}
+
+fn main() {}
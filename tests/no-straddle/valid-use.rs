@@ -0,0 +1,36 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+    Auto = 2,
+}
+
+#[bitfield(no_straddle)]
+pub struct Descriptor {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B1,
+    channel: B4,
+    length: u16,
+    #[repeat = 4]
+    flags: B2,
+    address: B24,
+}
+
+fn main() {
+    let descriptor = Descriptor::new()
+        .with_enabled(true)
+        .with_mode(Mode::Auto)
+        .with_channel(0xA)
+        .with_length(0x1234)
+        .with_address(0x56_789A);
+    assert_eq!(descriptor.mode(), Mode::Auto);
+    assert_eq!(
+        descriptor.into_bytes(),
+        [0b1010_0101, 0x34, 0x12, 0x00, 0x9A, 0x78, 0x56]
+    );
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Transfer {
+    #[offset_by = 1]
+    #[repeat = 2]
+    len: B4,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Transfer {
+    #[offset_by = 0]
+    len: B4,
+    count: B4,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield]
+pub struct Transfer {
+    #[offset_by = 1]
+    mode: Mode,
+    #[skip]
+    __: B6,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Transfer {
+    #[offset_by = 1]
+    len: u8,
+}
+
+fn main() {}
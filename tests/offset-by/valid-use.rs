@@ -0,0 +1,54 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Transfer {
+    #[offset_by = 1]
+    len: B4,
+    #[offset_by = 0x10]
+    #[const_values(MIN = 16, MAX = 0x1F)]
+    channel: B4,
+    #[offset_by = 1]
+    count: B15,
+    flag: bool,
+}
+
+#[bitfield(minimal, bit_order = be)]
+pub struct Minimal {
+    #[offset_by = 2]
+    value: B6,
+    #[skip]
+    __: B2,
+}
+
+fn main() {
+    let mut transfer = Transfer::new();
+    assert_eq!(transfer.len(), 1);
+    assert_eq!(transfer.channel(), 16);
+    assert_eq!(transfer.count(), 1);
+
+    transfer.set_len(16);
+    assert_eq!(transfer.len(), 16);
+    assert_eq!(transfer.len_or_err(), Ok(16));
+    assert_eq!(transfer.len_raw(), 15);
+    assert!(transfer.set_len_checked(0).is_err());
+    assert!(transfer.set_len_checked(17).is_err());
+    assert_eq!(transfer.len(), 16);
+
+    transfer.set_channel_to_max();
+    assert_eq!(transfer.channel(), Transfer::CHANNEL_MAX);
+    assert_eq!(transfer.channel_raw(), 0xF);
+
+    let transfer = transfer.with_count(0x8000);
+    assert_eq!(transfer.count(), 0x8000);
+    assert!(transfer.with_count_checked(0x8001).is_err());
+    assert_eq!(
+        format!("{:?}", Transfer::new().with_len(3)),
+        "Transfer { len: 3, channel: 16, count: 1, flag: false }",
+    );
+
+    let mut minimal = Minimal::new();
+    minimal.set_value(65);
+    assert_eq!(minimal.value(), 65);
+    assert_eq!(minimal.into_bytes(), [0b1111_1100]);
+}
@@ -37,3 +37,45 @@ fn invalid_access_d() {
     let mut bytes = EdgeCaseBytes::new();
     bytes.set_d(0b0001_0000_u8);
 }
+
+#[bitfield(unchecked_setters)]
+pub struct UncheckedBytes {
+    a: B9,
+    b: B7,
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "value out of bounds for field UncheckedBytes.b")]
+fn invalid_unchecked_access_b() {
+    let mut bytes = UncheckedBytes::new();
+    unsafe { bytes.set_b_unchecked(0b1000_0000_u8) };
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field EdgeCaseBytes.c")]
+fn invalid_chain_access_c() {
+    let mut bytes = EdgeCaseBytes::new();
+    bytes.set_a_chain(1).set_c_chain(0x2000_u16);
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field EdgeCaseBytes.b")]
+fn invalid_raw_access_b() {
+    let mut bytes = EdgeCaseBytes::new();
+    bytes.set_b_raw(0b0100_0000_u8);
+}
+
+#[bitfield]
+pub struct OffsetBytes {
+    #[offset_by = 1]
+    len: B4,
+    count: B4,
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field OffsetBytes.len")]
+fn invalid_offset_access_len() {
+    let mut bytes = OffsetBytes::new();
+    bytes.set_len(0);
+}
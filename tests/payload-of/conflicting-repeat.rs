@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Kind {
+    TypeOne,
+    TypeTwo,
+}
+
+#[bitfield]
+pub struct Message {
+    kind: Kind,
+    #[payload_of(tag = "kind", map(TypeOne => B3))]
+    #[repeat = 2]
+    payload: B3,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Kind {
+    TypeOne,
+    TypeTwo,
+}
+
+#[bitfield]
+pub struct Message {
+    kind: Kind,
+    #[payload_of(tag = "type_", map(TypeOne => B6))]
+    payload: B6,
+}
+
+fn main() {}
@@ -0,0 +1,70 @@
+use modular_bitfield::{
+    error::WrongVariant,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Kind {
+    TypeOne = 0,
+    TypeTwo = 1,
+    TypeThree = 2,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier)]
+pub struct PackedTypeOne {
+    flag: bool,
+    value: B13,
+}
+
+#[bitfield]
+pub struct Message {
+    kind: Kind,
+    #[payload_of(tag = "kind", map(TypeOne => PackedTypeOne, TypeTwo => B10))]
+    payload: B14,
+}
+
+#[bitfield(dirty_tracking)]
+pub struct Tracked {
+    #[payload_of(tag = "kind", map(TypeTwo => B4))]
+    payload: B6,
+    kind: Kind,
+}
+
+fn main() {
+    let mut message = Message::new();
+    assert!(message.payload_as_type_one().is_ok());
+    assert_eq!(message.payload_as_type_two(), Err(WrongVariant));
+
+    message.set_payload_as_type_one(PackedTypeOne::new().with_flag(true).with_value(1234));
+    assert_eq!(message.kind(), Kind::TypeOne);
+    let packed = message.payload_as_type_one().unwrap();
+    assert!(packed.flag());
+    assert_eq!(packed.value(), 1234);
+
+    message.set_payload_as_type_two(0b11_1111_1111);
+    assert_eq!(message.kind(), Kind::TypeTwo);
+    assert_eq!(message.payload(), 0b11_1111_1111);
+    assert_eq!(message.payload_as_type_two(), Ok(0b11_1111_1111));
+    assert_eq!(message.payload_as_type_one().err(), Some(WrongVariant));
+
+    // Setting a narrower payload type clears the remaining bits of the payload.
+    message.set_payload(0b11_1111_1111_1111);
+    message.set_payload_as_type_two(1);
+    assert_eq!(message.payload(), 1);
+
+    // An invalid tag does not select any payload type.
+    message.set_kind_raw(0b11);
+    assert_eq!(message.payload_as_type_one().err(), Some(WrongVariant));
+    assert_eq!(message.payload_as_type_two(), Err(WrongVariant));
+
+    let mut tracked = Tracked::new();
+    tracked.set_payload_as_type_two(0xA);
+    assert_eq!(tracked.kind(), Kind::TypeTwo);
+    assert_eq!(tracked.payload_as_type_two(), Ok(0xA));
+    assert_eq!(tracked.payload(), 0xA);
+    let dirty = tracked.take_dirty();
+    assert!(dirty.payload());
+    assert!(dirty.kind());
+}
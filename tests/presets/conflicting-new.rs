@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(new = "reset", preset(reset = 0x01))]
+pub struct Control {
+    low: u8,
+    high: u8,
+}
+
+fn main() {}
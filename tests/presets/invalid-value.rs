@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(preset(reset = "zero"))]
+pub struct Control {
+    low: u8,
+    high: u8,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(new = "zeroed", preset(reset = "0x00FF_1234", safe_mode = 0x8000_0000))]
+#[derive(Default)]
+pub struct Control {
+    low: u16,
+    high: B15,
+    safe: bool,
+}
+
+#[bitfield(bit_order = be, preset(reset = "0x8102"))]
+pub struct Status {
+    enabled: bool,
+    counter: B7,
+    level: u8,
+}
+
+#[bitfield(filled = false, preset(reset = 0x1FF))]
+pub struct Unaligned {
+    low: B4,
+    high: B5,
+}
+
+#[bitfield(preset(reset = "0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF"))]
+pub struct Wide {
+    low: u128,
+    high: u32,
+}
+
+const RESET: Control = Control::reset();
+
+fn main() {
+    let control = Control::zeroed();
+    assert_eq!(control.into_bytes(), [0x00; 4]);
+    assert_eq!(Control::default().into_bytes(), [0x00; 4]);
+    assert_eq!(RESET.into_bytes(), [0x34, 0x12, 0xFF, 0x00]);
+    assert_eq!(RESET.low(), 0x1234);
+    assert_eq!(RESET.high(), 0x00FF);
+    assert!(!RESET.safe());
+    assert!(Control::safe_mode().safe());
+
+    // The most significant bit of the packed value is the first bit for `bit_order = be`.
+    let status = Status::reset();
+    assert!(status.enabled());
+    assert_eq!(status.counter(), 1);
+    assert_eq!(status.level(), 2);
+    assert_eq!(status.into_bytes(), [0x81, 0x02]);
+
+    let unaligned = Unaligned::reset();
+    assert_eq!(unaligned.low(), 0xF);
+    assert_eq!(unaligned.high(), 0x1F);
+
+    let wide = Wide::reset();
+    assert_eq!(wide.low(), u128::MAX);
+    assert_eq!(wide.high(), 0);
+}
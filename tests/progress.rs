@@ -1,3 +1,7 @@
+mod bitstream_tests;
+mod fault_injection_tests;
+mod layout_guard_tests;
+mod neighbor_check_tests;
 mod panic_tests;
 
 #[cfg(all(test, not(miri)))]
@@ -27,12 +31,51 @@ fn tests() {
     t.compile_fail("tests/26-invalid-struct-specifier.rs");
     t.compile_fail("tests/27-invalid-union-specifier.rs");
     t.pass("tests/28-single-bit-enum.rs");
+    t.pass("tests/29-chain-setter.rs");
+    t.pass("tests/30-is-zeroed.rs");
+    t.pass("tests/31-active-low.rs");
+    t.pass("tests/32-bool-methods.rs");
+    t.compile_fail("tests/33-bits-attribute-nested-wrong.rs");
+    t.pass("tests/34-flags.rs");
+    t.compile_fail("tests/35-flags-too-many.rs");
+    t.pass("tests/36-integer-setters.rs");
+    t.compile_fail("tests/37-integer-setters-unavailable.rs");
+    t.pass("tests/38-byte-aligned-setters.rs");
+    t.pass("tests/39-signed-encodings.rs");
+    t.compile_fail("tests/40-signed-encodings-too-wide.rs");
+    t.pass("tests/41-folded-offsets.rs");
+    t.compile_fail("tests/42-shadowed-specifier.rs");
+    t.pass("tests/43-ascii-char.rs");
+    t.pass("tests/44-bitwise-field-ops.rs");
+    t.pass("tests/45-signed-specifiers.rs");
+    t.pass("tests/46-option-specifiers.rs");
+    t.pass("tests/47-non-zero-specifiers.rs");
 
     // Tests specific to the `#[derive(BitfieldSpecifier)]` proc. macro:
     t.pass("tests/derive-bitfield-specifier/06-enums.rs");
     t.pass("tests/derive-bitfield-specifier/07-optional-discriminant.rs");
     t.compile_fail("tests/derive-bitfield-specifier/08-non-power-of-two.rs");
     t.compile_fail("tests/derive-bitfield-specifier/09-variant-out-of-range.rs");
+    t.pass("tests/derive-bitfield-specifier/10-parse-from-str.rs");
+    t.pass("tests/derive-bitfield-specifier/11-wide-discriminants.rs");
+    t.pass("tests/derive-bitfield-specifier/12-repr-width.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/13-bits-exceed-repr.rs");
+    t.pass("tests/derive-bitfield-specifier/14-huge-discriminants.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/15-huge-discriminant-out-of-range.rs");
+    t.pass("tests/derive-bitfield-specifier/16-discriminant-conversions.rs");
+    t.pass("tests/derive-bitfield-specifier/17-custom-from-str.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/18-case-insensitive-without-from-str.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/19-duplicate-from-str.rs");
+    t.pass("tests/derive-bitfield-specifier/20-repr-power-of-two.rs");
+    t.pass("tests/derive-bitfield-specifier/21-manual-conversions.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/22-invalid-bitfield-specifier-param.rs");
+
+    // Tests for field types given by paths or generic const arguments:
+    t.pass("tests/field-types/paths-and-const-generics.rs");
+    // The `typenum` dependency of `uom` defines its own `B1` which changes how rustc
+    // prints the implementors of `Specifier`.
+    #[cfg(not(feature = "uom"))]
+    t.compile_fail("tests/field-types/non-specifier-path.rs");
 
     // Tests for regressions found in published versions:
     t.pass("tests/regressions/no-implicit-prelude.rs");
@@ -61,6 +104,200 @@ fn tests() {
     t.compile_fail("tests/filled-param/invalid-specified-as-filled.rs");
     t.compile_fail("tests/filled-param/invalid-specified-as-unfilled.rs");
 
+    // Tests for `uninit: bool` #[bitfield] parameter:
+    t.pass("tests/uninit-param/valid-use.rs");
+    t.compile_fail("tests/uninit-param/invalid-bool-value.rs");
+
+    // Tests for `emit_layout = "path"` #[bitfield] parameter:
+    t.pass("tests/emit-layout/valid-use.rs");
+    t.pass("tests/emit-layout/field-arrays.rs");
+    t.compile_fail("tests/emit-layout/unknown-bit-width.rs");
+    t.compile_fail("tests/emit-layout/mismatched-bit-width.rs");
+
+    // Tests for `unchecked_setters` #[bitfield] parameter:
+    t.pass("tests/unchecked-setters/valid-use.rs");
+    t.compile_fail("tests/unchecked-setters/skipped-setter.rs");
+    t.compile_fail("tests/unchecked-setters/duplicate-parameters.rs");
+
+    // Tests for `copy: bool` #[bitfield] parameter:
+    t.pass("tests/copy-param/valid-use.rs");
+    t.compile_fail("tests/copy-param/too-large-copy.rs");
+    t.compile_fail("tests/copy-param/stripped-copy.rs");
+
+    // Tests for `bit_queries` #[bitfield] parameter:
+    t.pass("tests/bit-queries/valid-use.rs");
+    t.compile_fail("tests/bit-queries/skipped-getter.rs");
+    t.pass("tests/bit-order/valid-use.rs");
+    t.pass("tests/bit-order/get-spanning-data.rs");
+    t.compile_fail("tests/bit-order/invalid-bit-order.rs");
+    t.pass("tests/bit-order/order-alias.rs");
+    t.compile_fail("tests/bit-order/invalid-order.rs");
+    t.pass("tests/c-embed/valid-use.rs");
+    t.compile_fail("tests/c-embed/dirty-tracking-conflict.rs");
+    t.compile_fail("tests/c-embed/outer-too-small.rs");
+    t.pass("tests/minimal/valid-use.rs");
+    t.compile_fail("tests/minimal/missing-methods.rs");
+    t.compile_fail("tests/minimal/duplicate-parameters.rs");
+    t.pass("tests/repeat/valid-use.rs");
+    t.compile_fail("tests/repeat/zero-repetitions.rs");
+    t.compile_fail("tests/repeat/duplicate-repeat.rs");
+    t.compile_fail("tests/repeat/reserved-conflict.rs");
+    t.pass("tests/field-arrays/valid-use.rs");
+    t.pass("tests/field-arrays/derives.rs");
+    t.compile_fail("tests/field-arrays/invalid-length.rs");
+    t.compile_fail("tests/field-arrays/repeat-conflict.rs");
+    t.compile_fail("tests/layout-guard/duplicate-parameters.rs");
+    t.pass("tests/split-at/valid-use.rs");
+    t.compile_fail("tests/split-at/unaligned-boundary.rs");
+    t.compile_fail("tests/split-at/unknown-field.rs");
+    t.compile_fail("tests/split-at/first-field.rs");
+    t.compile_fail("tests/split-at/dirty-tracking-conflict.rs");
+    t.pass("tests/doc-examples/valid-use.rs");
+    t.compile_fail("tests/doc-examples/duplicate-parameters.rs");
+    t.pass("tests/validate/valid-use.rs");
+    t.compile_fail("tests/validate/duplicate-attribute.rs");
+    t.compile_fail("tests/validate/invalid-rule.rs");
+    t.compile_fail("tests/validate/conflicting-repeat.rs");
+    t.pass("tests/transparent/valid-use.rs");
+    t.compile_fail("tests/transparent/duplicate-parameters.rs");
+    t.compile_fail("tests/transparent/conflicting-dirty-tracking.rs");
+    t.compile_fail("tests/transparent/conflicting-c-embed.rs");
+    t.pass("tests/raw-accessors/valid-use.rs");
+    t.pass("tests/payload-of/valid-use.rs");
+    t.compile_fail("tests/payload-of/unknown-tag-field.rs");
+    t.compile_fail("tests/payload-of/payload-too-wide.rs");
+    t.compile_fail("tests/payload-of/duplicate-variant.rs");
+    t.compile_fail("tests/payload-of/conflicting-repeat.rs");
+    t.pass("tests/ffi-export/valid-use.rs");
+    t.compile_fail("tests/ffi-export/duplicate-parameters.rs");
+    t.compile_fail("tests/ffi-export/invalid-prefix.rs");
+    t.compile_fail("tests/ffi-export/invalid-format.rs");
+    t.pass("tests/bit-ranges/valid-use.rs");
+    t.compile_fail("tests/bit-ranges/overlapping-ranges.rs");
+    t.pass("tests/bit-ranges/padding.rs");
+    t.compile_fail("tests/bit-ranges/missing-range.rs");
+    t.compile_fail("tests/bit-ranges/unnamed-fields.rs");
+    t.compile_fail("tests/bit-ranges/empty-range.rs");
+    t.compile_fail("tests/bit-ranges/mismatched-width.rs");
+    t.compile_fail("tests/bit-ranges/duplicate-range.rs");
+    t.pass("tests/doc-bit-numbering/valid-use.rs");
+    t.compile_fail("tests/doc-bit-numbering/invalid-value.rs");
+    t.compile_fail("tests/doc-bit-numbering/unknown-bit-width.rs");
+    t.compile_fail("tests/doc-bit-numbering/duplicate-doc-bit-numbering.rs");
+    t.pass("tests/method-collisions/valid-use.rs");
+    t.pass("tests/method-collisions/generated-families.rs");
+    t.compile_fail("tests/method-collisions/colliding-derives.rs");
+    t.compile_fail("tests/method-collisions/invalid-external-methods.rs");
+    t.pass("tests/hash-param/valid-use.rs");
+    t.compile_fail("tests/hash-param/conflicting-derive-hash.rs");
+    t.pass("tests/derive-policy/valid-use.rs");
+    t.compile_fail("tests/derive-policy/conflicting-policies.rs");
+    t.compile_fail("tests/derive-policy/duplicate-derive.rs");
+    t.compile_fail("tests/derive-policy/unsupported-derive.rs");
+    t.compile_fail("tests/derive-policy/mismatched-ord.rs");
+    t.pass("tests/field-defaults/valid-use.rs");
+    t.compile_fail("tests/field-defaults/skipped-setters.rs");
+    t.compile_fail("tests/field-defaults/duplicate-default.rs");
+    t.compile_fail("tests/field-defaults/invalid-format.rs");
+    t.pass("tests/modify-fields/valid-use.rs");
+    t.compile_fail("tests/modify-fields/skipped-setter.rs");
+    t.pass("tests/layout-version/valid-use.rs");
+    t.pass("tests/macro-hygiene/valid-use.rs");
+    t.pass("tests/const-values/valid-use.rs");
+    t.pass("tests/const-values/offset-by.rs");
+    t.compile_fail("tests/const-values/value-too-wide.rs");
+    t.compile_fail("tests/const-values/non-integer-field.rs");
+    t.compile_fail("tests/const-values/duplicate-name.rs");
+    t.compile_fail("tests/const-values/invalid-format.rs");
+    t.compile_fail("tests/const-values/conflicting-repeat.rs");
+    t.pass("tests/offset-by/valid-use.rs");
+    t.compile_fail("tests/offset-by/non-integer-field.rs");
+    t.compile_fail("tests/offset-by/overflowing-offset.rs");
+    t.compile_fail("tests/offset-by/invalid-offset.rs");
+    t.compile_fail("tests/offset-by/conflicting-repeat.rs");
+    t.pass("tests/fields-struct/valid-use.rs");
+    t.pass("tests/fields-struct/arrays.rs");
+    t.pass("tests/fields-struct/derives.rs");
+    t.compile_fail("tests/fields-struct/tuple-struct.rs");
+    t.compile_fail("tests/fields-struct/duplicate-param.rs");
+    t.compile_fail("tests/fields-struct/invalid-param.rs");
+    t.pass("tests/compat/valid-use.rs");
+    t.compile_fail("tests/compat/invalid-value.rs");
+    t.compile_fail("tests/compat/conflicting-bit-order.rs");
+    t.pass("tests/access-stats/valid-use.rs");
+    t.compile_fail("tests/access-stats/duplicate-param.rs");
+    t.pass("tests/group/valid-use.rs");
+    t.compile_fail("tests/group/non-bool-field.rs");
+    t.compile_fail("tests/group/non-adjacent-fields.rs");
+    t.compile_fail("tests/group/skipped-field.rs");
+    t.compile_fail("tests/group/invalid-name.rs");
+    t.pass("tests/masks/valid-use.rs");
+    t.compile_fail("tests/masks/out-of-bounds.rs");
+    t.pass("tests/bitfields/valid-use.rs");
+    t.compile_fail("tests/bitfields/invalid-item.rs");
+    t.compile_fail("tests/bitfields/invalid-struct.rs");
+    t.pass("tests/total-bytes/valid-use.rs");
+    t.compile_fail("tests/total-bytes/exceeded-budget.rs");
+    t.pass("tests/accessors-mod/valid-use.rs");
+    t.compile_fail("tests/accessors-mod/invalid-name.rs");
+    t.compile_fail("tests/accessors-mod/conflicting-dirty-tracking.rs");
+    t.pass("tests/iter-fields/valid-use.rs");
+    t.pass("tests/try-from/valid-use.rs");
+    t.compile_fail("tests/try-from/non-integer-field.rs");
+    t.compile_fail("tests/try-from/conflicting-offset-by.rs");
+    t.compile_fail("tests/try-from/invalid-format.rs");
+    t.pass("tests/layout-variants/valid-use.rs");
+    t.compile_fail("tests/layout-variants/invalid-inactive-variant.rs");
+    t.compile_fail("tests/layout-variants/too-many-predicates.rs");
+    t.compile_fail("tests/layout-variants/conflicting-emit-layout.rs");
+    t.pass("tests/lints/valid-use.rs");
+    t.compile_fail("tests/lints/suspicious-layout.rs");
+    t.compile_fail("tests/lints/duplicate-lints.rs");
+    t.pass("tests/presets/valid-use.rs");
+    t.compile_fail("tests/presets/value-too-large.rs");
+    t.compile_fail("tests/presets/invalid-value.rs");
+    t.compile_fail("tests/presets/duplicate-preset.rs");
+    t.compile_fail("tests/presets/conflicting-new.rs");
+    t.compile_fail("tests/presets/invalid-new.rs");
+    t.pass("tests/const-accessors/valid-use.rs");
+    t.pass("tests/const-accessors/from-bytes.rs");
+    t.compile_fail("tests/const-accessors/value-out-of-bounds.rs");
+    t.compile_fail("tests/const-accessors/conflicting-access-stats.rs");
+    t.compile_fail("tests/const-accessors/duplicate-const-accessors.rs");
+    t.pass("tests/computed/valid-use.rs");
+    t.compile_fail("tests/computed/unknown-field.rs");
+    t.compile_fail("tests/computed/field-without-getters.rs");
+    t.compile_fail("tests/computed/missing-type.rs");
+    t.compile_fail("tests/computed/duplicate-name.rs");
+    t.compile_fail("tests/computed/collides-with-field.rs");
+    t.compile_fail("tests/computed/invalid-format.rs");
+    t.pass("tests/bitstring/valid-use.rs");
+    t.compile_fail("tests/bitstring/duplicate-bitstring.rs");
+    t.pass("tests/no-straddle/valid-use.rs");
+    t.compile_fail("tests/no-straddle/straddling-field.rs");
+    t.compile_fail("tests/no-straddle/straddling-repeat.rs");
+    t.compile_fail("tests/no-straddle/duplicate-no-straddle.rs");
+    t.pass("tests/words/valid-use.rs");
+    t.compile_fail("tests/words/not-a-multiple.rs");
+    t.compile_fail("tests/words/invalid-word-type.rs");
+    t.compile_fail("tests/words/duplicate-word-type.rs");
+    t.compile_fail("tests/words/word-endian-without-words.rs");
+    t.compile_fail("tests/words/invalid-word-endian.rs");
+    t.pass("tests/mmio/valid-use.rs");
+    t.compile_fail("tests/mmio/missing-repr.rs");
+    t.compile_fail("tests/mmio/duplicate-mmio.rs");
+    t.pass("tests/auto-pad/valid-use.rs");
+    t.compile_fail("tests/auto-pad/missing-bits.rs");
+    t.compile_fail("tests/auto-pad/unknown-width.rs");
+    t.compile_fail("tests/auto-pad/duplicate-auto-pad.rs");
+    t.pass("tests/contiguous/valid-use.rs");
+    t.compile_fail("tests/contiguous/interior-gap.rs");
+    t.compile_fail("tests/contiguous/trailing-gap.rs");
+    t.compile_fail("tests/contiguous/duplicate-parameters.rs");
+    t.pass("tests/reserved/valid-use.rs");
+    t.compile_fail("tests/reserved/access-reserved.rs");
+    t.compile_fail("tests/reserved/invalid-reserved.rs");
+
     // Tests for `#[repr(uN)]` and `#[cfg_attr(cond, repr(uN))]`:
     t.pass("tests/repr/valid-use.rs");
     t.pass("tests/repr/valid-cond-use.rs");
@@ -76,6 +313,10 @@ fn tests() {
     t.compile_fail("tests/repr/invalid-repr-width-2.rs");
     t.compile_fail("tests/repr/conflicting-ignored-reprs.rs");
     t.compile_fail("tests/repr/invalid-repr-unfilled.rs");
+    t.pass("tests/repr/repr-endian.rs");
+    t.compile_fail("tests/repr/invalid-repr-endian.rs");
+    t.compile_fail("tests/repr/repr-endian-without-repr.rs");
+    t.compile_fail("tests/repr/duplicate-repr-endian.rs");
 
     // Tests for `#[derive(Debug)]`:
     t.pass("tests/derive-debug/valid-use.rs");
@@ -83,9 +324,16 @@ fn tests() {
     t.pass("tests/derive-debug/valid-use-specifier.rs");
     t.pass("tests/derive-debug/print-invalid-bits.rs");
     t.pass("tests/derive-debug/respects-other-derives.rs");
+    t.pass("tests/derive-debug/repeated-fields.rs");
     t.compile_fail("tests/derive-debug/duplicate-derive-debug.rs");
     t.compile_fail("tests/derive-debug/duplicate-derive-debug-2.rs");
 
+    // Tests for `#[derive(PartialOrd)]` and `#[derive(Ord)]`:
+    t.pass("tests/derive-ord/valid-use.rs");
+    t.pass("tests/derive-ord/invalid-bit-patterns.rs");
+    t.pass("tests/derive-ord/consistent-with-eq.rs");
+    t.compile_fail("tests/derive-ord/duplicate-derive-ord.rs");
+
     // Tests for `#[skip(..)]`:
     t.pass("tests/skip/skip-default.rs");
     t.pass("tests/skip/skip-getters-and-setters-1.rs");
@@ -137,4 +385,88 @@ fn tests() {
     t.compile_fail("tests/bits-param/missing-param-value.rs");
     t.compile_fail("tests/bits-param/too-few-bits.rs");
     t.compile_fail("tests/bits-param/too-many-bits.rs");
+
+    // Tests for `#[bitfield(bounds)]`:
+    t.pass("tests/bounds/valid-use.rs");
+    t.compile_fail("tests/bounds/skipped-setter.rs");
+
+    // Tests for `#[bitfield(dual_order)]`:
+    t.pass("tests/dual-order/valid-use.rs");
+
+    // Tests for `#[bitfield(keep_phantom_fields)]`:
+    t.pass("tests/keep-phantom-fields/valid-use.rs");
+    t.compile_fail("tests/keep-phantom-fields/tuple-struct.rs");
+
+    // Tests for `#[bitfield(cast_to(..))]`:
+    t.pass("tests/cast-to/valid-use.rs");
+    t.compile_fail("tests/cast-to/different-size.rs");
+    t.compile_fail("tests/cast-to/duplicate-cast-to.rs");
+
+    // Tests for `#[bitfield(dirty_tracking)]`:
+    t.pass("tests/dirty-tracking/valid-use.rs");
+    t.compile_fail("tests/dirty-tracking/bytes-conflict.rs");
+    t.compile_fail("tests/dirty-tracking/duplicate-dirty-tracking.rs");
+
+    // Tests for trailing `Rest` fields:
+    t.pass("tests/rest/valid-use.rs");
+    t.compile_fail("tests/rest/not-last.rs");
+    t.compile_fail("tests/rest/unnamed.rs");
+    t.compile_fail("tests/rest/only-rest.rs");
+    t.compile_fail("tests/rest/invalid-attribute.rs");
+
+    // Tests for `#[quantity(Kind, unit)]` field attributes:
+    #[cfg(feature = "uom")]
+    {
+        t.pass("tests/quantity/valid-use.rs");
+        t.compile_fail("tests/quantity/invalid-storage.rs");
+        t.compile_fail("tests/quantity/duplicate-quantity.rs");
+        t.compile_fail("tests/quantity/const-values-conflict.rs");
+    }
+    #[cfg(not(feature = "uom"))]
+    t.compile_fail("tests/quantity/missing-feature.rs");
+
+    // Tests for the `dump` #[bitfield] parameter of the `alloc` crate feature:
+    #[cfg(feature = "alloc")]
+    {
+        t.pass("tests/dump/valid-use.rs");
+        t.compile_fail("tests/dump/duplicate-parameters.rs");
+    }
+    #[cfg(not(feature = "alloc"))]
+    t.compile_fail("tests/dump/missing-feature.rs");
+
+    // Tests for the bit slice views of the `bitvec` crate feature:
+    #[cfg(feature = "bitvec")]
+    t.pass("tests/bitvec/valid-use.rs");
+
+    // Tests for the `Distribution` impls of the `rand` crate feature:
+    #[cfg(feature = "rand")]
+    t.pass("tests/rand/valid-use.rs");
+
+    // Tests for the `serde` #[bitfield] parameter of the `serde` crate feature:
+    #[cfg(feature = "serde")]
+    {
+        t.pass("tests/serde/valid-use.rs");
+        t.compile_fail("tests/serde/invalid-value.rs");
+        t.compile_fail("tests/serde/duplicate-parameters.rs");
+        t.compile_fail("tests/serde/conflicting-minimal.rs");
+        t.pass("tests/field-arrays/serde.rs");
+    }
+    #[cfg(not(feature = "serde"))]
+    t.compile_fail("tests/serde/missing-feature.rs");
+
+    // Tests for the intercepted `#[derive(defmt::Format)]` of the `defmt` crate feature:
+    #[cfg(feature = "defmt")]
+    {
+        t.pass("tests/defmt/valid-use.rs");
+        t.compile_fail("tests/defmt/duplicate-derive.rs");
+    }
+
+    // Tests for the `register_layout` #[bitfield] parameter of the `registry` crate feature:
+    #[cfg(feature = "registry")]
+    {
+        t.pass("tests/registry/valid-use.rs");
+        t.compile_fail("tests/registry/duplicate-register-layout.rs");
+    }
+    #[cfg(not(feature = "registry"))]
+    t.compile_fail("tests/registry/missing-feature.rs");
 }
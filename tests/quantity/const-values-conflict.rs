@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sensor {
+    #[quantity(ElectricPotential, millivolt)]
+    #[const_values(ZERO = 0)]
+    voltage: u16,
+}
+
+fn main() {}
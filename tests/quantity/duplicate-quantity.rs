@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sensor {
+    #[quantity(ElectricPotential, millivolt)]
+    #[quantity(ElectricPotential, volt)]
+    voltage: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sensor {
+    #[quantity(ElectricPotential, millivolt, u32)]
+    voltage: u16,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sensor {
+    #[quantity(ElectricPotential, millivolt)]
+    voltage: u16,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+use modular_bitfield::uom::si::{
+    electric_current::milliampere,
+    electric_potential::{
+        millivolt,
+        volt,
+    },
+    f32::{
+        ElectricCurrent,
+        ElectricPotential,
+    },
+    f64::ThermodynamicTemperature,
+    thermodynamic_temperature::degree_celsius,
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Sensor {
+    #[quantity(ElectricPotential, millivolt)]
+    voltage: B12,
+    #[quantity(ElectricCurrent, milliampere)]
+    current: B12,
+    #[quantity(ThermodynamicTemperature, degree_celsius, f64)]
+    temperature: u8,
+}
+
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-3
+}
+
+fn main() {
+    let mut sensor = Sensor::new()
+        .with_voltage(ElectricPotential::new::<volt>(3.3))
+        .with_current(ElectricCurrent::new::<milliampere>(250.0));
+    assert!(approx_eq(sensor.voltage().get::<millivolt>(), 3300.0));
+    assert!(approx_eq(sensor.current().get::<milliampere>(), 250.0));
+    assert!(approx_eq(sensor.voltage_or_err().unwrap().get::<volt>(), 3.3));
+
+    sensor.set_temperature(ThermodynamicTemperature::new::<degree_celsius>(21.0));
+    assert!((sensor.temperature().get::<degree_celsius>() - 21.0).abs() < 1e-9);
+
+    // Values are rounded to the nearest raw value of the unit.
+    sensor.set_voltage(ElectricPotential::new::<millivolt>(1199.6));
+    assert!(approx_eq(sensor.voltage().get::<millivolt>(), 1200.0));
+
+    // Values exceeding the bit width of the field are out of bounds.
+    assert_eq!(
+        sensor.set_voltage_checked(ElectricPotential::new::<volt>(5.0)),
+        Err(OutOfBounds)
+    );
+    assert_eq!(sensor.into_bytes()[0..3], [0xB0, 0xA4, 0x0F]);
+}
@@ -0,0 +1,87 @@
+use modular_bitfield::{
+    prelude::*,
+    rand::{
+        Rng,
+        RngCore,
+    },
+};
+
+/// A xorshift generator to keep the test deterministic.
+struct XorShift(u64);
+
+impl RngCore for XorShift {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.next_u64() as u8;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), modular_bitfield::rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+#[bits = 3]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 5,
+}
+
+#[bitfield]
+#[derive(BitfieldSpecifier)]
+pub struct Inner {
+    flag: bool,
+    value: B7,
+}
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    #[skip]
+    __: B4,
+    inner: Inner,
+    level: B12,
+    #[skip(setters)]
+    status: B12,
+    wide: u64,
+}
+
+#[bitfield(bit_order = be)]
+pub struct BigEndian {
+    mode: Mode,
+    rest: B5,
+}
+
+fn main() {
+    let mut rng = XorShift(0x0123_4567_89AB_CDEF);
+    let mut seen = [false; 8];
+    let mut enabled = 0;
+    for _ in 0..1000 {
+        let register: Register = rng.gen();
+        assert!(register.mode_or_err().is_ok());
+        assert!(register.inner().value() < 128);
+        seen[register.mode() as usize] = true;
+        enabled += register.enabled() as usize;
+        assert_eq!(register.into_bytes()[0] >> 4, 0);
+
+        let big_endian: BigEndian = rng.gen();
+        assert!(big_endian.mode_or_err().is_ok());
+    }
+    assert_eq!(seen, [true, true, false, false, false, true, false, false]);
+    assert!((400..600).contains(&enabled));
+}
@@ -0,0 +1,71 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    mode: Mode,
+    #[skip(getters)]
+    write_only: B5,
+    #[skip(setters)]
+    read_only: B8,
+}
+
+#[bitfield(bit_order = be)]
+pub struct BigEndian {
+    high: B3,
+    low: B5,
+}
+
+#[bitfield]
+pub struct Tuple(B4, B12);
+
+#[bitfield(minimal)]
+pub struct Minimal {
+    value: B8,
+}
+
+impl Minimal {
+    // Does not collide since `minimal` does not generate raw accessors.
+    fn value_raw(&self) -> u8 {
+        self.value()
+    }
+}
+
+fn main() {
+    let mut register = Register::new();
+    register.set_mode_raw(0b11);
+    assert_eq!(register.mode_raw(), 0b11);
+    assert!(register.mode_or_err().is_err());
+    register.set_mode_raw(0b10);
+    assert_eq!(register.mode(), Mode::C);
+    register.set_enabled_raw(1);
+    assert!(register.enabled());
+    assert_eq!(register.enabled_raw(), 1);
+    register.set_write_only_raw(0b1_0101);
+    assert_eq!(register.into_bytes(), [0b1010_1101, 0b0000_0000]);
+
+    let register = Register::from_bytes([0x00, 0xA0]);
+    assert_eq!(register.read_only_raw(), 0xA0);
+
+    let mut be = BigEndian::new();
+    be.set_high_raw(0b101);
+    assert_eq!(be.high(), 0b101);
+    assert_eq!(be.low_raw(), 0);
+    assert_eq!(be.into_bytes(), [0b1010_0000]);
+
+    let mut tuple = Tuple::new();
+    tuple.set_1_raw(0xABC);
+    assert_eq!(tuple.get_1_raw(), 0xABC);
+    assert_eq!(tuple.get_0_raw(), 0);
+
+    let minimal = Minimal::from_bytes([42]);
+    assert_eq!(minimal.value_raw(), 42);
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(register_layout, register_layout)]
+pub struct Control {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(register_layout)]
+pub struct Control {
+    enabled: bool,
+    divider: B7,
+}
+
+fn main() {}
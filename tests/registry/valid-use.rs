@@ -0,0 +1,88 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off,
+    Slow,
+    Fast,
+}
+
+#[bitfield(register_layout)]
+pub struct Control {
+    enabled: bool,
+    mode: Mode,
+    divider: B13,
+    #[repeat = 2]
+    flags: B4,
+}
+
+#[bitfield(bits = 12, bit_order = be, register_layout)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+    count: B4,
+}
+
+#[bitfield]
+pub struct Unregistered {
+    value: u8,
+}
+
+mod nested {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(register_layout)]
+    pub struct Nested {
+        value: u8,
+    }
+}
+
+fn main() {
+    use modular_bitfield::registry;
+
+    let mut names = registry::iter().map(|layout| layout.name()).collect::<Vec<_>>();
+    names.sort_unstable();
+    assert_eq!(names, ["Control", "Nested", "Status"]);
+
+    let control = registry::iter().find(|layout| layout.name() == "Control").unwrap();
+    assert_eq!(control.bits(), 24);
+    assert_eq!(control.bytes(), 3);
+    assert!(!control.is_big_endian());
+    let fields = control
+        .fields()
+        .iter()
+        .map(|field| (field.name(), field.offset(), field.bits()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        [("enabled", 0, 1), ("mode", 1, 2), ("divider", 3, 13), ("flags", 16, 8)],
+    );
+    let bytes = Control::new()
+        .with_enabled(true)
+        .with_mode(Mode::Fast)
+        .with_divider(0x1ABC)
+        .into_bytes();
+    let divider = control.field("divider").unwrap();
+    assert_eq!(control.read(divider, &bytes), Some(0x1ABC));
+    assert_eq!(control.read(control.field("mode").unwrap(), &bytes), Some(2));
+    assert_eq!(control.read(divider, &bytes[..2]), None);
+    assert!(control.field("unknown").is_none());
+
+    let status = registry::iter().find(|layout| layout.name() == "Status").unwrap();
+    assert_eq!(status.bits(), 12);
+    assert_eq!(status.bytes(), 2);
+    assert!(status.is_big_endian());
+    let bytes = Status::new().with_ready(true).with_code(0x2A).with_count(0x9).into_bytes();
+    for field in status.fields() {
+        let expected = match field.name() {
+            "ready" => 1,
+            "code" => 0x2A,
+            _ => 0x9,
+        };
+        assert_eq!(status.read(field, &bytes), Some(expected));
+    }
+
+    let nested = registry::iter().find(|layout| layout.name() == "Nested").unwrap();
+    assert!(nested.module_path().ends_with("::nested"));
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Table {
+    header: B8,
+    #[reserved]
+    #[repeat = 2]
+    entries: B8,
+}
+
+fn main() {}
@@ -0,0 +1,56 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Record {
+    enabled: bool,
+    kind: B3,
+    channel: B4,
+    threshold: B10,
+    hysteresis: B2,
+}
+
+#[bitfield]
+pub struct Eeprom {
+    version: B8,
+    #[repeat = 64]
+    records: Record,
+}
+
+#[bitfield(bit_order = be)]
+pub struct Nibbles {
+    head: B4,
+    #[repeat = 3]
+    nibbles: B4,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Eeprom>(), (8 + 64 * 20) / 8);
+
+    let mut eeprom = Eeprom::new();
+    eeprom.set_version(3);
+    for index in 0..64 {
+        let record = Record::new()
+            .with_enabled(index % 2 == 0)
+            .with_channel(index as u8 % 16)
+            .with_threshold(index as u16 * 10);
+        eeprom.set_records_at(index, record).unwrap();
+    }
+    assert_eq!(eeprom.version(), 3);
+    for index in 0..64 {
+        let record = eeprom.records_at(index).unwrap();
+        assert_eq!(record.enabled(), index % 2 == 0);
+        assert_eq!(record.kind(), 0);
+        assert_eq!(record.channel(), index as u8 % 16);
+        assert_eq!(record.threshold(), index as u16 * 10);
+    }
+    assert!(eeprom.records_at(64).is_err());
+    assert!(eeprom.set_records_at(64, Record::new()).is_err());
+
+    let mut nibbles = Nibbles::new();
+    nibbles.set_head(0xA);
+    nibbles.set_nibbles_at(0, 0xB).unwrap();
+    nibbles.set_nibbles_at(2, 0xD).unwrap();
+    assert!(nibbles.set_nibbles_at(1, 0x10).is_err());
+    assert_eq!(nibbles.into_bytes(), [0xAB, 0x0D]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Table {
+    header: B8,
+    #[repeat = 0]
+    entries: B8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+#[repr_endian = "big"]
+#[repr_endian = "little"]
+pub struct SignedInt {
+    sign: bool,
+    value: B31,
+}
+
+fn main() {}
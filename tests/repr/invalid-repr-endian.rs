@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+#[repr_endian = "middle"]
+pub struct SignedInt {
+    sign: bool,
+    value: B31,
+}
+
+fn main() {}
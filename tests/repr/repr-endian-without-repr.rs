@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr_endian = "big"]
+pub struct SignedInt {
+    sign: bool,
+    value: B31,
+}
+
+fn main() {}
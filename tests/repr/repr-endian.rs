@@ -0,0 +1,37 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+#[repr_endian = "big"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigWord {
+    a: B8,
+    b: B8,
+    c: B16,
+}
+
+#[bitfield]
+#[repr(u32)]
+#[repr_endian = "little"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LittleWord {
+    a: B8,
+    b: B8,
+    c: B16,
+}
+
+fn main() {
+    let big = BigWord::from(0x1122_3344_u32);
+    assert_eq!(big.into_bytes(), [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(big.a(), 0x11);
+    assert_eq!(u32::from(big), 0x1122_3344);
+    assert_eq!(BigWord::from_be(0x1122_3344), big);
+    assert_eq!(BigWord::from_le(0x4433_2211), big);
+
+    let little = LittleWord::from(0x1122_3344_u32);
+    assert_eq!(little.into_bytes(), [0x44, 0x33, 0x22, 0x11]);
+    assert_eq!(little.a(), 0x44);
+    assert_eq!(u32::from(little), 0x1122_3344);
+    assert_eq!(LittleWord::from_le(0x1122_3344), little);
+    assert_eq!(LittleWord::from_be(0x4433_2211), little);
+}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    version: B4,
+    #[reserved]
+    reserved_flags: B4,
+}
+
+fn main() {
+    let mut header = Header::new();
+    header.set_reserved_flags(1);
+    let _ = header.reserved_flags();
+}
@@ -0,0 +1,26 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct SkipAndReserved {
+    version: B4,
+    #[reserved]
+    #[skip]
+    reserved_flags: B4,
+}
+
+#[bitfield]
+pub struct DuplicateReserved {
+    version: B4,
+    #[reserved]
+    #[reserved]
+    reserved_flags: B4,
+}
+
+#[bitfield]
+pub struct InvalidFormat {
+    version: B4,
+    #[reserved(getters)]
+    reserved_flags: B4,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Header {
+    version: B4,
+    #[reserved]
+    reserved_flags: B3,
+    enabled: bool,
+    #[reserved]
+    reserved_tail: u8,
+}
+
+#[bitfield(emit_layout = "layouts/reserved-valid-use.json")]
+pub struct Tuple(B4, #[reserved] B4);
+
+fn main() {
+    let header = Header::from_bytes([0b1010_0001, 0xFF]);
+    assert_eq!(header.version(), 1);
+    assert!(header.enabled());
+    assert_eq!(
+        format!("{:?}", header),
+        "Header { version: 1, reserved_flags: 2, enabled: true, reserved_tail: 255 }",
+    );
+    // Reserved fields only break ties between otherwise equal bitfields.
+    let other = Header::new().with_version(1).with_enabled(true);
+    assert_ne!(header, other);
+    assert_eq!(header.cmp(&other), core::cmp::Ordering::Greater);
+    assert!(header < Header::new().with_version(2));
+
+    let tuple = Tuple::new().with_0(5);
+    assert_eq!(tuple.get_0(), 5);
+    let layout = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/layouts/reserved-valid-use.json"
+    ))
+    .unwrap();
+    assert!(layout.contains(
+        r#"{ "name": "1", "type": "B4", "offset": 4, "bits": 4, "getters": false, "setters": false }"#
+    ));
+}
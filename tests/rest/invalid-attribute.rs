@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Record {
+    kind: B8,
+    #[bits = 8]
+    payload: Rest,
+}
+
+fn main() {}
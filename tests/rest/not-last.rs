@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Record {
+    kind: B4,
+    payload: Rest,
+    len: B4,
+}
+
+fn main() {}
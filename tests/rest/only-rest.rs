@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Record {
+    payload: Rest,
+}
+
+fn main() {}
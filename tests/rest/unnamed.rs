@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Record(B8, Rest);
+
+fn main() {}
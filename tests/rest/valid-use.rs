@@ -0,0 +1,35 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(Debug)]
+pub struct Record {
+    kind: B4,
+    len: B8,
+    /// The variable-length payload.
+    pub payload: Rest,
+}
+
+#[bitfield]
+pub struct Aligned {
+    version: B4,
+    flags: B4,
+    len: u16,
+    r#type: Rest,
+}
+
+fn main() {
+    let buf = [0x21, 0x03, 0xAA, 0xBB, 0xCC];
+    let record = Record::from_bytes([buf[0], buf[1]]).unwrap();
+    assert_eq!(record.kind(), 1);
+    assert_eq!(record.len(), 0x32);
+    assert_eq!(Record::payload_offset_bits(), 12);
+    assert_eq!(record.payload_of(&buf), &buf[1..]);
+    assert_eq!(core::mem::size_of::<Record>(), 2);
+
+    const OFFSET: usize = Aligned::type_offset_bits();
+    assert_eq!(OFFSET, 24);
+    let aligned = Aligned::new().with_len(2);
+    let buf = [0x00, 0x02, 0x00, 0x11, 0x22];
+    assert_eq!(aligned.type_of(&buf), &[0x11, 0x22]);
+    assert_eq!(aligned.type_of(&buf[..3]), &[]);
+}
@@ -0,0 +1,75 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(serde)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Packed {
+    enabled: bool,
+    mode: B3,
+    counter: B12,
+}
+
+#[bitfield(serde = "fields")]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Header {
+    enabled: bool,
+    mode: B3,
+    counter: B12,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(serde = "bytes", filled = false)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sparse {
+    enabled: bool,
+    mode: B3,
+}
+
+#[bitfield(serde = "fields")]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Samples {
+    count: B4,
+    #[repeat = 3]
+    samples: B4,
+}
+
+fn main() {
+    let packed = Packed::new()
+        .with_enabled(true)
+        .with_mode(0b101)
+        .with_counter(0x123);
+    let json = serde_json::to_string(&packed).unwrap();
+    assert_eq!(json, "[59,18]");
+    assert_eq!(serde_json::from_str::<Packed>(&json).unwrap(), packed);
+    assert!(serde_json::from_str::<Packed>("[1]").is_err());
+    assert!(serde_json::from_str::<Packed>("[1,2,3]").is_err());
+
+    let header = Header::new()
+        .with_enabled(true)
+        .with_mode(0b101)
+        .with_counter(0x123);
+    let json = serde_json::to_string(&header).unwrap();
+    assert_eq!(json, r#"{"enabled":true,"mode":5,"counter":291}"#);
+    assert_eq!(serde_json::from_str::<Header>(&json).unwrap(), header);
+    assert!(serde_json::from_str::<Header>(r#"{"enabled":true,"mode":8,"counter":0}"#).is_err());
+    assert!(serde_json::from_str::<Header>(r#"{"enabled":true,"mode":1}"#).is_err());
+    assert!(
+        serde_json::from_str::<Header>(r#"{"enabled":true,"mode":1,"counter":0,"x":0}"#)
+            .is_err()
+    );
+
+    let sparse = Sparse::new().with_enabled(true).with_mode(0b011);
+    let json = serde_json::to_string(&sparse).unwrap();
+    assert_eq!(json, "[7]");
+    assert_eq!(serde_json::from_str::<Sparse>(&json).unwrap(), sparse);
+    assert!(serde_json::from_str::<Sparse>("[255]").is_err());
+
+    let mut samples = Samples::new().with_count(2);
+    samples.set_samples_at(0, 7).unwrap();
+    samples.set_samples_at(2, 15).unwrap();
+    let json = serde_json::to_string(&samples).unwrap();
+    assert_eq!(json, r#"{"count":2,"samples":[7,0,15]}"#);
+    assert_eq!(serde_json::from_str::<Samples>(&json).unwrap(), samples);
+    assert!(serde_json::from_str::<Samples>(r#"{"count":2,"samples":[7,0]}"#).is_err());
+    assert!(serde_json::from_str::<Samples>(r#"{"count":2,"samples":[7,0,16]}"#).is_err());
+}
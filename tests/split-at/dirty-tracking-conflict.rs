@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(split_at = "b", dirty_tracking)]
+pub struct Tracked {
+    a: B8,
+    b: B8,
+}
+
+fn main() {}
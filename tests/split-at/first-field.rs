@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(split_at = "a")]
+pub struct First {
+    a: B8,
+    b: B8,
+}
+
+fn main() {}
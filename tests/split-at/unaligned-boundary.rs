@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(split_at = "b")]
+pub struct Unaligned {
+    a: B3,
+    b: B5,
+    c: B8,
+}
+
+fn main() {}
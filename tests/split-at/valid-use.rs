@@ -0,0 +1,69 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+#[bits = 2]
+pub enum Power {
+    Off = 0,
+    Low = 1,
+    High = 2,
+}
+
+#[bitfield(split_at = "tx_level")]
+pub struct Radio {
+    rx_enabled: bool,
+    rx_power: Power,
+    rx_gain: B5,
+    tx_level: B12,
+    tx_enabled: bool,
+    tx_power: Power,
+    tx_channel: B9,
+}
+
+#[bitfield(bit_order = be, split_at = "c")]
+pub struct Be {
+    a: B4,
+    b: B4,
+    c: B3,
+    d: B5,
+}
+
+fn configure_rx(mut rx: RadioHalfA<'_>) {
+    rx.set_rx_enabled(true);
+    rx.set_rx_power(Power::Low);
+    rx.set_rx_gain(17);
+}
+
+fn configure_tx(tx: &mut RadioHalfB<'_>) {
+    tx.set_tx_level(0xABC);
+    tx.set_tx_enabled(true);
+    tx.set_tx_power(Power::High);
+    tx.set_tx_channel_checked(300).unwrap();
+}
+
+fn main() {
+    let mut radio = Radio::new();
+    {
+        let (rx, mut tx) = radio.split_mut();
+        configure_rx(rx);
+        configure_tx(&mut tx);
+        assert_eq!(tx.tx_level(), 0xABC);
+        assert!(tx.set_tx_channel_checked(512).is_err());
+    }
+    assert!(radio.rx_enabled());
+    assert_eq!(radio.rx_power(), Power::Low);
+    assert_eq!(radio.rx_gain(), 17);
+    assert_eq!(radio.tx_level(), 0xABC);
+    assert!(radio.tx_enabled());
+    assert_eq!(radio.tx_power(), Power::High);
+    assert_eq!(radio.tx_channel(), 300);
+
+    let mut be = Be::new();
+    {
+        let (mut head, mut tail) = be.split_mut();
+        head.set_b(0xF);
+        tail.set_c(0b101);
+        tail.set_d(1);
+        assert_eq!(head.a(), 0);
+    }
+    assert_eq!(be.into_bytes(), [0x0F, 0b1010_0001]);
+}
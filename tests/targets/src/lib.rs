@@ -0,0 +1,85 @@
+//! Checks that the code generated by `modular-bitfield` compiles for targets
+//! with a 16-bit wide `usize` such as AVR and MSP430.
+//!
+//! The checks are evaluated at compile time since these targets cannot execute
+//! tests on the host. Build this crate with for example:
+//!
+//! ```text
+//! cargo +nightly build -Z build-std=core --target msp430-none-elf
+//! RUSTFLAGS="-C target-cpu=atmega328p" cargo +nightly build -Z build-std=core --target avr-none
+//! ```
+
+#![no_std]
+#![forbid(unsafe_code)]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 16]
+pub enum Command {
+    Nop = 0,
+    Reset = 0x00FF,
+    Halt = 0xFFFF,
+}
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 64]
+pub enum Wide {
+    Low = 0,
+    High = 0xFFFF_FFFF_FFFF,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Register {
+    enabled: bool,
+    mode: B3,
+    channel: B12,
+    command: Command,
+}
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug)]
+pub struct Unfilled {
+    a: B7,
+    b: B10,
+}
+
+#[bitfield(bit_order = be)]
+#[repr(u16)]
+pub struct BigEndian {
+    a: B4,
+    b: B12,
+}
+
+#[bitfield]
+pub struct Large {
+    wide: Wide,
+    value: u128,
+    nested: Unfilled,
+    rest: B15,
+}
+
+const _: [(); 1] = [(); (core::mem::size_of::<Register>() == 4) as usize];
+const _: [(); 1] = [(); (<Unfilled as Specifier>::BITS == 17) as usize];
+const _: [(); 1] = [(); (core::mem::size_of::<BigEndian>() == 2) as usize];
+const _: [(); 1] = [(); (<Wide as Specifier>::BITS == 64) as usize];
+const _: [(); 1] = [(); (core::mem::size_of::<Large>() == 28) as usize];
+
+/// Exercises the generated accessors so that they are monomorphized for the target.
+pub fn exercise() -> u16 {
+    let mut register = Register::new()
+        .with_enabled(true)
+        .with_mode(0b101)
+        .with_channel(0x0FFF)
+        .with_command(Command::Reset);
+    register.set_channel(0x0ABC);
+    let unfilled = Unfilled::new().with_a(0x7F).with_b(0x3FF);
+    let large = Large::new()
+        .with_wide(Wide::High)
+        .with_value(u128::MAX)
+        .with_nested(unfilled)
+        .with_rest(1);
+    let big_endian = BigEndian::new().with_a(0xF).with_b(0x123);
+    u16::from(big_endian) ^ register.channel() ^ large.nested().b()
+}
@@ -0,0 +1,20 @@
+use modular_bitfield::{
+    assert_total_bytes,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Settings {
+    volume: B4,
+    brightness: B4,
+}
+
+#[bitfield]
+pub struct Calibration {
+    offset: u16,
+    gain: u16,
+}
+
+assert_total_bytes!(4, Settings, Calibration);
+
+fn main() {}
@@ -0,0 +1,35 @@
+use modular_bitfield::{
+    assert_total_bytes,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Settings {
+    volume: B4,
+    brightness: B4,
+}
+
+#[bitfield(filled = false)]
+pub struct Partial {
+    mode: B3,
+    level: B10,
+}
+
+#[bitfield(bits = 24)]
+pub struct Color {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+const PAGE_SIZE: usize = 6;
+
+assert_total_bytes!(PAGE_SIZE, Settings, Partial, Color);
+assert_total_bytes!(1, Settings,);
+
+fn main() {
+    assert_eq!(Settings::BYTES, 1);
+    assert_eq!(Partial::BYTES, 2);
+    assert_eq!(Color::BYTES, 3);
+    assert_eq!(Partial::new().into_bytes().len(), Partial::BYTES);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent, dirty_tracking)]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+fn main() {}
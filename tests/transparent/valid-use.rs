@@ -0,0 +1,34 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent)]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(transparent, filled = false, keep_phantom_fields)]
+pub struct Partial {
+    a: B3,
+    b: B9,
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<Header>() == core::mem::size_of::<[u8; 2]>());
+    assert!(core::mem::align_of::<Header>() == core::mem::align_of::<[u8; 2]>());
+    assert!(core::mem::size_of::<Partial>() == core::mem::size_of::<[u8; 2]>());
+    assert!(core::mem::align_of::<Partial>() == core::mem::align_of::<[u8; 2]>());
+};
+
+fn main() {
+    let buffer = [0x21_u8, 0x03];
+    // Sound since `Header` is `#[repr(transparent)]` over `[u8; 2]`.
+    let header = unsafe { &*(&buffer as *const [u8; 2] as *const Header) };
+    assert_eq!(header.kind(), 1);
+    assert_eq!(header.len(), 0x032);
+
+    let mut buffer = [0x00_u8; 2];
+    let partial = unsafe { &mut *(&mut buffer as *mut [u8; 2] as *mut Partial) };
+    partial.set_a(5);
+    partial.set_b(0x1FF);
+    assert_eq!(buffer, [0xFD, 0x0F]);
+}
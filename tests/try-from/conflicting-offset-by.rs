@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[try_from = core::num::NonZeroU8]
+    #[offset_by = 1]
+    count: B4,
+    rest: B4,
+}
+
+fn main() {}
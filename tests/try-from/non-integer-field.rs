@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Control {
+    #[try_from = u8]
+    enabled: bool,
+    rest: B7,
+}
+
+fn main() {}
@@ -0,0 +1,58 @@
+use core::{
+    convert::TryFrom,
+    num::NonZeroU8,
+};
+use modular_bitfield::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Mode {
+    Idle,
+    Run,
+    Sleep,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Mode::Idle),
+            1 => Ok(Mode::Run),
+            2 => Ok(Mode::Sleep),
+            invalid => Err(invalid),
+        }
+    }
+}
+
+impl From<Mode> for u8 {
+    fn from(mode: Mode) -> Self {
+        mode as u8
+    }
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Control {
+    #[try_from = Mode]
+    mode: B2,
+    #[try_from = NonZeroU8]
+    count: u8,
+    rest: B6,
+}
+
+fn main() {
+    let mut control = Control::new();
+    assert_eq!(control.mode(), Mode::Idle);
+    assert!(control.count_or_err().is_err());
+
+    control.set_mode(Mode::Sleep);
+    control.set_count(NonZeroU8::new(42).unwrap());
+    assert_eq!(control.mode(), Mode::Sleep);
+    assert_eq!(control.count().get(), 42);
+    assert_eq!(control.into_bytes(), [0b1010_1010, 0b0000_0000]);
+
+    let control = Control::from_bytes([0b0000_0011, 0]);
+    assert_eq!(control.mode_or_err(), Err(3));
+    assert_eq!(control.mode_raw(), 3);
+    assert_eq!(format!("{:?}", control), "Control { mode: 3, count: TryFromIntError(()), rest: 0 }");
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(unchecked_setters, unchecked_setters)]
+pub struct Packet {
+    header: B4,
+    body: B4,
+}
+
+fn main() {}
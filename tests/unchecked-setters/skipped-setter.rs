@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(unchecked_setters)]
+pub struct Packet {
+    header: B4,
+    #[skip(setters)]
+    checksum: B4,
+}
+
+fn main() {
+    let mut packet = Packet::new();
+    unsafe { packet.set_checksum_unchecked(1) };
+}
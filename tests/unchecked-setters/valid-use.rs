@@ -0,0 +1,28 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+pub enum Mode {
+    A, B, C, D,
+}
+
+#[bitfield(unchecked_setters)]
+pub struct Packet {
+    header: B3,
+    mode: Mode,
+    body: B13,
+    #[skip(setters)]
+    checksum: B14,
+}
+
+fn main() {
+    let mut packet = Packet::new();
+    unsafe {
+        packet.set_header_unchecked(0b101);
+        packet.set_mode_unchecked(Mode::C);
+        packet.set_body_unchecked(0x1FFF & 0xABCD);
+    }
+    assert_eq!(packet.header(), 0b101);
+    assert_eq!(packet.mode(), Mode::C);
+    assert_eq!(packet.body(), 0x0BCD);
+    assert_eq!(packet.checksum(), 0);
+}
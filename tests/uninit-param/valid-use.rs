@@ -0,0 +1,25 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(uninit = true)]
+pub struct Large {
+    a: u128,
+    b: u128,
+    c: B7,
+    d: bool,
+}
+
+fn main() {
+    let _uninit: core::mem::MaybeUninit<Large> = Large::new_uninit();
+    let large = unsafe {
+        Large::from_uninit_with(|bytes| {
+            let mut init = [0x00; 33];
+            init[0] = 0xFF;
+            init[32] = 0b1000_0011;
+            bytes.as_mut_ptr().write(init);
+        })
+    };
+    assert_eq!(large.a(), 0xFF);
+    assert_eq!(large.b(), 0);
+    assert_eq!(large.c(), 3);
+    assert!(large.d());
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Table {
+    #[repeat = 4]
+    #[validate(zero)]
+    entries: B2,
+}
+
+fn main() {}
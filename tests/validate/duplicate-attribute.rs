@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    #[skip]
+    #[validate(zero)]
+    #[validate(ones)]
+    __: B7,
+}
+
+fn main() {}
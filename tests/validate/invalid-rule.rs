@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    #[skip]
+    #[validate(one)]
+    __: B7,
+}
+
+fn main() {}
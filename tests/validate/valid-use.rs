@@ -0,0 +1,88 @@
+use modular_bitfield::{
+    error::{
+        InvalidBytes,
+        PaddingBitsSet,
+    },
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    #[skip]
+    #[validate(zero)]
+    __: B3,
+    #[reserved]
+    #[validate(ones)]
+    marker: B2,
+    #[skip]
+    #[validate(any)]
+    __: B2,
+    #[validate(zero)]
+    code: B8,
+}
+
+#[bitfield(filled = false)]
+pub struct Partial {
+    value: B4,
+    #[skip]
+    #[validate(zero)]
+    __: B2,
+}
+
+#[bitfield(bit_order = be)]
+pub struct BigEndian {
+    #[skip]
+    #[validate(ones)]
+    __: B3,
+    value: B5,
+}
+
+fn main() {
+    // Without `#[validate(..)]` rules being violated.
+    let status = Status::from_bytes_checked([0b0011_0000, 0x00]).unwrap();
+    assert!(!status.ready());
+    let status = Status::from_bytes_checked([0b1111_0001, 0x00]).unwrap();
+    assert!(status.ready());
+
+    // `from_bytes` does not validate.
+    let status = Status::from_bytes([0b0000_0010, 0x01]);
+    assert_eq!(status.code(), 0x01);
+
+    // Violated rules name the field.
+    assert_eq!(
+        Status::from_bytes_checked([0b0011_0010, 0x00]).err(),
+        Some(InvalidBytes::InvalidField("__")),
+    );
+    assert_eq!(
+        Status::from_bytes_checked([0b0001_0000, 0x00]).err(),
+        Some(InvalidBytes::InvalidField("marker")),
+    );
+    assert_eq!(
+        Status::from_bytes_checked([0b0011_0000, 0x01]).err(),
+        Some(InvalidBytes::InvalidField("code")),
+    );
+
+    // Padding bits are still checked for bitfields that are not filled.
+    let partial = Partial::from_bytes_checked([0b0000_1111]).unwrap();
+    assert_eq!(partial.value(), 0b1111);
+    assert_eq!(
+        Partial::from_bytes_checked([0b0001_0000]).err(),
+        Some(InvalidBytes::InvalidField("__")),
+    );
+    assert_eq!(
+        Partial::from_bytes_checked([0b0100_0000]).err(),
+        Some(InvalidBytes::PaddingBitsSet(PaddingBitsSet {
+            byte_index: 0,
+            mask: 0b0100_0000,
+        })),
+    );
+
+    // The rules respect the bit order.
+    let big_endian = BigEndian::from_bytes_checked([0b1110_0101]).unwrap();
+    assert_eq!(big_endian.value(), 0b0_0101);
+    assert_eq!(
+        BigEndian::from_bytes_checked([0b0110_0101]).err(),
+        Some(InvalidBytes::InvalidField("__")),
+    );
+}
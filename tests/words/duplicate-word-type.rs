@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(words(u16, u16))]
+pub struct Registers {
+    status: u16,
+    control: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(words(u16, u32))]
+pub struct Registers {
+    status: u16,
+    control: u16,
+    flags: u16,
+}
+
+fn main() {}
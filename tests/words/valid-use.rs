@@ -0,0 +1,50 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(words(u16, u32))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Registers {
+    status: u16,
+    control: u16,
+    counter: u32,
+}
+
+#[bitfield(words(u16), word_endian = "big")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Modbus {
+    address: u8,
+    function: u8,
+    value: u16,
+}
+
+#[bitfield(filled = false, words(u32))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Sparse {
+    level: B12,
+    offset: u16,
+}
+
+const WORDS: [u16; 2] = Modbus::from_bytes([0x11, 0x03, 0x00, 0x00]).into_words_u16();
+
+fn main() {
+    let registers = Registers::new()
+        .with_status(0x1234)
+        .with_control(0x5678)
+        .with_counter(0x9ABC_DEF0);
+    assert_eq!(registers.into_words_u16(), [0x1234, 0x5678, 0xDEF0, 0x9ABC]);
+    assert_eq!(registers.into_words_u32(), [0x5678_1234, 0x9ABC_DEF0]);
+    assert_eq!(Registers::from_words_u16([0x1234, 0x5678, 0xDEF0, 0x9ABC]), registers);
+    assert_eq!(Registers::from_words_u32([0x5678_1234, 0x9ABC_DEF0]), registers);
+
+    let modbus = Modbus::new()
+        .with_address(0x11)
+        .with_function(0x03)
+        .with_value(0xABCD);
+    assert_eq!(modbus.into_words_u16(), [0x1103, 0xCDAB]);
+    assert_eq!(Modbus::from_words_u16([0x1103, 0xCDAB]), modbus);
+    assert_eq!(WORDS, [0x1103, 0x0000]);
+
+    let sparse = Sparse::new().with_level(0xABC).with_offset(0x1234);
+    assert_eq!(sparse.into_words_u32(), [0x0123_4ABC]);
+    assert_eq!(Sparse::from_words_u32([0x0123_4ABC]), Ok(sparse));
+    assert!(Sparse::from_words_u32([0xF123_4ABC]).is_err());
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(word_endian = "big")]
+pub struct Registers {
+    status: u16,
+    control: u16,
+}
+
+fn main() {}